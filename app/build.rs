@@ -0,0 +1,4 @@
+// rebuild when the migrations change, since they are embedded by sqlx::migrate!
+fn main() {
+    println!("cargo:rerun-if-changed=../db/migrations");
+}
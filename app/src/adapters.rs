@@ -1 +1,4 @@
+pub mod events;
+pub mod metrics;
+pub mod persistence;
 pub mod rest;
@@ -0,0 +1,7 @@
+pub mod event_messages;
+pub mod file_sink;
+pub mod http_webhook_sender;
+// the in-process sink lets the tests observe the dispatched events
+#[cfg(test)]
+pub mod in_process_sink;
+pub mod webhook_sink;
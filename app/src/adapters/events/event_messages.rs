@@ -0,0 +1,252 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use svix_ksuid::Ksuid;
+
+use crate::domain::models;
+
+/// An ordered book of the OrderPlaced event
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OrderedBookData {
+    pub book_id: String,
+    pub quantity: i32,
+}
+
+/// The JSON representation of a domain event, the payload of the outbox.
+/// The amounts are decimal strings like the prices of the REST API.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", content = "data")]
+pub enum EventData {
+    BookCreated {
+        book_id: String,
+        title: String,
+        price: String,
+        currency: String,
+        available: i32,
+    },
+    StockChanged {
+        book_id: String,
+        available: i32,
+        delta: i32,
+    },
+    OrderPlaced {
+        order_id: String,
+        customer_id: String,
+        books: Vec<OrderedBookData>,
+        total: String,
+        currency: String,
+    },
+    OrderStatusChanged {
+        order_id: String,
+        from: String,
+        to: String,
+    },
+}
+
+/// The message the sinks publish for an event, e.g.
+/// {"id":1,"occurred_at":"...","type":"StockChanged","data":{"book_id":"...","available":9,"delta":-1}}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EventMessage {
+    /// the id of the event in the outbox, the consumers deduplicate by it
+    pub id: i64,
+    pub occurred_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub data: EventData,
+}
+
+pub fn map_event_to_data(event: &models::DomainEvent) -> EventData {
+    match event {
+        models::DomainEvent::BookCreated {
+            book_id,
+            title,
+            price,
+            available,
+        } => EventData::BookCreated {
+            book_id: book_id.to_string(),
+            title: title.clone(),
+            price: price.amount().to_string(),
+            currency: price.currency().to_string(),
+            available: *available,
+        },
+        models::DomainEvent::StockChanged {
+            book_id,
+            available,
+            delta,
+        } => EventData::StockChanged {
+            book_id: book_id.to_string(),
+            available: *available,
+            delta: *delta,
+        },
+        models::DomainEvent::OrderPlaced {
+            order_id,
+            customer_id,
+            books,
+            total,
+        } => EventData::OrderPlaced {
+            order_id: order_id.to_string(),
+            customer_id: customer_id.to_string(),
+            books: books
+                .iter()
+                .map(|b| OrderedBookData {
+                    book_id: b.book_id.to_string(),
+                    quantity: b.quantity,
+                })
+                .collect(),
+            total: total.amount().to_string(),
+            currency: total.currency().to_string(),
+        },
+        models::DomainEvent::OrderStatusChanged { order_id, from, to } => {
+            EventData::OrderStatusChanged {
+                order_id: order_id.to_string(),
+                from: from.to_string(),
+                to: to.to_string(),
+            }
+        }
+    }
+}
+
+fn parse_id(id: &str) -> Result<Ksuid, String> {
+    Ksuid::from_str(id).map_err(|_| format!("Invalid id: {}", id))
+}
+
+fn parse_money(amount: &str, currency: &str) -> Result<models::Money, String> {
+    models::Money::parse(amount, models::Currency::from_str(currency)?)
+}
+
+pub fn map_data_to_event(data: EventData) -> Result<models::DomainEvent, String> {
+    Ok(match data {
+        EventData::BookCreated {
+            book_id,
+            title,
+            price,
+            currency,
+            available,
+        } => models::DomainEvent::BookCreated {
+            book_id: parse_id(&book_id)?,
+            title,
+            price: parse_money(&price, &currency)?,
+            available,
+        },
+        EventData::StockChanged {
+            book_id,
+            available,
+            delta,
+        } => models::DomainEvent::StockChanged {
+            book_id: parse_id(&book_id)?,
+            available,
+            delta,
+        },
+        EventData::OrderPlaced {
+            order_id,
+            customer_id,
+            books,
+            total,
+            currency,
+        } => models::DomainEvent::OrderPlaced {
+            order_id: parse_id(&order_id)?,
+            customer_id: parse_id(&customer_id)?,
+            books: books
+                .into_iter()
+                .map(|b| {
+                    Ok(models::OrderedBookDomain {
+                        book_id: parse_id(&b.book_id)?,
+                        quantity: b.quantity,
+                    })
+                })
+                .collect::<Result<Vec<models::OrderedBookDomain>, String>>()?,
+            total: parse_money(&total, &currency)?,
+        },
+        EventData::OrderStatusChanged { order_id, from, to } => {
+            models::DomainEvent::OrderStatusChanged {
+                order_id: parse_id(&order_id)?,
+                from: models::OrderStatus::from_str(&from)?,
+                to: models::OrderStatus::from_str(&to)?,
+            }
+        }
+    })
+}
+
+pub fn map_event_to_message(event: &models::OutboxEventDomain) -> EventMessage {
+    EventMessage {
+        id: event.id,
+        occurred_at: event.occurred_at,
+        data: map_event_to_data(&event.event),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use svix_ksuid::KsuidLike;
+
+    fn usd(amount: &str) -> models::Money {
+        models::Money::parse(amount, models::Currency::USD).unwrap()
+    }
+
+    #[test]
+    fn test_map_event_data_round_trip() {
+        // Arrange
+        let events = vec![
+            models::DomainEvent::BookCreated {
+                book_id: Ksuid::new(None, None),
+                title: String::from("Faust"),
+                price: usd("12.50"),
+                available: 10,
+            },
+            models::DomainEvent::StockChanged {
+                book_id: Ksuid::new(None, None),
+                available: 8,
+                delta: -2,
+            },
+            models::DomainEvent::OrderPlaced {
+                order_id: Ksuid::new(None, None),
+                customer_id: Ksuid::new(None, None),
+                books: vec![models::OrderedBookDomain {
+                    book_id: Ksuid::new(None, None),
+                    quantity: 2,
+                }],
+                total: usd("25.00"),
+            },
+            models::DomainEvent::OrderStatusChanged {
+                order_id: Ksuid::new(None, None),
+                from: models::OrderStatus::Placed,
+                to: models::OrderStatus::Shipped,
+            },
+        ];
+
+        // Act & Assert
+        for event in events {
+            assert_eq!(map_data_to_event(map_event_to_data(&event)), Ok(event));
+        }
+    }
+
+    #[test]
+    fn test_map_event_to_message_json() {
+        // Arrange
+        let book_id = Ksuid::new(None, None);
+        let event = models::OutboxEventDomain {
+            id: 7,
+            event: models::DomainEvent::StockChanged {
+                book_id,
+                available: 9,
+                delta: -1,
+            },
+            occurred_at: Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap(),
+        };
+
+        // Act
+        let json = serde_json::to_string(&map_event_to_message(&event)).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            format!(
+                "{{\"id\":7,\"occurred_at\":\"2025-01-01T12:00:00Z\",\"type\":\"StockChanged\",\
+                 \"data\":{{\"book_id\":\"{}\",\"available\":9,\"delta\":-1}}}}",
+                book_id
+            )
+        );
+    }
+}
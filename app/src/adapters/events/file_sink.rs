@@ -0,0 +1,101 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::Mutex;
+
+use super::event_messages::map_event_to_message;
+use crate::domain::{error, models, store};
+
+/// The EventSink writing the events as JSON lines to a file or stdout, e.g. to be shipped by a log
+/// collector. The lines are flushed one by one, so an event is written once publish returns.
+pub struct FileEventSink {
+    writer: Mutex<Box<dyn AsyncWrite + Send + Unpin>>,
+}
+
+impl FileEventSink {
+    pub fn new(writer: Box<dyn AsyncWrite + Send + Unpin>) -> Arc<Self> {
+        Arc::new(FileEventSink {
+            writer: Mutex::new(writer),
+        })
+    }
+
+    /// Writes the events to stdout
+    pub fn stdout() -> Arc<Self> {
+        Self::new(Box::new(tokio::io::stdout()))
+    }
+
+    /// Appends the events to the file, the file is created if it does not exist
+    pub async fn open(path: &Path) -> std::io::Result<Arc<Self>> {
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await?;
+        Ok(Self::new(Box::new(file)))
+    }
+}
+
+fn publish_failure(
+    event: &models::OutboxEventDomain,
+    e: impl std::error::Error + Send + Sync + 'static,
+) -> error::DomainError {
+    error::DomainError::PublishFailure {
+        message: format!("failed to write event {}", event.id),
+        source: Box::new(e),
+    }
+}
+
+#[async_trait]
+impl store::EventSink for FileEventSink {
+    /// Writes the event as one line of JSON
+    async fn publish(&self, event: &models::OutboxEventDomain) -> Result<(), error::DomainError> {
+        let mut line = serde_json::to_vec(&map_event_to_message(event))
+            .map_err(|e| publish_failure(event, e))?;
+        line.push(b'\n');
+        let mut writer = self.writer.lock().await;
+        writer
+            .write_all(&line)
+            .await
+            .map_err(|e| publish_failure(event, e))?;
+        writer.flush().await.map_err(|e| publish_failure(event, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::store::EventSink;
+    use chrono::Utc;
+    use svix_ksuid::{Ksuid, KsuidLike};
+
+    #[tokio::test]
+    async fn test_publish_appends_json_lines() {
+        // Arrange
+        let path = std::env::temp_dir().join(format!("events-{}.jsonl", Ksuid::new(None, None)));
+        let sink = FileEventSink::open(&path).await.unwrap();
+        let event = |id| models::OutboxEventDomain {
+            id,
+            event: models::DomainEvent::StockChanged {
+                book_id: Ksuid::new(None, None),
+                available: 9,
+                delta: -1,
+            },
+            occurred_at: Utc::now(),
+        };
+
+        // Act
+        sink.publish(&event(1)).await.unwrap();
+        sink.publish(&event(2)).await.unwrap();
+
+        // Assert
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("{\"id\":1,"));
+        assert!(lines[1].starts_with("{\"id\":2,"));
+        assert!(lines[1].contains("\"type\":\"StockChanged\""));
+    }
+}
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::domain::{error, models, store};
+
+/// The header with the id of the delivery, the same for every attempt of a delivery
+pub const DELIVERY_HEADER: &str = "X-Webhook-Id";
+/// The header with the type of the delivered event
+pub const EVENT_HEADER: &str = "X-Webhook-Event";
+/// The header with the signature of the payload, t=<unix seconds>,v1=<hex HMAC-SHA256>
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// The hex encoded HMAC-SHA256 of the message with the secret as key
+pub fn signature(secret: &str, message: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(message);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// The value of the signature header, the timestamp is signed together with the payload
+/// as "<timestamp>.<payload>", so a receiver can reject replayed deliveries
+pub fn signature_header(secret: &str, timestamp: i64, payload: &str) -> String {
+    let message = format!("{}.{}", timestamp, payload);
+    format!(
+        "t={},v1={}",
+        timestamp,
+        signature(secret, message.as_bytes())
+    )
+}
+
+/// The WebhookSender posting the payload as JSON with reqwest, signed with the secret of the webhook
+pub struct HttpWebhookSender {
+    client: reqwest::Client,
+}
+
+impl HttpWebhookSender {
+    /// Builds the client, a request exceeding the timeout is a failed attempt
+    pub fn new(timeout: Duration) -> Result<Arc<Self>, reqwest::Error> {
+        let client = reqwest::Client::builder().timeout(timeout).build()?;
+        Ok(Arc::new(HttpWebhookSender { client }))
+    }
+}
+
+#[async_trait]
+impl store::WebhookSender for HttpWebhookSender {
+    /// Posts the payload, the redirects are followed and any status is returned
+    async fn send(
+        &self,
+        webhook: &models::WebhookDomain,
+        delivery: &models::WebhookDeliveryDomain,
+    ) -> Result<u16, error::DomainError> {
+        let timestamp = Utc::now().timestamp();
+        let response = self
+            .client
+            .post(&webhook.url)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(DELIVERY_HEADER, delivery.id.to_string())
+            .header(EVENT_HEADER, &delivery.event_type)
+            .header(
+                SIGNATURE_HEADER,
+                signature_header(&webhook.secret, timestamp, &delivery.payload),
+            )
+            .body(delivery.payload.clone())
+            .send()
+            .await
+            .map_err(|e| error::DomainError::PublishFailure {
+                message: format!(
+                    "failed to post delivery {} to {}: {}",
+                    delivery.id, webhook.url, e
+                ),
+                source: Box::new(e),
+            })?;
+        Ok(response.status().as_u16())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::store::WebhookSender;
+    use axum::Router;
+    use axum::extract::State;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use std::sync::Mutex;
+    use svix_ksuid::{Ksuid, KsuidLike};
+
+    /// The headers and bodies the mock endpoint received
+    type Received = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+    async fn receive(
+        State(received): State<Received>,
+        headers: HeaderMap,
+        body: String,
+    ) -> StatusCode {
+        received.lock().unwrap().push((headers, body));
+        StatusCode::NO_CONTENT
+    }
+
+    /// Starts a mock endpoint on a free local port, returns its url
+    async fn mock_endpoint(received: Received) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let app = Router::new()
+            .route("/hooks", post(receive))
+            .with_state(received);
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{}/hooks", address)
+    }
+
+    fn delivery(webhook: &models::WebhookDomain) -> models::WebhookDeliveryDomain {
+        models::WebhookDeliveryDomain {
+            id: Ksuid::new(None, None),
+            webhook_id: webhook.id,
+            event_id: 7,
+            event_type: String::from("OrderPlaced"),
+            payload: String::from("{\"id\":7,\"type\":\"OrderPlaced\"}"),
+            status: models::DeliveryStatus::Pending,
+            attempts: 0,
+            last_status_code: None,
+            last_error: None,
+            next_attempt_at: Some(Utc::now()),
+            created_at: Utc::now(),
+            delivered_at: None,
+        }
+    }
+
+    #[test]
+    fn test_signature_is_hmac_sha256() {
+        // Act
+        let signature = signature("key", b"The quick brown fox jumps over the lazy dog");
+
+        // Assert
+        assert_eq!(
+            signature,
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_posts_signed_payload() {
+        // Arrange
+        let received = Received::default();
+        let webhook = models::WebhookDomain {
+            id: Ksuid::new(None, None),
+            url: mock_endpoint(received.clone()).await,
+            event_types: vec![String::from("OrderPlaced")],
+            secret: String::from("0123456789abcdef"),
+        };
+        let delivery = delivery(&webhook);
+        let sender = HttpWebhookSender::new(Duration::from_secs(5)).unwrap();
+
+        // Act
+        let status = sender.send(&webhook, &delivery).await.unwrap();
+
+        // Assert
+        assert_eq!(status, 204);
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let (headers, body) = &received[0];
+        assert_eq!(body, &delivery.payload);
+        assert_eq!(headers["content-type"], "application/json");
+        assert_eq!(headers[DELIVERY_HEADER], delivery.id.to_string());
+        assert_eq!(headers[EVENT_HEADER], "OrderPlaced");
+        let header = headers[SIGNATURE_HEADER].to_str().unwrap();
+        let timestamp: i64 = header
+            .strip_prefix("t=")
+            .and_then(|h| h.split(',').next())
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            header,
+            signature_header(&webhook.secret, timestamp, &delivery.payload)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_to_unreachable_endpoint_fails() {
+        // Arrange
+        // binding and dropping the listener leaves a port nobody listens on
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let webhook = models::WebhookDomain {
+            id: Ksuid::new(None, None),
+            url: format!("http://{}/hooks", address),
+            event_types: vec![String::from("OrderPlaced")],
+            secret: String::from("0123456789abcdef"),
+        };
+        let sender = HttpWebhookSender::new(Duration::from_secs(5)).unwrap();
+
+        // Act
+        let result = sender.send(&webhook, &delivery(&webhook)).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::PublishFailure { .. })
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+use crate::domain::{error, models, store};
+
+/// The EventSink broadcasting the events to the subscribers within the process, e.g. the tests.
+/// A subscriber lagging more than the capacity behind misses the oldest events,
+/// events published without subscribers are dropped.
+pub struct InProcessEventSink {
+    sender: broadcast::Sender<models::OutboxEventDomain>,
+}
+
+impl InProcessEventSink {
+    pub fn new(capacity: usize) -> Arc<Self> {
+        let (sender, _) = broadcast::channel(capacity);
+        Arc::new(InProcessEventSink { sender })
+    }
+
+    /// Receives the events published from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<models::OutboxEventDomain> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl store::EventSink for InProcessEventSink {
+    /// Sends the event to the current subscribers
+    async fn publish(&self, event: &models::OutboxEventDomain) -> Result<(), error::DomainError> {
+        // without subscribers nobody is interested in the event
+        let _ = self.sender.send(event.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::persistence::in_memory_repository::InMemoryRepository;
+    use crate::domain::event_dispatcher::EventDispatcher;
+    use crate::domain::store::BookHandler;
+    use chrono::NaiveDate;
+    use svix_ksuid::{Ksuid, KsuidLike};
+
+    #[tokio::test]
+    async fn test_dispatched_events_are_received() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let sink = InProcessEventSink::new(16);
+        let mut receiver = sink.subscribe();
+        let dispatcher = EventDispatcher::new(repository.clone(), vec![sink], 10);
+        let author = repository
+            .create_author(models::AuthorDomain {
+                id: Ksuid::new(None, None),
+                title: None,
+                first_name: String::from("Johann"),
+                second_names: None,
+                last_name: String::from("Goethe"),
+                date_of_birth: NaiveDate::from_ymd_opt(1749, 8, 28).unwrap(),
+                date_of_death: None,
+                version: models::INITIAL_VERSION,
+            })
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(models::NewBookDomain {
+                id: Ksuid::new(None, None),
+                authors: vec![author.id],
+                available: 10,
+                discounts: None,
+                edition: 1,
+                first_release: NaiveDate::from_ymd_opt(1808, 1, 1).unwrap(),
+                genres: None,
+                price: models::Money::parse("12.50", models::Currency::USD).unwrap(),
+                product_type: models::ProductType::Print,
+                release: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                series: None,
+                status: models::BookStatus::Available,
+                title: String::from("Faust"),
+            })
+            .await
+            .unwrap();
+
+        // Act
+        let published = dispatcher.dispatch().await.unwrap();
+
+        // Assert
+        assert_eq!(published, 1);
+        let received = receiver.try_recv().unwrap();
+        assert_eq!(received.event, models::DomainEvent::book_created(&book));
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(dispatcher.dispatch().await.unwrap(), 0);
+    }
+}
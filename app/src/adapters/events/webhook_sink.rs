@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::event_messages::map_event_to_message;
+use crate::domain::{error, models, store};
+
+/// The EventSink enqueueing a delivery of the event for every webhook subscribed to its type.
+/// The payload is the same JSON message the other sinks publish, the WebhookDispatcher posts it.
+pub struct WebhookEventSink {
+    deliveries: Arc<dyn store::WebhookDeliveryHandler + Send + Sync>,
+}
+
+impl WebhookEventSink {
+    pub fn new(deliveries: Arc<dyn store::WebhookDeliveryHandler + Send + Sync>) -> Arc<Self> {
+        Arc::new(WebhookEventSink { deliveries })
+    }
+}
+
+#[async_trait]
+impl store::EventSink for WebhookEventSink {
+    /// Enqueues the deliveries, an event published again is not delivered twice
+    async fn publish(&self, event: &models::OutboxEventDomain) -> Result<(), error::DomainError> {
+        let payload = serde_json::to_string(&map_event_to_message(event)).map_err(|e| {
+            error::DomainError::PublishFailure {
+                message: format!("failed to serialize event {}", event.id),
+                source: Box::new(e),
+            }
+        })?;
+        self.deliveries
+            .enqueue_deliveries(event.id, event.event.event_type(), &payload)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::events::http_webhook_sender::{HttpWebhookSender, signature_header};
+    use crate::adapters::persistence::in_memory_repository::InMemoryRepository;
+    use crate::domain::event_dispatcher::EventDispatcher;
+    use crate::domain::store::{BookHandler, WebhookHandler};
+    use crate::domain::webhook_dispatcher::{RetryPolicy, WebhookDispatcher};
+    use axum::Router;
+    use axum::extract::State;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use chrono::{NaiveDate, Utc};
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use svix_ksuid::{Ksuid, KsuidLike};
+
+    const SECRET: &str = "0123456789abcdef";
+
+    /// The bodies the mock endpoint received with a valid signature
+    type Received = Arc<Mutex<Vec<String>>>;
+
+    async fn receive(
+        State(received): State<Received>,
+        headers: HeaderMap,
+        body: String,
+    ) -> StatusCode {
+        let signature = headers["X-Webhook-Signature"].to_str().unwrap();
+        let timestamp = signature[2..].split(',').next().unwrap().parse().unwrap();
+        if signature != signature_header(SECRET, timestamp, &body) {
+            return StatusCode::UNAUTHORIZED;
+        }
+        received.lock().unwrap().push(body);
+        StatusCode::OK
+    }
+
+    #[tokio::test]
+    async fn test_published_events_are_delivered_to_the_webhooks() {
+        // Arrange
+        let received = Received::default();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+        let app = Router::new()
+            .route("/hooks", post(receive))
+            .with_state(received.clone());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let repository = InMemoryRepository::new();
+        let webhook = repository
+            .create_webhook(models::WebhookDomain {
+                id: Ksuid::new(None, None),
+                url,
+                event_types: vec![String::from("BookCreated")],
+                secret: String::from(SECRET),
+            })
+            .await
+            .unwrap();
+        let event_dispatcher = EventDispatcher::new(
+            repository.clone(),
+            vec![WebhookEventSink::new(repository.clone())],
+            10,
+        );
+        let webhook_dispatcher = WebhookDispatcher::new(
+            repository.clone(),
+            HttpWebhookSender::new(Duration::from_secs(5)).unwrap(),
+            RetryPolicy {
+                max_attempts: 3,
+                initial_backoff: Duration::from_secs(10),
+                max_backoff: Duration::from_secs(60),
+            },
+            Duration::from_secs(10),
+            10,
+        );
+        let author = repository
+            .create_author(models::AuthorDomain {
+                id: Ksuid::new(None, None),
+                title: None,
+                first_name: String::from("Johann"),
+                second_names: None,
+                last_name: String::from("Goethe"),
+                date_of_birth: NaiveDate::from_ymd_opt(1749, 8, 28).unwrap(),
+                date_of_death: None,
+                version: models::INITIAL_VERSION,
+            })
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(models::NewBookDomain {
+                id: Ksuid::new(None, None),
+                authors: vec![author.id],
+                available: 10,
+                discounts: None,
+                edition: 1,
+                first_release: NaiveDate::from_ymd_opt(1808, 1, 1).unwrap(),
+                genres: None,
+                price: models::Money::parse("12.50", models::Currency::USD).unwrap(),
+                product_type: models::ProductType::Print,
+                release: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                series: None,
+                status: models::BookStatus::Available,
+                title: String::from("Faust"),
+            })
+            .await
+            .unwrap();
+
+        // Act
+        event_dispatcher.dispatch().await.unwrap();
+        let attempted = webhook_dispatcher.dispatch().await.unwrap();
+        let log = repository
+            .get_webhook_deliveries(
+                webhook.id,
+                None,
+                models::PageRequest {
+                    cursor: None,
+                    limit: 10,
+                },
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(attempted, 1);
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert!(received[0].contains("\"type\":\"BookCreated\""));
+        assert!(received[0].contains(&book.id.to_string()));
+        assert_eq!(log.items.len(), 1);
+        assert_eq!(log.items[0].status, models::DeliveryStatus::Delivered);
+        assert_eq!(log.items[0].last_status_code, Some(200));
+        assert!(log.items[0].delivered_at.unwrap() <= Utc::now());
+    }
+}
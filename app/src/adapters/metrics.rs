@@ -0,0 +1 @@
+pub mod prometheus_metrics;
@@ -0,0 +1,239 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+    TextEncoder,
+};
+use sqlx::PgPool;
+
+use crate::domain::store;
+
+/// The content type of the text exposition format
+pub const CONTENT_TYPE: &str = prometheus::TEXT_FORMAT;
+
+/// The gauges of the connections of the database pool, set when the metrics are rendered
+struct PoolGauges {
+    pool: PgPool,
+    connections: IntGaugeVec,
+    max_connections: IntGauge,
+}
+
+/// The metrics of the service in a Prometheus registry:
+/// the rate, errors and duration of the requests by route and status,
+/// the connections of the database pool and the business counters of the orders.
+pub struct PrometheusMetrics {
+    registry: Registry,
+    requests: IntCounterVec,
+    request_duration: HistogramVec,
+    pool: Option<PoolGauges>,
+    orders_placed: IntCounter,
+    discount_redemptions: IntCounterVec,
+    stock_outs: IntCounter,
+}
+
+impl PrometheusMetrics {
+    /// Registers the metrics, the pool gauges only for the postgres store
+    pub fn new(pool: Option<PgPool>) -> Arc<Self> {
+        let registry = Registry::new();
+        let requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "The handled HTTP requests"),
+            &["method", "route", "status"],
+        )
+        .expect("valid metric");
+        let request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "The duration of the handled HTTP requests",
+            ),
+            &["method", "route", "status"],
+        )
+        .expect("valid metric");
+        let orders_placed =
+            IntCounter::new("orders_placed_total", "The placed orders").expect("valid metric");
+        let discount_redemptions = IntCounterVec::new(
+            Opts::new(
+                "discount_redemptions_total",
+                "The discount codes redeemed by placed orders",
+            ),
+            &["code"],
+        )
+        .expect("valid metric");
+        let stock_outs = IntCounter::new(
+            "stock_outs_total",
+            "The orders rejected as a book has insufficient stock",
+        )
+        .expect("valid metric");
+        registry
+            .register(Box::new(requests.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(request_duration.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(orders_placed.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(discount_redemptions.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(stock_outs.clone()))
+            .expect("unique metric");
+
+        let pool = pool.map(|pool| {
+            let connections = IntGaugeVec::new(
+                Opts::new(
+                    "db_pool_connections",
+                    "The open connections of the database pool by state",
+                ),
+                &["state"],
+            )
+            .expect("valid metric");
+            let max_connections = IntGauge::new(
+                "db_pool_max_connections",
+                "The maximum connections of the database pool",
+            )
+            .expect("valid metric");
+            registry
+                .register(Box::new(connections.clone()))
+                .expect("unique metric");
+            registry
+                .register(Box::new(max_connections.clone()))
+                .expect("unique metric");
+            PoolGauges {
+                pool,
+                connections,
+                max_connections,
+            }
+        });
+
+        Arc::new(PrometheusMetrics {
+            registry,
+            requests,
+            request_duration,
+            pool,
+            orders_placed,
+            discount_redemptions,
+            stock_outs,
+        })
+    }
+
+    /// Records a handled request, the route is the path template, e.g. /api/v1/books/{book_id}
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        self.requests.with_label_values(&labels).inc();
+        self.request_duration
+            .with_label_values(&labels)
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Renders the current metrics in the text exposition format
+    pub fn render(&self) -> Result<String, prometheus::Error> {
+        if let Some(gauges) = &self.pool {
+            let size = i64::from(gauges.pool.size());
+            let idle = i64::try_from(gauges.pool.num_idle()).unwrap_or(i64::MAX);
+            gauges.connections.with_label_values(&["idle"]).set(idle);
+            gauges
+                .connections
+                .with_label_values(&["in_use"])
+                .set((size - idle).max(0));
+            gauges
+                .max_connections
+                .set(i64::from(gauges.pool.options().get_max_connections()));
+        }
+        TextEncoder::new().encode_to_string(&self.registry.gather())
+    }
+}
+
+impl store::OrderMetrics for PrometheusMetrics {
+    fn order_placed(&self) {
+        self.orders_placed.inc();
+    }
+
+    fn discount_redeemed(&self, code: &str) {
+        self.discount_redemptions.with_label_values(&[code]).inc();
+    }
+
+    fn stock_out(&self) {
+        self.stock_outs.inc();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::store::OrderMetrics;
+
+    #[test]
+    fn test_render_requests() {
+        // Arrange
+        let metrics = PrometheusMetrics::new(None);
+        metrics.observe_request(
+            "GET",
+            "/api/v1/books/{book_id}",
+            200,
+            Duration::from_millis(20),
+        );
+        metrics.observe_request(
+            "GET",
+            "/api/v1/books/{book_id}",
+            200,
+            Duration::from_millis(40),
+        );
+        metrics.observe_request("POST", "/api/v1/store/orders", 500, Duration::from_secs(2));
+
+        // Act
+        let rendered = metrics.render().unwrap();
+
+        // Assert
+        assert!(rendered.contains(
+            "http_requests_total{method=\"GET\",route=\"/api/v1/books/{book_id}\",status=\"200\"} 2"
+        ));
+        assert!(rendered.contains(
+            "http_requests_total{method=\"POST\",route=\"/api/v1/store/orders\",status=\"500\"} 1"
+        ));
+        assert!(rendered.contains(
+            "http_request_duration_seconds_bucket{method=\"GET\",route=\"/api/v1/books/{book_id}\",status=\"200\",le=\"0.05\"} 2"
+        ));
+        assert!(rendered.contains(
+            "http_request_duration_seconds_bucket{method=\"POST\",route=\"/api/v1/store/orders\",status=\"500\",le=\"1\"} 0"
+        ));
+        assert!(!rendered.contains("db_pool_connections"));
+    }
+
+    #[test]
+    fn test_render_order_metrics() {
+        // Arrange
+        let metrics = PrometheusMetrics::new(None);
+        metrics.order_placed();
+        metrics.order_placed();
+        metrics.discount_redeemed("VIP25");
+        metrics.stock_out();
+
+        // Act
+        let rendered = metrics.render().unwrap();
+
+        // Assert
+        assert!(rendered.contains("orders_placed_total 2"));
+        assert!(rendered.contains("discount_redemptions_total{code=\"VIP25\"} 1"));
+        assert!(rendered.contains("stock_outs_total 1"));
+    }
+
+    #[sqlx::test(migrations = false)]
+    #[ignore = "requires a local Postgres"]
+    async fn test_render_pool_gauges(pool: PgPool) {
+        // Arrange
+        let max_connections = pool.options().get_max_connections();
+        let metrics = PrometheusMetrics::new(Some(pool.clone()));
+        let connection = pool.acquire().await.unwrap();
+
+        // Act
+        let rendered = metrics.render().unwrap();
+
+        // Assert
+        assert!(rendered.contains("db_pool_connections{state=\"in_use\"} 1"));
+        assert!(rendered.contains(&format!("db_pool_max_connections {}", max_connections)));
+        drop(connection);
+    }
+}
@@ -0,0 +1,17 @@
+pub mod addresses;
+pub mod api_key_repository;
+pub mod book_repository;
+pub mod config_api_key_repository;
+pub mod config_tax_rate_repository;
+pub mod customer_repository;
+pub mod db_errors;
+pub mod db_tracing;
+pub mod exchange_rate_repository;
+pub mod file_exchange_rate_repository;
+pub mod health_repository;
+pub mod idempotency_repository;
+pub mod in_memory_repository;
+pub mod migrations;
+pub mod order_repository;
+pub mod outbox_repository;
+pub mod webhook_repository;
@@ -0,0 +1,47 @@
+use sqlx::PgConnection;
+
+use super::db_errors::*;
+use super::db_tracing::traced;
+use crate::domain::{error, models};
+
+/// A row of the addresses table, shared by the orders and the address books of the customers
+#[derive(sqlx::FromRow)]
+pub struct AddressRow {
+    pub id: i32,
+    pub street: String,
+    pub street_number: String,
+    pub zip_code: String,
+    pub city: String,
+    pub province: Option<String>,
+    pub country: String,
+}
+
+pub fn map_address_row(row: AddressRow) -> models::AddressDomain {
+    models::AddressDomain {
+        street: row.street,
+        street_number: row.street_number,
+        zip_code: row.zip_code,
+        city: row.city,
+        province: row.province,
+        country: row.country,
+    }
+}
+
+pub async fn insert_address(
+    conn: &mut PgConnection,
+    address: &models::AddressDomain,
+) -> Result<i32, error::DomainError> {
+    sqlx::query_scalar(
+        "INSERT INTO addresses (street, street_number, zip_code, city, province, country) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING id",
+    )
+    .bind(&address.street)
+    .bind(&address.street_number)
+    .bind(&address.zip_code)
+    .bind(&address.city)
+    .bind(&address.province)
+    .bind(&address.country)
+    .fetch_one(traced(&mut *conn))
+    .await
+    .map_err(|e| map_db_error(String::from("failed to insert address"), e))
+}
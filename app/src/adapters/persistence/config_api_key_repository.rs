@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::{error, models, store};
+
+/// The keystore for the API keys defined in the configuration
+pub struct ConfigApiKeyRepository {
+    keys: HashMap<String, models::ApiKeyDomain>,
+}
+
+impl ConfigApiKeyRepository {
+    pub fn new(keys: Vec<models::ApiKeyDomain>) -> Arc<Self> {
+        Arc::new(ConfigApiKeyRepository {
+            keys: keys
+                .into_iter()
+                .map(|key| (key.key_hash.clone(), key))
+                .collect(),
+        })
+    }
+}
+
+#[async_trait]
+impl store::ApiKeyHandler for ConfigApiKeyRepository {
+    /// Get an API key by the hash of the key
+    async fn get_api_key_by_hash(
+        &self,
+        key_hash: &str,
+    ) -> Result<models::ApiKeyDomain, error::DomainError> {
+        self.keys
+            .get(key_hash)
+            .cloned()
+            .ok_or_else(|| error::DomainError::NotFound {
+                id: key_hash.to_string(),
+                source: Box::new(error::ApiKeyNotFoundError(key_hash.to_string())),
+            })
+    }
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::{error, models, store};
+
+/// The tax rates defined in the configuration
+pub struct ConfigTaxRateRepository {
+    rates: Vec<models::TaxRateDomain>,
+}
+
+impl ConfigTaxRateRepository {
+    pub fn new(rates: Vec<models::TaxRateDomain>) -> Arc<Self> {
+        Arc::new(ConfigTaxRateRepository { rates })
+    }
+}
+
+#[async_trait]
+impl store::TaxRateHandler for ConfigTaxRateRepository {
+    /// Get the rates of all countries orders are taxed for
+    async fn get_tax_rates(&self) -> Result<Vec<models::TaxRateDomain>, error::DomainError> {
+        Ok(self.rates.clone())
+    }
+}
@@ -0,0 +1,194 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::error::DomainError;
+use crate::domain::models::{Currency, Money};
+use rust_decimal::Decimal;
+use svix_ksuid::Ksuid;
+
+// Postgres SQLSTATE codes which indicate that the request violated a constraint
+// of the schema rather than a failure of the database itself.
+// see https://www.postgresql.org/docs/current/errcodes-appendix.html
+const FOREIGN_KEY_VIOLATION: &str = "23503";
+const UNIQUE_VIOLATION: &str = "23505";
+const CHECK_VIOLATION: &str = "23514";
+// raised by the plpgsql triggers in db/migrations
+const RAISE_EXCEPTION: &str = "P0001";
+
+#[derive(Debug)]
+pub struct InvalidRecordError(pub String);
+
+impl fmt::Display for InvalidRecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid record in database: {}", self.0)
+    }
+}
+
+impl Error for InvalidRecordError {}
+
+#[derive(Debug)]
+pub struct ConstraintViolationError(pub String);
+
+impl fmt::Display for ConstraintViolationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Constraint violated: {}", self.0)
+    }
+}
+
+impl Error for ConstraintViolationError {}
+
+#[derive(Debug)]
+pub enum MigrationError {
+    SchemaAhead {
+        versions: Vec<i64>,
+    },
+    ChecksumMismatch {
+        version: i64,
+    },
+    DirtyVersion {
+        version: i64,
+    },
+    Failed {
+        message: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationError::SchemaAhead { versions } => write!(
+                f,
+                "Database schema is ahead of the binary, unknown migrations applied: {:?}",
+                versions
+            ),
+            MigrationError::ChecksumMismatch { version } => write!(
+                f,
+                "Applied migration {} differs from the embedded migration",
+                version
+            ),
+            MigrationError::DirtyVersion { version } => {
+                write!(f, "Migration {} was partially applied and failed", version)
+            }
+            MigrationError::Failed { message, .. } => write!(f, "Migration failed: {}", message),
+        }
+    }
+}
+
+impl Error for MigrationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MigrationError::Failed { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a KSUID read from the database.
+/// A malformed id can only be the result of a corrupted record, hence a fatal failure.
+pub fn parse_ksuid(id: &str) -> Result<Ksuid, DomainError> {
+    Ksuid::from_str(id).map_err(|_| DomainError::FatalDBFailure {
+        message: format!("failed to parse stored id {}", id),
+        source: Box::new(InvalidRecordError(format!("invalid KSUID {}", id))),
+    })
+}
+
+/// Maps a currency code read from the database, an invalid code is a corrupted record
+pub fn parse_currency(currency: &str) -> Result<Currency, DomainError> {
+    Currency::from_str(currency).map_err(|e| DomainError::FatalDBFailure {
+        message: format!("failed to parse stored currency {}", currency),
+        source: Box::new(InvalidRecordError(e)),
+    })
+}
+
+/// Maps an amount and currency code read from the database to Money.
+/// NUMERIC maps losslessly to Decimal, an unknown currency code is a corrupted record.
+pub fn parse_money(amount: Decimal, currency: &str) -> Result<Money, DomainError> {
+    Ok(Money::new(amount, parse_currency(currency)?))
+}
+
+/// Maps a sqlx error to a DomainError.
+/// Constraint and trigger violations are business constraint violations,
+/// everything else is considered to be a fatal DB failure.
+pub fn map_db_error(message: String, err: sqlx::Error) -> DomainError {
+    let is_constraint_violation = match &err {
+        sqlx::Error::Database(db_err) => matches!(
+            db_err.code().as_deref(),
+            Some(FOREIGN_KEY_VIOLATION | UNIQUE_VIOLATION | CHECK_VIOLATION | RAISE_EXCEPTION)
+        ),
+        _ => false,
+    };
+
+    if is_constraint_violation {
+        DomainError::BusinessConstraintViolation {
+            message,
+            source: Box::new(err),
+        }
+    } else {
+        DomainError::FatalDBFailure {
+            message,
+            source: Box::new(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use svix_ksuid::KsuidLike;
+
+    #[test]
+    fn test_parse_ksuid_success() {
+        // Arrange
+        let id = Ksuid::new(None, None);
+
+        // Act
+        let result = parse_ksuid(&id.to_string());
+
+        // Assert
+        assert_eq!(result.unwrap(), id);
+    }
+
+    #[test]
+    fn test_parse_ksuid_invalid() {
+        // Act
+        let result = parse_ksuid("invalid-id");
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::FatalDBFailure { .. })));
+    }
+
+    #[test]
+    fn test_parse_money() {
+        // Act
+        let price = parse_money(Decimal::new(12500, 3), "USD").unwrap();
+        let invalid = parse_money(Decimal::new(12500, 3), "usd");
+
+        // Assert
+        assert_eq!(price.amount().to_string(), "12.50");
+        assert_eq!(price.currency(), Currency::USD);
+        assert!(matches!(invalid, Err(DomainError::FatalDBFailure { .. })));
+    }
+
+    #[test]
+    fn test_map_db_error_row_not_found_is_fatal() {
+        // Act
+        let result = map_db_error(String::from("failed"), sqlx::Error::RowNotFound);
+
+        // Assert
+        match result {
+            DomainError::FatalDBFailure { message, .. } => assert_eq!(message, "failed"),
+            _ => panic!("Expected FatalDBFailure error"),
+        }
+    }
+
+    #[test]
+    fn test_map_db_error_pool_timeout_is_fatal() {
+        // Act
+        let result = map_db_error(String::from("failed"), sqlx::Error::PoolTimedOut);
+
+        // Assert
+        assert!(matches!(result, DomainError::FatalDBFailure { .. }));
+    }
+}
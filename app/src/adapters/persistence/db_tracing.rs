@@ -0,0 +1,188 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::future::BoxFuture;
+use futures_core::stream::{BoxStream, Stream};
+use sqlx::postgres::{PgQueryResult, PgRow, PgStatement, PgTypeInfo};
+use sqlx::{Describe, Either, Execute, Executor, Postgres};
+use tracing::{Instrument, Span, info_span};
+
+/// Runs every statement of the executor in its own client span, a child of the span of the
+/// repository method. The span records the statement as db.query.text, the bound values are
+/// not recorded.
+pub fn traced<'c, X>(executor: X) -> Traced<X>
+where
+    X: Executor<'c, Database = Postgres>,
+{
+    Traced(executor)
+}
+
+#[derive(Debug)]
+pub struct Traced<X>(X);
+
+fn statement_span(sql: &str) -> Span {
+    let operation = sql
+        .split_whitespace()
+        .next()
+        .unwrap_or("postgresql")
+        .to_uppercase();
+    info_span!(
+        "statement",
+        otel.name = %operation,
+        otel.kind = "client",
+        db.system.name = "postgresql",
+        db.operation.name = %operation,
+        db.query.text = sql,
+    )
+}
+
+/// Polls the rows of a statement within its span, the span ends with the stream
+struct InSpan<S> {
+    inner: S,
+    span: Span,
+}
+
+impl<S: Stream + Unpin> Stream for InSpan<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let _entered = this.span.enter();
+        Pin::new(&mut this.inner).poll_next(cx)
+    }
+}
+
+impl<'c, X> Executor<'c> for Traced<X>
+where
+    X: Executor<'c, Database = Postgres>,
+{
+    type Database = Postgres;
+
+    fn fetch_many<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, Result<Either<PgQueryResult, PgRow>, sqlx::Error>>
+    where
+        'c: 'e,
+        E: 'q + Execute<'q, Postgres>,
+    {
+        let span = statement_span(query.sql());
+        let inner = span.in_scope(|| self.0.fetch_many(query));
+        Box::pin(InSpan { inner, span })
+    }
+
+    fn fetch_optional<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<Option<PgRow>, sqlx::Error>>
+    where
+        'c: 'e,
+        E: 'q + Execute<'q, Postgres>,
+    {
+        let span = statement_span(query.sql());
+        Box::pin(self.0.fetch_optional(query).instrument(span))
+    }
+
+    fn prepare_with<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+        parameters: &'e [PgTypeInfo],
+    ) -> BoxFuture<'e, Result<PgStatement<'q>, sqlx::Error>>
+    where
+        'c: 'e,
+    {
+        self.0.prepare_with(sql, parameters)
+    }
+
+    fn describe<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+    ) -> BoxFuture<'e, Result<Describe<Postgres>, sqlx::Error>>
+    where
+        'c: 'e,
+    {
+        self.0.describe(sql)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::PgPool;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id};
+    use tracing_subscriber::Layer;
+    use tracing_subscriber::layer::{Context as LayerContext, SubscriberExt};
+
+    /// Collects the statements of the statement spans
+    #[derive(Clone, Default)]
+    struct Statements(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Statements {
+        fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) {}
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "db.query.text" {
+                self.0.lock().unwrap().push(String::from(value));
+            }
+        }
+    }
+
+    impl<S: tracing::Subscriber> Layer<S> for Statements {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: LayerContext<'_, S>) {
+            attrs.record(&mut self.clone());
+        }
+    }
+
+    #[test]
+    fn test_statement_span_records_the_statement() {
+        // Arrange
+        let statements = Statements::default();
+        let _guard = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(statements.clone()),
+        );
+
+        // Act
+        let span = statement_span("select id FROM books WHERE id = $1");
+
+        // Assert
+        assert_eq!(span.metadata().unwrap().name(), "statement");
+        assert_eq!(
+            *statements.0.lock().unwrap(),
+            vec![String::from("select id FROM books WHERE id = $1")]
+        );
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "requires a local Postgres"]
+    async fn test_every_statement_gets_a_span(pool: PgPool) {
+        // Arrange
+        let statements = Statements::default();
+        let _guard = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(statements.clone()),
+        );
+
+        // Act
+        let one: i32 = sqlx::query_scalar("SELECT 1")
+            .fetch_one(traced(&pool))
+            .await
+            .unwrap();
+        let mut tx = pool.begin().await.unwrap();
+        let rows = sqlx::query("SELECT id FROM genres")
+            .fetch_all(traced(&mut *tx))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(one, 1);
+        assert!(rows.is_empty());
+        assert_eq!(
+            *statements.0.lock().unwrap(),
+            vec![
+                String::from("SELECT 1"),
+                String::from("SELECT id FROM genres")
+            ]
+        );
+    }
+}
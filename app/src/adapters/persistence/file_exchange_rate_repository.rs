@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::{error, models, store};
+
+/// The exchange rates loaded from the rates file on startup
+pub struct FileExchangeRateRepository {
+    rates: Vec<models::ExchangeRateDomain>,
+}
+
+impl FileExchangeRateRepository {
+    pub fn new(rates: Vec<models::ExchangeRateDomain>) -> Arc<Self> {
+        Arc::new(FileExchangeRateRepository { rates })
+    }
+}
+
+#[async_trait]
+impl store::ExchangeRateHandler for FileExchangeRateRepository {
+    /// Get the rates of all currencies the prices can be converted to
+    async fn get_exchange_rates(
+        &self,
+    ) -> Result<Vec<models::ExchangeRateDomain>, error::DomainError> {
+        Ok(self.rates.clone())
+    }
+}
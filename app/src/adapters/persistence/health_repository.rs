@@ -0,0 +1,126 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::instrument;
+
+use super::db_tracing::traced;
+use super::migrations::{self, SchemaStatus};
+use crate::domain::{models, store};
+
+/// The time a health check may take before the dependency is reported as down
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The postgres implementation of the HealthHandler.
+/// Checks that the pool can reach the database and the schema has no pending migrations.
+pub struct HealthRepository {
+    pool: PgPool,
+}
+
+impl HealthRepository {
+    pub fn new(pool: PgPool) -> Arc<Self> {
+        Arc::new(HealthRepository { pool })
+    }
+
+    async fn check_database(&self) -> models::HealthCheckDomain {
+        let ping = sqlx::query("SELECT 1").execute(traced(&self.pool));
+        match tokio::time::timeout(CHECK_TIMEOUT, ping).await {
+            Ok(Ok(_)) => models::HealthCheckDomain::up("database"),
+            Ok(Err(e)) => models::HealthCheckDomain::down("database", e.to_string()),
+            Err(_) => models::HealthCheckDomain::down(
+                "database",
+                format!("no response within {:?}", CHECK_TIMEOUT),
+            ),
+        }
+    }
+
+    async fn check_migrations(&self) -> models::HealthCheckDomain {
+        match tokio::time::timeout(CHECK_TIMEOUT, migrations::schema_status(&self.pool)).await {
+            Ok(Ok(SchemaStatus::UpToDate)) => models::HealthCheckDomain::up("migrations"),
+            Ok(Ok(SchemaStatus::Pending { versions })) => models::HealthCheckDomain::down(
+                "migrations",
+                format!("pending migrations {:?}", versions),
+            ),
+            Ok(Err(e)) => models::HealthCheckDomain::down("migrations", e.to_string()),
+            Err(_) => models::HealthCheckDomain::down(
+                "migrations",
+                format!("no response within {:?}", CHECK_TIMEOUT),
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl store::HealthHandler for HealthRepository {
+    /// Check the dependencies, a failing check is reported as down and never as error
+    #[instrument(
+        name = "HealthRepository::check_health",
+        skip_all,
+        fields(otel.kind = "client", db.system.name = "postgresql")
+    )]
+    async fn check_health(&self) -> Vec<models::HealthCheckDomain> {
+        let database = self.check_database().await;
+        if database.status == models::HealthStatus::Down {
+            return vec![database];
+        }
+        vec![database, self.check_migrations().await]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::store::HealthHandler;
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "requires a local Postgres"]
+    async fn test_check_health_migrated(pool: PgPool) {
+        // Arrange
+        let repository = HealthRepository::new(pool);
+
+        // Act
+        let checks = repository.check_health().await;
+
+        // Assert
+        assert_eq!(
+            checks,
+            vec![
+                models::HealthCheckDomain::up("database"),
+                models::HealthCheckDomain::up("migrations")
+            ]
+        );
+    }
+
+    #[sqlx::test(migrations = false)]
+    #[ignore = "requires a local Postgres"]
+    async fn test_check_health_with_pending_migrations(pool: PgPool) {
+        // Arrange
+        let repository = HealthRepository::new(pool);
+
+        // Act
+        let checks = repository.check_health().await;
+
+        // Assert
+        assert_eq!(checks[0].status, models::HealthStatus::Up);
+        assert_eq!(checks[1].status, models::HealthStatus::Down);
+        assert!(checks[1].detail.as_ref().unwrap().contains("pending"));
+    }
+
+    #[tokio::test]
+    async fn test_check_health_unreachable_database() {
+        // Arrange
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(200))
+            .connect_lazy("postgres://postgres@127.0.0.1:1/postgres")
+            .unwrap();
+        let repository = HealthRepository::new(pool);
+
+        // Act
+        let checks = repository.check_health().await;
+
+        // Assert
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].status, models::HealthStatus::Down);
+    }
+}
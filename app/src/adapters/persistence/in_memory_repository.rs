@@ -0,0 +1,2617 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::sync::atomic::{self, AtomicI64};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use dashmap::DashMap;
+use svix_ksuid::{Ksuid, KsuidLike};
+
+use super::db_errors::ConstraintViolationError;
+use crate::domain::{error, models, store};
+
+/// A book with the ids of its relations, the equivalent of a row in the books table
+/// together with its rows in the join tables
+#[derive(Debug, Clone)]
+struct BookRecord {
+    id: Ksuid,
+    title: String,
+    release: NaiveDate,
+    first_release: NaiveDate,
+    series: Option<String>,
+    edition: i32,
+    price: models::Money,
+    product_type: models::ProductType,
+    available: i32,
+    status: models::BookStatus,
+    authors: Vec<Ksuid>,
+    genres: Vec<Ksuid>,
+    discounts: Vec<Ksuid>,
+    version: i32,
+}
+
+/// Checks all filters of the query, same as the WHERE clause of the BookRepository search
+fn matches_query(book: &BookRecord, query: &models::BookQuery) -> bool {
+    (query.authors.is_empty() || book.authors.iter().any(|id| query.authors.contains(id)))
+        && (query.genres.is_empty() || book.genres.iter().any(|id| query.genres.contains(id)))
+        && (query.status.is_empty() || query.status.contains(&book.status))
+        && query
+            .series
+            .as_ref()
+            .is_none_or(|series| book.series.as_ref() == Some(series))
+        && query
+            .min_price
+            .is_none_or(|price| book.price.amount() >= price)
+        && query
+            .max_price
+            .is_none_or(|price| book.price.amount() <= price)
+        && query.released_from.is_none_or(|date| book.release >= date)
+        && query.released_to.is_none_or(|date| book.release <= date)
+        && query
+            .title
+            .as_ref()
+            .is_none_or(|title| book.title.to_lowercase().contains(&title.to_lowercase()))
+}
+
+/// Derives the book status from the available books, same as the update_book_status trigger
+fn derive_book_status(available: i32) -> models::BookStatus {
+    if available <= 0 {
+        models::BookStatus::OutOfStock
+    } else if available <= 5 {
+        models::BookStatus::ReOrdered
+    } else {
+        models::BookStatus::Available
+    }
+}
+
+fn constraint_violation(message: String) -> error::DomainError {
+    error::DomainError::BusinessConstraintViolation {
+        message: message.clone(),
+        source: Box::new(ConstraintViolationError(message)),
+    }
+}
+
+/// Removes duplicated ids while keeping the order, same as ON CONFLICT DO NOTHING for the join tables
+fn dedup_ids(ids: &[Ksuid]) -> Vec<Ksuid> {
+    let mut seen = HashSet::new();
+    ids.iter().copied().filter(|id| seen.insert(*id)).collect()
+}
+
+/// The ordered books in the order their stock is changed, same as the locking order of the OrderRepository
+fn sorted_books(books: &[models::OrderedBookDomain]) -> Vec<models::OrderedBookDomain> {
+    let mut books = books.to_vec();
+    books.sort_by_key(|b| b.book_id.to_string());
+    books
+}
+
+fn non_empty<T>(items: Vec<T>) -> Option<Vec<T>> {
+    if items.is_empty() { None } else { Some(items) }
+}
+
+/// Checks the constraints of the books table
+fn check_book_record(book: &BookRecord) -> Result<(), error::DomainError> {
+    if book.edition <= 0 {
+        return Err(constraint_violation(format!(
+            "edition of book {} must be positive",
+            book.id
+        )));
+    }
+    if book.price.is_negative() {
+        return Err(constraint_violation(format!(
+            "price of book {} must not be negative",
+            book.id
+        )));
+    }
+    if book.available < 0 {
+        return Err(constraint_violation(format!(
+            "available books of book {} must not be negative",
+            book.id
+        )));
+    }
+    if book.first_release > book.release {
+        return Err(constraint_violation(format!(
+            "first release of book {} must not be after the release",
+            book.id
+        )));
+    }
+    Ok(())
+}
+
+/// The in-memory implementation of the BookHandler, OrderHandler, CustomerHandler, IdempotencyHandler,
+/// OutboxHandler, WebhookHandler and WebhookDeliveryHandler.
+/// Enforces the same constraints and triggers as the postgres schema, so the service can run
+/// without a database, e.g. for frontend development and the handler tests.
+/// The state is lost when the process stops.
+pub struct InMemoryRepository {
+    authors: DashMap<Ksuid, models::AuthorDomain>,
+    books: DashMap<Ksuid, BookRecord>,
+    customers: DashMap<Ksuid, models::CustomerDomain>,
+    discount_codes: DashMap<Ksuid, models::DiscountCodeDomain>,
+    genres: DashMap<Ksuid, models::GenereDomain>,
+    idempotency_keys: DashMap<(String, String), models::IdempotencyRecordDomain>,
+    orders: DashMap<Ksuid, models::OrderDomain>,
+    order_events: DashMap<Ksuid, Vec<models::OrderStatusEventDomain>>,
+    outbox: DashMap<i64, models::OutboxEventDomain>,
+    outbox_sequence: AtomicI64,
+    webhooks: DashMap<Ksuid, models::WebhookDomain>,
+    webhook_deliveries: DashMap<Ksuid, models::WebhookDeliveryDomain>,
+    // serializes the writes, since the constraints span multiple maps
+    write_lock: Mutex<()>,
+}
+
+impl InMemoryRepository {
+    pub fn new() -> Arc<Self> {
+        Arc::new(InMemoryRepository {
+            authors: DashMap::new(),
+            books: DashMap::new(),
+            customers: DashMap::new(),
+            discount_codes: DashMap::new(),
+            genres: DashMap::new(),
+            idempotency_keys: DashMap::new(),
+            orders: DashMap::new(),
+            order_events: DashMap::new(),
+            outbox: DashMap::new(),
+            outbox_sequence: AtomicI64::new(0),
+            webhooks: DashMap::new(),
+            webhook_deliveries: DashMap::new(),
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ()> {
+        // the guarded state lives in the maps, a panic while holding the lock can not corrupt it
+        self.write_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Appends the event to the outbox, the writes record their events while holding the write lock
+    fn record_event(&self, event: models::DomainEvent) {
+        let id = self.outbox_sequence.fetch_add(1, atomic::Ordering::SeqCst) + 1;
+        self.outbox.insert(
+            id,
+            models::OutboxEventDomain {
+                id,
+                event,
+                occurred_at: Utc::now(),
+            },
+        );
+    }
+
+    /// Changes the available books by the delta and records the stock change
+    fn change_stock(&self, book_id: Ksuid, delta: i32) {
+        if let Some(mut stock) = self.books.get_mut(&book_id) {
+            stock.available += delta;
+            stock.status = derive_book_status(stock.available);
+            stock.version += 1;
+            self.record_event(models::DomainEvent::StockChanged {
+                book_id,
+                available: stock.available,
+                delta,
+            });
+        }
+    }
+
+    /// Moves the order to the given status, a canceled order returns its books to the stock.
+    /// With a version the transition is rejected if the order changed since that version.
+    /// An order which is already in the status and keeps its shipping date is left untouched.
+    fn transition_order(
+        &self,
+        id: Ksuid,
+        status: models::OrderStatus,
+        shipping_date: Option<NaiveDate>,
+        version: Option<Vec<i32>>,
+        change: models::OrderChangeDomain,
+    ) -> Result<models::OrderDomain, error::DomainError> {
+        let _guard = self.lock();
+        let mut order = self
+            .orders
+            .get_mut(&id)
+            .ok_or_else(|| error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::OrderNotFoundError(id.to_string())),
+            })?;
+        models::check_version(id, order.version, version.as_deref())?;
+        let loaded = order.clone();
+        let previous = order.status.clone();
+        let now = Utc::now();
+        order.transition_to(status, now)?;
+        if let Some(shipping_date) = shipping_date {
+            order.shipping_date = shipping_date;
+        }
+        if *order == loaded {
+            return Ok(loaded);
+        }
+        order.version += 1;
+        let status_changed = previous != order.status;
+        if status_changed {
+            self.record_event(models::DomainEvent::OrderStatusChanged {
+                order_id: id,
+                from: previous.clone(),
+                to: order.status.clone(),
+            });
+        }
+        if status_changed && order.status == models::OrderStatus::Canceled {
+            for book in sorted_books(&order.books) {
+                self.change_stock(book.book_id, book.quantity);
+            }
+        }
+        if status_changed {
+            self.order_events
+                .entry(id)
+                .or_default()
+                .push(models::OrderStatusEventDomain {
+                    order_id: id,
+                    from: Some(previous),
+                    to: order.status.clone(),
+                    actor: change.actor,
+                    reason: change.reason,
+                    occurred_at: now,
+                });
+        }
+        Ok(order.clone())
+    }
+
+    /// Resolves the relations of the book, ordered the same way as by the postgres repository
+    fn to_book_domain(&self, book: BookRecord) -> models::BookDomain {
+        let mut authors: Vec<models::AuthorDomain> = book
+            .authors
+            .iter()
+            .filter_map(|id| self.authors.get(id).map(|a| a.clone()))
+            .collect();
+        authors.sort_by(|a, b| (&a.last_name, &a.first_name).cmp(&(&b.last_name, &b.first_name)));
+        let mut genres: Vec<models::GenereDomain> = book
+            .genres
+            .iter()
+            .filter_map(|id| self.genres.get(id).map(|g| g.clone()))
+            .collect();
+        genres.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut discounts: Vec<models::DiscountCodeDomain> = book
+            .discounts
+            .iter()
+            .filter_map(|id| self.discount_codes.get(id).map(|d| d.clone()))
+            .collect();
+        discounts.sort_by(|a, b| a.code.cmp(&b.code));
+
+        models::BookDomain {
+            id: book.id,
+            authors,
+            genres: non_empty(genres),
+            discounts: non_empty(discounts),
+            title: book.title,
+            release: book.release,
+            firs_release: book.first_release,
+            series: book.series,
+            edition: book.edition,
+            price: book.price,
+            product_type: book.product_type,
+            available: book.available,
+            status: book.status,
+            version: book.version,
+        }
+    }
+
+    /// Returns the page of the books matching the query, same as the keyset pagination of the BookRepository
+    fn find_books(
+        &self,
+        query: &models::BookQuery,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError> {
+        let directed = |ordering: Ordering| match query.order {
+            models::SortOrder::Asc => ordering,
+            models::SortOrder::Desc => ordering.reverse(),
+        };
+        let compare = |a: &BookRecord, b: &BookRecord| {
+            let ordering = match query.sort {
+                Some(models::BookSortField::Price) => a.price.amount().cmp(&b.price.amount()),
+                Some(models::BookSortField::Release) => a.release.cmp(&b.release),
+                Some(models::BookSortField::Title) => a.title.cmp(&b.title),
+                None => Ordering::Equal,
+            };
+            directed(ordering.then(a.id.cmp(&b.id)))
+        };
+        let cursor = page
+            .cursor
+            .map(|id| (id, self.books.get(&id).map(|b| b.value().clone())));
+        // the position of a deleted cursor book in the sort order is lost
+        if let (Some((id, None)), Some(_)) = (&cursor, query.sort) {
+            return Err(models::PageRequest::stale_cursor(*id));
+        }
+
+        let mut books: Vec<BookRecord> = self
+            .books
+            .iter()
+            .filter(|b| matches_query(b.value(), query))
+            .map(|b| b.value().clone())
+            .filter(|b| match &cursor {
+                None => true,
+                Some((_, Some(cursor))) => compare(b, cursor) == Ordering::Greater,
+                // without sort field only the ids are compared, a deleted cursor book still works
+                Some((id, None)) => directed(b.id.cmp(id)) == Ordering::Greater,
+            })
+            .collect();
+        books.sort_by(compare);
+        let books = books
+            .into_iter()
+            .take(page.limit as usize + 1)
+            .map(|b| self.to_book_domain(b))
+            .collect();
+        Ok(models::PageDomain::from_items(books, page.limit, |b| b.id))
+    }
+
+    /// Ranks the book for the words of a full text search with the weights of the BookRepository,
+    /// a word found in the title counts 1.0, in the series 0.4 and in the author names 0.2.
+    /// The words match as substrings, misspelled words are only matched by the BookRepository.
+    fn rank_book(&self, book: &BookRecord, words: &[String]) -> Option<f32> {
+        let title = book.title.to_lowercase();
+        let series = book.series.as_deref().unwrap_or_default().to_lowercase();
+        let authors = book
+            .authors
+            .iter()
+            .filter_map(|id| self.authors.get(id))
+            .map(|a| {
+                let second_names = a.second_names.as_deref().unwrap_or_default().join(" ");
+                format!("{} {} {}", a.first_name, second_names, a.last_name)
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+            .to_lowercase();
+        words
+            .iter()
+            .map(|word| {
+                let rank: f32 = [(&title, 1.0), (&series, 0.4), (&authors, 0.2)]
+                    .iter()
+                    .filter(|(text, _)| text.contains(word.as_str()))
+                    .map(|(_, weight)| weight)
+                    .sum();
+                (rank > 0.0).then_some(rank)
+            })
+            .sum()
+    }
+
+    /// Checks the foreign keys of the join tables
+    fn check_book_relations(&self, book: &BookRecord) -> Result<(), error::DomainError> {
+        if let Some(id) = book
+            .authors
+            .iter()
+            .find(|id| !self.authors.contains_key(id))
+        {
+            return Err(constraint_violation(format!(
+                "author {} does not exist",
+                id
+            )));
+        }
+        if let Some(id) = book.genres.iter().find(|id| !self.genres.contains_key(id)) {
+            return Err(constraint_violation(format!("genre {} does not exist", id)));
+        }
+        if let Some(id) = book
+            .discounts
+            .iter()
+            .find(|id| !self.discount_codes.contains_key(id))
+        {
+            return Err(constraint_violation(format!(
+                "discount code {} does not exist",
+                id
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl store::BookHandler for InMemoryRepository {
+    /// Create a new book in the store
+    async fn create_book(
+        &self,
+        book: models::NewBookDomain,
+    ) -> Result<models::BookDomain, error::DomainError> {
+        let _guard = self.lock();
+        if self.books.contains_key(&book.id) {
+            return Err(constraint_violation(format!(
+                "book {} already exists",
+                book.id
+            )));
+        }
+        let record = BookRecord {
+            id: book.id,
+            title: book.title,
+            release: book.release,
+            first_release: book.first_release,
+            series: book.series,
+            edition: book.edition,
+            price: book.price,
+            product_type: book.product_type,
+            available: book.available,
+            status: derive_book_status(book.available),
+            authors: dedup_ids(&book.authors),
+            genres: dedup_ids(&book.genres.unwrap_or_default()),
+            discounts: dedup_ids(&book.discounts.unwrap_or_default()),
+            version: models::INITIAL_VERSION,
+        };
+        check_book_record(&record)?;
+        self.check_book_relations(&record)?;
+        self.books.insert(record.id, record.clone());
+        let created = self.to_book_domain(record);
+        self.record_event(models::DomainEvent::book_created(&created));
+        Ok(created)
+    }
+
+    /// Delete an existing book in the store
+    async fn delete_book_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
+        let _guard = self.lock();
+        if !self.books.contains_key(&id) {
+            return Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::BookNotFoundError(id.to_string())),
+            });
+        }
+        if self
+            .orders
+            .iter()
+            .any(|o| o.books.iter().any(|b| b.book_id == id))
+        {
+            return Err(constraint_violation(format!(
+                "Cannot delete book {} that is part of order history",
+                id
+            )));
+        }
+        self.books.remove(&id);
+        Ok(())
+    }
+
+    /// Get an existing book by id
+    async fn get_book_by_id(&self, id: Ksuid) -> Result<models::BookDomain, error::DomainError> {
+        let book = self.books.get(&id).map(|b| b.clone());
+        match book {
+            Some(book) => Ok(self.to_book_domain(book)),
+            None => Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::BookNotFoundError(id.to_string())),
+            }),
+        }
+    }
+
+    /// get a page of the books in the list of authors
+    async fn get_books_by_authors(
+        &self,
+        authors: Vec<Ksuid>,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError> {
+        let query = models::BookQuery {
+            authors,
+            ..Default::default()
+        };
+        self.find_books(&query, page)
+    }
+
+    /// Get a page of the books matching at least one genre
+    async fn get_books_by_generes(
+        &self,
+        genres: Vec<Ksuid>,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError> {
+        let query = models::BookQuery {
+            genres,
+            ..Default::default()
+        };
+        self.find_books(&query, page)
+    }
+
+    /// Get a page of the books matching one of the status given in the list
+    async fn get_books_by_status(
+        &self,
+        status: Vec<models::BookStatus>,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError> {
+        let query = models::BookQuery {
+            status,
+            ..Default::default()
+        };
+        self.find_books(&query, page)
+    }
+
+    /// Query a page of the books matching all filters of the query
+    async fn query_books(
+        &self,
+        query: models::BookQuery,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError> {
+        self.find_books(&query, page)
+    }
+
+    /// Search the titles, series and author names of the books for the words of the text,
+    /// the books are ordered by their rank
+    async fn search_books(
+        &self,
+        text: String,
+        limit: u32,
+    ) -> Result<Vec<models::BookSearchHitDomain>, error::DomainError> {
+        let words = models::search_words(&text);
+        if words.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut ranked: Vec<(f32, BookRecord)> = self
+            .books
+            .iter()
+            .filter_map(|b| {
+                self.rank_book(b.value(), &words)
+                    .map(|rank| (rank, b.value().clone()))
+            })
+            .collect();
+        ranked.sort_by(|(a_rank, a), (b_rank, b)| b_rank.total_cmp(a_rank).then(a.id.cmp(&b.id)));
+        Ok(ranked
+            .into_iter()
+            .take(limit as usize)
+            .map(|(rank, book)| {
+                let snippet =
+                    models::highlight_snippet(&book.title, book.series.as_deref(), &words);
+                models::BookSearchHitDomain {
+                    book: self.to_book_domain(book),
+                    rank,
+                    snippet,
+                }
+            })
+            .collect())
+    }
+
+    /// Update an existing book in the store
+    async fn update_book(
+        &self,
+        props: models::BookUpdateProps,
+    ) -> Result<models::BookDomain, error::DomainError> {
+        let _guard = self.lock();
+        let mut record = match self.books.get(&props.id) {
+            Some(book) => book.clone(),
+            None => {
+                return Err(error::DomainError::NotFound {
+                    id: props.id.to_string(),
+                    source: Box::new(error::BookNotFoundError(props.id.to_string())),
+                });
+            }
+        };
+        models::check_version(props.id, record.version, props.version.as_deref())?;
+        let previous_available = record.available;
+
+        if let Some(title) = props.title {
+            record.title = title;
+        }
+        if let Some(release) = props.release {
+            record.release = release;
+        }
+        if let Some(series) = props.series {
+            record.series = Some(series);
+        }
+        if let Some(edition) = props.edition {
+            record.edition = edition;
+        }
+        if let Some(price) = props.price {
+            record.price = price;
+        }
+        if let Some(product_type) = props.product_type {
+            record.product_type = product_type;
+        }
+        if let Some(available) = props.available {
+            record.available = available;
+        }
+        // the status is always derived from the available books
+        record.status = derive_book_status(record.available);
+        if let Some(authors) = props.authors {
+            record.authors = dedup_ids(&authors);
+        }
+        if let Some(genres) = props.genres {
+            record.genres = dedup_ids(&genres);
+        }
+        if let Some(discounts) = props.discounts {
+            record.discounts = dedup_ids(&discounts);
+        }
+        record.version += 1;
+
+        check_book_record(&record)?;
+        self.check_book_relations(&record)?;
+        if record.available != previous_available {
+            self.record_event(models::DomainEvent::StockChanged {
+                book_id: record.id,
+                available: record.available,
+                delta: record.available - previous_available,
+            });
+        }
+        self.books.insert(record.id, record.clone());
+        Ok(self.to_book_domain(record))
+    }
+
+    // discount code functions
+    /// Create a new discount code in the store
+    async fn create_discount_code(
+        &self,
+        discount_code: models::DiscountCodeDomain,
+    ) -> Result<models::DiscountCodeDomain, error::DomainError> {
+        let _guard = self.lock();
+        if self.discount_codes.contains_key(&discount_code.id) {
+            return Err(constraint_violation(format!(
+                "discount code {} already exists",
+                discount_code.id
+            )));
+        }
+        if self
+            .discount_codes
+            .iter()
+            .any(|d| d.code == discount_code.code)
+        {
+            return Err(constraint_violation(format!(
+                "discount code {} is not unique",
+                discount_code.code
+            )));
+        }
+        if !(0..=100).contains(&discount_code.percentage_discount) {
+            return Err(constraint_violation(format!(
+                "percentage discount of {} must be between 0 and 100",
+                discount_code.id
+            )));
+        }
+        if discount_code.valid_to < discount_code.valid_from {
+            return Err(constraint_violation(format!(
+                "discount code {} must not expire before it is valid",
+                discount_code.id
+            )));
+        }
+        self.discount_codes
+            .insert(discount_code.id, discount_code.clone());
+        Ok(discount_code)
+    }
+
+    /// Delete an existing discount code
+    /// The discount is removed from all books, same as ON DELETE CASCADE
+    async fn delte_discount_code_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
+        let _guard = self.lock();
+        if self.discount_codes.remove(&id).is_none() {
+            return Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::DiscountCodeNotFoundError(id.to_string())),
+            });
+        }
+        self.books
+            .iter_mut()
+            .for_each(|mut b| b.discounts.retain(|d| *d != id));
+        Ok(())
+    }
+
+    /// Get a discount code by id
+    async fn get_discount_code_by_id(
+        &self,
+        id: Ksuid,
+    ) -> Result<models::DiscountCodeDomain, error::DomainError> {
+        match self.discount_codes.get(&id) {
+            Some(discount_code) => Ok(discount_code.clone()),
+            None => Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::DiscountCodeNotFoundError(id.to_string())),
+            }),
+        }
+    }
+
+    // gerne functions
+    /// Create a new genre in the book store
+    async fn create_genre(
+        &self,
+        genre: models::GenereDomain,
+    ) -> Result<models::GenereDomain, error::DomainError> {
+        let _guard = self.lock();
+        if self.genres.contains_key(&genre.id) {
+            return Err(constraint_violation(format!(
+                "genre {} already exists",
+                genre.id
+            )));
+        }
+        if self.genres.iter().any(|g| g.name == genre.name) {
+            return Err(constraint_violation(format!(
+                "genre {} is not unique",
+                genre.name
+            )));
+        }
+        self.genres.insert(genre.id, genre.clone());
+        Ok(genre)
+    }
+
+    /// Delete an existing genre in the store
+    async fn delte_genre_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
+        let _guard = self.lock();
+        if !self.genres.contains_key(&id) {
+            return Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::GenreNotFoundError(id.to_string())),
+            });
+        }
+        if self.books.iter().any(|b| b.genres.contains(&id)) {
+            return Err(constraint_violation(format!(
+                "Cannot delete genre {} that is assigned to books in the catalog",
+                id
+            )));
+        }
+        self.genres.remove(&id);
+        Ok(())
+    }
+
+    /// Get an existing genre by id
+    async fn get_genre_by_id(&self, id: Ksuid) -> Result<models::GenereDomain, error::DomainError> {
+        match self.genres.get(&id) {
+            Some(genre) => Ok(genre.clone()),
+            None => Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::GenreNotFoundError(id.to_string())),
+            }),
+        }
+    }
+
+    // author functions
+    /// Create a new book author
+    async fn create_author(
+        &self,
+        author: models::AuthorDomain,
+    ) -> Result<models::AuthorDomain, error::DomainError> {
+        let _guard = self.lock();
+        if self.authors.contains_key(&author.id) {
+            return Err(constraint_violation(format!(
+                "author {} already exists",
+                author.id
+            )));
+        }
+        check_author_dates(&author)?;
+        self.authors.insert(author.id, author.clone());
+        Ok(author)
+    }
+
+    /// Delete an existing author
+    async fn delte_author_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
+        let _guard = self.lock();
+        if !self.authors.contains_key(&id) {
+            return Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::AuthorNotFoundError(id.to_string())),
+            });
+        }
+        if self.books.iter().any(|b| b.authors.contains(&id)) {
+            return Err(constraint_violation(format!(
+                "Cannot delete author {} that has books in the catalog",
+                id
+            )));
+        }
+        self.authors.remove(&id);
+        Ok(())
+    }
+
+    /// Get an existing author by id
+    async fn get_author_by_id(
+        &self,
+        id: Ksuid,
+    ) -> Result<models::AuthorDomain, error::DomainError> {
+        match self.authors.get(&id) {
+            Some(author) => Ok(author.clone()),
+            None => Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::AuthorNotFoundError(id.to_string())),
+            }),
+        }
+    }
+
+    /// Update an existing author
+    async fn update_author(
+        &self,
+        props: models::AuthorUpdateProps,
+    ) -> Result<models::AuthorDomain, error::DomainError> {
+        let _guard = self.lock();
+        let mut author = match self.authors.get(&props.id) {
+            Some(author) => author.clone(),
+            None => {
+                return Err(error::DomainError::NotFound {
+                    id: props.id.to_string(),
+                    source: Box::new(error::AuthorNotFoundError(props.id.to_string())),
+                });
+            }
+        };
+        models::check_version(props.id, author.version, props.version.as_deref())?;
+        if let Some(date_of_death) = props.date_of_death {
+            author.date_of_death = Some(date_of_death);
+        }
+        if let Some(last_name) = props.last_name {
+            author.last_name = last_name;
+        }
+        if let Some(second_names) = props.second_names {
+            author.second_names = Some(second_names);
+        }
+        if let Some(title) = props.title {
+            author.title = Some(title);
+        }
+        author.version += 1;
+        check_author_dates(&author)?;
+        self.authors.insert(author.id, author.clone());
+        Ok(author)
+    }
+}
+
+fn check_author_dates(author: &models::AuthorDomain) -> Result<(), error::DomainError> {
+    match author.date_of_death {
+        Some(date_of_death) if date_of_death < author.date_of_birth => Err(constraint_violation(
+            format!("author {} must not die before being born", author.id),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[async_trait]
+impl store::OrderHandler for InMemoryRepository {
+    /// Get an existing order by id
+    async fn get_order_by_id(&self, id: Ksuid) -> Result<models::OrderDomain, error::DomainError> {
+        match self.orders.get(&id) {
+            Some(order) => Ok(order.clone()),
+            None => Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::OrderNotFoundError(id.to_string())),
+            }),
+        }
+    }
+
+    /// Delete an existing order by id
+    /// Orders are part of the order history, hence the order is canceled instead of deleted
+    async fn delete_order_by_id(
+        &self,
+        id: Ksuid,
+        change: models::OrderChangeDomain,
+    ) -> Result<(), error::DomainError> {
+        self.transition_order(id, models::OrderStatus::Canceled, None, None, change)
+            .map(|_| ())
+    }
+
+    /// Get inventory statistics
+    async fn get_inventory(&self) -> Result<models::InventoryDomain, error::DomainError> {
+        let mut inventory = models::InventoryDomain {
+            books_available: 0,
+            books_out_of_stock: 0,
+            books_reordered: 0,
+        };
+        for book in self.books.iter() {
+            match book.status {
+                models::BookStatus::Available => inventory.books_available += 1,
+                models::BookStatus::OutOfStock => inventory.books_out_of_stock += 1,
+                models::BookStatus::ReOrdered => inventory.books_reordered += 1,
+            }
+        }
+        Ok(inventory)
+    }
+
+    /// Create a new book order
+    /// The ordered books are reserved, either all or none of them
+    async fn create_order(
+        &self,
+        order: models::OrderDomain,
+        change: models::OrderChangeDomain,
+    ) -> Result<models::OrderDomain, error::DomainError> {
+        let _guard = self.lock();
+        if self.orders.contains_key(&order.id) {
+            return Err(constraint_violation(format!(
+                "order {} already exists",
+                order.id
+            )));
+        }
+        if !self.customers.contains_key(&order.customer_id) {
+            return Err(constraint_violation(format!(
+                "customer {} does not exist",
+                order.customer_id
+            )));
+        }
+        let mut ordered = HashSet::new();
+        for book in &order.books {
+            if book.quantity <= 0 {
+                return Err(constraint_violation(format!(
+                    "quantity of book {} must be positive",
+                    book.book_id
+                )));
+            }
+            if !ordered.insert(book.book_id) {
+                return Err(constraint_violation(format!(
+                    "book {} is ordered more than once",
+                    book.book_id
+                )));
+            }
+        }
+
+        let sufficient = |b: &models::OrderedBookDomain| {
+            self.books
+                .get(&b.book_id)
+                .is_some_and(|stock| stock.available >= b.quantity)
+        };
+        if let Some(book) = order.books.iter().find(|b| !sufficient(b)) {
+            return Err(error::DomainError::BusinessConstraintViolation {
+                message: format!(
+                    "book {} is unknown or has less than {} available",
+                    book.book_id, book.quantity
+                ),
+                source: Box::new(error::InsufficientStockError(book.book_id.to_string())),
+            });
+        }
+        self.record_event(models::DomainEvent::order_placed(&order));
+        for book in sorted_books(&order.books) {
+            self.change_stock(book.book_id, -book.quantity);
+        }
+
+        self.order_events.insert(
+            order.id,
+            vec![models::OrderStatusEventDomain {
+                order_id: order.id,
+                from: None,
+                to: order.status.clone(),
+                actor: change.actor,
+                reason: change.reason,
+                occurred_at: order.placed_at,
+            }],
+        );
+        self.orders.insert(order.id, order.clone());
+        Ok(order)
+    }
+
+    /// Update an existing order
+    async fn update_order(
+        &self,
+        props: models::OrderUpdateProps,
+        change: models::OrderChangeDomain,
+    ) -> Result<models::OrderDomain, error::DomainError> {
+        self.transition_order(
+            props.id,
+            props.status,
+            Some(props.shipping_date.date_naive()),
+            props.version,
+            change,
+        )
+    }
+
+    /// Get the status changes of an order, oldest first
+    async fn get_order_history(
+        &self,
+        id: Ksuid,
+    ) -> Result<Vec<models::OrderStatusEventDomain>, error::DomainError> {
+        match self.order_events.get(&id) {
+            Some(events) => Ok(events.clone()),
+            None => Err(error::DomainError::NotFound {
+                id: id.to_string(),
+                source: Box::new(error::OrderNotFoundError(id.to_string())),
+            }),
+        }
+    }
+
+    /// Get a page of the orders of a customer matching one of the status given in the list,
+    /// all orders of the customer if the list is empty
+    async fn get_orders_by_customer(
+        &self,
+        customer_id: Ksuid,
+        status: Vec<models::OrderStatus>,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::OrderDomain>, error::DomainError> {
+        let mut orders: Vec<models::OrderDomain> = self
+            .orders
+            .iter()
+            .filter(|o| o.customer_id == customer_id)
+            .filter(|o| status.is_empty() || status.contains(&o.status))
+            .filter(|o| page.cursor.is_none_or(|cursor| o.id > cursor))
+            .map(|o| o.value().clone())
+            .collect();
+        orders.sort_by_key(|o| o.id);
+        orders.truncate(page.limit as usize + 1);
+        Ok(models::PageDomain::from_items(orders, page.limit, |o| o.id))
+    }
+}
+
+/// Checks the constraints of the customers table
+fn check_customer(
+    customers: &DashMap<Ksuid, models::CustomerDomain>,
+    customer: &models::CustomerDomain,
+) -> Result<(), error::DomainError> {
+    if customer.name.is_empty() {
+        return Err(constraint_violation(format!(
+            "name of customer {} must not be empty",
+            customer.id
+        )));
+    }
+    let email = customer.email.to_lowercase();
+    if customers
+        .iter()
+        .any(|c| c.id != customer.id && c.email.to_lowercase() == email)
+    {
+        return Err(constraint_violation(format!(
+            "email {} is already used by another customer",
+            customer.email
+        )));
+    }
+    Ok(())
+}
+
+fn customer_not_found(id: Ksuid) -> error::DomainError {
+    error::DomainError::NotFound {
+        id: id.to_string(),
+        source: Box::new(error::CustomerNotFoundError(id.to_string())),
+    }
+}
+
+#[async_trait]
+impl store::CustomerHandler for InMemoryRepository {
+    /// Create a new customer
+    async fn create_customer(
+        &self,
+        customer: models::CustomerDomain,
+    ) -> Result<models::CustomerDomain, error::DomainError> {
+        let _guard = self.lock();
+        if self.customers.contains_key(&customer.id) {
+            return Err(constraint_violation(format!(
+                "customer {} already exists",
+                customer.id
+            )));
+        }
+        check_customer(&self.customers, &customer)?;
+        self.customers.insert(customer.id, customer.clone());
+        Ok(customer)
+    }
+
+    /// Delete an existing customer without orders
+    async fn delete_customer_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
+        let _guard = self.lock();
+        if !self.customers.contains_key(&id) {
+            return Err(customer_not_found(id));
+        }
+        if self.orders.iter().any(|o| o.customer_id == id) {
+            return Err(constraint_violation(format!(
+                "Cannot delete customer {} that has placed orders",
+                id
+            )));
+        }
+        self.customers.remove(&id);
+        Ok(())
+    }
+
+    /// Get an existing customer by id
+    async fn get_customer_by_id(
+        &self,
+        id: Ksuid,
+    ) -> Result<models::CustomerDomain, error::DomainError> {
+        match self.customers.get(&id) {
+            Some(customer) => Ok(customer.clone()),
+            None => Err(customer_not_found(id)),
+        }
+    }
+
+    /// Update an existing customer
+    async fn update_customer(
+        &self,
+        props: models::CustomerUpdateProps,
+    ) -> Result<models::CustomerDomain, error::DomainError> {
+        let _guard = self.lock();
+        let mut customer = match self.customers.get(&props.id) {
+            Some(customer) => customer.clone(),
+            None => return Err(customer_not_found(props.id)),
+        };
+        if let Some(name) = props.name {
+            customer.name = name;
+        }
+        if let Some(email) = props.email {
+            customer.email = email;
+        }
+        if let Some(addresses) = props.addresses {
+            customer.addresses = addresses;
+        }
+        check_customer(&self.customers, &customer)?;
+        self.customers.insert(customer.id, customer.clone());
+        Ok(customer)
+    }
+}
+
+#[async_trait]
+impl store::IdempotencyHandler for InMemoryRepository {
+    /// Reserve the key of the request if it is unused or expired, expired keys are purged
+    async fn reserve_idempotency_key(
+        &self,
+        request: models::IdempotentRequestDomain,
+    ) -> Result<Option<models::IdempotencyRecordDomain>, error::DomainError> {
+        let now = Utc::now();
+        self.idempotency_keys
+            .retain(|_, record| record.request.expires_at > now);
+        // the entry locks the shard of the key, so concurrent requests can not both reserve it
+        match self
+            .idempotency_keys
+            .entry((request.scope.clone(), request.key.clone()))
+        {
+            dashmap::Entry::Occupied(entry) => Ok(Some(entry.get().clone())),
+            dashmap::Entry::Vacant(entry) => {
+                entry.insert(models::IdempotencyRecordDomain {
+                    request,
+                    response: None,
+                });
+                Ok(None)
+            }
+        }
+    }
+
+    /// Store the response of a reserved key to replay it until the key expires
+    async fn complete_idempotency_key(
+        &self,
+        scope: &str,
+        key: &str,
+        response: models::IdempotentResponseDomain,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), error::DomainError> {
+        if let Some(mut record) = self
+            .idempotency_keys
+            .get_mut(&(String::from(scope), String::from(key)))
+        {
+            record.response = Some(response);
+            record.request.expires_at = expires_at;
+        }
+        Ok(())
+    }
+
+    /// Release a reserved key without response, so the request can be retried
+    async fn release_idempotency_key(
+        &self,
+        scope: &str,
+        key: &str,
+    ) -> Result<(), error::DomainError> {
+        self.idempotency_keys
+            .remove_if(&(String::from(scope), String::from(key)), |_, record| {
+                record.response.is_none()
+            });
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl store::HealthHandler for InMemoryRepository {
+    /// The maps live in the process, the in-memory store has no dependencies to check
+    async fn check_health(&self) -> Vec<models::HealthCheckDomain> {
+        vec![]
+    }
+}
+
+#[async_trait]
+impl store::OutboxHandler for InMemoryRepository {
+    /// Get the oldest unpublished events in the order they were written, at most limit
+    async fn get_unpublished_events(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<models::OutboxEventDomain>, error::DomainError> {
+        let mut events: Vec<models::OutboxEventDomain> =
+            self.outbox.iter().map(|e| e.value().clone()).collect();
+        events.sort_by_key(|e| e.id);
+        events.truncate(limit as usize);
+        Ok(events)
+    }
+
+    /// Mark the events as published, the published events are removed to bound the memory
+    async fn mark_events_published(&self, ids: &[i64]) -> Result<(), error::DomainError> {
+        for id in ids {
+            self.outbox.remove(id);
+        }
+        Ok(())
+    }
+}
+
+/// Checks the constraints of the webhooks table
+fn check_webhook(webhook: &models::WebhookDomain) -> Result<(), error::DomainError> {
+    if !webhook.url.starts_with("http://") && !webhook.url.starts_with("https://") {
+        return Err(constraint_violation(format!(
+            "url of webhook {} must be an http or https url",
+            webhook.id
+        )));
+    }
+    if webhook.event_types.is_empty() {
+        return Err(constraint_violation(format!(
+            "webhook {} must subscribe to an event type",
+            webhook.id
+        )));
+    }
+    if webhook.secret.chars().count() < 16 {
+        return Err(constraint_violation(format!(
+            "secret of webhook {} must have at least 16 characters",
+            webhook.id
+        )));
+    }
+    Ok(())
+}
+
+fn webhook_not_found(id: Ksuid) -> error::DomainError {
+    error::DomainError::NotFound {
+        id: id.to_string(),
+        source: Box::new(error::WebhookNotFoundError(id.to_string())),
+    }
+}
+
+#[async_trait]
+impl store::WebhookHandler for InMemoryRepository {
+    /// Create a new webhook
+    async fn create_webhook(
+        &self,
+        webhook: models::WebhookDomain,
+    ) -> Result<models::WebhookDomain, error::DomainError> {
+        let _guard = self.lock();
+        if self.webhooks.contains_key(&webhook.id) {
+            return Err(constraint_violation(format!(
+                "webhook {} already exists",
+                webhook.id
+            )));
+        }
+        check_webhook(&webhook)?;
+        self.webhooks.insert(webhook.id, webhook.clone());
+        Ok(webhook)
+    }
+
+    /// Delete an existing webhook together with its deliveries, same as ON DELETE CASCADE
+    async fn delete_webhook_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
+        let _guard = self.lock();
+        if self.webhooks.remove(&id).is_none() {
+            return Err(webhook_not_found(id));
+        }
+        self.webhook_deliveries.retain(|_, d| d.webhook_id != id);
+        Ok(())
+    }
+
+    /// Get an existing webhook by id
+    async fn get_webhook_by_id(
+        &self,
+        id: Ksuid,
+    ) -> Result<models::WebhookDomain, error::DomainError> {
+        match self.webhooks.get(&id) {
+            Some(webhook) => Ok(webhook.clone()),
+            None => Err(webhook_not_found(id)),
+        }
+    }
+
+    /// Get all webhooks ordered by id
+    async fn get_webhooks(&self) -> Result<Vec<models::WebhookDomain>, error::DomainError> {
+        let mut webhooks: Vec<models::WebhookDomain> =
+            self.webhooks.iter().map(|w| w.value().clone()).collect();
+        webhooks.sort_by_key(|w| w.id);
+        Ok(webhooks)
+    }
+
+    /// Update an existing webhook
+    async fn update_webhook(
+        &self,
+        props: models::WebhookUpdateProps,
+    ) -> Result<models::WebhookDomain, error::DomainError> {
+        let _guard = self.lock();
+        let mut webhook = match self.webhooks.get(&props.id) {
+            Some(webhook) => webhook.clone(),
+            None => return Err(webhook_not_found(props.id)),
+        };
+        if let Some(url) = props.url {
+            webhook.url = url;
+        }
+        if let Some(event_types) = props.event_types {
+            webhook.event_types = event_types;
+        }
+        if let Some(secret) = props.secret {
+            webhook.secret = secret;
+        }
+        check_webhook(&webhook)?;
+        self.webhooks.insert(webhook.id, webhook.clone());
+        Ok(webhook)
+    }
+
+    /// Get a page of the deliveries of an existing webhook ordered by id, all if status is None
+    async fn get_webhook_deliveries(
+        &self,
+        webhook_id: Ksuid,
+        status: Option<models::DeliveryStatus>,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::WebhookDeliveryDomain>, error::DomainError> {
+        if !self.webhooks.contains_key(&webhook_id) {
+            return Err(webhook_not_found(webhook_id));
+        }
+        let mut deliveries: Vec<models::WebhookDeliveryDomain> = self
+            .webhook_deliveries
+            .iter()
+            .filter(|d| d.webhook_id == webhook_id)
+            .filter(|d| status.is_none_or(|status| d.status == status))
+            .filter(|d| page.cursor.is_none_or(|cursor| d.id > cursor))
+            .map(|d| d.value().clone())
+            .collect();
+        deliveries.sort_by_key(|d| d.id);
+        deliveries.truncate(page.limit as usize + 1);
+        Ok(models::PageDomain::from_items(
+            deliveries,
+            page.limit,
+            |d| d.id,
+        ))
+    }
+}
+
+#[async_trait]
+impl store::WebhookDeliveryHandler for InMemoryRepository {
+    /// Create a pending delivery of the event for every webhook subscribed to the event type,
+    /// skips the webhooks the event is already enqueued for, same as the unique (webhook_id, event_id)
+    async fn enqueue_deliveries(
+        &self,
+        event_id: i64,
+        event_type: &str,
+        payload: &str,
+    ) -> Result<usize, error::DomainError> {
+        let _guard = self.lock();
+        let now = Utc::now();
+        let mut enqueued = 0;
+        for webhook in self.webhooks.iter().filter(|w| w.subscribes_to(event_type)) {
+            if self
+                .webhook_deliveries
+                .iter()
+                .any(|d| d.webhook_id == webhook.id && d.event_id == event_id)
+            {
+                continue;
+            }
+            let id = Ksuid::new(None, None);
+            self.webhook_deliveries.insert(
+                id,
+                models::WebhookDeliveryDomain {
+                    id,
+                    webhook_id: webhook.id,
+                    event_id,
+                    event_type: String::from(event_type),
+                    payload: String::from(payload),
+                    status: models::DeliveryStatus::Pending,
+                    attempts: 0,
+                    last_status_code: None,
+                    last_error: None,
+                    next_attempt_at: Some(now),
+                    created_at: now,
+                    delivered_at: None,
+                },
+            );
+            enqueued += 1;
+        }
+        Ok(enqueued)
+    }
+
+    /// Claim the oldest pending deliveries which are due, at most limit.
+    /// The claimed deliveries are due again after the lease.
+    async fn claim_due_deliveries(
+        &self,
+        limit: u32,
+        lease: chrono::Duration,
+    ) -> Result<Vec<models::ClaimedDeliveryDomain>, error::DomainError> {
+        let _guard = self.lock();
+        let now = Utc::now();
+        let mut due: Vec<Ksuid> = self
+            .webhook_deliveries
+            .iter()
+            .filter(|d| d.status == models::DeliveryStatus::Pending)
+            .filter(|d| d.next_attempt_at.is_some_and(|at| at <= now))
+            .map(|d| d.id)
+            .collect();
+        due.sort();
+        due.truncate(limit as usize);
+        let mut claimed = Vec::with_capacity(due.len());
+        for id in due {
+            if let Some(mut delivery) = self.webhook_deliveries.get_mut(&id)
+                && let Some(webhook) = self.webhooks.get(&delivery.webhook_id)
+            {
+                delivery.next_attempt_at = Some(now + lease);
+                claimed.push(models::ClaimedDeliveryDomain {
+                    webhook: webhook.clone(),
+                    delivery: delivery.clone(),
+                });
+            }
+        }
+        Ok(claimed)
+    }
+
+    /// Record the outcome of an attempt, a delivery of a deleted webhook is gone
+    async fn update_delivery(
+        &self,
+        delivery: &models::WebhookDeliveryDomain,
+    ) -> Result<(), error::DomainError> {
+        let _guard = self.lock();
+        if let Some(mut stored) = self.webhook_deliveries.get_mut(&delivery.id) {
+            *stored = delivery.clone();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::store::{
+        BookHandler, CustomerHandler, IdempotencyHandler, OrderHandler, OutboxHandler,
+        WebhookDeliveryHandler, WebhookHandler,
+    };
+    use chrono::{TimeZone, Utc};
+
+    fn usd(amount: &str) -> models::Money {
+        models::Money::parse(amount, models::Currency::USD).unwrap()
+    }
+
+    fn new_author(last_name: &str) -> models::AuthorDomain {
+        models::AuthorDomain {
+            id: Ksuid::new(None, None),
+            title: None,
+            first_name: String::from("Johann"),
+            second_names: None,
+            last_name: String::from(last_name),
+            date_of_birth: NaiveDate::from_ymd_opt(1749, 8, 28).unwrap(),
+            date_of_death: None,
+            version: 1,
+        }
+    }
+
+    fn new_genre(name: &str) -> models::GenereDomain {
+        models::GenereDomain {
+            id: Ksuid::new(None, None),
+            name: String::from(name),
+        }
+    }
+
+    fn new_book(
+        authors: Vec<Ksuid>,
+        genres: Option<Vec<Ksuid>>,
+        available: i32,
+    ) -> models::NewBookDomain {
+        models::NewBookDomain {
+            id: Ksuid::new(None, None),
+            title: String::from("Faust"),
+            release: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            first_release: NaiveDate::from_ymd_opt(1808, 1, 1).unwrap(),
+            authors,
+            series: None,
+            genres,
+            edition: 1,
+            price: usd("12.50"),
+            product_type: models::ProductType::Print,
+            discounts: None,
+            available,
+            status: models::BookStatus::Available,
+        }
+    }
+
+    fn address() -> models::AddressDomain {
+        models::AddressDomain {
+            street: String::from("Berlinerstrasse"),
+            street_number: String::from("12b"),
+            zip_code: String::from("80331"),
+            city: String::from("Munich"),
+            province: None,
+            country: String::from("Germany"),
+        }
+    }
+
+    fn clerk() -> models::OrderChangeDomain {
+        models::OrderChangeDomain {
+            actor: String::from("clerk"),
+            reason: None,
+        }
+    }
+
+    fn new_customer(email: &str) -> models::CustomerDomain {
+        models::CustomerDomain {
+            id: Ksuid::new(None, None),
+            name: String::from("Johann Wolfgang"),
+            email: String::from(email),
+            addresses: vec![address()],
+        }
+    }
+
+    fn new_order(customer_id: Ksuid, books: Vec<(Ksuid, i32)>) -> models::OrderDomain {
+        models::OrderDomain {
+            id: Ksuid::new(None, None),
+            customer_id,
+            books: books
+                .into_iter()
+                .map(|(book_id, quantity)| models::OrderedBookDomain { book_id, quantity })
+                .collect(),
+            shipping_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            billing_address: address(),
+            shipping_address: address(),
+            status: models::OrderStatus::Placed,
+            placed_at: Utc::now(),
+            shipped_at: None,
+            delivered_at: None,
+            canceled_at: None,
+            discount_codes: vec![],
+            currency: None,
+            pricing: models::OrderPricingDomain::default(),
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn test_derive_book_status() {
+        assert_eq!(derive_book_status(0), models::BookStatus::OutOfStock);
+        assert_eq!(derive_book_status(1), models::BookStatus::ReOrdered);
+        assert_eq!(derive_book_status(5), models::BookStatus::ReOrdered);
+        assert_eq!(derive_book_status(6), models::BookStatus::Available);
+    }
+
+    #[tokio::test]
+    async fn test_create_book_with_relations() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let goethe = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let schiller = repository
+            .create_author(new_author("Schiller"))
+            .await
+            .unwrap();
+        let drama = repository.create_genre(new_genre("Drama")).await.unwrap();
+
+        // Act
+        let book = repository
+            .create_book(new_book(
+                vec![schiller.id, goethe.id, goethe.id],
+                Some(vec![drama.id]),
+                3,
+            ))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(book.authors, vec![goethe, schiller]);
+        assert_eq!(book.genres, Some(vec![drama]));
+        assert_eq!(book.discounts, None);
+        assert_eq!(book.status, models::BookStatus::ReOrdered);
+        assert_eq!(repository.get_book_by_id(book.id).await.unwrap(), book);
+    }
+
+    #[tokio::test]
+    async fn test_create_book_with_unknown_author_rejected() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let book = new_book(vec![Ksuid::new(None, None)], None, 10);
+        let id = book.id;
+
+        // Act
+        let result = repository.create_book(book).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert!(matches!(
+            repository.get_book_by_id(id).await,
+            Err(error::DomainError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_books_by_authors_paginated() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let mut ids = vec![];
+        for _ in 0..3 {
+            let book = repository
+                .create_book(new_book(vec![author.id], None, 10))
+                .await
+                .unwrap();
+            ids.push(book.id);
+        }
+        ids.sort();
+        let page = models::PageRequest {
+            cursor: None,
+            limit: 2,
+        };
+
+        // Act
+        let first = repository
+            .get_books_by_authors(vec![author.id], page.clone())
+            .await
+            .unwrap();
+        let second = repository
+            .get_books_by_authors(
+                vec![author.id],
+                models::PageRequest {
+                    cursor: first.next_cursor,
+                    ..page
+                },
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(first.items.len(), 2);
+        assert_eq!(first.next_cursor, Some(ids[1]));
+        assert_eq!(second.items.len(), 1);
+        assert_eq!(second.items[0].id, ids[2]);
+        assert_eq!(second.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_query_books_with_deleted_cursor_rejected() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        for title in ["Faust", "Werther", "Egmont"] {
+            repository
+                .create_book(models::NewBookDomain {
+                    title: String::from(title),
+                    ..new_book(vec![author.id], None, 10)
+                })
+                .await
+                .unwrap();
+        }
+        let sorted = models::BookQuery {
+            sort: Some(models::BookSortField::Title),
+            ..Default::default()
+        };
+        let first = repository
+            .query_books(
+                sorted.clone(),
+                models::PageRequest {
+                    cursor: None,
+                    limit: 1,
+                },
+            )
+            .await
+            .unwrap();
+        let cursor = first.next_cursor.unwrap();
+        repository.delete_book_by_id(cursor).await.unwrap();
+        let page = models::PageRequest {
+            cursor: Some(cursor),
+            limit: 5,
+        };
+
+        // Act
+        let stale = repository.query_books(sorted, page.clone()).await;
+        let unsorted = repository
+            .query_books(models::BookQuery::default(), page)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            stale,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        // without sort field the ids of the books are compared, a deleted cursor book still works
+        assert!(unsorted.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_query_books_sorted_by_price_desc() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        for (title, price) in [("Faust", "10"), ("Werther", "20"), ("Faust II", "30")] {
+            repository
+                .create_book(models::NewBookDomain {
+                    title: String::from(title),
+                    price: usd(price),
+                    ..new_book(vec![author.id], None, 10)
+                })
+                .await
+                .unwrap();
+        }
+        let query = models::BookQuery {
+            sort: Some(models::BookSortField::Price),
+            order: models::SortOrder::Desc,
+            ..Default::default()
+        };
+
+        // Act
+        let filtered = repository
+            .query_books(
+                models::BookQuery {
+                    min_price: Some(rust_decimal::Decimal::from(15)),
+                    title: Some(String::from("fAuSt")),
+                    ..query.clone()
+                },
+                models::PageRequest::default(),
+            )
+            .await
+            .unwrap();
+        let first = repository
+            .query_books(
+                query.clone(),
+                models::PageRequest {
+                    cursor: None,
+                    limit: 1,
+                },
+            )
+            .await
+            .unwrap();
+        let next = repository
+            .query_books(
+                query,
+                models::PageRequest {
+                    cursor: first.next_cursor,
+                    limit: 5,
+                },
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        let titles: Vec<&str> = filtered.items.iter().map(|b| b.title.as_str()).collect();
+        assert_eq!(titles, vec!["Faust II"]);
+        assert_eq!(first.items[0].title, "Faust II");
+        let prices: Vec<String> = next.items.iter().map(|b| b.price.to_string()).collect();
+        assert_eq!(prices, vec!["20.00 USD", "10.00 USD"]);
+        assert_eq!(next.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_search_books_ranks_title_matches() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let goethe = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let faust = repository
+            .create_book(new_book(vec![goethe.id], None, 10))
+            .await
+            .unwrap();
+        let faust_two = repository
+            .create_book(models::NewBookDomain {
+                title: String::from("Faust II"),
+                series: Some(String::from("Faust")),
+                ..new_book(vec![goethe.id], None, 10)
+            })
+            .await
+            .unwrap();
+        let markup = repository
+            .create_book(models::NewBookDomain {
+                title: String::from("<img src=x onerror=alert(1)> Urworte"),
+                ..new_book(vec![goethe.id], None, 10)
+            })
+            .await
+            .unwrap();
+        repository
+            .create_book(models::NewBookDomain {
+                title: String::from("Werther"),
+                ..new_book(vec![goethe.id], None, 10)
+            })
+            .await
+            .unwrap();
+
+        // Act
+        let hits = repository
+            .search_books(String::from("goethe FAUST"), 10)
+            .await
+            .unwrap();
+        let escaped = repository
+            .search_books(String::from("urworte"), 10)
+            .await
+            .unwrap();
+        let none = repository
+            .search_books(String::from("schiller"), 10)
+            .await
+            .unwrap();
+
+        // Assert
+        let ids: Vec<Ksuid> = hits.iter().map(|h| h.book.id).collect();
+        assert_eq!(ids, vec![faust_two.id, faust.id]);
+        assert_eq!(hits[0].rank, 1.6);
+        assert_eq!(
+            hits[0].snippet,
+            "<mark>Faust</mark> II - <mark>Faust</mark>"
+        );
+        let markup_hit = escaped.iter().find(|h| h.book.id == markup.id).unwrap();
+        assert_eq!(
+            markup_hit.snippet,
+            "&lt;img src=x onerror=alert(1)&gt; <mark>Urworte</mark>"
+        );
+        assert_eq!(hits[1].snippet, "<mark>Faust</mark>");
+        assert!(none.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_book_derives_status() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+
+        // Act
+        let updated = repository
+            .update_book(models::BookUpdateProps {
+                id: book.id,
+                authors: None,
+                available: Some(0),
+                discounts: None,
+                genres: None,
+                edition: Some(2),
+                price: None,
+                product_type: None,
+                release: None,
+                series: None,
+                status: Some(models::BookStatus::Available),
+                title: None,
+                version: None,
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(updated.available, 0);
+        assert_eq!(updated.edition, 2);
+        assert_eq!(updated.status, models::BookStatus::OutOfStock);
+    }
+
+    #[tokio::test]
+    async fn test_update_book_with_stale_version_rejected() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+        let props = models::BookUpdateProps {
+            id: book.id,
+            authors: None,
+            available: Some(5),
+            discounts: None,
+            genres: None,
+            edition: None,
+            price: None,
+            product_type: None,
+            release: None,
+            series: None,
+            status: None,
+            title: None,
+            version: Some(vec![book.version]),
+        };
+
+        // Act
+        let updated = repository.update_book(props.clone()).await.unwrap();
+        let stale = repository.update_book(props).await;
+
+        // Assert
+        assert_eq!(updated.version, book.version + 1);
+        assert!(matches!(
+            stale,
+            Err(error::DomainError::VersionConflict { id, .. }) if id == book.id.to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_referenced_author_and_genre_rejected() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let genre = repository.create_genre(new_genre("Drama")).await.unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], Some(vec![genre.id]), 10))
+            .await
+            .unwrap();
+
+        // Act
+        let author_result = repository.delte_author_by_id(author.id).await;
+        let genre_result = repository.delte_genre_by_id(genre.id).await;
+
+        // Assert
+        assert!(matches!(
+            author_result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert!(matches!(
+            genre_result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        repository.delete_book_by_id(book.id).await.unwrap();
+        repository.delte_author_by_id(author.id).await.unwrap();
+        repository.delte_genre_by_id(genre.id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_delete_ordered_book_rejected() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+        repository
+            .create_order(new_order(customer.id, vec![(book.id, 1)]), clerk())
+            .await
+            .unwrap();
+
+        // Act
+        let result = repository.delete_book_by_id(book.id).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert!(repository.get_book_by_id(book.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_order_reserves_books() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 8))
+            .await
+            .unwrap();
+
+        // Act
+        let order = repository
+            .create_order(new_order(customer.id, vec![(book.id, 3)]), clerk())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(repository.get_order_by_id(order.id).await.unwrap(), order);
+        let stock = repository.get_book_by_id(book.id).await.unwrap();
+        assert_eq!(stock.available, 5);
+        assert_eq!(stock.status, models::BookStatus::ReOrdered);
+        assert_eq!(
+            repository.get_inventory().await.unwrap(),
+            models::InventoryDomain {
+                books_available: 0,
+                books_out_of_stock: 0,
+                books_reordered: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_order_insufficient_stock_reserves_nothing() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let plenty = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+        let scarce = repository
+            .create_book(new_book(vec![author.id], None, 1))
+            .await
+            .unwrap();
+        let order = new_order(customer.id, vec![(plenty.id, 2), (scarce.id, 2)]);
+        let order_id = order.id;
+
+        // Act
+        let result = repository.create_order(order, clerk()).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert_eq!(
+            repository
+                .get_book_by_id(plenty.id)
+                .await
+                .unwrap()
+                .available,
+            10
+        );
+        assert!(matches!(
+            repository.get_order_by_id(order_id).await,
+            Err(error::DomainError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_cancel_order() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+        let order = repository
+            .create_order(new_order(customer.id, vec![(book.id, 6)]), clerk())
+            .await
+            .unwrap();
+
+        // Act
+        let updated = repository
+            .update_order(
+                models::OrderUpdateProps {
+                    id: order.id,
+                    shipping_date: Utc.with_ymd_and_hms(2025, 2, 1, 12, 0, 0).unwrap(),
+                    status: models::OrderStatus::Placed,
+                    version: None,
+                },
+                clerk(),
+            )
+            .await
+            .unwrap();
+        repository
+            .delete_order_by_id(order.id, clerk())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            updated.shipping_date,
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()
+        );
+        assert_eq!(updated.status, models::OrderStatus::Placed);
+        let canceled = repository.get_order_by_id(order.id).await.unwrap();
+        assert_eq!(canceled.status, models::OrderStatus::Canceled);
+        assert!(canceled.canceled_at.is_some());
+        // the canceled order returns its books to the stock
+        let stock = repository.get_book_by_id(book.id).await.unwrap();
+        assert_eq!(stock.available, 10);
+        assert_eq!(stock.status, models::BookStatus::Available);
+    }
+
+    #[tokio::test]
+    async fn test_order_changes_are_written_to_the_outbox() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+
+        // Act
+        let order = repository
+            .create_order(new_order(customer.id, vec![(book.id, 6)]), clerk())
+            .await
+            .unwrap();
+        repository
+            .delete_order_by_id(order.id, clerk())
+            .await
+            .unwrap();
+
+        // Assert
+        let events: Vec<models::DomainEvent> = repository
+            .get_unpublished_events(10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.event)
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                models::DomainEvent::book_created(&book),
+                models::DomainEvent::order_placed(&order),
+                models::DomainEvent::StockChanged {
+                    book_id: book.id,
+                    available: 4,
+                    delta: -6,
+                },
+                models::DomainEvent::OrderStatusChanged {
+                    order_id: order.id,
+                    from: models::OrderStatus::Placed,
+                    to: models::OrderStatus::Canceled,
+                },
+                models::DomainEvent::StockChanged {
+                    book_id: book.id,
+                    available: 10,
+                    delta: 6,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_illegal_order_transitions_rejected() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+        let order = repository
+            .create_order(new_order(customer.id, vec![(book.id, 1)]), clerk())
+            .await
+            .unwrap();
+        let shipped = repository
+            .update_order(
+                models::OrderUpdateProps {
+                    id: order.id,
+                    shipping_date: Utc.with_ymd_and_hms(2025, 2, 1, 12, 0, 0).unwrap(),
+                    status: models::OrderStatus::Shipped,
+                    version: None,
+                },
+                clerk(),
+            )
+            .await
+            .unwrap();
+
+        // Act
+        let cancel = repository.delete_order_by_id(order.id, clerk()).await;
+        let back_to_placed = repository
+            .update_order(
+                models::OrderUpdateProps {
+                    id: order.id,
+                    shipping_date: Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap(),
+                    status: models::OrderStatus::Placed,
+                    version: None,
+                },
+                clerk(),
+            )
+            .await;
+
+        // Assert
+        assert!(shipped.shipped_at.is_some());
+        assert!(matches!(
+            cancel,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert!(matches!(
+            back_to_placed,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        // the rejected transitions change neither the order nor the stock
+        assert_eq!(repository.get_order_by_id(order.id).await.unwrap(), shipped);
+        assert_eq!(
+            repository.get_book_by_id(book.id).await.unwrap().available,
+            9
+        );
+    }
+
+    #[tokio::test]
+    async fn test_order_history() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+        let order = repository
+            .create_order(new_order(customer.id, vec![(book.id, 1)]), clerk())
+            .await
+            .unwrap();
+        repository
+            .delete_order_by_id(
+                order.id,
+                models::OrderChangeDomain {
+                    actor: String::from("support"),
+                    reason: Some(String::from("customer request")),
+                },
+            )
+            .await
+            .unwrap();
+
+        // Act
+        let history = repository.get_order_history(order.id).await.unwrap();
+        let unknown = repository.get_order_history(Ksuid::new(None, None)).await;
+
+        // Assert
+        let transitions: Vec<(Option<models::OrderStatus>, models::OrderStatus, &str)> = history
+            .iter()
+            .map(|e| (e.from.clone(), e.to.clone(), e.actor.as_str()))
+            .collect();
+        assert_eq!(
+            transitions,
+            vec![
+                (None, models::OrderStatus::Placed, "clerk"),
+                (
+                    Some(models::OrderStatus::Placed),
+                    models::OrderStatus::Canceled,
+                    "support"
+                ),
+            ]
+        );
+        assert_eq!(history[0].occurred_at, order.placed_at);
+        assert_eq!(history[1].reason.as_deref(), Some("customer request"));
+        assert!(matches!(unknown, Err(error::DomainError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_order_twice_records_single_event() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+        let order = repository
+            .create_order(new_order(customer.id, vec![(book.id, 1)]), clerk())
+            .await
+            .unwrap();
+        repository
+            .delete_order_by_id(order.id, clerk())
+            .await
+            .unwrap();
+        let canceled = repository.get_order_by_id(order.id).await.unwrap();
+
+        // Act
+        let result = repository.delete_order_by_id(order.id, clerk()).await;
+
+        // Assert
+        assert!(result.is_ok());
+        let history = repository.get_order_history(order.id).await.unwrap();
+        let cancellations = history
+            .iter()
+            .filter(|e| e.to == models::OrderStatus::Canceled)
+            .count();
+        assert_eq!(history.len(), 2);
+        assert_eq!(cancellations, 1);
+        assert_eq!(
+            repository.get_order_by_id(order.id).await.unwrap(),
+            canceled
+        );
+        // the books are returned to the stock only once
+        assert_eq!(
+            repository.get_book_by_id(book.id).await.unwrap().available,
+            10
+        );
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_genre_rejected() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        repository.create_genre(new_genre("Drama")).await.unwrap();
+
+        // Act
+        let result = repository.create_genre(new_genre("Drama")).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_customer_email_rejected() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let other = repository
+            .create_customer(new_customer("other@example.com"))
+            .await
+            .unwrap();
+
+        // Act
+        let duplicate = repository
+            .create_customer(new_customer("Johann@Example.com"))
+            .await;
+        let taken = repository
+            .update_customer(models::CustomerUpdateProps {
+                id: other.id,
+                name: None,
+                email: Some(String::from("JOHANN@example.com")),
+                addresses: None,
+            })
+            .await;
+        let own = repository
+            .update_customer(models::CustomerUpdateProps {
+                id: customer.id,
+                name: Some(String::from("Johann")),
+                email: Some(String::from("Johann@example.com")),
+                addresses: Some(vec![]),
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(matches!(
+            duplicate,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert!(matches!(
+            taken,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert_eq!(own.name, "Johann");
+        assert!(own.addresses.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_customer_orders_reference_existing_customers() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let customer = repository
+            .create_customer(new_customer("johann@example.com"))
+            .await
+            .unwrap();
+        let author = repository
+            .create_author(new_author("Goethe"))
+            .await
+            .unwrap();
+        let book = repository
+            .create_book(new_book(vec![author.id], None, 10))
+            .await
+            .unwrap();
+        let order = repository
+            .create_order(new_order(customer.id, vec![(book.id, 1)]), clerk())
+            .await
+            .unwrap();
+
+        // Act
+        let unknown = repository
+            .create_order(
+                new_order(Ksuid::new(None, None), vec![(book.id, 1)]),
+                clerk(),
+            )
+            .await;
+        let deleted = repository.delete_customer_by_id(customer.id).await;
+        let placed = repository
+            .get_orders_by_customer(
+                customer.id,
+                vec![models::OrderStatus::Placed],
+                models::PageRequest {
+                    cursor: None,
+                    limit: 10,
+                },
+            )
+            .await
+            .unwrap();
+        let shipped = repository
+            .get_orders_by_customer(
+                customer.id,
+                vec![models::OrderStatus::Shipped],
+                models::PageRequest {
+                    cursor: None,
+                    limit: 10,
+                },
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(matches!(
+            unknown,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert!(matches!(
+            deleted,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert_eq!(placed.items, vec![order]);
+        assert!(shipped.items.is_empty());
+        // the unknown order did not reserve any books
+        assert_eq!(
+            repository.get_book_by_id(book.id).await.unwrap().available,
+            9
+        );
+    }
+
+    fn idempotent_request(
+        key: &str,
+        expires_at: chrono::DateTime<Utc>,
+    ) -> models::IdempotentRequestDomain {
+        models::IdempotentRequestDomain {
+            scope: String::from("sha256:checkout"),
+            key: String::from(key),
+            fingerprint: String::from("abc"),
+            expires_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_keys() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let expires_at = Utc::now() + chrono::Duration::hours(1);
+        let response = models::IdempotentResponseDomain {
+            status: 201,
+            headers: vec![(String::from("location"), String::from("/orders/1"))],
+            body: vec![],
+        };
+
+        // Act
+        let reserved = repository
+            .reserve_idempotency_key(idempotent_request("order-1", expires_at))
+            .await
+            .unwrap();
+        let in_progress = repository
+            .reserve_idempotency_key(idempotent_request("order-1", expires_at))
+            .await
+            .unwrap();
+        repository
+            .complete_idempotency_key("sha256:checkout", "order-1", response.clone(), expires_at)
+            .await
+            .unwrap();
+        // a completed key is not released
+        repository
+            .release_idempotency_key("sha256:checkout", "order-1")
+            .await
+            .unwrap();
+        let completed = repository
+            .reserve_idempotency_key(idempotent_request("order-1", expires_at))
+            .await
+            .unwrap();
+        repository
+            .reserve_idempotency_key(idempotent_request("order-2", Utc::now()))
+            .await
+            .unwrap();
+        let expired = repository
+            .reserve_idempotency_key(idempotent_request("order-2", expires_at))
+            .await
+            .unwrap();
+        // the response extends the lapsed lease of the request to the expiry of the response
+        repository
+            .reserve_idempotency_key(idempotent_request("order-3", Utc::now()))
+            .await
+            .unwrap();
+        repository
+            .complete_idempotency_key("sha256:checkout", "order-3", response.clone(), expires_at)
+            .await
+            .unwrap();
+        let extended = repository
+            .reserve_idempotency_key(idempotent_request("order-3", expires_at))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(reserved, None);
+        assert_eq!(in_progress.unwrap().response, None);
+        assert_eq!(completed.unwrap().response, Some(response.clone()));
+        assert_eq!(expired, None);
+        assert_eq!(extended.unwrap().response, Some(response));
+    }
+
+    fn webhook(event_types: &[&str]) -> models::WebhookDomain {
+        models::WebhookDomain {
+            id: Ksuid::new(None, None),
+            url: String::from("https://partner.example.com/hooks"),
+            event_types: event_types.iter().map(|t| String::from(*t)).collect(),
+            secret: String::from("0123456789abcdef"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deliveries_are_enqueued_once_per_subscribed_webhook() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let orders = repository
+            .create_webhook(webhook(&["OrderPlaced", "OrderStatusChanged"]))
+            .await
+            .unwrap();
+        let stock = repository
+            .create_webhook(webhook(&["StockChanged"]))
+            .await
+            .unwrap();
+        let lease = chrono::Duration::seconds(30);
+
+        // Act
+        let enqueued = repository
+            .enqueue_deliveries(1, "OrderPlaced", "{}")
+            .await
+            .unwrap();
+        let enqueued_again = repository
+            .enqueue_deliveries(1, "OrderPlaced", "{}")
+            .await
+            .unwrap();
+        let claimed = repository.claim_due_deliveries(10, lease).await.unwrap();
+        let claimed_during_lease = repository.claim_due_deliveries(10, lease).await.unwrap();
+        let log = repository
+            .get_webhook_deliveries(
+                orders.id,
+                Some(models::DeliveryStatus::Pending),
+                models::PageRequest {
+                    cursor: None,
+                    limit: 10,
+                },
+            )
+            .await
+            .unwrap();
+        let stock_log = repository
+            .get_webhook_deliveries(
+                stock.id,
+                None,
+                models::PageRequest {
+                    cursor: None,
+                    limit: 10,
+                },
+            )
+            .await
+            .unwrap();
+        repository.delete_webhook_by_id(orders.id).await.unwrap();
+        let deleted = repository.webhook_deliveries.len();
+
+        // Assert
+        assert_eq!(enqueued, 1);
+        assert_eq!(enqueued_again, 0);
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].webhook, orders);
+        assert_eq!(claimed[0].delivery.event_type, "OrderPlaced");
+        assert!(claimed_during_lease.is_empty());
+        assert_eq!(log.items.len(), 1);
+        assert!(stock_log.items.is_empty());
+        // the deliveries are deleted with their webhook
+        assert_eq!(deleted, 0);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_with_short_secret_rejected() {
+        // Arrange
+        let repository = InMemoryRepository::new();
+        let mut webhook = webhook(&["BookCreated"]);
+        webhook.secret = String::from("too short");
+
+        // Act
+        let result = repository.create_webhook(webhook).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+    }
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use tracing::instrument;
+
+use super::db_errors::*;
+use super::db_tracing::traced;
+use crate::adapters::events::event_messages::{EventData, map_data_to_event, map_event_to_data};
+use crate::domain::{error, models, store};
+
+#[derive(sqlx::FromRow)]
+struct OutboxRow {
+    id: i64,
+    payload: String,
+    occurred_at: DateTime<Utc>,
+}
+
+fn map_outbox_row(row: OutboxRow) -> Result<models::OutboxEventDomain, error::DomainError> {
+    let invalid = |message: String| error::DomainError::FatalDBFailure {
+        message: format!("failed to parse stored event {}", row.id),
+        source: Box::new(InvalidRecordError(message)),
+    };
+    let data: EventData = serde_json::from_str(&row.payload).map_err(|e| invalid(e.to_string()))?;
+    Ok(models::OutboxEventDomain {
+        id: row.id,
+        event: map_data_to_event(data).map_err(invalid)?,
+        occurred_at: row.occurred_at,
+    })
+}
+
+/// Writes the events to the outbox, called with the transaction of the change the events describe
+pub async fn insert_events(
+    conn: &mut PgConnection,
+    events: &[models::DomainEvent],
+) -> Result<(), error::DomainError> {
+    if events.is_empty() {
+        return Ok(());
+    }
+    let event_types: Vec<&str> = events.iter().map(|e| e.event_type()).collect();
+    let aggregate_ids: Vec<String> = events
+        .iter()
+        .map(|e| e.aggregate_id().to_string())
+        .collect();
+    let payloads = events
+        .iter()
+        .map(|e| serde_json::to_string(&map_event_to_data(e)))
+        .collect::<Result<Vec<String>, serde_json::Error>>()
+        .map_err(|e| error::DomainError::FatalDBFailure {
+            message: String::from("failed to serialize events"),
+            source: Box::new(e),
+        })?;
+    sqlx::query(
+        "INSERT INTO outbox (event_type, aggregate_id, payload) \
+         SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[]::jsonb[])",
+    )
+    .bind(&event_types)
+    .bind(&aggregate_ids)
+    .bind(&payloads)
+    .execute(traced(&mut *conn))
+    .await
+    .map_err(|e| map_db_error(String::from("failed to insert events into the outbox"), e))?;
+    Ok(())
+}
+
+/// The postgres implementation of the OutboxHandler on the outbox table.
+/// The events are written by the BookRepository and the OrderRepository with their changes,
+/// the published events are kept with their published_at as a log of the changes.
+pub struct OutboxRepository {
+    pool: PgPool,
+}
+
+impl OutboxRepository {
+    pub fn new(pool: PgPool) -> Arc<Self> {
+        Arc::new(OutboxRepository { pool })
+    }
+}
+
+#[async_trait]
+impl store::OutboxHandler for OutboxRepository {
+    /// Get the oldest unpublished events in the order they were written, at most limit
+    #[instrument(
+        name = "OutboxRepository::get_unpublished_events",
+        skip_all,
+        fields(otel.kind = "client", db.system.name = "postgresql")
+    )]
+    async fn get_unpublished_events(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<models::OutboxEventDomain>, error::DomainError> {
+        let rows: Vec<OutboxRow> = sqlx::query_as(
+            "SELECT id, payload::text AS payload, occurred_at FROM outbox \
+             WHERE published_at IS NULL ORDER BY id LIMIT $1",
+        )
+        .bind(i64::from(limit))
+        .fetch_all(traced(&self.pool))
+        .await
+        .map_err(|e| map_db_error(String::from("failed to load the outbox"), e))?;
+        rows.into_iter().map(map_outbox_row).collect()
+    }
+
+    /// Mark the events as published, they are not returned as unpublished again
+    #[instrument(
+        name = "OutboxRepository::mark_events_published",
+        skip_all,
+        fields(otel.kind = "client", db.system.name = "postgresql")
+    )]
+    async fn mark_events_published(&self, ids: &[i64]) -> Result<(), error::DomainError> {
+        sqlx::query("UPDATE outbox SET published_at = now() WHERE id = ANY($1)")
+            .bind(ids)
+            .execute(traced(&self.pool))
+            .await
+            .map_err(|e| map_db_error(String::from("failed to mark events as published"), e))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::store::OutboxHandler;
+    use svix_ksuid::{Ksuid, KsuidLike};
+
+    fn stock_changed(delta: i32) -> models::DomainEvent {
+        models::DomainEvent::StockChanged {
+            book_id: Ksuid::new(None, None),
+            available: 10 + delta,
+            delta,
+        }
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "requires a local Postgres"]
+    async fn test_unpublished_events_in_order(pool: PgPool) {
+        // Arrange
+        let repository = OutboxRepository::new(pool.clone());
+        let events = vec![stock_changed(-1), stock_changed(-2), stock_changed(3)];
+        let mut conn = pool.acquire().await.unwrap();
+        insert_events(&mut conn, &events).await.unwrap();
+
+        // Act
+        let first = repository.get_unpublished_events(2).await.unwrap();
+        repository
+            .mark_events_published(&first.iter().map(|e| e.id).collect::<Vec<i64>>())
+            .await
+            .unwrap();
+        let rest = repository.get_unpublished_events(2).await.unwrap();
+
+        // Assert
+        let first: Vec<models::DomainEvent> = first.into_iter().map(|e| e.event).collect();
+        assert_eq!(first, events[..2]);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].event, events[2]);
+    }
+}
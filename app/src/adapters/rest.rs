@@ -1,4 +1,10 @@
+pub mod authentication;
 pub mod domain_mappers;
+pub mod idempotency;
 pub mod mapper_errors;
+pub mod metrics;
+pub mod problem_mappers;
 pub mod rest_mappers;
 pub mod server;
+pub mod telemetry;
+pub mod tls;
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use axum::extract::{Request, State};
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::Response;
+use http::StatusCode;
+use http::header::CONTENT_TYPE;
+use tracing::error;
+
+use super::problem_mappers::*;
+use crate::domain::auth_service::AuthService;
+use crate::domain::error::DomainError;
+use crate::domain::models::ClaimsDomain;
+
+/// The header of the API key of the caller
+pub const API_KEY_HEADER: &str = "X-API-KEY";
+
+/// The outcome of the authentication of a request
+#[derive(Debug, Clone, PartialEq)]
+pub enum Authentication {
+    /// the request carries no API key
+    Anonymous,
+    /// the API key is unknown or not valid at the moment
+    Rejected,
+    /// the API key could not be looked up, e.g. the keystore database is unavailable
+    Unavailable,
+    Authenticated(ClaimsDomain),
+}
+
+impl Authentication {
+    pub fn into_claims(self) -> Option<ClaimsDomain> {
+        match self {
+            Authentication::Authenticated(claims) => Some(claims),
+            _ => None,
+        }
+    }
+}
+
+tokio::task_local! {
+    /// The authentication of the request the current task serves, set by the middleware
+    pub static AUTHENTICATION: Authentication;
+}
+
+/// Looks up the API key of the header, only an unknown or invalid key is rejected
+pub async fn authenticate(
+    auth_service: &AuthService,
+    headers: &HeaderMap,
+    header: &str,
+) -> Authentication {
+    let Some(api_key) = headers.get(header) else {
+        return Authentication::Anonymous;
+    };
+    let Ok(api_key) = api_key.to_str() else {
+        return Authentication::Rejected;
+    };
+    match auth_service.authenticate(api_key).await {
+        Ok(claims) => Authentication::Authenticated(claims),
+        Err(DomainError::NotFound { .. }) => Authentication::Rejected,
+        Err(e) => {
+            error!("failed to look up the api key: {}", e);
+            Authentication::Unavailable
+        }
+    }
+}
+
+/// Authenticates the API key once per request. The handlers get the claims from AUTHENTICATION,
+/// the inner layers from the ClaimsDomain request extension.
+/// The generated handlers answer 401 without claims and without body, so the 401 gets a problem,
+/// or is replaced by a 503 if the key could not be looked up, as the caller is not at fault.
+pub async fn authentication_middleware(
+    State(auth_service): State<Arc<AuthService>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let authentication = authenticate(&auth_service, request.headers(), API_KEY_HEADER).await;
+    if let Authentication::Authenticated(claims) = &authentication {
+        request.extensions_mut().insert(claims.clone());
+    }
+    let response = AUTHENTICATION
+        .scope(authentication.clone(), next.run(request))
+        .await;
+    if response.status() != StatusCode::UNAUTHORIZED
+        || response.headers().contains_key(CONTENT_TYPE)
+    {
+        return response;
+    }
+    match authentication {
+        Authentication::Unavailable => {
+            problem_response(map_authentication_unavailable_to_problem())
+        }
+        authentication => problem_response(map_unauthorized_to_problem(
+            authentication != Authentication::Anonymous,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::persistence::config_api_key_repository::ConfigApiKeyRepository;
+    use crate::domain::auth_service::hash_api_key;
+    use crate::domain::models::{ApiKeyDomain, ApiKeyRole};
+    use crate::domain::{error, models, store};
+    use async_trait::async_trait;
+    use axum::Router;
+    use axum::body::{Body, to_bytes};
+    use axum::routing::get;
+    use chrono::{Duration, Utc};
+    use tower::ServiceExt;
+
+    const API_KEY: &str = "0123456789abcdef0123456789abcdef";
+
+    /// The keystore of a database which is down
+    struct UnavailableKeys;
+
+    #[async_trait]
+    impl store::ApiKeyHandler for UnavailableKeys {
+        async fn get_api_key_by_hash(
+            &self,
+            _key_hash: &str,
+        ) -> Result<models::ApiKeyDomain, error::DomainError> {
+            Err(error::DomainError::FatalDBFailure {
+                message: String::from("pool timed out"),
+                source: Box::new(std::io::Error::other("pool timed out")),
+            })
+        }
+    }
+
+    /// Answers like the generated handlers, 401 without claims
+    async fn handler() -> Result<String, StatusCode> {
+        AUTHENTICATION
+            .with(|authentication| authentication.clone().into_claims())
+            .map(|claims| claims.key_name)
+            .ok_or(StatusCode::UNAUTHORIZED)
+    }
+
+    fn router(auth_service: Arc<AuthService>) -> Router {
+        Router::new()
+            .route("/books", get(handler))
+            .layer(axum::middleware::from_fn_with_state(
+                auth_service,
+                authentication_middleware,
+            ))
+    }
+
+    fn request(api_key: Option<&str>) -> Request {
+        let mut builder = Request::get("/books");
+        if let Some(api_key) = api_key {
+            builder = builder.header(API_KEY_HEADER, api_key);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_request_gets_the_claims() {
+        // Arrange
+        let app = router(AuthService::new(ConfigApiKeyRepository::new(vec![
+            ApiKeyDomain {
+                key_hash: hash_api_key(API_KEY),
+                name: String::from("backoffice"),
+                roles: vec![ApiKeyRole::CatalogRead],
+                valid_from: Utc::now() - Duration::days(1),
+                valid_until: None,
+            },
+        ])));
+
+        // Act
+        let authenticated = app.clone().oneshot(request(Some(API_KEY))).await.unwrap();
+        let unknown = app.clone().oneshot(request(Some("unknown"))).await.unwrap();
+        let anonymous = app.oneshot(request(None)).await.unwrap();
+
+        // Assert
+        assert_eq!(authenticated.status(), StatusCode::OK);
+        assert_eq!(unknown.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(unknown.headers()[CONTENT_TYPE], "application/problem+json");
+        assert_eq!(anonymous.status(), StatusCode::UNAUTHORIZED);
+        let body = to_bytes(anonymous.into_body(), usize::MAX).await.unwrap();
+        let problem: openapi::models::Problem = serde_json::from_slice(&body).unwrap();
+        assert_eq!(problem.status, 401);
+        assert!(problem.detail.unwrap().contains(API_KEY_HEADER));
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_keystore_is_not_unauthorized() {
+        // Arrange
+        let app = router(AuthService::new(Arc::new(UnavailableKeys)));
+
+        // Act
+        let response = app.oneshot(request(Some(API_KEY))).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[CONTENT_TYPE], "application/problem+json");
+    }
+}
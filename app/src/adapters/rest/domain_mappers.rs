@@ -4,6 +4,7 @@ use super::mapper_errors::*;
 use crate::domain::models as dmodels;
 use chrono::{DateTime, Utc};
 use openapi::models as rmodels;
+use rust_decimal::Decimal;
 use svix_ksuid::*;
 
 pub fn map_address_to_domain(address: &rmodels::Address) -> dmodels::AddressDomain {
@@ -19,33 +20,77 @@ pub fn map_address_to_domain(address: &rmodels::Address) -> dmodels::AddressDoma
 
 pub fn map_author_update_props_to_domain(
     id: &str,
+    if_match: &Option<String>,
     props: &rmodels::AuthorProperties,
 ) -> Result<dmodels::AuthorUpdateProps, MapperError> {
-    let kid = Ksuid::from_str(id).map_err(|e| MapperError::InvalidKsuid {
-        id: String::from(id),
-        source: e,
-    })?;
+    let kid = map_string_to_ksuid(id, "author_id")?;
     Ok(dmodels::AuthorUpdateProps {
         id: kid,
         date_of_death: props.date_of_death,
         last_name: props.last_name.clone(),
         second_names: props.second_names.clone(),
         title: props.title.clone(),
+        version: map_if_match_to_version(if_match)?,
     })
 }
 
+/// Maps the If-Match header to the versions an update may be based on.
+/// Without header or with * the update is unconditional, otherwise a list of ETags like "3", W/"2"
+/// is expected. The ETags are compared strongly, weak ETags and ETags of no version never match.
+pub fn map_if_match_to_version(if_match: &Option<String>) -> Result<Option<Vec<i32>>, MapperError> {
+    let Some(if_match) = if_match else {
+        return Ok(None);
+    };
+    if if_match.trim() == "*" {
+        return Ok(None);
+    }
+    let etags = parse_entity_tags(if_match).ok_or_else(|| MapperError::InvalidETag {
+        etag: String::from(if_match),
+        source: Box::new(ETagError(String::from(if_match))),
+    })?;
+    Ok(Some(
+        etags
+            .into_iter()
+            .filter(|(weak, _)| !weak)
+            .filter_map(|(_, tag)| tag.parse::<i32>().ok())
+            .collect(),
+    ))
+}
+
+/// Splits a comma separated list of entity tags into the weak flags and the opaque tags,
+/// empty list elements are skipped. None if the list is malformed or empty.
+fn parse_entity_tags(list: &str) -> Option<Vec<(bool, &str)>> {
+    let mut etags = vec![];
+    let mut rest = list;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_ascii_whitespace());
+        if rest.is_empty() {
+            return (!etags.is_empty()).then_some(etags);
+        }
+        let (weak, etag) = match rest.strip_prefix("W/") {
+            Some(etag) => (true, etag),
+            None => (false, rest),
+        };
+        let etag = etag.strip_prefix('"')?;
+        let end = etag.find('"')?;
+        etags.push((weak, &etag[..end]));
+        rest = etag[end + 1..].trim_start();
+        if !rest.is_empty() && !rest.starts_with(',') {
+            return None;
+        }
+    }
+}
+
 pub fn map_book_props_to_domain(
     id: &str,
+    if_match: &Option<String>,
     props: &rmodels::BookProperties,
 ) -> Result<dmodels::BookUpdateProps, MapperError> {
-    let kid = Ksuid::from_str(id).map_err(|e| MapperError::InvalidKsuid {
-        id: String::from(id),
-        source: e,
-    })?;
+    let kid = map_string_to_ksuid(id, "book_id")?;
 
     let authors = match &props.authors {
         Some(authors) => {
-            let result = map_strings_to_ksuids(authors);
+            let result = map_strings_to_ksuids(authors, "authors");
             Some(result?)
         }
         None => None,
@@ -53,7 +98,7 @@ pub fn map_book_props_to_domain(
 
     let genres = match &props.genres {
         Some(genres) => {
-            let result = map_strings_to_ksuids(genres);
+            let result = map_strings_to_ksuids(genres, "genres");
             Some(result?)
         }
         None => None,
@@ -61,7 +106,7 @@ pub fn map_book_props_to_domain(
 
     let discounts = match &props.discount_codes {
         Some(discounts) => {
-            let result = map_strings_to_ksuids(discounts);
+            let result = map_strings_to_ksuids(discounts, "discount_codes");
             Some(result?)
         }
         None => None,
@@ -80,6 +125,22 @@ pub fn map_book_props_to_domain(
         None => None,
     };
 
+    let product_type = map_product_type_to_domain(&props.product_type)?;
+
+    let price = match (&props.price, &props.currency) {
+        (Some(price), currency) => Some(map_money_to_domain(price, currency, "price")?),
+        (None, Some(currency)) => {
+            return Err(MapperError::InvalidMoney {
+                value: currency.clone(),
+                field: String::from("currency"),
+                source: Box::new(MoneyError(String::from(
+                    "the currency can only be changed together with the price",
+                ))),
+            });
+        }
+        (None, None) => None,
+    };
+
     Ok(dmodels::BookUpdateProps {
         id: kid,
         authors,
@@ -87,14 +148,72 @@ pub fn map_book_props_to_domain(
         discounts,
         genres,
         edition: props.edition,
-        price: props.price,
+        price,
+        product_type,
         release: props.release,
         series: props.series.clone(),
         status,
         title: props.title.clone(),
+        version: map_if_match_to_version(if_match)?,
     })
 }
 
+pub fn map_product_type_to_domain(
+    product_type: &Option<String>,
+) -> Result<Option<dmodels::ProductType>, MapperError> {
+    product_type
+        .as_ref()
+        .map(|value| {
+            dmodels::ProductType::from_str(value).map_err(|_| MapperError::InvalidProductType {
+                product_type: value.clone(),
+                source: Box::new(ProductTypeError(value.clone())),
+            })
+        })
+        .transpose()
+}
+
+pub fn map_customer_update_props_to_domain(
+    id: &str,
+    props: &rmodels::CustomerProperties,
+) -> Result<dmodels::CustomerUpdateProps, MapperError> {
+    let kid = map_string_to_ksuid(id, "customer_id")?;
+    Ok(dmodels::CustomerUpdateProps {
+        id: kid,
+        name: props.name.as_deref().map(str::trim).map(String::from),
+        email: props
+            .email
+            .as_deref()
+            .map(map_email_to_domain)
+            .transpose()?,
+        addresses: props
+            .addresses
+            .as_ref()
+            .map(|addresses| addresses.iter().map(map_address_to_domain).collect()),
+    })
+}
+
+/// Maps an email address with a single @ between a local part and a domain with a dot
+pub fn map_email_to_domain(email: &str) -> Result<String, MapperError> {
+    let trimmed = email.trim();
+    let valid = match trimmed.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.split('.').count() > 1
+                && domain.split('.').all(|part| !part.is_empty())
+                && !trimmed.contains(char::is_whitespace)
+        }
+        None => false,
+    };
+    if !valid {
+        return Err(MapperError::InvalidEmail {
+            email: String::from(email),
+            source: Box::new(EmailError(String::from(email))),
+        });
+    }
+    Ok(String::from(trimmed))
+}
+
 pub fn map_new_author_to_domain(new_author: &rmodels::NewAuthor) -> dmodels::AuthorDomain {
     dmodels::AuthorDomain {
         id: Ksuid::new(None, None),
@@ -104,6 +223,7 @@ pub fn map_new_author_to_domain(new_author: &rmodels::NewAuthor) -> dmodels::Aut
         second_names: new_author.second_names.clone(),
         date_of_birth: new_author.date_of_birth,
         date_of_death: new_author.date_of_death,
+        version: dmodels::INITIAL_VERSION,
     }
 }
 
@@ -124,12 +244,12 @@ pub fn map_new_book_to_domain(
     let edition = new_book.edition.unwrap_or(1);
 
     // map authorIds to Ksuid
-    let d_authors = map_strings_to_ksuids(&new_book.authors)?;
+    let d_authors = map_strings_to_ksuids(&new_book.authors, "authors")?;
 
     // map genereIds to Ksuid
     let d_genres = match &new_book.genres {
         Some(genres) => {
-            let result = map_strings_to_ksuids(genres);
+            let result = map_strings_to_ksuids(genres, "genres");
             Some(result?)
         }
         None => None,
@@ -138,7 +258,7 @@ pub fn map_new_book_to_domain(
     // map discount codes to Ksuid
     let d_discounts = match &new_book.discount_codes {
         Some(discounts) => {
-            let result = map_strings_to_ksuids(discounts);
+            let result = map_strings_to_ksuids(discounts, "discount_codes");
             Some(result?)
         }
         None => None,
@@ -153,13 +273,30 @@ pub fn map_new_book_to_domain(
         series: new_book.series.clone(),
         genres: d_genres,
         edition,
-        price: new_book.price,
+        price: map_money_to_domain(&new_book.price, &new_book.currency, "price")?,
+        product_type: map_product_type_to_domain(&new_book.product_type)?.unwrap_or_default(),
         discounts: d_discounts.clone(),
         available: new_book.available,
         status: dmodels::BookStatus::Available,
     })
 }
 
+pub fn map_new_customer_to_domain(
+    new_customer: &rmodels::NewCustomer,
+) -> Result<dmodels::CustomerDomain, MapperError> {
+    Ok(dmodels::CustomerDomain {
+        id: Ksuid::new(None, None),
+        name: String::from(new_customer.name.trim()),
+        email: map_email_to_domain(&new_customer.email)?,
+        addresses: new_customer
+            .addresses
+            .iter()
+            .flatten()
+            .map(map_address_to_domain)
+            .collect(),
+    })
+}
+
 pub fn map_new_discount_code_to_domain(
     new_discount: &rmodels::NewDiscountCode,
 ) -> Result<dmodels::DiscountCodeDomain, MapperError> {
@@ -200,10 +337,7 @@ pub fn map_new_order_to_domain(
                     source: Box::new(OrderQuantityError(b.quantity)),
                 });
             }
-            let book_id = Ksuid::from_str(&b.book_id).map_err(|e| MapperError::InvalidKsuid {
-                id: b.book_id.clone(),
-                source: e,
-            })?;
+            let book_id = map_string_to_ksuid(&b.book_id, "books.book_id")?;
             Ok(dmodels::OrderedBookDomain {
                 book_id,
                 quantity: b.quantity,
@@ -211,11 +345,7 @@ pub fn map_new_order_to_domain(
         })
         .collect::<Result<Vec<dmodels::OrderedBookDomain>, MapperError>>()?;
 
-    let customer_id =
-        Ksuid::from_str(&new_order.customer_id).map_err(|e| MapperError::InvalidKsuid {
-            id: new_order.customer_id.clone(),
-            source: e,
-        })?;
+    let customer_id = map_string_to_ksuid(&new_order.customer_id, "customer_id")?;
 
     // Handle shipping address: if override exists use it, otherwise use billing address
     let shipping_address = match &new_order.shipping_address_override {
@@ -231,17 +361,36 @@ pub fn map_new_order_to_domain(
         billing_address: map_address_to_domain(&new_order.billing_address),
         shipping_address,
         status: dmodels::OrderStatus::Placed,
+        placed_at: Utc::now(),
+        shipped_at: None,
+        delivered_at: None,
+        canceled_at: None,
+        discount_codes: new_order.discount_codes.clone().unwrap_or_default(),
+        // requested by the currency query parameter or the Accept-Currency header
+        currency: None,
+        // priced by the order service with the current catalog
+        pricing: dmodels::OrderPricingDomain::default(),
+        version: dmodels::INITIAL_VERSION,
+    })
+}
+
+pub fn map_new_webhook_to_domain(
+    new_webhook: &rmodels::NewWebhook,
+) -> Result<dmodels::WebhookDomain, MapperError> {
+    Ok(dmodels::WebhookDomain {
+        id: Ksuid::new(None, None),
+        url: map_webhook_url_to_domain(&new_webhook.url)?,
+        event_types: map_event_types_to_domain(&new_webhook.event_types)?,
+        secret: new_webhook.secret.clone(),
     })
 }
 
 pub fn map_order_props_to_domain(
     id: &str,
+    if_match: &Option<String>,
     props: &rmodels::OrderProperties,
 ) -> Result<dmodels::OrderUpdateProps, MapperError> {
-    let kid = Ksuid::from_str(id).map_err(|e| MapperError::InvalidKsuid {
-        id: String::from(id),
-        source: e,
-    })?;
+    let kid = map_string_to_ksuid(id, "order_id")?;
 
     let status = dmodels::OrderStatus::from_str(&props.status).map_err(|_| {
         MapperError::InvalidOrderStatus {
@@ -257,35 +406,292 @@ pub fn map_order_props_to_domain(
             Utc,
         ),
         status,
+        version: map_if_match_to_version(if_match)?,
     })
 }
 
-pub fn map_strings_to_ksuids(ids_str: &Vec<String>) -> Result<Vec<Ksuid>, MapperError> {
-    ids_str
-        .iter()
-        .map(|id| {
-            Ksuid::from_str(id).map_err(|e| MapperError::InvalidKsuid {
-                id: id.clone(),
-                source: e,
-            })
+/// The actor of an order change is the name of the API key, a blank reason is dropped
+pub fn map_order_change_to_domain(
+    claims: &dmodels::ClaimsDomain,
+    reason: &Option<String>,
+) -> dmodels::OrderChangeDomain {
+    dmodels::OrderChangeDomain {
+        actor: claims.key_name.clone(),
+        reason: reason
+            .as_deref()
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .map(String::from),
+    }
+}
+
+pub fn map_string_to_ksuid(id: &str, field: &str) -> Result<Ksuid, MapperError> {
+    Ksuid::from_str(id).map_err(|e| MapperError::InvalidKsuid {
+        id: String::from(id),
+        field: String::from(field),
+        source: e,
+    })
+}
+
+fn invalid_money(value: &str, field: &str, reason: String) -> MapperError {
+    MapperError::InvalidMoney {
+        value: String::from(value),
+        field: String::from(field),
+        source: Box::new(MoneyError(reason)),
+    }
+}
+
+/// Maps a decimal amount like 12.50 and an optional ISO 4217 currency code, USD if not given
+pub fn map_money_to_domain(
+    amount: &str,
+    currency: &Option<String>,
+    field: &str,
+) -> Result<dmodels::Money, MapperError> {
+    let currency = match currency {
+        Some(code) => dmodels::Currency::from_str(code)
+            .map_err(|reason| invalid_money(code, "currency", reason))?,
+        None => dmodels::Currency::default(),
+    };
+    dmodels::Money::parse(amount, currency).map_err(|reason| invalid_money(amount, field, reason))
+}
+
+/// Maps the currency requested by the currency query parameter or the Accept-Currency header,
+/// the query parameter takes precedence, without either the prices are not converted
+pub fn map_requested_currency_to_domain(
+    query: &Option<String>,
+    header: &Option<String>,
+) -> Result<Option<dmodels::Currency>, MapperError> {
+    let (code, field) = match (query, header) {
+        (Some(code), _) => (code, "currency"),
+        (None, Some(code)) => (code, "Accept-Currency"),
+        (None, None) => return Ok(None),
+    };
+    dmodels::Currency::from_str(&code.trim().to_ascii_uppercase())
+        .map(Some)
+        .map_err(|reason| invalid_money(code, field, reason))
+}
+
+/// Maps an optional non negative decimal amount of a query parameter
+fn map_amount_to_domain(
+    amount: &Option<String>,
+    field: &str,
+) -> Result<Option<Decimal>, MapperError> {
+    amount
+        .as_deref()
+        .map(|value| match Decimal::from_str(value) {
+            Ok(amount) if !amount.is_sign_negative() => Ok(amount),
+            _ => Err(invalid_money(
+                value,
+                field,
+                format!("{} is not a non negative decimal number", value),
+            )),
         })
+        .transpose()
+}
+
+/// Splits the comma separated values of non exploded query array parameters
+fn split_values(values: &[String]) -> impl Iterator<Item = &str> {
+    values
+        .iter()
+        .flat_map(|v| v.split(','))
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+}
+
+pub fn map_strings_to_ksuids(ids_str: &[String], field: &str) -> Result<Vec<Ksuid>, MapperError> {
+    split_values(ids_str)
+        .map(|id| map_string_to_ksuid(id, field))
         .collect::<Result<Vec<Ksuid>, MapperError>>()
 }
 
+pub fn map_page_request_to_domain(
+    limit: Option<i32>,
+    cursor: &Option<String>,
+) -> Result<dmodels::PageRequest, MapperError> {
+    let limit = match limit {
+        Some(limit) if limit < 1 || limit as u32 > dmodels::MAX_PAGE_LIMIT => {
+            return Err(MapperError::PageLimitOutOfBounds {
+                limit,
+                source: Box::new(PageLimitError(limit)),
+            });
+        }
+        Some(limit) => limit as u32,
+        None => dmodels::DEFAULT_PAGE_LIMIT,
+    };
+    let cursor = match cursor {
+        Some(cursor) => Some(map_string_to_ksuid(cursor, "cursor")?),
+        None => None,
+    };
+    Ok(dmodels::PageRequest { cursor, limit })
+}
+
 pub fn map_book_status_list_to_domain(
-    status_str: &Vec<String>,
+    status_str: &[String],
 ) -> Result<Vec<dmodels::BookStatus>, MapperError> {
-    status_str
-        .iter()
+    split_values(status_str)
         .map(|s| {
             dmodels::BookStatus::from_str(s).map_err(|_| MapperError::InvalidBookStatus {
-                status: s.clone(),
-                source: Box::new(BookStatusError(s.clone())),
+                status: String::from(s),
+                source: Box::new(BookStatusError(String::from(s))),
+            })
+        })
+        .collect()
+}
+
+pub fn map_order_status_list_to_domain(
+    status_str: &[String],
+) -> Result<Vec<dmodels::OrderStatus>, MapperError> {
+    split_values(status_str)
+        .map(|s| {
+            dmodels::OrderStatus::from_str(s).map_err(|_| MapperError::InvalidOrderStatus {
+                status: String::from(s),
+                source: Box::new(OrderStatusError(String::from(s))),
             })
         })
         .collect()
 }
 
+pub fn map_delivery_status_to_domain(
+    status: &Option<String>,
+) -> Result<Option<dmodels::DeliveryStatus>, MapperError> {
+    status
+        .as_deref()
+        .map(|s| {
+            dmodels::DeliveryStatus::from_str(s).map_err(|_| MapperError::InvalidDeliveryStatus {
+                status: String::from(s),
+                source: Box::new(DeliveryStatusError(String::from(s))),
+            })
+        })
+        .transpose()
+}
+
+pub fn map_webhook_update_props_to_domain(
+    id: &str,
+    props: &rmodels::WebhookProperties,
+) -> Result<dmodels::WebhookUpdateProps, MapperError> {
+    let kid = map_string_to_ksuid(id, "webhook_id")?;
+    Ok(dmodels::WebhookUpdateProps {
+        id: kid,
+        url: props
+            .url
+            .as_deref()
+            .map(map_webhook_url_to_domain)
+            .transpose()?,
+        event_types: props
+            .event_types
+            .as_deref()
+            .map(map_event_types_to_domain)
+            .transpose()?,
+        secret: props.secret.clone(),
+    })
+}
+
+/// Maps the endpoint of a webhook, an absolute http or https URL with a host
+pub fn map_webhook_url_to_domain(url: &str) -> Result<String, MapperError> {
+    let trimmed = url.trim();
+    match reqwest::Url::parse(trimmed) {
+        Ok(parsed)
+            if matches!(parsed.scheme(), "http" | "https") && parsed.host_str().is_some() =>
+        {
+            Ok(String::from(trimmed))
+        }
+        _ => Err(MapperError::InvalidUrl {
+            url: String::from(url),
+            source: Box::new(UrlError(String::from(url))),
+        }),
+    }
+}
+
+/// Maps the subscribed event types to the names of the domain events, duplicates are removed
+pub fn map_event_types_to_domain(event_types: &[String]) -> Result<Vec<String>, MapperError> {
+    let mut mapped: Vec<String> = vec![];
+    for event_type in event_types {
+        if !dmodels::DomainEvent::EVENT_TYPES.contains(&event_type.as_str()) {
+            return Err(MapperError::InvalidEventType {
+                event_type: event_type.clone(),
+                source: Box::new(EventTypeError(event_type.clone())),
+            });
+        }
+        if !mapped.contains(event_type) {
+            mapped.push(event_type.clone());
+        }
+    }
+    Ok(mapped)
+}
+
+fn map_sort_value<T: FromStr>(
+    value: &Option<String>,
+    field: &str,
+) -> Result<Option<T>, MapperError> {
+    value
+        .as_deref()
+        .map(|v| {
+            T::from_str(v).map_err(|_| MapperError::InvalidSort {
+                value: String::from(v),
+                field: String::from(field),
+                source: Box::new(SortError(String::from(v))),
+            })
+        })
+        .transpose()
+}
+
+fn check_range<T: PartialOrd + std::fmt::Display>(
+    from: Option<T>,
+    to: Option<T>,
+    from_field: &str,
+    to_field: &str,
+) -> Result<(), MapperError> {
+    match (from, to) {
+        (Some(from), Some(to)) if from > to => {
+            let message = format!("{} {} exceeds {} {}", from_field, from, to_field, to);
+            Err(MapperError::InvalidRange {
+                field: String::from(from_field),
+                source: Box::new(RangeError(message)),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn map_book_query_to_domain(
+    params: &rmodels::SearchBooksQueryParams,
+) -> Result<dmodels::BookQuery, MapperError> {
+    let min_price = map_amount_to_domain(&params.min_price, "min_price")?;
+    let max_price = map_amount_to_domain(&params.max_price, "max_price")?;
+    check_range(min_price, max_price, "min_price", "max_price")?;
+    check_range(
+        params.released_from,
+        params.released_to,
+        "released_from",
+        "released_to",
+    )?;
+    let empty = Vec::new();
+    Ok(dmodels::BookQuery {
+        authors: map_strings_to_ksuids(params.authors.as_ref().unwrap_or(&empty), "authors")?,
+        genres: map_strings_to_ksuids(params.genres.as_ref().unwrap_or(&empty), "genres")?,
+        status: map_book_status_list_to_domain(params.status.as_ref().unwrap_or(&empty))?,
+        series: params.series.clone(),
+        min_price,
+        max_price,
+        released_from: params.released_from,
+        released_to: params.released_to,
+        title: params.title.clone().filter(|t| !t.is_empty()),
+        sort: map_sort_value(&params.sort, "sort")?,
+        order: map_sort_value(&params.order, "order")?.unwrap_or_default(),
+    })
+}
+
+pub fn map_search_text_to_domain(text: &str) -> Result<String, MapperError> {
+    let words = dmodels::search_words(text).len();
+    if words == 0 || words > dmodels::MAX_SEARCH_WORDS {
+        return Err(MapperError::InvalidSearchText {
+            text: String::from(text),
+            source: Box::new(SearchTextError(String::from(text))),
+        });
+    }
+    Ok(String::from(text))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -293,6 +699,70 @@ mod tests {
     use chrono::{NaiveDate, Utc};
     use openapi::models as rmodels;
 
+    #[test]
+    fn test_map_book_query_to_domain_splits_values() {
+        // Arrange
+        let author = Ksuid::new(None, None);
+        let params = rmodels::SearchBooksQueryParams {
+            authors: Some(vec![format!("{},{}", author, author)]),
+            genres: None,
+            status: Some(vec![String::from("available,out-of-stock")]),
+            series: None,
+            min_price: None,
+            max_price: None,
+            released_from: None,
+            released_to: None,
+            title: Some(String::new()),
+            sort: Some(String::from("release")),
+            order: Some(String::from("desc")),
+            limit: None,
+            cursor: None,
+            currency: None,
+        };
+
+        // Act
+        let query = map_book_query_to_domain(&params).unwrap();
+
+        // Assert
+        assert_eq!(query.authors, vec![author, author]);
+        assert_eq!(
+            query.status,
+            vec![BookStatus::Available, BookStatus::OutOfStock]
+        );
+        assert_eq!(query.title, None);
+        assert_eq!(query.sort, Some(dmodels::BookSortField::Release));
+        assert_eq!(query.order, dmodels::SortOrder::Desc);
+    }
+
+    #[test]
+    fn test_map_book_query_to_domain_invalid_sort() {
+        // Arrange
+        let params = rmodels::SearchBooksQueryParams {
+            authors: None,
+            genres: None,
+            status: None,
+            series: None,
+            min_price: None,
+            max_price: None,
+            released_from: None,
+            released_to: None,
+            title: None,
+            sort: Some(String::from("author")),
+            order: None,
+            limit: None,
+            cursor: None,
+            currency: None,
+        };
+
+        // Act
+        let result = map_book_query_to_domain(&params);
+
+        // Assert
+        let e = result.unwrap_err();
+        assert_eq!(e.field(), "sort");
+        assert_eq!(e.to_string(), "Invalid sort: author");
+    }
+
     #[test]
     fn test_map_new_order_to_domain_success() {
         // Arrange
@@ -312,6 +782,7 @@ mod tests {
                 country: String::from("Country"),
             },
             shipping_address_override: None,
+            discount_codes: None,
         };
 
         // Act
@@ -325,6 +796,7 @@ mod tests {
         assert_eq!(order.status, dmodels::OrderStatus::Placed);
         assert_eq!(order.billing_address.street, "Main St");
         assert_eq!(order.shipping_address.street, "Main St"); // Same as billing since no override
+        assert!(order.discount_codes.is_empty());
     }
 
     #[test]
@@ -353,6 +825,7 @@ mod tests {
                 province: Some(String::from("Other Province")),
                 country: String::from("Other Country"),
             }),
+            discount_codes: Some(vec![String::from("SUMMER10")]),
         };
 
         // Act
@@ -361,6 +834,7 @@ mod tests {
         // Assert
         assert!(result.is_ok());
         let order = result.unwrap();
+        assert_eq!(order.discount_codes, vec![String::from("SUMMER10")]);
         assert_eq!(order.billing_address.street, "Main St");
         assert_eq!(order.shipping_address.street, "Second St");
     }
@@ -384,6 +858,7 @@ mod tests {
                 country: String::from("Country"),
             },
             shipping_address_override: None,
+            discount_codes: None,
         };
 
         // Act
@@ -418,6 +893,7 @@ mod tests {
                 country: String::from("Country"),
             },
             shipping_address_override: None,
+            discount_codes: None,
         };
 
         // Act
@@ -452,6 +928,7 @@ mod tests {
                 country: String::from("Country"),
             },
             shipping_address_override: None,
+            discount_codes: None,
         };
 
         // Act
@@ -478,7 +955,9 @@ mod tests {
             series: Some(String::from("Test Series")),
             genres: Some(vec![String::from("2N1yQqzh1fhkGEPv5rJRqOZqxE3")]),
             edition: Some(1),
-            price: 29.99,
+            price: String::from("29.99"),
+            currency: None,
+            product_type: None,
             discount_codes: Some(vec![String::from("2N1yQqzh1fhkGEPv5rJRqOZqxE3")]),
             available: 10,
         };
@@ -490,8 +969,10 @@ mod tests {
         assert!(result.is_ok());
         let book = result.unwrap();
         assert_eq!(book.title, "Test Book");
+        assert_eq!(book.price.to_string(), "29.99 USD");
         assert_eq!(book.available, 10);
         assert_eq!(book.status, dmodels::BookStatus::Available);
+        assert_eq!(book.product_type, dmodels::ProductType::Print);
         assert_eq!(book.authors.len(), 1);
         assert!(book.genres.is_some());
         assert!(book.discounts.is_some());
@@ -508,7 +989,9 @@ mod tests {
             series: None,
             genres: None,
             edition: None,
-            price: 29.99,
+            price: String::from("29.99"),
+            currency: None,
+            product_type: None,
             discount_codes: None,
             available: -1,
         };
@@ -539,7 +1022,9 @@ mod tests {
             series: None,
             genres: None,
             edition: None,
-            price: 29.99,
+            price: String::from("29.99"),
+            currency: None,
+            product_type: None,
             discount_codes: None,
             available: 10,
         };
@@ -568,7 +1053,9 @@ mod tests {
             series: None,
             genres: Some(vec![String::from("invalid-ksuid")]),
             edition: None,
-            price: 29.99,
+            price: String::from("29.99"),
+            currency: None,
+            product_type: None,
             discount_codes: None,
             available: 10,
         };
@@ -597,7 +1084,9 @@ mod tests {
             series: None,
             genres: None,
             edition: None,
-            price: 29.99,
+            price: String::from("29.99"),
+            currency: None,
+            product_type: None,
             discount_codes: Some(vec![String::from("invalid-ksuid")]),
             available: 10,
         };
@@ -790,10 +1279,11 @@ mod tests {
         let order_props = rmodels::OrderProperties {
             shipping_date: Utc::now().date_naive(),
             status: String::from("shipped"),
+            reason: None,
         };
 
         // Act
-        let result = map_order_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &order_props);
+        let result = map_order_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &None, &order_props);
 
         // Assert
         assert!(result.is_ok());
@@ -807,16 +1297,18 @@ mod tests {
         let order_props = rmodels::OrderProperties {
             shipping_date: Utc::now().date_naive(),
             status: String::from("shipped"),
+            reason: None,
         };
 
         // Act
-        let result = map_order_props_to_domain("invalid-id", &order_props);
+        let result = map_order_props_to_domain("invalid-id", &None, &order_props);
 
         // Assert
         assert!(result.is_err());
         match result {
-            Err(MapperError::InvalidKsuid { id, .. }) => {
+            Err(MapperError::InvalidKsuid { id, field, .. }) => {
                 assert_eq!(id, "invalid-id");
+                assert_eq!(field, "order_id");
             }
             _ => panic!("Expected InvalidKsuid error"),
         }
@@ -828,10 +1320,11 @@ mod tests {
         let order_props = rmodels::OrderProperties {
             shipping_date: Utc::now().date_naive(),
             status: String::from("invalid-status"),
+            reason: None,
         };
 
         // Act
-        let result = map_order_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &order_props);
+        let result = map_order_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &None, &order_props);
 
         // Assert
         assert!(result.is_err());
@@ -853,19 +1346,22 @@ mod tests {
             series: Some(String::from("Updated Series")),
             genres: Some(vec![String::from("2N1yQqzh1fhkGEPv5rJRqOZqxE3")]),
             edition: Some(2),
-            price: Some(39.99),
+            price: Some(String::from("39.99")),
+            currency: Some(String::from("EUR")),
+            product_type: None,
             discount_codes: Some(vec![String::from("2N1yQqzh1fhkGEPv5rJRqOZqxE3")]),
             available: Some(15),
             status: Some(String::from("available")),
         };
 
         // Act
-        let result = map_book_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &book_props);
+        let result = map_book_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &None, &book_props);
 
         // Assert
         assert!(result.is_ok());
         let props = result.unwrap();
         assert_eq!(props.title.unwrap(), "Updated Title");
+        assert_eq!(props.price.unwrap().to_string(), "39.99 EUR");
         assert_eq!(props.available.unwrap(), 15);
         assert_eq!(props.status.unwrap(), dmodels::BookStatus::Available);
     }
@@ -881,13 +1377,15 @@ mod tests {
             genres: None,
             edition: None,
             price: None,
+            currency: None,
+            product_type: None,
             discount_codes: None,
             available: None,
             status: None,
         };
 
         // Act
-        let result = map_book_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &book_props);
+        let result = map_book_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &None, &book_props);
 
         // Assert
         assert!(result.is_ok());
@@ -909,13 +1407,15 @@ mod tests {
             genres: None,
             edition: None,
             price: None,
+            currency: None,
+            product_type: None,
             discount_codes: None,
             available: None,
             status: None,
         };
 
         // Act
-        let result = map_book_props_to_domain("invalid-id", &book_props);
+        let result = map_book_props_to_domain("invalid-id", &None, &book_props);
 
         // Assert
         assert!(result.is_err());
@@ -938,13 +1438,15 @@ mod tests {
             genres: None,
             edition: None,
             price: None,
+            currency: None,
+            product_type: None,
             discount_codes: None,
             available: None,
             status: None,
         };
 
         // Act
-        let result = map_book_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &book_props);
+        let result = map_book_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &None, &book_props);
 
         // Assert
         assert!(result.is_err());
@@ -967,13 +1469,15 @@ mod tests {
             genres: None,
             edition: None,
             price: None,
+            currency: None,
+            product_type: None,
             discount_codes: None,
             available: None,
             status: Some(String::from("invalid-status")),
         };
 
         // Act
-        let result = map_book_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &book_props);
+        let result = map_book_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &None, &book_props);
 
         // Assert
         assert!(result.is_err());
@@ -985,6 +1489,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_map_book_props_to_domain_invalid_product_type() {
+        // Arrange
+        let book_props = rmodels::BookProperties {
+            title: None,
+            release: None,
+            authors: None,
+            series: None,
+            genres: None,
+            edition: None,
+            price: None,
+            currency: None,
+            product_type: Some(String::from("vinyl")),
+            discount_codes: None,
+            available: None,
+            status: None,
+        };
+
+        // Act
+        let result = map_book_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &None, &book_props);
+
+        // Assert
+        match result {
+            Err(MapperError::InvalidProductType { product_type, .. }) => {
+                assert_eq!(product_type, "vinyl");
+            }
+            _ => panic!("Expected InvalidProductType error"),
+        }
+    }
+
     #[test]
     fn test_map_author_update_props_to_domain_success() {
         // Arrange
@@ -997,7 +1531,7 @@ mod tests {
 
         // Act
         let result =
-            map_author_update_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &author_props);
+            map_author_update_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &None, &author_props);
 
         // Assert
         assert!(result.is_ok());
@@ -1019,7 +1553,7 @@ mod tests {
 
         // Act
         let result =
-            map_author_update_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &author_props);
+            map_author_update_props_to_domain("2N1yQqzh1fhkGEPv5rJRqOZqxE3", &None, &author_props);
 
         // Assert
         assert!(result.is_ok());
@@ -1041,7 +1575,7 @@ mod tests {
         };
 
         // Act
-        let result = map_author_update_props_to_domain("invalid-id", &author_props);
+        let result = map_author_update_props_to_domain("invalid-id", &None, &author_props);
 
         // Assert
         assert!(result.is_err());
@@ -1053,6 +1587,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_map_order_props_to_domain_with_if_match() {
+        // Arrange
+        let order_props = rmodels::OrderProperties {
+            shipping_date: Utc::now().date_naive(),
+            status: String::from("shipped"),
+            reason: None,
+        };
+
+        // Act
+        let result = map_order_props_to_domain(
+            "2N1yQqzh1fhkGEPv5rJRqOZqxE3",
+            &Some(String::from("\"3\"")),
+            &order_props,
+        );
+
+        // Assert
+        assert_eq!(result.unwrap().version, Some(vec![3]));
+    }
+
+    #[test]
+    fn test_map_if_match_to_version() {
+        // Act & Assert
+        assert_eq!(map_if_match_to_version(&None).unwrap(), None);
+        assert_eq!(
+            map_if_match_to_version(&Some(String::from("*"))).unwrap(),
+            None
+        );
+        assert_eq!(
+            map_if_match_to_version(&Some(String::from(" \"12\" "))).unwrap(),
+            Some(vec![12])
+        );
+        assert_eq!(
+            map_if_match_to_version(&Some(String::from("\"1\", W/\"2\" ,, \"3\""))).unwrap(),
+            Some(vec![1, 3])
+        );
+        // weak ETags and ETags of no version never match
+        assert_eq!(
+            map_if_match_to_version(&Some(String::from("W/\"2\", \"abc\""))).unwrap(),
+            Some(vec![])
+        );
+        for invalid in [
+            "12",
+            "",
+            "\"12",
+            "\"1\" \"2\"",
+            "*, \"1\"",
+            "W/12",
+            "\"1\"; \"2\"",
+        ] {
+            match map_if_match_to_version(&Some(String::from(invalid))) {
+                Err(e @ MapperError::InvalidETag { .. }) => assert_eq!(e.field(), "If-Match"),
+                _ => panic!("Expected InvalidETag error for {}", invalid),
+            }
+        }
+    }
+
     #[test]
     fn test_map_book_status_list_to_domain_success() {
         // Arrange
@@ -1109,4 +1700,150 @@ mod tests {
         let statuses = result.unwrap();
         assert!(statuses.is_empty());
     }
+
+    #[test]
+    fn test_map_order_status_list_to_domain_splits_values() {
+        // Arrange
+        let status_list = vec![String::from("placed,canceled")];
+
+        // Act
+        let result = map_order_status_list_to_domain(&status_list);
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            vec![dmodels::OrderStatus::Placed, dmodels::OrderStatus::Canceled]
+        );
+    }
+
+    #[test]
+    fn test_map_email_to_domain() {
+        // Act
+        let valid = map_email_to_domain(" johann@example.com ");
+        let invalid = [
+            "johann",
+            "@example.com",
+            "johann@example",
+            "johann@@example.com",
+            "johann@example..com",
+            "jo hann@example.com",
+        ]
+        .map(map_email_to_domain);
+
+        // Assert
+        assert_eq!(valid.unwrap(), "johann@example.com");
+        for result in invalid {
+            assert!(matches!(result, Err(MapperError::InvalidEmail { .. })));
+        }
+    }
+
+    #[test]
+    fn test_map_page_request_to_domain_defaults() {
+        // Act
+        let result = map_page_request_to_domain(None, &None);
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            dmodels::PageRequest {
+                cursor: None,
+                limit: dmodels::DEFAULT_PAGE_LIMIT,
+            }
+        );
+    }
+
+    #[test]
+    fn test_map_page_request_to_domain_invalid() {
+        // Act
+        let limit = map_page_request_to_domain(Some(101), &None);
+        let cursor = map_page_request_to_domain(Some(10), &Some(String::from("invalid")));
+
+        // Assert
+        assert!(matches!(
+            limit,
+            Err(MapperError::PageLimitOutOfBounds { limit: 101, .. })
+        ));
+        match cursor {
+            Err(e @ MapperError::InvalidKsuid { .. }) => assert_eq!(e.field(), "cursor"),
+            _ => panic!("Expected InvalidKsuid error"),
+        }
+    }
+
+    #[test]
+    fn test_map_requested_currency_to_domain() {
+        // Arrange
+        let query = Some(String::from("eur"));
+        let header = Some(String::from("JPY"));
+
+        // Act
+        let from_query = map_requested_currency_to_domain(&query, &header).unwrap();
+        let from_header = map_requested_currency_to_domain(&None, &header).unwrap();
+        let none = map_requested_currency_to_domain(&None, &None).unwrap();
+        let invalid = map_requested_currency_to_domain(&None, &Some(String::from("Euro")));
+
+        // Assert
+        assert_eq!(from_query.map(|c| c.to_string()), Some(String::from("EUR")));
+        assert_eq!(
+            from_header.map(|c| c.to_string()),
+            Some(String::from("JPY"))
+        );
+        assert_eq!(none, None);
+        match invalid {
+            Err(e) => assert_eq!(e.field(), "Accept-Currency"),
+            Ok(_) => panic!("Expected InvalidMoney error"),
+        }
+    }
+
+    #[test]
+    fn test_map_new_webhook_to_domain() {
+        // Arrange
+        let new_webhook = rmodels::NewWebhook::new(
+            String::from(" https://partner.example.com/hooks "),
+            vec![
+                String::from("OrderPlaced"),
+                String::from("StockChanged"),
+                String::from("OrderPlaced"),
+            ],
+            String::from("0123456789abcdef"),
+        );
+
+        // Act
+        let webhook = map_new_webhook_to_domain(&new_webhook).unwrap();
+
+        // Assert
+        assert_eq!(webhook.url, "https://partner.example.com/hooks");
+        assert_eq!(webhook.event_types, vec!["OrderPlaced", "StockChanged"]);
+    }
+
+    #[test]
+    fn test_map_new_webhook_to_domain_rejects_invalid_values() {
+        // Arrange
+        let ftp = rmodels::NewWebhook::new(
+            String::from("ftp://partner.example.com"),
+            vec![String::from("OrderPlaced")],
+            String::from("0123456789abcdef"),
+        );
+        let unknown_event = rmodels::NewWebhook::new(
+            String::from("https://partner.example.com/hooks"),
+            vec![String::from("OrderShipped")],
+            String::from("0123456789abcdef"),
+        );
+
+        // Act
+        let ftp = map_new_webhook_to_domain(&ftp);
+        let unknown_event = map_new_webhook_to_domain(&unknown_event);
+
+        // Assert
+        assert!(matches!(ftp, Err(MapperError::InvalidUrl { .. })));
+        match unknown_event {
+            Err(e @ MapperError::InvalidEventType { .. }) => {
+                assert_eq!(e.field(), "event_types");
+                assert_eq!(
+                    e.to_string(),
+                    "Invalid event type: OrderShipped. Must be one of BookCreated, StockChanged, OrderPlaced, OrderStatusChanged"
+                );
+            }
+            other => panic!("Expected InvalidEventType error, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,493 @@
+use std::sync::Arc;
+
+use axum::body::{Body, to_bytes};
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use http::header::{CONTENT_LENGTH, TRANSFER_ENCODING};
+use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use tracing::error;
+
+use super::problem_mappers::*;
+use crate::domain::idempotency_service::{
+    IdempotencyOutcome, IdempotencyService, fingerprint_request, is_valid_idempotency_key,
+};
+use crate::domain::models::{ClaimsDomain, IdempotentResponseDomain};
+
+/// The header of the client chosen key which identifies the retries of a request
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+/// The header which marks a replayed response
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+/// The limit of the buffered request bodies, the same as the default limit of the axum extractors
+pub const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// The headers of the response to replay, the framing headers are set for the replayed body
+fn stored_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(name, _)| **name != CONTENT_LENGTH && **name != TRANSFER_ENCODING)
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|value| (String::from(name.as_str()), String::from(value)))
+        })
+        .collect()
+}
+
+fn replay_response(stored: IdempotentResponseDomain) -> Response {
+    let mut response = Response::new(Body::from(stored.body));
+    *response.status_mut() =
+        StatusCode::from_u16(stored.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    for (name, value) in stored.headers {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            response.headers_mut().append(name, value);
+        }
+    }
+    response
+        .headers_mut()
+        .insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+/// The reservation of a key by the request in progress. If the request is aborted, e.g. when the
+/// client times out and the connection is closed, its future is dropped and the reservation
+/// releases the key, so the retry is executed instead of being answered with 409.
+struct Reservation {
+    service: Arc<IdempotencyService>,
+    scope: String,
+    key: String,
+    done: bool,
+}
+
+impl Reservation {
+    /// Releases the key of a failed request
+    async fn release(mut self) {
+        if let Err(e) = self.service.release(&self.scope, &self.key).await {
+            error!("failed to release idempotency key {}: {}", self.key, e);
+        }
+        self.done = true;
+    }
+
+    /// Stores the response of the executed request
+    async fn complete(mut self, response: IdempotentResponseDomain) {
+        if let Err(e) = self
+            .service
+            .complete(&self.scope, &self.key, response)
+            .await
+        {
+            // the response is still returned, the key stays reserved until its lease expires
+            error!(
+                "failed to store the response of idempotency key {}: {}",
+                self.key, e
+            );
+        }
+        // a release after the response is stored does not remove it
+        self.done = true;
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        if self.done {
+            return;
+        }
+        // the dropped request can not await the release, it runs in the background
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let service = self.service.clone();
+        let scope = std::mem::take(&mut self.scope);
+        let key = std::mem::take(&mut self.key);
+        runtime.spawn(async move {
+            if let Err(e) = service.release(&scope, &key).await {
+                error!("failed to release idempotency key {}: {}", key, e);
+            }
+        });
+    }
+}
+
+/// Makes the retries of POST requests with an Idempotency-Key header safe.
+/// The first request with a key is executed and its response is stored, the retries with the
+/// same key and body get the stored response. Runs inside the authentication middleware, the keys
+/// are scoped to the name of the authenticated API key. Unauthenticated requests are rejected by
+/// the handlers and are passed through without reserving the key.
+/// Server errors and rejected credentials are not stored, so the retry executes the request again.
+/// The key of an aborted request is released, the key of a crashed one is reserved until the lease
+/// of the request expires.
+pub async fn idempotency_middleware(
+    State(service): State<Arc<IdempotencyService>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if request.method() != Method::POST {
+        return next.run(request).await;
+    }
+    let Some(key) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return next.run(request).await;
+    };
+    let Some(key) = key
+        .to_str()
+        .ok()
+        .filter(|key| is_valid_idempotency_key(key))
+        .map(String::from)
+    else {
+        return problem_response(map_invalid_idempotency_key_to_problem());
+    };
+    let Some(scope) = request
+        .extensions()
+        .get::<ClaimsDomain>()
+        .map(|claims| claims.key_name.clone())
+    else {
+        return next.run(request).await;
+    };
+
+    let (parts, body) = request.into_parts();
+    let Ok(body) = to_bytes(body, MAX_BODY_BYTES).await else {
+        return problem_response(map_payload_too_large_to_problem(MAX_BODY_BYTES));
+    };
+    let uri = parts
+        .uri
+        .path_and_query()
+        .map(|p| p.as_str())
+        .unwrap_or_else(|| parts.uri.path());
+    let fingerprint = fingerprint_request(parts.method.as_str(), uri, &body);
+
+    match service.begin(&scope, &key, fingerprint).await {
+        Ok(IdempotencyOutcome::Execute) => {}
+        Ok(IdempotencyOutcome::Replay(stored)) => return replay_response(stored),
+        Ok(IdempotencyOutcome::Mismatch) => {
+            return problem_response(map_idempotency_key_reused_to_problem(&key));
+        }
+        Ok(IdempotencyOutcome::InProgress) => {
+            return problem_response(map_idempotency_key_in_progress_to_problem(&key));
+        }
+        Err(e) => return problem_response(map_domain_error_to_problem(500, &e)),
+    }
+    let reservation = Reservation {
+        service,
+        scope,
+        key,
+        done: false,
+    };
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(e) => {
+            error!(
+                "failed to buffer the response of idempotency key {}: {}",
+                reservation.key, e
+            );
+            reservation.release().await;
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    if parts.status.is_server_error()
+        || parts.status == StatusCode::UNAUTHORIZED
+        || parts.status == StatusCode::FORBIDDEN
+    {
+        reservation.release().await;
+    } else {
+        reservation
+            .complete(IdempotentResponseDomain {
+                status: parts.status.as_u16(),
+                headers: stored_headers(&parts.headers),
+                body: body.to_vec(),
+            })
+            .await;
+    }
+    Response::from_parts(parts, Body::from(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::persistence::in_memory_repository::InMemoryRepository;
+    use axum::Router;
+    use axum::routing::{get, post};
+    use http::header::{CONTENT_TYPE, ETAG, LOCATION};
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    const API_KEY: &str = "0123456789abcdef0123456789abcdef";
+
+    /// Stands in for the authentication middleware, every API key is accepted with its value as name
+    async fn authenticate(mut request: Request, next: Next) -> Response {
+        let key_name = request
+            .headers()
+            .get("X-API-KEY")
+            .and_then(|api_key| api_key.to_str().ok())
+            .map(String::from);
+        if let Some(key_name) = key_name {
+            request.extensions_mut().insert(ClaimsDomain {
+                key_name,
+                roles: vec![],
+            });
+        }
+        next.run(request).await
+    }
+
+    /// A router which counts the created resources, fails for the body "fail",
+    /// rejects the body "forbidden" and never answers the first request with the body "stall"
+    fn router() -> Router {
+        let created = Arc::new(AtomicU32::new(0));
+        let stalled = Arc::new(AtomicBool::new(false));
+        let service = IdempotencyService::new(
+            InMemoryRepository::new(),
+            chrono::Duration::hours(1),
+            chrono::Duration::minutes(1),
+        );
+        Router::new()
+            .route(
+                "/api/v1/genres",
+                post(move |body: String| async move {
+                    if body == "fail" {
+                        return (StatusCode::SERVICE_UNAVAILABLE, String::from("unavailable"))
+                            .into_response();
+                    }
+                    if body == "forbidden" {
+                        return (StatusCode::FORBIDDEN, String::from("forbidden")).into_response();
+                    }
+                    if body == "stall" && !stalled.swap(true, Ordering::SeqCst) {
+                        std::future::pending::<()>().await;
+                    }
+                    let id = created.fetch_add(1, Ordering::SeqCst) + 1;
+                    (
+                        StatusCode::CREATED,
+                        [
+                            (CONTENT_TYPE, String::from("application/json")),
+                            (LOCATION, format!("/api/v1/genres/{}", id)),
+                            (ETAG, String::from("\"1\"")),
+                        ],
+                        format!("{{\"id\":\"{}\"}}", id),
+                    )
+                        .into_response()
+                }),
+            )
+            .route("/api/v1/genres/{id}", get(|| async { "{}" }))
+            .layer(axum::middleware::from_fn_with_state(
+                service,
+                idempotency_middleware,
+            ))
+            .layer(axum::middleware::from_fn(authenticate))
+    }
+
+    fn request(method: Method, key: Option<&str>, body: &str) -> Request {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri("/api/v1/genres")
+            .header("X-API-KEY", API_KEY);
+        if let Some(key) = key {
+            builder = builder.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+        builder.body(Body::from(String::from(body))).unwrap()
+    }
+
+    async fn send(router: &Router, request: Request) -> (StatusCode, Option<String>, String) {
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let replayed = response
+            .headers()
+            .get(IDEMPOTENT_REPLAYED_HEADER)
+            .map(|h| h.to_str().unwrap().to_string());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, replayed, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_retry_replays_response() {
+        // Arrange
+        let router = router();
+
+        // Act
+        let first = send(&router, request(Method::POST, Some("genre-1"), "Drama")).await;
+        let retry = send(&router, request(Method::POST, Some("genre-1"), "Drama")).await;
+        let other = send(&router, request(Method::POST, Some("genre-2"), "Drama")).await;
+
+        // Assert
+        assert_eq!(
+            first,
+            (StatusCode::CREATED, None, String::from("{\"id\":\"1\"}"))
+        );
+        assert_eq!(
+            retry,
+            (
+                StatusCode::CREATED,
+                Some(String::from("true")),
+                String::from("{\"id\":\"1\"}")
+            )
+        );
+        assert_eq!(other.2, "{\"id\":\"2\"}");
+    }
+
+    #[tokio::test]
+    async fn test_retry_replays_headers() {
+        // Arrange
+        let router = router();
+        send(&router, request(Method::POST, Some("genre-1"), "Drama")).await;
+
+        // Act
+        let retry = router
+            .clone()
+            .oneshot(request(Method::POST, Some("genre-1"), "Drama"))
+            .await
+            .unwrap();
+
+        // Assert
+        let headers = retry.headers();
+        assert_eq!(headers[CONTENT_TYPE], "application/json");
+        assert_eq!(headers[LOCATION], "/api/v1/genres/1");
+        assert_eq!(headers[ETAG], "\"1\"");
+        assert_eq!(headers[IDEMPOTENT_REPLAYED_HEADER], "true");
+        assert_eq!(headers[CONTENT_LENGTH], "10");
+    }
+
+    #[tokio::test]
+    async fn test_aborted_request_releases_key() {
+        // Arrange
+        let router = router();
+
+        // Act
+        // the client times out, the future of the request is dropped
+        let aborted = tokio::time::timeout(
+            Duration::from_millis(50),
+            router
+                .clone()
+                .oneshot(request(Method::POST, Some("genre-1"), "stall")),
+        )
+        .await;
+        // the key is released in the background
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let retry = send(&router, request(Method::POST, Some("genre-1"), "stall")).await;
+
+        // Assert
+        assert!(aborted.is_err());
+        assert_eq!(
+            retry,
+            (StatusCode::CREATED, None, String::from("{\"id\":\"1\"}"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reused_key_with_different_body_rejected() {
+        // Arrange
+        let router = router();
+        send(&router, request(Method::POST, Some("genre-1"), "Drama")).await;
+
+        // Act
+        let (status, _, body) =
+            send(&router, request(Method::POST, Some("genre-1"), "Poetry")).await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.contains("idempotency-key-reused"));
+    }
+
+    #[tokio::test]
+    async fn test_requests_without_key_are_executed() {
+        // Arrange
+        let router = router();
+
+        // Act
+        send(&router, request(Method::POST, None, "Drama")).await;
+        let (status, replayed, body) = send(&router, request(Method::POST, None, "Drama")).await;
+        let (get_status, _, _) = send(
+            &router,
+            Request::builder()
+                .uri("/api/v1/genres/1")
+                .header(IDEMPOTENCY_KEY_HEADER, "genre-1")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+
+        // Assert
+        assert_eq!((status, replayed), (StatusCode::CREATED, None));
+        assert_eq!(body, "{\"id\":\"2\"}");
+        assert_eq!(get_status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_are_not_stored() {
+        // Arrange
+        let router = router();
+
+        // Act
+        let first = send(&router, request(Method::POST, Some("genre-1"), "fail")).await;
+        let retry = send(&router, request(Method::POST, Some("genre-1"), "fail")).await;
+
+        // Assert
+        assert_eq!(first.0, StatusCode::SERVICE_UNAVAILABLE);
+        // the retry is executed again instead of replaying the failure
+        assert_eq!(retry.1, None);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_credentials_are_not_stored() {
+        // Arrange
+        let router = router();
+
+        // Act
+        let first = send(&router, request(Method::POST, Some("genre-1"), "forbidden")).await;
+        let retry = send(&router, request(Method::POST, Some("genre-1"), "Drama")).await;
+
+        // Assert
+        assert_eq!(first.0, StatusCode::FORBIDDEN);
+        // the key is released, the request with another body is executed
+        assert_eq!(
+            retry,
+            (StatusCode::CREATED, None, String::from("{\"id\":\"1\"}"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keys_are_scoped_to_the_authenticated_api_key() {
+        // Arrange
+        let router = router();
+        let anonymous = || {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api/v1/genres")
+                .header(IDEMPOTENCY_KEY_HEADER, "genre-1")
+                .body(Body::from("Drama"))
+                .unwrap()
+        };
+        send(&router, request(Method::POST, Some("genre-1"), "Drama")).await;
+
+        // Act
+        let mut other = request(Method::POST, Some("genre-1"), "Poetry");
+        other
+            .headers_mut()
+            .insert("X-API-KEY", HeaderValue::from_static("other"));
+        let other = send(&router, other).await;
+        let first_anonymous = send(&router, anonymous()).await;
+        let second_anonymous = send(&router, anonymous()).await;
+
+        // Assert
+        assert_eq!(other.2, "{\"id\":\"2\"}");
+        // unauthenticated requests neither reserve nor replay a key
+        assert_eq!(first_anonymous.1, None);
+        assert_eq!(second_anonymous.1, None);
+        assert_eq!(second_anonymous.2, "{\"id\":\"4\"}");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_key_rejected() {
+        // Arrange
+        let router = router();
+
+        // Act
+        let (status, _, body) = send(&router, request(Method::POST, Some(""), "Drama")).await;
+
+        // Assert
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.contains("invalid-idempotency-key"));
+    }
+}
@@ -1,6 +1,8 @@
 use std::error::Error;
 use std::fmt;
 
+use crate::domain::models as dmodels;
+
 #[derive(Debug)]
 pub struct BookAvailabilityError(pub i32);
 
@@ -23,6 +25,17 @@ impl fmt::Display for BookStatusError {
 
 impl Error for BookStatusError {}
 
+#[derive(Debug)]
+pub struct ProductTypeError(pub String);
+
+impl fmt::Display for ProductTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid product type: {}", self.0)
+    }
+}
+
+impl Error for ProductTypeError {}
+
 #[derive(Debug)]
 pub struct DiscountPercentageError(pub i32);
 
@@ -60,10 +73,121 @@ impl fmt::Display for OrderQuantityError {
 
 impl Error for OrderQuantityError {}
 
+#[derive(Debug)]
+pub struct PageLimitError(pub i32);
+
+impl fmt::Display for PageLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid page limit: {}", self.0)
+    }
+}
+
+impl Error for PageLimitError {}
+
+#[derive(Debug)]
+pub struct SortError(pub String);
+
+impl fmt::Display for SortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid sort: {}", self.0)
+    }
+}
+
+impl Error for SortError {}
+
+#[derive(Debug)]
+pub struct RangeError(pub String);
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid range: {}", self.0)
+    }
+}
+
+impl Error for RangeError {}
+
+#[derive(Debug)]
+pub struct SearchTextError(pub String);
+
+impl fmt::Display for SearchTextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid search text: {}", self.0)
+    }
+}
+
+impl Error for SearchTextError {}
+
+#[derive(Debug)]
+pub struct EmailError(pub String);
+
+impl fmt::Display for EmailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid email: {}", self.0)
+    }
+}
+
+impl Error for EmailError {}
+
+#[derive(Debug)]
+pub struct ETagError(pub String);
+
+impl fmt::Display for ETagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid ETag: {}", self.0)
+    }
+}
+
+impl Error for ETagError {}
+
+#[derive(Debug)]
+pub struct MoneyError(pub String);
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for MoneyError {}
+
+#[derive(Debug)]
+pub struct UrlError(pub String);
+
+impl fmt::Display for UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid URL: {}", self.0)
+    }
+}
+
+impl Error for UrlError {}
+
+#[derive(Debug)]
+pub struct EventTypeError(pub String);
+
+impl fmt::Display for EventTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid event type: {}", self.0)
+    }
+}
+
+impl Error for EventTypeError {}
+
+#[derive(Debug)]
+pub struct DeliveryStatusError(pub String);
+
+impl fmt::Display for DeliveryStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid delivery status: {}", self.0)
+    }
+}
+
+impl Error for DeliveryStatusError {}
+
 #[derive(Debug)]
 pub enum MapperError {
     InvalidKsuid {
         id: String,
+        field: String,
         source: svix_ksuid::Error,
     },
     BooksAvailableOutOfBound {
@@ -82,10 +206,56 @@ pub enum MapperError {
         status: String,
         source: Box<dyn Error + Send + Sync>,
     },
+    InvalidProductType {
+        product_type: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
     OrderQuantityOutOfBounds {
         quantity: i32,
         source: Box<dyn Error + Send + Sync>,
     },
+    PageLimitOutOfBounds {
+        limit: i32,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    InvalidSort {
+        value: String,
+        field: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    InvalidRange {
+        field: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    InvalidSearchText {
+        text: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    InvalidMoney {
+        value: String,
+        field: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    InvalidEmail {
+        email: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    InvalidETag {
+        etag: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    InvalidUrl {
+        url: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    InvalidEventType {
+        event_type: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    InvalidDeliveryStatus {
+        status: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
 }
 
 impl fmt::Display for MapperError {
@@ -95,7 +265,7 @@ impl fmt::Display for MapperError {
             MapperError::BooksAvailableOutOfBound {
                 books_available, ..
             } => {
-                write!(f, "Invalid order status: {}", books_available)
+                write!(f, "Invalid number of books available: {}", books_available)
             }
             MapperError::DiscountPercentageOutOfBounds { percentage, .. } => {
                 write!(
@@ -110,9 +280,82 @@ impl fmt::Display for MapperError {
             MapperError::InvalidOrderStatus { status, .. } => {
                 write!(f, "Invalid order status: {}", status)
             }
+            MapperError::InvalidProductType { product_type, .. } => {
+                write!(f, "Invalid product type: {}", product_type)
+            }
             MapperError::OrderQuantityOutOfBounds { quantity, .. } => {
                 write!(f, "Invalid quantity for order: {}. Minimum is 1", quantity)
             }
+            MapperError::PageLimitOutOfBounds { limit, .. } => {
+                write!(
+                    f,
+                    "Invalid page limit: {}. Must be between 1 and {}",
+                    limit,
+                    dmodels::MAX_PAGE_LIMIT
+                )
+            }
+            MapperError::InvalidSort { value, field, .. } => {
+                write!(f, "Invalid {}: {}", field, value)
+            }
+            MapperError::InvalidRange { source, .. } => write!(f, "{}", source),
+            MapperError::InvalidSearchText { text, .. } => {
+                write!(
+                    f,
+                    "Invalid search text: '{}'. Must have between 1 and {} words",
+                    text,
+                    dmodels::MAX_SEARCH_WORDS
+                )
+            }
+            MapperError::InvalidMoney { value, source, .. } => {
+                write!(f, "Invalid amount '{}': {}", value, source)
+            }
+            MapperError::InvalidEmail { email, .. } => write!(f, "Invalid email: '{}'", email),
+            MapperError::InvalidETag { etag, .. } => {
+                write!(
+                    f,
+                    "Invalid ETag '{}', expected a list of quoted versions like \"3\"",
+                    etag
+                )
+            }
+            MapperError::InvalidUrl { url, .. } => {
+                write!(f, "Invalid URL: '{}', expected an http or https URL", url)
+            }
+            MapperError::InvalidEventType { event_type, .. } => {
+                write!(
+                    f,
+                    "Invalid event type: {}. Must be one of {}",
+                    event_type,
+                    dmodels::DomainEvent::EVENT_TYPES.join(", ")
+                )
+            }
+            MapperError::InvalidDeliveryStatus { status, .. } => {
+                write!(f, "Invalid delivery status: {}", status)
+            }
+        }
+    }
+}
+
+impl MapperError {
+    /// The name of the request field which could not be mapped
+    pub fn field(&self) -> &str {
+        match self {
+            MapperError::InvalidKsuid { field, .. } => field,
+            MapperError::BooksAvailableOutOfBound { .. } => "available",
+            MapperError::DiscountPercentageOutOfBounds { .. } => "percentage_discount",
+            MapperError::InvalidBookStatus { .. } => "status",
+            MapperError::InvalidOrderStatus { .. } => "status",
+            MapperError::InvalidProductType { .. } => "product_type",
+            MapperError::OrderQuantityOutOfBounds { .. } => "books.quantity",
+            MapperError::PageLimitOutOfBounds { .. } => "limit",
+            MapperError::InvalidSort { field, .. } => field,
+            MapperError::InvalidRange { field, .. } => field,
+            MapperError::InvalidSearchText { .. } => "q",
+            MapperError::InvalidMoney { field, .. } => field,
+            MapperError::InvalidEmail { .. } => "email",
+            MapperError::InvalidETag { .. } => "If-Match",
+            MapperError::InvalidUrl { .. } => "url",
+            MapperError::InvalidEventType { .. } => "event_types",
+            MapperError::InvalidDeliveryStatus { .. } => "status",
         }
     }
 }
@@ -125,7 +368,18 @@ impl Error for MapperError {
             MapperError::DiscountPercentageOutOfBounds { source, .. } => Some(source.as_ref()),
             MapperError::InvalidBookStatus { source, .. } => Some(source.as_ref()),
             MapperError::InvalidOrderStatus { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidProductType { source, .. } => Some(source.as_ref()),
             MapperError::OrderQuantityOutOfBounds { source, .. } => Some(source.as_ref()),
+            MapperError::PageLimitOutOfBounds { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidSort { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidRange { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidSearchText { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidMoney { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidEmail { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidETag { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidUrl { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidEventType { source, .. } => Some(source.as_ref()),
+            MapperError::InvalidDeliveryStatus { source, .. } => Some(source.as_ref()),
         }
     }
 }
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::Router;
+use axum::extract::{MatchedPath, Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use http::header::CONTENT_TYPE;
+use http::{Method, StatusCode};
+use tokio::net::TcpListener;
+use tracing::{error, info};
+
+use crate::adapters::metrics::prometheus_metrics::{self, PrometheusMetrics};
+
+/// The route label of the requests which matched no route, e.g. the 404s of unknown paths
+pub const UNMATCHED_ROUTE: &str = "unmatched";
+/// The method label of the requests with an extension method, e.g. PROPFIND
+pub const OTHER_METHOD: &str = "other";
+
+/// The method label of the request, the extension methods are chosen by the client
+/// and would create a time series per method, so they share one label
+fn method_label(method: &Method) -> &'static str {
+    match *method {
+        Method::GET => "GET",
+        Method::HEAD => "HEAD",
+        Method::POST => "POST",
+        Method::PUT => "PUT",
+        Method::PATCH => "PATCH",
+        Method::DELETE => "DELETE",
+        Method::OPTIONS => "OPTIONS",
+        Method::CONNECT => "CONNECT",
+        Method::TRACE => "TRACE",
+        _ => OTHER_METHOD,
+    }
+}
+
+/// Records the rate, the status and the duration of every request by its route.
+/// The route is the path template of the generated router, so the ids in the paths
+/// do not create a time series per resource.
+pub async fn metrics_middleware(
+    State(metrics): State<Arc<PrometheusMetrics>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = method_label(request.method());
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_owned());
+    let start = Instant::now();
+    let response = next.run(request).await;
+    metrics.observe_request(
+        method,
+        route.as_deref().unwrap_or(UNMATCHED_ROUTE),
+        response.status().as_u16(),
+        start.elapsed(),
+    );
+    response
+}
+
+async fn render_metrics(State(metrics): State<Arc<PrometheusMetrics>>) -> Response {
+    match metrics.render() {
+        Ok(body) => ([(CONTENT_TYPE, prometheus_metrics::CONTENT_TYPE)], body).into_response(),
+        Err(e) => {
+            error!("failed to render the metrics: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Builds the router of the admin server, which is not exposed with the API
+pub fn admin_router(metrics: Arc<PrometheusMetrics>) -> Router {
+    Router::new()
+        .route("/metrics", get(render_metrics))
+        .with_state(metrics)
+}
+
+/// Serves the metrics on the admin listener until the task is aborted
+pub async fn start_admin_server(listener: TcpListener, metrics: Arc<PrometheusMetrics>) {
+    if let Ok(address) = listener.local_addr() {
+        info!(%address, "serving the metrics");
+    }
+    if let Err(e) = axum::serve(listener, admin_router(metrics)).await {
+        error!("the admin server failed: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{Body, to_bytes};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_extension_methods_share_one_label() {
+        // Arrange
+        let metrics = PrometheusMetrics::new(None);
+        let router = Router::new()
+            .route("/api/v1/books", get(|| async { "[]" }))
+            .layer(axum::middleware::from_fn_with_state(
+                metrics.clone(),
+                metrics_middleware,
+            ));
+
+        // Act
+        for method in ["GET", "PROPFIND", "X-RANDOM-1", "X-RANDOM-2"] {
+            let request = Request::builder()
+                .method(method)
+                .uri("/api/v1/unknown")
+                .body(Body::empty())
+                .unwrap();
+            router.clone().oneshot(request).await.unwrap();
+        }
+
+        // Assert
+        let body = metrics.render().unwrap();
+        assert!(
+            body.contains(
+                "http_requests_total{method=\"GET\",route=\"unmatched\",status=\"404\"} 1"
+            )
+        );
+        assert!(body.contains(
+            "http_requests_total{method=\"other\",route=\"unmatched\",status=\"404\"} 3"
+        ));
+        assert!(!body.contains("PROPFIND"));
+    }
+
+    #[tokio::test]
+    async fn test_admin_router_renders_metrics() {
+        // Arrange
+        let metrics = PrometheusMetrics::new(None);
+        metrics.observe_request(
+            "GET",
+            "/api/v1/health/liveness",
+            200,
+            std::time::Duration::from_millis(1),
+        );
+        let request = Request::get("/metrics").body(Body::empty()).unwrap();
+
+        // Act
+        let response = admin_router(metrics).oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[CONTENT_TYPE],
+            prometheus_metrics::CONTENT_TYPE
+        );
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains(
+            "http_requests_total{method=\"GET\",route=\"/api/v1/health/liveness\",status=\"200\"} 1"
+        ));
+    }
+}
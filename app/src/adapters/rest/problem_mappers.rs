@@ -0,0 +1,331 @@
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use http::StatusCode;
+use http::header::CONTENT_TYPE;
+use openapi::models as rmodels;
+use svix_ksuid::{Ksuid, KsuidLike};
+use tracing::{error, info};
+
+use super::mapper_errors::MapperError;
+use super::telemetry::current_request_id;
+use crate::domain::error::DomainError;
+use crate::domain::models as dmodels;
+
+const PROBLEM_TYPE_BASE: &str = "https://gocrud-book-store-service.grntlrduck.cloud/problems/";
+
+/// The correlation id of the problem is the request id, so the problem can be found in the logs
+/// and traces of the request. Outside of a request a new id is generated.
+fn new_problem(
+    status: u16,
+    problem_type: &str,
+    title: &str,
+    detail: String,
+    field: Option<String>,
+) -> rmodels::Problem {
+    rmodels::Problem {
+        r#type: format!("{}{}", PROBLEM_TYPE_BASE, problem_type),
+        title: String::from(title),
+        status: status as i32,
+        detail: Some(detail),
+        field,
+        correlation_id: Some(
+            current_request_id().unwrap_or_else(|| Ksuid::new(None, None).to_string()),
+        ),
+    }
+}
+
+/// Renders the problem as application/problem+json response with its status,
+/// for the middlewares which answer without a generated handler
+pub fn problem_response(problem: rmodels::Problem) -> Response {
+    let status =
+        StatusCode::from_u16(problem.status as u16).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    (
+        status,
+        [(CONTENT_TYPE, "application/problem+json")],
+        Json(problem),
+    )
+        .into_response()
+}
+
+/// Maps a request which could not be mapped to the domain to a 400 problem
+pub fn map_mapper_error_to_problem(e: &MapperError) -> rmodels::Problem {
+    let (problem_type, title) = match e {
+        MapperError::InvalidKsuid { .. } => ("invalid-id", "Invalid id"),
+        MapperError::InvalidBookStatus { .. }
+        | MapperError::InvalidOrderStatus { .. }
+        | MapperError::InvalidDeliveryStatus { .. } => ("invalid-status", "Invalid status"),
+        MapperError::InvalidProductType { .. } => ("invalid-product-type", "Invalid product type"),
+        MapperError::BooksAvailableOutOfBound { .. }
+        | MapperError::DiscountPercentageOutOfBounds { .. }
+        | MapperError::OrderQuantityOutOfBounds { .. }
+        | MapperError::PageLimitOutOfBounds { .. } => {
+            ("value-out-of-bounds", "Value out of bounds")
+        }
+        MapperError::InvalidSort { .. } => ("invalid-sort", "Invalid sort"),
+        MapperError::InvalidRange { .. } => ("invalid-range", "Invalid range"),
+        MapperError::InvalidSearchText { .. } => ("invalid-search-text", "Invalid search text"),
+        MapperError::InvalidMoney { .. } => ("invalid-amount", "Invalid amount"),
+        MapperError::InvalidEmail { .. } => ("invalid-email", "Invalid email"),
+        MapperError::InvalidETag { .. } => ("invalid-etag", "Invalid ETag"),
+        MapperError::InvalidUrl { .. } => ("invalid-url", "Invalid URL"),
+        MapperError::InvalidEventType { .. } => ("invalid-event-type", "Invalid event type"),
+    };
+    let problem = new_problem(
+        400,
+        problem_type,
+        title,
+        e.to_string(),
+        Some(String::from(e.field())),
+    );
+    info!(
+        correlation_id = problem.correlation_id.as_deref(),
+        "rejected invalid request: {}", e
+    );
+    problem
+}
+
+/// Maps a DomainError to a problem with the given status.
+/// Server errors never expose the underlying error, it is only logged with the correlation id.
+pub fn map_domain_error_to_problem(status: u16, e: &DomainError) -> rmodels::Problem {
+    if status >= 500 {
+        let problem = new_problem(
+            status,
+            "internal-error",
+            "Internal server error",
+            String::from("The request could not be processed, please report the correlation id"),
+            None,
+        );
+        error!(
+            correlation_id = problem.correlation_id.as_deref(),
+            "failed to process request: {}", e
+        );
+        return problem;
+    }
+    let problem = match e {
+        DomainError::NotFound { source, .. } => new_problem(
+            status,
+            "not-found",
+            "Resource not found",
+            source.to_string(),
+            None,
+        ),
+        DomainError::BusinessConstraintViolation { message, .. } => new_problem(
+            status,
+            "constraint-violation",
+            "Business constraint violated",
+            message.clone(),
+            None,
+        ),
+        DomainError::VersionConflict { source, .. } => new_problem(
+            status,
+            "version-conflict",
+            "Version conflict",
+            format!(
+                "{}, load the current version and retry the update with its ETag",
+                source
+            ),
+            None,
+        ),
+        DomainError::FatalDBFailure { .. } | DomainError::PublishFailure { .. } => new_problem(
+            status,
+            "internal-error",
+            "Internal server error",
+            String::from("The request could not be processed, please report the correlation id"),
+            None,
+        ),
+    };
+    info!(
+        correlation_id = problem.correlation_id.as_deref(),
+        "rejected request: {}", e
+    );
+    problem
+}
+
+/// Maps a request of an API key lacking the required role to a 403 problem
+pub fn map_forbidden_to_problem(claims: &dmodels::ClaimsDomain) -> rmodels::Problem {
+    let problem = new_problem(
+        403,
+        "forbidden",
+        "Forbidden",
+        format!(
+            "The api key {} lacks the role required for this operation",
+            claims.key_name
+        ),
+        None,
+    );
+    info!(
+        correlation_id = problem.correlation_id.as_deref(),
+        "rejected request of api key {}", claims.key_name
+    );
+    problem
+}
+
+/// Maps a request without a valid API key to a 401 problem
+pub fn map_unauthorized_to_problem(api_key_given: bool) -> rmodels::Problem {
+    let detail = if api_key_given {
+        "The api key is unknown or not valid at the moment"
+    } else {
+        "The request requires an api key in the X-API-KEY header"
+    };
+    let problem = new_problem(
+        401,
+        "unauthorized",
+        "Unauthorized",
+        String::from(detail),
+        None,
+    );
+    info!(
+        correlation_id = problem.correlation_id.as_deref(),
+        "rejected unauthenticated request"
+    );
+    problem
+}
+
+/// Maps a request whose API key could not be looked up to a 503 problem,
+/// the key of the caller is not at fault, e.g. the keystore database is unavailable
+pub fn map_authentication_unavailable_to_problem() -> rmodels::Problem {
+    let problem = new_problem(
+        503,
+        "authentication-unavailable",
+        "Authentication unavailable",
+        String::from("The api key could not be verified, retry later"),
+        None,
+    );
+    error!(
+        correlation_id = problem.correlation_id.as_deref(),
+        "rejected request as the api key could not be verified"
+    );
+    problem
+}
+
+/// Maps a request with an invalid Idempotency-Key header to a 400 problem
+pub fn map_invalid_idempotency_key_to_problem() -> rmodels::Problem {
+    let problem = new_problem(
+        400,
+        "invalid-idempotency-key",
+        "Invalid idempotency key",
+        String::from("The Idempotency-Key must have 1 to 255 visible ASCII characters"),
+        Some(String::from("Idempotency-Key")),
+    );
+    info!(
+        correlation_id = problem.correlation_id.as_deref(),
+        "rejected request with invalid idempotency key"
+    );
+    problem
+}
+
+/// Maps the reuse of an Idempotency-Key for a different request to a 422 problem
+pub fn map_idempotency_key_reused_to_problem(key: &str) -> rmodels::Problem {
+    let problem = new_problem(
+        422,
+        "idempotency-key-reused",
+        "Idempotency key reused",
+        format!(
+            "The idempotency key {} was used for a different request, use a new key for a new request",
+            key
+        ),
+        Some(String::from("Idempotency-Key")),
+    );
+    info!(
+        correlation_id = problem.correlation_id.as_deref(),
+        "rejected reuse of idempotency key {}", key
+    );
+    problem
+}
+
+/// Maps a retry while the first request with the Idempotency-Key is processed to a 409 problem
+pub fn map_idempotency_key_in_progress_to_problem(key: &str) -> rmodels::Problem {
+    let problem = new_problem(
+        409,
+        "idempotency-key-in-progress",
+        "Idempotency key in progress",
+        format!(
+            "The request with the idempotency key {} is still processed, retry later",
+            key
+        ),
+        Some(String::from("Idempotency-Key")),
+    );
+    info!(
+        correlation_id = problem.correlation_id.as_deref(),
+        "rejected concurrent retry of idempotency key {}", key
+    );
+    problem
+}
+
+/// Maps a request with a body exceeding the limit to a 413 problem
+pub fn map_payload_too_large_to_problem(limit: usize) -> rmodels::Problem {
+    let problem = new_problem(
+        413,
+        "payload-too-large",
+        "Payload too large",
+        format!("The request body exceeds the limit of {} bytes", limit),
+        None,
+    );
+    info!(
+        correlation_id = problem.correlation_id.as_deref(),
+        "rejected request exceeding the body limit"
+    );
+    problem
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::rest::domain_mappers::map_string_to_ksuid;
+    use crate::domain::error::BookNotFoundError;
+
+    #[test]
+    fn test_map_mapper_error_to_problem() {
+        // Arrange
+        let e = map_string_to_ksuid("invalid-id", "book_id").unwrap_err();
+
+        // Act
+        let problem = map_mapper_error_to_problem(&e);
+
+        // Assert
+        assert_eq!(
+            problem.r#type,
+            "https://gocrud-book-store-service.grntlrduck.cloud/problems/invalid-id"
+        );
+        assert_eq!(problem.status, 400);
+        assert_eq!(problem.field, Some(String::from("book_id")));
+        assert_eq!(
+            problem.detail,
+            Some(String::from("Invalid KSUID format: invalid-id"))
+        );
+        assert!(problem.correlation_id.is_some());
+    }
+
+    #[test]
+    fn test_map_not_found_to_problem() {
+        // Arrange
+        let e = DomainError::NotFound {
+            id: String::from("123"),
+            source: Box::new(BookNotFoundError(String::from("123"))),
+        };
+
+        // Act
+        let problem = map_domain_error_to_problem(404, &e);
+
+        // Assert
+        assert_eq!(problem.status, 404);
+        assert_eq!(problem.title, "Resource not found");
+        assert_eq!(problem.field, None);
+    }
+
+    #[test]
+    fn test_map_server_error_to_problem_hides_details() {
+        // Arrange
+        let e = DomainError::FatalDBFailure {
+            message: String::from("connection refused"),
+            source: Box::new(BookNotFoundError(String::from("123"))),
+        };
+
+        // Act
+        let problem = map_domain_error_to_problem(500, &e);
+
+        // Assert
+        assert_eq!(problem.status, 500);
+        assert!(!problem.detail.unwrap().contains("connection refused"));
+    }
+}
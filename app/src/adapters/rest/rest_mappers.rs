@@ -1,3 +1,5 @@
+use crate::domain::error::DomainError;
+use crate::domain::exchange_service::ExchangeRates;
 use crate::domain::models as dmodels;
 use openapi::models as rmodels;
 
@@ -12,6 +14,11 @@ pub fn map_address_to_rest(address: dmodels::AddressDomain) -> rmodels::Address
     }
 }
 
+/// Maps the version of an item to its strong ETag, e.g. "3"
+pub fn map_version_to_etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
 pub fn map_author_to_rest(author: dmodels::AuthorDomain) -> rmodels::Author {
     rmodels::Author {
         id: author.id.to_string(),
@@ -59,12 +66,83 @@ pub fn map_book_to_rest(book: dmodels::BookDomain) -> rmodels::Book {
         edition: book.edition,
         genres,
         discounts,
-        price: book.price,
+        price: book.price.amount().to_string(),
+        currency: book.price.currency().to_string(),
+        product_type: book.product_type.to_string(),
+        exchange_rate: None,
         available: book.available,
         status: book.status.to_string(),
     }
 }
 
+/// Maps the book with the price converted to the target currency of the rates, if requested
+pub fn map_converted_book_to_rest(
+    book: dmodels::BookDomain,
+    rates: Option<&ExchangeRates>,
+) -> Result<rmodels::Book, DomainError> {
+    let (book, quote) = match rates {
+        Some(rates) => rates.convert_book(book)?,
+        None => (book, None),
+    };
+    let mut model = map_book_to_rest(book);
+    model.exchange_rate = quote.map(map_exchange_quote_to_rest);
+    Ok(model)
+}
+
+pub fn map_book_page_to_rest(
+    page: dmodels::PageDomain<dmodels::BookDomain>,
+    rates: Option<&ExchangeRates>,
+) -> Result<rmodels::BookPage, DomainError> {
+    Ok(rmodels::BookPage {
+        items: page
+            .items
+            .into_iter()
+            .map(|book| map_converted_book_to_rest(book, rates))
+            .collect::<Result<Vec<rmodels::Book>, DomainError>>()?,
+        next_cursor: page.next_cursor.map(|c| c.to_string()),
+    })
+}
+
+pub fn map_book_search_hits_to_rest(
+    hits: Vec<dmodels::BookSearchHitDomain>,
+    rates: Option<&ExchangeRates>,
+) -> Result<rmodels::BookSearchResult, DomainError> {
+    Ok(rmodels::BookSearchResult {
+        items: hits
+            .into_iter()
+            .map(|hit| {
+                Ok(rmodels::BookSearchHit {
+                    book: map_converted_book_to_rest(hit.book, rates)?,
+                    rank: hit.rank,
+                    snippet: hit.snippet,
+                })
+            })
+            .collect::<Result<Vec<rmodels::BookSearchHit>, DomainError>>()?,
+    })
+}
+
+pub fn map_customer_to_rest(customer: dmodels::CustomerDomain) -> rmodels::Customer {
+    rmodels::Customer {
+        id: customer.id.to_string(),
+        name: customer.name,
+        email: customer.email,
+        addresses: customer
+            .addresses
+            .into_iter()
+            .map(map_address_to_rest)
+            .collect(),
+    }
+}
+
+pub fn map_exchange_quote_to_rest(quote: dmodels::ExchangeQuoteDomain) -> rmodels::ExchangeRate {
+    rmodels::ExchangeRate {
+        source_currency: quote.source.to_string(),
+        currency: quote.target.to_string(),
+        rate: quote.rate.normalize().to_string(),
+        updated_at: quote.updated_at,
+    }
+}
+
 pub fn map_discount_code_to_rest(discount: dmodels::DiscountCodeDomain) -> rmodels::DiscountCode {
     rmodels::DiscountCode {
         id: discount.id.to_string(),
@@ -82,6 +160,28 @@ pub fn map_genre_to_rest(genre: dmodels::GenereDomain) -> rmodels::Genre {
     }
 }
 
+pub fn map_readiness_to_rest(readiness: dmodels::ReadinessDomain) -> rmodels::HealthCheckResponse {
+    let status = if readiness.is_ready() {
+        "ready"
+    } else {
+        "unavailable"
+    };
+    rmodels::HealthCheckResponse {
+        status: Some(String::from(status)),
+        checks: Some(
+            readiness
+                .checks
+                .into_iter()
+                .map(|c| rmodels::HealthCheck {
+                    name: c.name,
+                    status: c.status.to_string(),
+                    detail: c.detail,
+                })
+                .collect(),
+        ),
+    }
+}
+
 pub fn map_inventory_to_rest(inventory: dmodels::InventoryDomain) -> rmodels::Inventory {
     rmodels::Inventory {
         books_available: inventory.books_available,
@@ -113,6 +213,109 @@ pub fn map_order_to_rest(order: dmodels::OrderDomain) -> rmodels::Order {
         billing_address: map_address_to_rest(order.billing_address.clone()),
         shipping_address_override: address_override,
         status: order.status.to_string(),
+        placed_at: order.placed_at,
+        shipped_at: order.shipped_at,
+        delivered_at: order.delivered_at,
+        canceled_at: order.canceled_at,
+        discount_codes: if order.discount_codes.is_empty() {
+            None
+        } else {
+            Some(order.discount_codes)
+        },
+        lines: order
+            .pricing
+            .lines
+            .into_iter()
+            .map(|l| rmodels::OrderLine {
+                book_id: l.book_id.to_string(),
+                quantity: l.quantity,
+                unit_price: l.unit_price.amount().to_string(),
+                discount_code: l.discount_code,
+                discount_percentage: l.discount_percentage,
+                discount_amount: l.discount_amount.amount().to_string(),
+                line_total: l.line_total.amount().to_string(),
+                tax_rate: l.tax_rate.normalize().to_string(),
+                tax_amount: l.tax_amount.amount().to_string(),
+            })
+            .collect(),
+        currency: order.pricing.total.currency().to_string(),
+        subtotal: order.pricing.subtotal.amount().to_string(),
+        discount_total: order.pricing.discount_total.amount().to_string(),
+        total: order.pricing.total.amount().to_string(),
+        prices_include_tax: order.pricing.prices_include_tax,
+        net_total: order.pricing.net_total.amount().to_string(),
+        tax_total: order.pricing.tax_total.amount().to_string(),
+        tax_lines: order
+            .pricing
+            .tax_lines
+            .into_iter()
+            .map(|t| rmodels::TaxLine {
+                rate: t.rate.normalize().to_string(),
+                net: t.net.amount().to_string(),
+                tax: t.tax.amount().to_string(),
+            })
+            .collect(),
+        exchange_rate: order.pricing.exchange_rate.map(map_exchange_quote_to_rest),
+    }
+}
+
+pub fn map_order_page_to_rest(
+    page: dmodels::PageDomain<dmodels::OrderDomain>,
+) -> rmodels::OrderPage {
+    rmodels::OrderPage {
+        items: page.items.into_iter().map(map_order_to_rest).collect(),
+        next_cursor: page.next_cursor.map(|c| c.to_string()),
+    }
+}
+
+pub fn map_order_history_to_rest(
+    order_id: &str,
+    events: Vec<dmodels::OrderStatusEventDomain>,
+) -> rmodels::OrderHistory {
+    rmodels::OrderHistory {
+        order_id: String::from(order_id),
+        events: events
+            .into_iter()
+            .map(|e| rmodels::OrderStatusEvent {
+                from_status: e.from.map(|s| s.to_string()),
+                to_status: e.to.to_string(),
+                actor: e.actor,
+                reason: e.reason,
+                occurred_at: e.occurred_at,
+            })
+            .collect(),
+    }
+}
+
+pub fn map_webhook_to_rest(webhook: dmodels::WebhookDomain) -> rmodels::Webhook {
+    rmodels::Webhook {
+        id: webhook.id.to_string(),
+        url: webhook.url,
+        event_types: webhook.event_types,
+    }
+}
+
+pub fn map_webhook_delivery_page_to_rest(
+    page: dmodels::PageDomain<dmodels::WebhookDeliveryDomain>,
+) -> rmodels::WebhookDeliveryPage {
+    rmodels::WebhookDeliveryPage {
+        items: page
+            .items
+            .into_iter()
+            .map(|d| rmodels::WebhookDelivery {
+                id: d.id.to_string(),
+                event_id: d.event_id,
+                event_type: d.event_type,
+                status: d.status.to_string(),
+                attempts: d.attempts,
+                last_status_code: d.last_status_code.map(i32::from),
+                last_error: d.last_error,
+                next_attempt_at: d.next_attempt_at,
+                created_at: d.created_at,
+                delivered_at: d.delivered_at,
+            })
+            .collect(),
+        next_cursor: page.next_cursor.map(|c| c.to_string()),
     }
 }
 
@@ -152,6 +355,14 @@ mod tests {
                 country: String::from("Other Country"),
             },
             status: dmodels::OrderStatus::Placed,
+            placed_at: Utc::now(),
+            shipped_at: None,
+            delivered_at: None,
+            canceled_at: None,
+            discount_codes: vec![],
+            currency: None,
+            pricing: dmodels::OrderPricingDomain::default(),
+            version: 1,
         };
 
         // Act
@@ -193,6 +404,14 @@ mod tests {
             billing_address: billing_address.clone(),
             shipping_address: billing_address,
             status: dmodels::OrderStatus::Placed,
+            placed_at: Utc::now(),
+            shipped_at: None,
+            delivered_at: None,
+            canceled_at: None,
+            discount_codes: vec![],
+            currency: None,
+            pricing: dmodels::OrderPricingDomain::default(),
+            version: 1,
         };
 
         // Act
@@ -237,6 +456,7 @@ mod tests {
             last_name: String::from("Doe"),
             date_of_birth: NaiveDate::from_ymd_opt(2024, 12, 24).unwrap(),
             date_of_death: None,
+            version: 1,
         };
 
         let genre = dmodels::GenereDomain {
@@ -261,10 +481,12 @@ mod tests {
             series: Some(String::from("Test Series")),
             genres: Some(vec![genre]),
             edition: 1,
-            price: 29.99,
+            price: dmodels::Money::parse("29.9", dmodels::Currency::USD).unwrap(),
+            product_type: dmodels::ProductType::Print,
             discounts: Some(vec![discount]),
             available: 10,
             status: dmodels::BookStatus::Available,
+            version: 1,
         };
 
         // Act
@@ -272,6 +494,8 @@ mod tests {
 
         // Assert
         assert_eq!(result.title, "Test Book");
+        assert_eq!(result.price, "29.90");
+        assert_eq!(result.currency, "USD");
         assert_eq!(result.authors.len(), 1);
         assert_eq!(result.authors[0].first_name, "John");
         assert!(result.genres.is_some());
@@ -295,6 +519,7 @@ mod tests {
             last_name: String::from("Doe"),
             date_of_birth: NaiveDate::from_ymd_opt(2024, 12, 9).unwrap(),
             date_of_death: None,
+            version: 1,
         };
 
         let book = dmodels::BookDomain {
@@ -306,10 +531,12 @@ mod tests {
             series: None,
             genres: None,
             edition: 1,
-            price: 29.99,
+            price: dmodels::Money::parse("29.9", dmodels::Currency::USD).unwrap(),
+            product_type: dmodels::ProductType::Print,
             discounts: None,
             available: 10,
             status: dmodels::BookStatus::Available,
+            version: 1,
         };
 
         // Act
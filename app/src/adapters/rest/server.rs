@@ -1,56 +1,149 @@
 use async_trait::async_trait;
+use axum::Router;
 use axum::http::header::HeaderMap;
 use axum_extra::extract::{CookieJar, Host};
 use http::Method;
-use openapi::apis::{ApiKeyAuthHeader, author, book, discount, genre, health, store};
+use openapi::apis::{
+    ApiKeyAuthHeader, author, book, customer, discount, genre, health, store, webhook,
+};
 use openapi::models;
-use std::str::FromStr;
 use std::sync::Arc;
-use svix_ksuid::Ksuid;
+use std::time::Duration;
 use tokio::net::TcpListener;
 use tokio::signal;
-use tracing_subscriber;
+use tokio_rustls::TlsAcceptor;
+use tracing::{info, instrument, warn};
 
+use crate::adapters::metrics::prometheus_metrics::PrometheusMetrics;
 use crate::domain;
 
+use super::authentication;
 use super::domain_mappers::*;
+use super::idempotency;
+use super::metrics;
+use super::problem_mappers::*;
 use super::rest_mappers::*;
+use super::telemetry;
+use super::tls::TlsListener;
 
-/// TODO: implement function bodies
-/// here will come the implementation of the API handler
+/// Implements the API handlers of the generated openapi crate on top of the domain services
 pub struct BookStoreServer {
     order_service: Arc<dyn domain::store::OrderHandler + Send + Sync>,
     book_service: Arc<dyn domain::store::BookHandler + Send + Sync>,
+    customer_service: Arc<dyn domain::store::CustomerHandler + Send + Sync>,
+    auth_service: Arc<domain::auth_service::AuthService>,
+    health_service: Arc<domain::health_service::HealthService>,
+    exchange_service: Arc<domain::exchange_service::ExchangeService>,
+    webhook_service: Arc<dyn domain::store::WebhookHandler + Send + Sync>,
 }
 
-impl openapi::apis::ErrorHandler for BookStoreServer {}
-
-pub async fn start_server(addr: &str) {
-    // initialize tracing
-    tracing_subscriber::fmt::init();
+impl BookStoreServer {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        book_repository: Arc<dyn domain::store::BookHandler + Send + Sync>,
+        order_repository: Arc<dyn domain::store::OrderHandler + Send + Sync>,
+        customer_repository: Arc<dyn domain::store::CustomerHandler + Send + Sync>,
+        api_key_repository: Arc<dyn domain::store::ApiKeyHandler + Send + Sync>,
+        health_repository: Arc<dyn domain::store::HealthHandler + Send + Sync>,
+        exchange_service: Arc<domain::exchange_service::ExchangeService>,
+        tax_service: Arc<domain::tax_service::TaxService>,
+        webhook_repository: Arc<dyn domain::store::WebhookHandler + Send + Sync>,
+        order_metrics: Arc<dyn domain::store::OrderMetrics + Send + Sync>,
+    ) -> Arc<Self> {
+        Arc::new(BookStoreServer {
+            order_service: domain::order_service::OrderService::new(
+                order_repository,
+                customer_repository.clone(),
+                book_repository.clone(),
+                exchange_service.clone(),
+                tax_service,
+                order_metrics,
+            ),
+            book_service: domain::book_service::BookService::new(book_repository),
+            customer_service: domain::customer_service::CustomerService::new(customer_repository),
+            auth_service: domain::auth_service::AuthService::new(api_key_repository),
+            health_service: domain::health_service::HealthService::new(health_repository),
+            exchange_service,
+            webhook_service: domain::webhook_service::WebhookService::new(webhook_repository),
+        })
+    }
 
-    let order_service = domain::order_service::OrderService::new(String::from("TODO"));
+    /// Loads the exchange rates to convert the prices to the requested currency,
+    /// without a requested currency the prices are not converted
+    async fn exchange_rates(
+        &self,
+        currency: Option<domain::models::Currency>,
+    ) -> Result<Option<domain::exchange_service::ExchangeRates>, domain::error::DomainError> {
+        match currency {
+            Some(currency) => self.exchange_service.rates_to(currency).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
 
-    let book_service = domain::book_service::BookService::new(String::from("TODO"));
+impl openapi::apis::ErrorHandler for BookStoreServer {}
 
+/// Builds the router of the API with the middleware layers
+pub fn router(
+    server: Arc<BookStoreServer>,
+    idempotency_service: Arc<domain::idempotency_service::IdempotencyService>,
+    metrics: Arc<PrometheusMetrics>,
+) -> Router {
     // Init Axum router
-    let app = openapi::server::new(Arc::new(BookStoreServer {
-        order_service,
-        book_service,
-    }));
+    let auth_service = server.auth_service.clone();
+    let app = openapi::server::new(server);
 
-    // Add layers to the router
-    //let app = app.layer(...);
+    // Add layers to the router, the last one is the outermost,
+    // so the metrics include the replayed responses and everything runs in the request span
+    app.layer(axum::middleware::from_fn_with_state(
+        idempotency_service,
+        idempotency::idempotency_middleware,
+    ))
+    .layer(axum::middleware::from_fn_with_state(
+        auth_service,
+        authentication::authentication_middleware,
+    ))
+    .layer(axum::middleware::from_fn_with_state(
+        metrics,
+        metrics::metrics_middleware,
+    ))
+    .layer(axum::middleware::from_fn(telemetry::telemetry_middleware))
+}
+
+pub async fn start_server(
+    addr: &str,
+    shutdown_drain: Duration,
+    server: Arc<BookStoreServer>,
+    idempotency_service: Arc<domain::idempotency_service::IdempotencyService>,
+    metrics: Arc<PrometheusMetrics>,
+    tls: Option<TlsAcceptor>,
+) -> std::io::Result<()> {
+    let health_service = server.health_service.clone();
+    let app = router(server, idempotency_service, metrics);
 
     // Run the server with graceful shutdown
-    let listener = TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await
-        .unwrap();
+    let listener = TcpListener::bind(addr).await?;
+    match tls {
+        Some(acceptor) => {
+            info!(address = addr, "serving HTTPS");
+            axum::serve(TlsListener::new(listener, acceptor)?, app)
+                .with_graceful_shutdown(shutdown_signal(health_service, shutdown_drain))
+                .await
+        }
+        None => {
+            axum::serve(listener, app)
+                .with_graceful_shutdown(shutdown_signal(health_service, shutdown_drain))
+                .await
+        }
+    }
 }
 
-async fn shutdown_signal() {
+/// Resolves on ctrl + c or SIGTERM once the drain period elapsed.
+/// The readiness check fails during the drain period, while the requests are still served.
+async fn shutdown_signal(
+    health_service: Arc<domain::health_service::HealthService>,
+    drain: Duration,
+) {
     let ctrl_c = async {
         signal::ctrl_c()
             .await
@@ -69,39 +162,75 @@ async fn shutdown_signal() {
     let terminate = std::future::pending::<()>();
 
     tokio::select! {
-        // TODO:: handle shut down with DB connection
-        _ = ctrl_c => println!("Received ctrl + c"),
-        _ = terminate => println!("Received terminate"),
+        _ = ctrl_c => info!("received ctrl + c"),
+        _ = terminate => info!("received terminate"),
+    }
+
+    health_service.begin_shutdown();
+    if !drain.is_zero() {
+        info!(?drain, "draining before shutdown");
+        tokio::time::sleep(drain).await;
     }
 }
 
 #[allow(unused_variables)]
 #[async_trait]
 impl health::Health for BookStoreServer {
-    async fn get_readiness(
+    #[instrument(name = "BookStoreServer::get_liveness", skip_all)]
+    async fn get_liveness(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
-    ) -> Result<health::GetReadinessResponse, ()> {
-        Ok(health::GetReadinessResponse::Status200_Successful(
+    ) -> Result<health::GetLivenessResponse, ()> {
+        Ok(health::GetLivenessResponse::Status200_Successful(
             models::HealthCheckResponse {
                 status: Some(String::from("serving")),
+                checks: None,
             },
         ))
     }
+
+    #[instrument(name = "BookStoreServer::get_readiness", skip_all)]
+    async fn get_readiness(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+    ) -> Result<health::GetReadinessResponse, ()> {
+        let readiness = self.health_service.readiness().await;
+        if readiness.is_ready() {
+            Ok(health::GetReadinessResponse::Status200_Successful(
+                map_readiness_to_rest(readiness),
+            ))
+        } else {
+            warn!(?readiness.checks, "service is not ready");
+            Ok(health::GetReadinessResponse::Status503_ServiceUnavailable(
+                map_readiness_to_rest(readiness),
+            ))
+        }
+    }
 }
 
 #[allow(unused_variables)]
 #[async_trait]
 impl author::Author for BookStoreServer {
+    type Claims = domain::models::ClaimsDomain;
+
+    #[instrument(name = "BookStoreServer::add_author", skip_all)]
     async fn add_author(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         body: &models::NewAuthor,
     ) -> Result<author::AddAuthorResponse, ()> {
+        if !claims.can_manage_catalog() {
+            return Ok(author::AddAuthorResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
         let domain = map_new_author_to_domain(body);
         match self.book_service.create_author(domain).await {
             Ok(result) => {
@@ -110,76 +239,144 @@ impl author::Author for BookStoreServer {
                     model,
                 ))
             }
-            Err(_) => Ok(author::AddAuthorResponse::Status500_ServerError),
+            Err(e) => Ok(author::AddAuthorResponse::Status500_ServerError(
+                map_domain_error_to_problem(500, &e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::delete_author", skip_all)]
     async fn delete_author(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteAuthorPathParams,
     ) -> Result<author::DeleteAuthorResponse, ()> {
-        match Ksuid::from_str(&path_params.author_id) {
+        if !claims.can_manage_catalog() {
+            return Ok(author::DeleteAuthorResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.author_id, "author_id") {
             Ok(id) => match self.book_service.delte_author_by_id(id).await {
                 Ok(author) => Ok(author::DeleteAuthorResponse::Status200_SuccessfullyDeleted),
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(author::DeleteAuthorResponse::Status404_AuthorNotFound)
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(author::DeleteAuthorResponse::Status404_AuthorNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
+                }
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                    Ok(author::DeleteAuthorResponse::Status422_AuthorHasBooks(
+                        map_domain_error_to_problem(422, &e),
+                    ))
                 }
-                Err(_) => Ok(author::DeleteAuthorResponse::Status500_ServerError),
+                Err(e) => Ok(author::DeleteAuthorResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(author::DeleteAuthorResponse::Status400_InvalidAuthorIdValue),
+            Err(e) => Ok(
+                author::DeleteAuthorResponse::Status400_InvalidAuthorIdValue(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
         }
     }
 
+    #[instrument(name = "BookStoreServer::get_author_by_id", skip_all)]
     async fn get_author_by_id(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::GetAuthorByIdPathParams,
     ) -> Result<author::GetAuthorByIdResponse, ()> {
-        match Ksuid::from_str(&path_params.author_id) {
+        if !claims.can_read_catalog() {
+            return Ok(author::GetAuthorByIdResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.author_id, "author_id") {
             Ok(id) => match self.book_service.get_author_by_id(id).await {
                 Ok(author) => {
-                    let model = map_author_to_rest(author);
-                    Ok(author::GetAuthorByIdResponse::Status200_SuccessfulOperation(model))
+                    let etag = map_version_to_etag(author.version);
+                    Ok(
+                        author::GetAuthorByIdResponse::Status200_SuccessfulOperation {
+                            body: map_author_to_rest(author),
+                            etag: Some(etag),
+                        },
+                    )
                 }
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(author::GetAuthorByIdResponse::Status404_AuthorNotFound)
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(author::GetAuthorByIdResponse::Status404_AuthorNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
                 }
-                Err(_) => Ok(author::GetAuthorByIdResponse::Status500_ServerError),
+                Err(e) => Ok(author::GetAuthorByIdResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(author::GetAuthorByIdResponse::Status400_InvalidParameters),
+            Err(e) => Ok(author::GetAuthorByIdResponse::Status400_InvalidParameters(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::update_author", skip_all)]
     async fn update_author(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::UpdateAuthorHeaderParams,
         path_params: &models::UpdateAuthorPathParams,
         body: &models::AuthorProperties,
     ) -> Result<author::UpdateAuthorResponse, ()> {
-        match map_author_update_props_to_domain(&path_params.author_id, body) {
+        if !claims.can_manage_catalog() {
+            return Ok(author::UpdateAuthorResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_author_update_props_to_domain(
+            &path_params.author_id,
+            &header_params.if_match,
+            body,
+        ) {
             Ok(props) => match self.book_service.update_author(props).await {
                 Ok(author) => {
-                    let model = map_author_to_rest(author);
-                    Ok(author::UpdateAuthorResponse::Status200_SuccessfulOperation(
-                        model,
+                    let etag = map_version_to_etag(author.version);
+                    Ok(
+                        author::UpdateAuthorResponse::Status200_SuccessfulOperation {
+                            body: map_author_to_rest(author),
+                            etag: Some(etag),
+                        },
+                    )
+                }
+                Err(e @ domain::error::DomainError::VersionConflict { .. }) => {
+                    Ok(author::UpdateAuthorResponse::Status412_PreconditionFailed(
+                        map_domain_error_to_problem(412, &e),
                     ))
                 }
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(author::UpdateAuthorResponse::Status404_AuthorNotFound)
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(author::UpdateAuthorResponse::Status404_AuthorNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
                 }
-                Err(domain::error::DomainError::BusinessConstraintViolation { .. }) => {
-                    Ok(author::UpdateAuthorResponse::Status422_ValidationException)
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                    Ok(author::UpdateAuthorResponse::Status422_ValidationException(
+                        map_domain_error_to_problem(422, &e),
+                    ))
                 }
-                Err(_) => Ok(author::UpdateAuthorResponse::Status500_ServerError),
+                Err(e) => Ok(author::UpdateAuthorResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(author::UpdateAuthorResponse::Status400_InvalidParameters),
+            Err(e) => Ok(author::UpdateAuthorResponse::Status400_InvalidParameters(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 }
@@ -187,153 +384,663 @@ impl author::Author for BookStoreServer {
 #[allow(unused_variables)]
 #[async_trait]
 impl book::Book for BookStoreServer {
+    type Claims = domain::models::ClaimsDomain;
+
+    #[instrument(name = "BookStoreServer::add_book", skip_all)]
     async fn add_book(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         body: &models::NewBook,
     ) -> Result<book::AddBookResponse, ()> {
+        if !claims.can_manage_catalog() {
+            return Ok(book::AddBookResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
         match map_new_book_to_domain(body) {
             Ok(new_book) => match self.book_service.create_book(new_book).await {
                 Ok(book) => {
                     let model = map_book_to_rest(book);
                     Ok(book::AddBookResponse::Status200_SuccessfulOperation(model))
                 }
-                Err(domain::error::DomainError::BusinessConstraintViolation { .. }) => {
-                    Ok(book::AddBookResponse::Status422_ValidationException)
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                    Ok(book::AddBookResponse::Status422_ValidationException(
+                        map_domain_error_to_problem(422, &e),
+                    ))
                 }
-                Err(_) => Ok(book::AddBookResponse::Status500_ServerError),
+                Err(e) => Ok(book::AddBookResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(book::AddBookResponse::Status400_InvalidInput),
+            Err(e) => Ok(book::AddBookResponse::Status400_InvalidInput(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::delete_book", skip_all)]
     async fn delete_book(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteBookPathParams,
     ) -> Result<book::DeleteBookResponse, ()> {
-        match Ksuid::from_str(&path_params.book_id) {
+        if !claims.can_manage_catalog() {
+            return Ok(book::DeleteBookResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.book_id, "book_id") {
             Ok(id) => match self.book_service.delete_book_by_id(id).await {
                 Ok(_) => Ok(book::DeleteBookResponse::Status200_SuccessfulOperation),
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(book::DeleteBookResponse::Status404_BookIdNotFound)
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(book::DeleteBookResponse::Status404_BookIdNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
                 }
-                Err(_) => Ok(book::DeleteBookResponse::Status500_ServerError),
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                    Ok(book::DeleteBookResponse::Status422_BookIsPartOfOrders(
+                        map_domain_error_to_problem(422, &e),
+                    ))
+                }
+                Err(e) => Ok(book::DeleteBookResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(book::DeleteBookResponse::Status400_InvalidBookIdValue),
+            Err(e) => Ok(book::DeleteBookResponse::Status400_InvalidBookIdValue(
+                map_mapper_error_to_problem(&e),
+            )),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::full_text_search_books", skip_all)]
+    async fn full_text_search_books(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::FullTextSearchBooksHeaderParams,
+        query_params: &models::FullTextSearchBooksQueryParams,
+    ) -> Result<book::FullTextSearchBooksResponse, ()> {
+        if !claims.can_read_catalog() {
+            return Ok(book::FullTextSearchBooksResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        let request = map_search_text_to_domain(&query_params.q).and_then(|text| {
+            map_page_request_to_domain(query_params.limit, &None).map(|page| (text, page.limit))
+        });
+        let request = request.and_then(|(text, limit)| {
+            map_requested_currency_to_domain(&query_params.currency, &header_params.accept_currency)
+                .map(|currency| (text, limit, currency))
+        });
+        match request {
+            Ok((text, limit, currency)) => {
+                let result = async {
+                    let rates = self.exchange_rates(currency).await?;
+                    let hits = self.book_service.search_books(text, limit).await?;
+                    map_book_search_hits_to_rest(hits, rates.as_ref())
+                }
+                .await;
+                match result {
+                    Ok(model) => {
+                        Ok(book::FullTextSearchBooksResponse::Status200_SuccessfulOperation(model))
+                    }
+                    Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                        book::FullTextSearchBooksResponse::Status400_InvalidSearchText(
+                            map_domain_error_to_problem(400, &e),
+                        ),
+                    ),
+                    Err(e) => Ok(book::FullTextSearchBooksResponse::Status500_ServerError(
+                        map_domain_error_to_problem(500, &e),
+                    )),
+                }
+            }
+            Err(e) => Ok(
+                book::FullTextSearchBooksResponse::Status400_InvalidSearchText(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
         }
     }
 
+    #[instrument(name = "BookStoreServer::get_book_by_id", skip_all)]
     async fn get_book_by_id(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::GetBookByIdHeaderParams,
         path_params: &models::GetBookByIdPathParams,
+        query_params: &models::GetBookByIdQueryParams,
     ) -> Result<book::GetBookByIdResponse, ()> {
-        match Ksuid::from_str(&path_params.book_id) {
-            Ok(id) => match self.book_service.get_book_by_id(id).await {
-                Ok(book) => {
-                    let model = map_book_to_rest(book);
-                    Ok(book::GetBookByIdResponse::Status200_SuccessfulOperation(
-                        model,
-                    ))
+        if !claims.can_read_catalog() {
+            return Ok(book::GetBookByIdResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        let request = map_string_to_ksuid(&path_params.book_id, "book_id").and_then(|id| {
+            map_requested_currency_to_domain(&query_params.currency, &header_params.accept_currency)
+                .map(|currency| (id, currency))
+        });
+        match request {
+            Ok((id, currency)) => {
+                let result = async {
+                    let rates = self.exchange_rates(currency).await?;
+                    let book = self.book_service.get_book_by_id(id).await?;
+                    let etag = map_version_to_etag(book.version);
+                    map_converted_book_to_rest(book, rates.as_ref()).map(|model| (model, etag))
                 }
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(book::GetBookByIdResponse::Status404_BookNotFound)
+                .await;
+                match result {
+                    Ok((model, etag)) => {
+                        Ok(book::GetBookByIdResponse::Status200_SuccessfulOperation {
+                            body: model,
+                            etag: Some(etag),
+                        })
+                    }
+                    Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                        Ok(book::GetBookByIdResponse::Status404_BookNotFound(
+                            map_domain_error_to_problem(404, &e),
+                        ))
+                    }
+                    Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                        Ok(book::GetBookByIdResponse::Status400_InvalidParameters(
+                            map_domain_error_to_problem(400, &e),
+                        ))
+                    }
+                    Err(e) => Ok(book::GetBookByIdResponse::Status500_ServerError(
+                        map_domain_error_to_problem(500, &e),
+                    )),
                 }
-                Err(_) => Ok(book::GetBookByIdResponse::Status500_ServerError),
-            },
-            Err(_) => Ok(book::GetBookByIdResponse::Status400_InvalidParameters),
+            }
+            Err(e) => Ok(book::GetBookByIdResponse::Status400_InvalidParameters(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::get_books_by_authors", skip_all)]
     async fn get_books_by_authors(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::GetBooksByAuthorsHeaderParams,
         query_params: &models::GetBooksByAuthorsQueryParams,
     ) -> Result<book::GetBooksByAuthorsResponse, ()> {
-        match map_strings_to_ksuids(&query_params.authors) {
-            Ok(ids) => match self.book_service.get_books_by_authors(ids).await {
-                Ok(books) => {
-                    let models = books.into_iter().map(map_book_to_rest).collect();
-                    Ok(book::GetBooksByAuthorsResponse::Status200_SuccessfulOperation(models))
+        if !claims.can_read_catalog() {
+            return Ok(book::GetBooksByAuthorsResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        let request = map_strings_to_ksuids(&query_params.authors, "authors").and_then(|filter| {
+            map_page_request_to_domain(query_params.limit, &query_params.cursor)
+                .map(|page| (filter, page))
+        });
+        let request = request.and_then(|(filter, page)| {
+            map_requested_currency_to_domain(&query_params.currency, &header_params.accept_currency)
+                .map(|currency| (filter, page, currency))
+        });
+        match request {
+            Ok((filter, page, currency)) => {
+                let result = async {
+                    let rates = self.exchange_rates(currency).await?;
+                    let page = self.book_service.get_books_by_authors(filter, page).await?;
+                    map_book_page_to_rest(page, rates.as_ref())
                 }
-                Err(_) => Ok(book::GetBooksByAuthorsResponse::Status500_ServerError),
-            },
-            Err(_) => Ok(book::GetBooksByAuthorsResponse::Status400_InvalidAuthorValues),
+                .await;
+                match result {
+                    Ok(model) => {
+                        Ok(book::GetBooksByAuthorsResponse::Status200_SuccessfulOperation(model))
+                    }
+                    Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                        book::GetBooksByAuthorsResponse::Status400_InvalidAuthorValues(
+                            map_domain_error_to_problem(400, &e),
+                        ),
+                    ),
+                    Err(e) => Ok(book::GetBooksByAuthorsResponse::Status500_ServerError(
+                        map_domain_error_to_problem(500, &e),
+                    )),
+                }
+            }
+            Err(e) => Ok(
+                book::GetBooksByAuthorsResponse::Status400_InvalidAuthorValues(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
         }
     }
 
+    #[instrument(name = "BookStoreServer::get_books_by_genres", skip_all)]
     async fn get_books_by_genres(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::GetBooksByGenresHeaderParams,
         query_params: &models::GetBooksByGenresQueryParams,
     ) -> Result<book::GetBooksByGenresResponse, ()> {
-        match map_strings_to_ksuids(&query_params.genres) {
-            Ok(ids) => match self.book_service.get_books_by_generes(ids).await {
-                Ok(books) => {
-                    let models = books.into_iter().map(map_book_to_rest).collect();
-                    Ok(book::GetBooksByGenresResponse::Status200_SuccessfulOperation(models))
+        if !claims.can_read_catalog() {
+            return Ok(book::GetBooksByGenresResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        let request = map_strings_to_ksuids(&query_params.genres, "genres").and_then(|filter| {
+            map_page_request_to_domain(query_params.limit, &query_params.cursor)
+                .map(|page| (filter, page))
+        });
+        let request = request.and_then(|(filter, page)| {
+            map_requested_currency_to_domain(&query_params.currency, &header_params.accept_currency)
+                .map(|currency| (filter, page, currency))
+        });
+        match request {
+            Ok((filter, page, currency)) => {
+                let result = async {
+                    let rates = self.exchange_rates(currency).await?;
+                    let page = self.book_service.get_books_by_generes(filter, page).await?;
+                    map_book_page_to_rest(page, rates.as_ref())
                 }
-                Err(_) => Ok(book::GetBooksByGenresResponse::Status500_ServerError),
-            },
-            Err(_) => Ok(book::GetBooksByGenresResponse::Status400_InvalidGenreValues),
+                .await;
+                match result {
+                    Ok(model) => {
+                        Ok(book::GetBooksByGenresResponse::Status200_SuccessfulOperation(model))
+                    }
+                    Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                        book::GetBooksByGenresResponse::Status400_InvalidGenreValues(
+                            map_domain_error_to_problem(400, &e),
+                        ),
+                    ),
+                    Err(e) => Ok(book::GetBooksByGenresResponse::Status500_ServerError(
+                        map_domain_error_to_problem(500, &e),
+                    )),
+                }
+            }
+            Err(e) => Ok(
+                book::GetBooksByGenresResponse::Status400_InvalidGenreValues(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
         }
     }
 
+    #[instrument(name = "BookStoreServer::get_books_by_status", skip_all)]
     async fn get_books_by_status(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::GetBooksByStatusHeaderParams,
         query_params: &models::GetBooksByStatusQueryParams,
     ) -> Result<book::GetBooksByStatusResponse, ()> {
-        match map_book_status_list_to_domain(&query_params.status) {
-            Ok(status_list) => match self.book_service.get_books_by_status(status_list).await {
-                Ok(books) => {
-                    let models = books.into_iter().map(map_book_to_rest).collect();
-                    Ok(book::GetBooksByStatusResponse::Status200_SuccessfulOperation(models))
+        if !claims.can_read_catalog() {
+            return Ok(book::GetBooksByStatusResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        let request = map_book_status_list_to_domain(&query_params.status).and_then(|filter| {
+            map_page_request_to_domain(query_params.limit, &query_params.cursor)
+                .map(|page| (filter, page))
+        });
+        let request = request.and_then(|(filter, page)| {
+            map_requested_currency_to_domain(&query_params.currency, &header_params.accept_currency)
+                .map(|currency| (filter, page, currency))
+        });
+        match request {
+            Ok((filter, page, currency)) => {
+                let result = async {
+                    let rates = self.exchange_rates(currency).await?;
+                    let page = self.book_service.get_books_by_status(filter, page).await?;
+                    map_book_page_to_rest(page, rates.as_ref())
                 }
-                Err(_) => Ok(book::GetBooksByStatusResponse::Status500_ServerError),
-            },
-            Err(_) => Ok(book::GetBooksByStatusResponse::Status400_InvalidStatusValue),
+                .await;
+                match result {
+                    Ok(model) => {
+                        Ok(book::GetBooksByStatusResponse::Status200_SuccessfulOperation(model))
+                    }
+                    Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                        book::GetBooksByStatusResponse::Status400_InvalidStatusValue(
+                            map_domain_error_to_problem(400, &e),
+                        ),
+                    ),
+                    Err(e) => Ok(book::GetBooksByStatusResponse::Status500_ServerError(
+                        map_domain_error_to_problem(500, &e),
+                    )),
+                }
+            }
+            Err(e) => Ok(
+                book::GetBooksByStatusResponse::Status400_InvalidStatusValue(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::search_books", skip_all)]
+    async fn search_books(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::SearchBooksHeaderParams,
+        query_params: &models::SearchBooksQueryParams,
+    ) -> Result<book::SearchBooksResponse, ()> {
+        if !claims.can_read_catalog() {
+            return Ok(book::SearchBooksResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        let request = map_book_query_to_domain(query_params).and_then(|filter| {
+            map_page_request_to_domain(query_params.limit, &query_params.cursor)
+                .map(|page| (filter, page))
+        });
+        let request = request.and_then(|(filter, page)| {
+            map_requested_currency_to_domain(&query_params.currency, &header_params.accept_currency)
+                .map(|currency| (filter, page, currency))
+        });
+        match request {
+            Ok((filter, page, currency)) => {
+                let result = async {
+                    let rates = self.exchange_rates(currency).await?;
+                    let page = self.book_service.query_books(filter, page).await?;
+                    map_book_page_to_rest(page, rates.as_ref())
+                }
+                .await;
+                match result {
+                    Ok(model) => Ok(book::SearchBooksResponse::Status200_SuccessfulOperation(
+                        model,
+                    )),
+                    Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                        book::SearchBooksResponse::Status400_InvalidSearchParameters(
+                            map_domain_error_to_problem(400, &e),
+                        ),
+                    ),
+                    Err(e) => Ok(book::SearchBooksResponse::Status500_ServerError(
+                        map_domain_error_to_problem(500, &e),
+                    )),
+                }
+            }
+            Err(e) => Ok(
+                book::SearchBooksResponse::Status400_InvalidSearchParameters(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
         }
     }
 
+    #[instrument(name = "BookStoreServer::update_book", skip_all)]
     async fn update_book(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::UpdateBookHeaderParams,
         path_params: &models::UpdateBookPathParams,
         body: &models::BookProperties,
     ) -> Result<book::UpdateBookResponse, ()> {
-        match map_book_props_to_domain(&path_params.book_id, body) {
+        if !claims.can_manage_catalog() {
+            return Ok(book::UpdateBookResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_book_props_to_domain(&path_params.book_id, &header_params.if_match, body) {
             Ok(props) => match self.book_service.update_book(props).await {
                 Ok(book) => {
-                    let model = map_book_to_rest(book);
-                    Ok(book::UpdateBookResponse::Status200_SuccessfulOperation(
-                        model,
+                    let etag = map_version_to_etag(book.version);
+                    Ok(book::UpdateBookResponse::Status200_SuccessfulOperation {
+                        body: map_book_to_rest(book),
+                        etag: Some(etag),
+                    })
+                }
+                Err(e @ domain::error::DomainError::VersionConflict { .. }) => {
+                    Ok(book::UpdateBookResponse::Status412_PreconditionFailed(
+                        map_domain_error_to_problem(412, &e),
                     ))
                 }
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(book::UpdateBookResponse::Status404_BookNotFound)
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(book::UpdateBookResponse::Status404_BookNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
+                }
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                    Ok(book::UpdateBookResponse::Status422_ValidationException(
+                        map_domain_error_to_problem(422, &e),
+                    ))
                 }
-                Err(domain::error::DomainError::BusinessConstraintViolation { .. }) => {
-                    Ok(book::UpdateBookResponse::Status422_ValidationException)
+                Err(e) => Ok(book::UpdateBookResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
+            },
+            Err(e) => Ok(book::UpdateBookResponse::Status400_InvalidParameters(
+                map_mapper_error_to_problem(&e),
+            )),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[async_trait]
+impl customer::Customer for BookStoreServer {
+    type Claims = domain::models::ClaimsDomain;
+
+    #[instrument(name = "BookStoreServer::add_customer", skip_all)]
+    async fn add_customer(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::NewCustomer,
+    ) -> Result<customer::AddCustomerResponse, ()> {
+        if !claims.can_manage_orders() {
+            return Ok(customer::AddCustomerResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_new_customer_to_domain(body) {
+            Ok(new_customer) => match self.customer_service.create_customer(new_customer).await {
+                Ok(created) => Ok(
+                    customer::AddCustomerResponse::Status200_SuccessfulOperation(
+                        map_customer_to_rest(created),
+                    ),
+                ),
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                    customer::AddCustomerResponse::Status422_ValidationException(
+                        map_domain_error_to_problem(422, &e),
+                    ),
+                ),
+                Err(e) => Ok(customer::AddCustomerResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
+            },
+            Err(e) => Ok(customer::AddCustomerResponse::Status400_InvalidInput(
+                map_mapper_error_to_problem(&e),
+            )),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::delete_customer", skip_all)]
+    async fn delete_customer(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::DeleteCustomerPathParams,
+    ) -> Result<customer::DeleteCustomerResponse, ()> {
+        if !claims.can_manage_orders() {
+            return Ok(customer::DeleteCustomerResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.customer_id, "customer_id") {
+            Ok(id) => match self.customer_service.delete_customer_by_id(id).await {
+                Ok(_) => Ok(customer::DeleteCustomerResponse::Status200_SuccessfullyDeleted),
+                Err(e @ domain::error::DomainError::NotFound { .. }) => Ok(
+                    customer::DeleteCustomerResponse::Status404_CustomerNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ),
+                ),
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                    customer::DeleteCustomerResponse::Status422_CustomerHasPlacedOrders(
+                        map_domain_error_to_problem(422, &e),
+                    ),
+                ),
+                Err(e) => Ok(customer::DeleteCustomerResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
+            },
+            Err(e) => Ok(
+                customer::DeleteCustomerResponse::Status400_InvalidCustomerIdValue(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::get_customer_by_id", skip_all)]
+    async fn get_customer_by_id(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetCustomerByIdPathParams,
+    ) -> Result<customer::GetCustomerByIdResponse, ()> {
+        if !claims.can_manage_orders() {
+            return Ok(customer::GetCustomerByIdResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.customer_id, "customer_id") {
+            Ok(id) => match self.customer_service.get_customer_by_id(id).await {
+                Ok(found) => Ok(
+                    customer::GetCustomerByIdResponse::Status200_SuccessfulOperation(
+                        map_customer_to_rest(found),
+                    ),
+                ),
+                Err(e @ domain::error::DomainError::NotFound { .. }) => Ok(
+                    customer::GetCustomerByIdResponse::Status404_CustomerNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ),
+                ),
+                Err(e) => Ok(customer::GetCustomerByIdResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
+            },
+            Err(e) => Ok(
+                customer::GetCustomerByIdResponse::Status400_InvalidParameters(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::get_customer_orders", skip_all)]
+    async fn get_customer_orders(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetCustomerOrdersPathParams,
+        query_params: &models::GetCustomerOrdersQueryParams,
+    ) -> Result<customer::GetCustomerOrdersResponse, ()> {
+        if !claims.can_manage_orders() {
+            return Ok(customer::GetCustomerOrdersResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        let request = map_string_to_ksuid(&path_params.customer_id, "customer_id").and_then(|id| {
+            let status = map_order_status_list_to_domain(
+                query_params.status.as_deref().unwrap_or_default(),
+            )?;
+            let page = map_page_request_to_domain(query_params.limit, &query_params.cursor)?;
+            Ok((id, status, page))
+        });
+        match request {
+            Ok((id, status, page)) => {
+                match self
+                    .order_service
+                    .get_orders_by_customer(id, status, page)
+                    .await
+                {
+                    Ok(page) => Ok(
+                        customer::GetCustomerOrdersResponse::Status200_SuccessfulOperation(
+                            map_order_page_to_rest(page),
+                        ),
+                    ),
+                    Err(e @ domain::error::DomainError::NotFound { .. }) => Ok(
+                        customer::GetCustomerOrdersResponse::Status404_CustomerNotFound(
+                            map_domain_error_to_problem(404, &e),
+                        ),
+                    ),
+                    Err(e) => Ok(customer::GetCustomerOrdersResponse::Status500_ServerError(
+                        map_domain_error_to_problem(500, &e),
+                    )),
                 }
-                Err(_) => Ok(book::UpdateBookResponse::Status500_ServerError),
+            }
+            Err(e) => Ok(
+                customer::GetCustomerOrdersResponse::Status400_InvalidParameters(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::update_customer", skip_all)]
+    async fn update_customer(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::UpdateCustomerPathParams,
+        body: &models::CustomerProperties,
+    ) -> Result<customer::UpdateCustomerResponse, ()> {
+        if !claims.can_manage_orders() {
+            return Ok(customer::UpdateCustomerResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_customer_update_props_to_domain(&path_params.customer_id, body) {
+            Ok(props) => match self.customer_service.update_customer(props).await {
+                Ok(updated) => Ok(
+                    customer::UpdateCustomerResponse::Status200_SuccessfulOperation(
+                        map_customer_to_rest(updated),
+                    ),
+                ),
+                Err(e @ domain::error::DomainError::NotFound { .. }) => Ok(
+                    customer::UpdateCustomerResponse::Status404_CustomerNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ),
+                ),
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                    customer::UpdateCustomerResponse::Status422_ValidationException(
+                        map_domain_error_to_problem(422, &e),
+                    ),
+                ),
+                Err(e) => Ok(customer::UpdateCustomerResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(book::UpdateBookResponse::Status400_InvalidParameters),
+            Err(e) => Ok(
+                customer::UpdateCustomerResponse::Status400_InvalidParameters(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
         }
     }
 }
@@ -341,63 +1048,106 @@ impl book::Book for BookStoreServer {
 #[allow(unused_variables)]
 #[async_trait]
 impl discount::Discount for BookStoreServer {
+    type Claims = domain::models::ClaimsDomain;
+
+    #[instrument(name = "BookStoreServer::add_discount", skip_all)]
     async fn add_discount(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         body: &models::NewDiscountCode,
     ) -> Result<discount::AddDiscountResponse, ()> {
+        if !claims.can_manage_catalog() {
+            return Ok(discount::AddDiscountResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
         match map_new_discount_code_to_domain(body) {
             Ok(new_discount) => match self.book_service.create_discount_code(new_discount).await {
                 Ok(d) => {
                     let model = map_discount_code_to_rest(d);
                     Ok(discount::AddDiscountResponse::Status200_SuccessfulOperation(model))
                 }
-                Err(_) => Ok(discount::AddDiscountResponse::Status500_ServerError),
+                Err(e) => Ok(discount::AddDiscountResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(discount::AddDiscountResponse::Status400_InvalidInput),
+            Err(e) => Ok(discount::AddDiscountResponse::Status400_InvalidInput(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::delete_discount", skip_all)]
     async fn delete_discount(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteDiscountPathParams,
     ) -> Result<discount::DeleteDiscountResponse, ()> {
-        match Ksuid::from_str(&path_params.discount_id) {
+        if !claims.can_manage_catalog() {
+            return Ok(discount::DeleteDiscountResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.discount_id, "discount_id") {
             Ok(id) => match self.book_service.delte_discount_code_by_id(id).await {
                 Ok(_) => Ok(discount::DeleteDiscountResponse::Status200_SuccessfulOperation),
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(discount::DeleteDiscountResponse::Status404_DiscountNotFound)
-                }
-                Err(_) => Ok(discount::DeleteDiscountResponse::Status500_ServerError),
+                Err(e @ domain::error::DomainError::NotFound { .. }) => Ok(
+                    discount::DeleteDiscountResponse::Status404_DiscountNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ),
+                ),
+                Err(e) => Ok(discount::DeleteDiscountResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(discount::DeleteDiscountResponse::Status400_InvalidDiscountIdValue),
+            Err(e) => Ok(
+                discount::DeleteDiscountResponse::Status400_InvalidDiscountIdValue(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
         }
     }
 
+    #[instrument(name = "BookStoreServer::get_discount_by_id", skip_all)]
     async fn get_discount_by_id(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::GetDiscountByIdPathParams,
     ) -> Result<discount::GetDiscountByIdResponse, ()> {
-        match Ksuid::from_str(&path_params.discount_id) {
+        if !claims.can_read_catalog() {
+            return Ok(discount::GetDiscountByIdResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.discount_id, "discount_id") {
             Ok(id) => match self.book_service.get_discount_code_by_id(id).await {
                 Ok(d) => {
                     let model = map_discount_code_to_rest(d);
                     Ok(discount::GetDiscountByIdResponse::Status200_SuccessfulOperation(model))
                 }
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(discount::GetDiscountByIdResponse::Status404_DiscountCodeNotFound)
-                }
-                Err(_) => Ok(discount::GetDiscountByIdResponse::Status500_ServerError),
+                Err(e @ domain::error::DomainError::NotFound { .. }) => Ok(
+                    discount::GetDiscountByIdResponse::Status404_DiscountCodeNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ),
+                ),
+                Err(e) => Ok(discount::GetDiscountByIdResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(discount::GetDiscountByIdResponse::Status400_InvalidParameters),
+            Err(e) => Ok(
+                discount::GetDiscountByIdResponse::Status400_InvalidParameters(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
         }
     }
 }
@@ -405,31 +1155,67 @@ impl discount::Discount for BookStoreServer {
 #[allow(unused_variables)]
 #[async_trait]
 impl store::Store for BookStoreServer {
+    type Claims = domain::models::ClaimsDomain;
+
+    #[instrument(name = "BookStoreServer::delete_order", skip_all)]
     async fn delete_order(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteOrderPathParams,
+        query_params: &models::DeleteOrderQueryParams,
     ) -> Result<store::DeleteOrderResponse, ()> {
-        match Ksuid::from_str(&path_params.order_id) {
+        if !claims.can_manage_orders() {
+            return Ok(store::DeleteOrderResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.order_id, "order_id") {
             Ok(order_id) => {
                 // Now we have a valid Ksuid, we can use it with the order service
-                match self.order_service.delete_order_by_id(order_id).await {
+                let change = map_order_change_to_domain(claims, &query_params.reason);
+                match self
+                    .order_service
+                    .delete_order_by_id(order_id, change)
+                    .await
+                {
                     Ok(_) => Ok(store::DeleteOrderResponse::Status200_SuccessfulOperation),
-                    Err(_) => Ok(store::DeleteOrderResponse::Status404_OrderNotFound),
+                    Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                        Ok(store::DeleteOrderResponse::Status404_OrderNotFound(
+                            map_domain_error_to_problem(404, &e),
+                        ))
+                    }
+                    Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                        Ok(store::DeleteOrderResponse::Status422_OrderCanNotBeCanceled(
+                            map_domain_error_to_problem(422, &e),
+                        ))
+                    }
+                    Err(e) => Ok(store::DeleteOrderResponse::Status500_ServerError(
+                        map_domain_error_to_problem(500, &e),
+                    )),
                 }
             }
-            Err(_) => Ok(store::DeleteOrderResponse::Status400_InvalidIDSupplied),
+            Err(e) => Ok(store::DeleteOrderResponse::Status400_InvalidIDSupplied(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::get_inventory", skip_all)]
     async fn get_inventory(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
     ) -> Result<store::GetInventoryResponse, ()> {
+        if !claims.can_manage_orders() {
+            return Ok(store::GetInventoryResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
         match self.order_service.get_inventory().await {
             Ok(inventory) => {
                 let model = map_inventory_to_rest(inventory);
@@ -437,88 +1223,193 @@ impl store::Store for BookStoreServer {
                     model,
                 ))
             }
-            Err(_) => Ok(store::GetInventoryResponse::Status500_ServerError),
+            Err(e) => Ok(store::GetInventoryResponse::Status500_ServerError(
+                map_domain_error_to_problem(500, &e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::get_order_by_id", skip_all)]
     async fn get_order_by_id(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::GetOrderByIdPathParams,
     ) -> Result<store::GetOrderByIdResponse, ()> {
-        match Ksuid::from_str(&path_params.order_id) {
+        if !claims.can_manage_orders() {
+            return Ok(store::GetOrderByIdResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.order_id, "order_id") {
             Ok(order_id) => match self.order_service.get_order_by_id(order_id).await {
                 Ok(order) => {
-                    let model = map_order_to_rest(order);
-                    Ok(store::GetOrderByIdResponse::Status200_SuccessfulOperation(
-                        model,
-                    ))
+                    let etag = map_version_to_etag(order.version);
+                    Ok(store::GetOrderByIdResponse::Status200_SuccessfulOperation {
+                        body: map_order_to_rest(order),
+                        etag: Some(etag),
+                    })
                 }
-                Err(domain::error::DomainError::BusinessConstraintViolation { .. }) => {
-                    Ok(store::GetOrderByIdResponse::Status400_InvalidIDSupplied)
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                    Ok(store::GetOrderByIdResponse::Status400_InvalidIDSupplied(
+                        map_domain_error_to_problem(400, &e),
+                    ))
                 }
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(store::GetOrderByIdResponse::Status404_OrderNotFound)
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(store::GetOrderByIdResponse::Status404_OrderNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
                 }
-                Err(domain::error::DomainError::FatalDBFailure { .. }) => {
-                    Ok(store::GetOrderByIdResponse::Status500_ServerError)
+                Err(e) => Ok(store::GetOrderByIdResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
+            },
+            Err(e) => Ok(store::GetOrderByIdResponse::Status400_InvalidIDSupplied(
+                map_mapper_error_to_problem(&e),
+            )),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::get_order_history", skip_all)]
+    async fn get_order_history(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetOrderHistoryPathParams,
+    ) -> Result<store::GetOrderHistoryResponse, ()> {
+        if !claims.can_manage_orders() {
+            return Ok(store::GetOrderHistoryResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.order_id, "order_id") {
+            Ok(order_id) => match self.order_service.get_order_history(order_id).await {
+                Ok(events) => Ok(
+                    store::GetOrderHistoryResponse::Status200_SuccessfulOperation(
+                        map_order_history_to_rest(&path_params.order_id, events),
+                    ),
+                ),
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(store::GetOrderHistoryResponse::Status404_OrderNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
                 }
+                Err(e) => Ok(store::GetOrderHistoryResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(store::GetOrderByIdResponse::Status400_InvalidIDSupplied),
+            Err(e) => Ok(store::GetOrderHistoryResponse::Status400_InvalidIDSupplied(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::place_order", skip_all)]
     async fn place_order(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::PlaceOrderHeaderParams,
+        query_params: &models::PlaceOrderQueryParams,
         body: &models::NewOrder,
     ) -> Result<store::PlaceOrderResponse, ()> {
-        match map_new_order_to_domain(body) {
-            Ok(domain) => match self.order_service.create_order(domain).await {
+        if !claims.can_manage_orders() {
+            return Ok(store::PlaceOrderResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        let request = map_new_order_to_domain(body).and_then(|order| {
+            map_requested_currency_to_domain(&query_params.currency, &header_params.accept_currency)
+                .map(|currency| domain::models::OrderDomain { currency, ..order })
+        });
+        match request {
+            Ok(domain) => match self
+                .order_service
+                .create_order(domain, map_order_change_to_domain(claims, &None))
+                .await
+            {
                 Ok(result) => {
                     let model = map_order_to_rest(result);
                     Ok(store::PlaceOrderResponse::Status200_SuccessfulOperation(
                         model,
                     ))
                 }
-                Err(domain::error::DomainError::BusinessConstraintViolation { .. }) => {
-                    Ok(store::PlaceOrderResponse::Status422_ValidationException)
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                    Ok(store::PlaceOrderResponse::Status422_ValidationException(
+                        map_domain_error_to_problem(422, &e),
+                    ))
                 }
-                Err(_) => Ok(store::PlaceOrderResponse::Status500_ServerError),
+                Err(e) => Ok(store::PlaceOrderResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(store::PlaceOrderResponse::Status400_InvalidInput),
+            Err(e) => Ok(store::PlaceOrderResponse::Status400_InvalidInput(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::update_order", skip_all)]
     async fn update_order(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::UpdateOrderHeaderParams,
         path_params: &models::UpdateOrderPathParams,
         body: &models::OrderProperties,
     ) -> Result<store::UpdateOrderResponse, ()> {
-        match map_order_props_to_domain(path_params.order_id.as_str(), body) {
-            Ok(domain) => match self.order_service.update_order(domain).await {
+        if !claims.can_manage_orders() {
+            return Ok(store::UpdateOrderResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_order_props_to_domain(
+            path_params.order_id.as_str(),
+            &header_params.if_match,
+            body,
+        ) {
+            Ok(domain) => match self
+                .order_service
+                .update_order(domain, map_order_change_to_domain(claims, &body.reason))
+                .await
+            {
                 Ok(result) => {
-                    let model = map_order_to_rest(result);
-                    Ok(store::UpdateOrderResponse::Status200_SuccessfulOperation(
-                        model,
+                    let etag = map_version_to_etag(result.version);
+                    Ok(store::UpdateOrderResponse::Status200_SuccessfulOperation {
+                        body: map_order_to_rest(result),
+                        etag: Some(etag),
+                    })
+                }
+                Err(e @ domain::error::DomainError::VersionConflict { .. }) => {
+                    Ok(store::UpdateOrderResponse::Status412_PreconditionFailed(
+                        map_domain_error_to_problem(412, &e),
                     ))
                 }
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(store::UpdateOrderResponse::Status404_OrderNotFound)
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(store::UpdateOrderResponse::Status404_OrderNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
                 }
-                Err(domain::error::DomainError::BusinessConstraintViolation { .. }) => {
-                    Ok(store::UpdateOrderResponse::Status422_ValidationException)
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                    Ok(store::UpdateOrderResponse::Status422_ValidationException(
+                        map_domain_error_to_problem(422, &e),
+                    ))
                 }
-                Err(_) => Ok(store::UpdateOrderResponse::Status500_ServerError),
+                Err(e) => Ok(store::UpdateOrderResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(store::UpdateOrderResponse::Status400_InvalidParameters),
+            Err(e) => Ok(store::UpdateOrderResponse::Status400_InvalidParameters(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 }
@@ -526,13 +1417,22 @@ impl store::Store for BookStoreServer {
 #[allow(unused_variables)]
 #[async_trait]
 impl genre::Genre for BookStoreServer {
+    type Claims = domain::models::ClaimsDomain;
+
+    #[instrument(name = "BookStoreServer::add_genre", skip_all)]
     async fn add_genre(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         body: &models::NewGenre,
     ) -> Result<genre::AddGenreResponse, ()> {
+        if !claims.can_manage_catalog() {
+            return Ok(genre::AddGenreResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
         let new_genre = map_new_genre_to_domain(&body.name);
         match self.book_service.create_genre(new_genre).await {
             Ok(genre) => {
@@ -541,40 +1441,69 @@ impl genre::Genre for BookStoreServer {
                     model,
                 ))
             }
-            Err(domain::error::DomainError::BusinessConstraintViolation { .. }) => {
-                Ok(genre::AddGenreResponse::Status422_ValidationException)
+            Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                Ok(genre::AddGenreResponse::Status422_ValidationException(
+                    map_domain_error_to_problem(422, &e),
+                ))
             }
-            Err(_) => Ok(genre::AddGenreResponse::Status500_ServerError),
+            Err(e) => Ok(genre::AddGenreResponse::Status500_ServerError(
+                map_domain_error_to_problem(500, &e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::delete_genre", skip_all)]
     async fn delete_genre(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteGenrePathParams,
     ) -> Result<genre::DeleteGenreResponse, ()> {
-        match Ksuid::from_str(&path_params.genre_id) {
+        if !claims.can_manage_catalog() {
+            return Ok(genre::DeleteGenreResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.genre_id, "genre_id") {
             Ok(id) => match self.book_service.delte_genre_by_id(id).await {
                 Ok(_) => Ok(genre::DeleteGenreResponse::Status200_SuccessfulOperation),
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(genre::DeleteGenreResponse::Status404_GenreNotFound)
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(genre::DeleteGenreResponse::Status404_GenreNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
                 }
-                Err(_) => Ok(genre::DeleteGenreResponse::Status500_ServerError),
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                    genre::DeleteGenreResponse::Status422_GenreIsAssignedToBooks(
+                        map_domain_error_to_problem(422, &e),
+                    ),
+                ),
+                Err(e) => Ok(genre::DeleteGenreResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(genre::DeleteGenreResponse::Status400_InvalidGenreIdValue),
+            Err(e) => Ok(genre::DeleteGenreResponse::Status400_InvalidGenreIdValue(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 
+    #[instrument(name = "BookStoreServer::get_genre_by_id", skip_all)]
     async fn get_genre_by_id(
         &self,
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::GetGenreByIdPathParams,
     ) -> Result<genre::GetGenreByIdResponse, ()> {
-        match Ksuid::from_str(&path_params.genre_id) {
+        if !claims.can_read_catalog() {
+            return Ok(genre::GetGenreByIdResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.genre_id, "genre_id") {
             Ok(id) => match self.book_service.get_genre_by_id(id).await {
                 Ok(genre) => {
                     let model = map_genre_to_rest(genre);
@@ -582,12 +1511,18 @@ impl genre::Genre for BookStoreServer {
                         model,
                     ))
                 }
-                Err(domain::error::DomainError::NotFound { .. }) => {
-                    Ok(genre::GetGenreByIdResponse::Status404_GenreNotFound)
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(genre::GetGenreByIdResponse::Status404_GenreNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
                 }
-                Err(_) => Ok(genre::GetGenreByIdResponse::Status500_ServerError),
+                Err(e) => Ok(genre::GetGenreByIdResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
             },
-            Err(_) => Ok(genre::GetGenreByIdResponse::Status400_InvalidParameters),
+            Err(e) => Ok(genre::GetGenreByIdResponse::Status400_InvalidParameters(
+                map_mapper_error_to_problem(&e),
+            )),
         }
     }
 }
@@ -595,13 +1530,1935 @@ impl genre::Genre for BookStoreServer {
 #[allow(unused_variables)]
 #[async_trait]
 impl ApiKeyAuthHeader for BookStoreServer {
-    type Claims = bool;
-    // TODO: check how to add API key auth as request interceptor, or offload to CloudFront/ALB
+    type Claims = domain::models::ClaimsDomain;
+
     async fn extract_claims_from_header(
         &self,
         headers: &HeaderMap,
         key: &str,
     ) -> Option<Self::Claims> {
-        return Some(true);
+        // the authentication middleware looked up the key of the request already
+        if let Ok(authentication) = authentication::AUTHENTICATION.try_with(Clone::clone) {
+            return authentication.into_claims();
+        }
+        authentication::authenticate(&self.auth_service, headers, key)
+            .await
+            .into_claims()
+    }
+}
+
+#[allow(unused_variables)]
+#[async_trait]
+impl webhook::Webhook for BookStoreServer {
+    type Claims = domain::models::ClaimsDomain;
+
+    #[instrument(name = "BookStoreServer::add_webhook", skip_all)]
+    async fn add_webhook(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::NewWebhook,
+    ) -> Result<webhook::AddWebhookResponse, ()> {
+        if !claims.can_manage_webhooks() {
+            return Ok(webhook::AddWebhookResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_new_webhook_to_domain(body) {
+            Ok(new_webhook) => match self.webhook_service.create_webhook(new_webhook).await {
+                Ok(created) => Ok(webhook::AddWebhookResponse::Status200_SuccessfulOperation(
+                    map_webhook_to_rest(created),
+                )),
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => {
+                    Ok(webhook::AddWebhookResponse::Status422_ValidationException(
+                        map_domain_error_to_problem(422, &e),
+                    ))
+                }
+                Err(e) => Ok(webhook::AddWebhookResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
+            },
+            Err(e) => Ok(webhook::AddWebhookResponse::Status400_InvalidInput(
+                map_mapper_error_to_problem(&e),
+            )),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::delete_webhook", skip_all)]
+    async fn delete_webhook(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::DeleteWebhookPathParams,
+    ) -> Result<webhook::DeleteWebhookResponse, ()> {
+        if !claims.can_manage_webhooks() {
+            return Ok(webhook::DeleteWebhookResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.webhook_id, "webhook_id") {
+            Ok(id) => match self.webhook_service.delete_webhook_by_id(id).await {
+                Ok(_) => Ok(webhook::DeleteWebhookResponse::Status200_SuccessfullyDeleted),
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(webhook::DeleteWebhookResponse::Status404_WebhookNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
+                }
+                Err(e) => Ok(webhook::DeleteWebhookResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
+            },
+            Err(e) => Ok(
+                webhook::DeleteWebhookResponse::Status400_InvalidWebhookIdValue(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::get_webhook_by_id", skip_all)]
+    async fn get_webhook_by_id(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetWebhookByIdPathParams,
+    ) -> Result<webhook::GetWebhookByIdResponse, ()> {
+        if !claims.can_manage_webhooks() {
+            return Ok(webhook::GetWebhookByIdResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_string_to_ksuid(&path_params.webhook_id, "webhook_id") {
+            Ok(id) => match self.webhook_service.get_webhook_by_id(id).await {
+                Ok(found) => Ok(
+                    webhook::GetWebhookByIdResponse::Status200_SuccessfulOperation(
+                        map_webhook_to_rest(found),
+                    ),
+                ),
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(webhook::GetWebhookByIdResponse::Status404_WebhookNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
+                }
+                Err(e) => Ok(webhook::GetWebhookByIdResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
+            },
+            Err(e) => Ok(
+                webhook::GetWebhookByIdResponse::Status400_InvalidParameters(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::get_webhook_deliveries", skip_all)]
+    async fn get_webhook_deliveries(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetWebhookDeliveriesPathParams,
+        query_params: &models::GetWebhookDeliveriesQueryParams,
+    ) -> Result<webhook::GetWebhookDeliveriesResponse, ()> {
+        if !claims.can_manage_webhooks() {
+            return Ok(webhook::GetWebhookDeliveriesResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        let request = map_string_to_ksuid(&path_params.webhook_id, "webhook_id").and_then(|id| {
+            let status = map_delivery_status_to_domain(&query_params.status)?;
+            let page = map_page_request_to_domain(query_params.limit, &query_params.cursor)?;
+            Ok((id, status, page))
+        });
+        match request {
+            Ok((id, status, page)) => {
+                match self
+                    .webhook_service
+                    .get_webhook_deliveries(id, status, page)
+                    .await
+                {
+                    Ok(page) => Ok(
+                        webhook::GetWebhookDeliveriesResponse::Status200_SuccessfulOperation(
+                            map_webhook_delivery_page_to_rest(page),
+                        ),
+                    ),
+                    Err(e @ domain::error::DomainError::NotFound { .. }) => Ok(
+                        webhook::GetWebhookDeliveriesResponse::Status404_WebhookNotFound(
+                            map_domain_error_to_problem(404, &e),
+                        ),
+                    ),
+                    Err(e) => Ok(
+                        webhook::GetWebhookDeliveriesResponse::Status500_ServerError(
+                            map_domain_error_to_problem(500, &e),
+                        ),
+                    ),
+                }
+            }
+            Err(e) => Ok(
+                webhook::GetWebhookDeliveriesResponse::Status400_InvalidParameters(
+                    map_mapper_error_to_problem(&e),
+                ),
+            ),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::get_webhooks", skip_all)]
+    async fn get_webhooks(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+    ) -> Result<webhook::GetWebhooksResponse, ()> {
+        if !claims.can_manage_webhooks() {
+            return Ok(webhook::GetWebhooksResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match self.webhook_service.get_webhooks().await {
+            Ok(webhooks) => Ok(webhook::GetWebhooksResponse::Status200_SuccessfulOperation(
+                webhooks.into_iter().map(map_webhook_to_rest).collect(),
+            )),
+            Err(e) => Ok(webhook::GetWebhooksResponse::Status500_ServerError(
+                map_domain_error_to_problem(500, &e),
+            )),
+        }
+    }
+
+    #[instrument(name = "BookStoreServer::update_webhook", skip_all)]
+    async fn update_webhook(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::UpdateWebhookPathParams,
+        body: &models::WebhookProperties,
+    ) -> Result<webhook::UpdateWebhookResponse, ()> {
+        if !claims.can_manage_webhooks() {
+            return Ok(webhook::UpdateWebhookResponse::Status403_Forbidden(
+                map_forbidden_to_problem(claims),
+            ));
+        }
+        match map_webhook_update_props_to_domain(&path_params.webhook_id, body) {
+            Ok(props) => match self.webhook_service.update_webhook(props).await {
+                Ok(updated) => Ok(
+                    webhook::UpdateWebhookResponse::Status200_SuccessfulOperation(
+                        map_webhook_to_rest(updated),
+                    ),
+                ),
+                Err(e @ domain::error::DomainError::NotFound { .. }) => {
+                    Ok(webhook::UpdateWebhookResponse::Status404_WebhookNotFound(
+                        map_domain_error_to_problem(404, &e),
+                    ))
+                }
+                Err(e @ domain::error::DomainError::BusinessConstraintViolation { .. }) => Ok(
+                    webhook::UpdateWebhookResponse::Status422_ValidationException(
+                        map_domain_error_to_problem(422, &e),
+                    ),
+                ),
+                Err(e) => Ok(webhook::UpdateWebhookResponse::Status500_ServerError(
+                    map_domain_error_to_problem(500, &e),
+                )),
+            },
+            Err(e) => Ok(webhook::UpdateWebhookResponse::Status400_InvalidParameters(
+                map_mapper_error_to_problem(&e),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::persistence::config_api_key_repository::ConfigApiKeyRepository;
+    use crate::adapters::persistence::config_tax_rate_repository::ConfigTaxRateRepository;
+    use crate::adapters::persistence::file_exchange_rate_repository::FileExchangeRateRepository;
+    use crate::adapters::persistence::in_memory_repository::InMemoryRepository;
+    use crate::domain::auth_service::hash_api_key;
+    use chrono::{Duration, NaiveDate, Utc};
+    use openapi::apis::author::Author;
+    use openapi::apis::book::Book;
+    use openapi::apis::customer::Customer;
+    use openapi::apis::discount::Discount;
+    use openapi::apis::genre::Genre;
+    use openapi::apis::health::Health;
+    use openapi::apis::store::Store;
+    use openapi::apis::webhook::Webhook;
+    use svix_ksuid::{Ksuid, KsuidLike};
+    use tower::ServiceExt;
+
+    const API_KEY: &str = "0123456789abcdef0123456789abcdef";
+    const EXPIRED_API_KEY: &str = "fedcba9876543210fedcba9876543210";
+    const ADMIN_API_KEY: &str = "00112233445566778899aabbccddeeff";
+
+    fn api_key(key: &str, valid_until: chrono::DateTime<Utc>) -> domain::models::ApiKeyDomain {
+        domain::models::ApiKeyDomain {
+            key_hash: hash_api_key(key),
+            name: String::from("backoffice"),
+            roles: vec![domain::models::ApiKeyRole::CatalogRead],
+            valid_from: Utc::now() - Duration::days(30),
+            valid_until: Some(valid_until),
+        }
+    }
+
+    fn new_server() -> Arc<BookStoreServer> {
+        new_taxed_server(vec![])
+    }
+
+    fn new_taxed_server(tax_rates: Vec<domain::models::TaxRateDomain>) -> Arc<BookStoreServer> {
+        new_metered_server(tax_rates, PrometheusMetrics::new(None))
+    }
+
+    fn new_metered_server(
+        tax_rates: Vec<domain::models::TaxRateDomain>,
+        metrics: Arc<PrometheusMetrics>,
+    ) -> Arc<BookStoreServer> {
+        let repository = InMemoryRepository::new();
+        let api_keys = ConfigApiKeyRepository::new(vec![
+            api_key(API_KEY, Utc::now() + Duration::days(1)),
+            api_key(EXPIRED_API_KEY, Utc::now() - Duration::days(1)),
+            domain::models::ApiKeyDomain {
+                key_hash: hash_api_key(ADMIN_API_KEY),
+                name: String::from("admin"),
+                roles: admin().roles,
+                valid_from: Utc::now() - Duration::days(30),
+                valid_until: None,
+            },
+        ]);
+        BookStoreServer::new(
+            repository.clone(),
+            repository.clone(),
+            repository.clone(),
+            api_keys,
+            repository.clone(),
+            domain::exchange_service::ExchangeService::new(
+                domain::models::Currency::USD,
+                FileExchangeRateRepository::new(vec![domain::models::ExchangeRateDomain {
+                    currency: "EUR".parse().unwrap(),
+                    rate: "0.92".parse().unwrap(),
+                    updated_at: Utc::now(),
+                }]),
+            ),
+            domain::tax_service::TaxService::new(false, ConfigTaxRateRepository::new(tax_rates)),
+            repository,
+            metrics,
+        )
+    }
+
+    fn admin() -> domain::models::ClaimsDomain {
+        domain::models::ClaimsDomain {
+            key_name: String::from("admin"),
+            roles: vec![
+                domain::models::ApiKeyRole::CatalogAdmin,
+                domain::models::ApiKeyRole::OrderClerk,
+            ],
+        }
+    }
+
+    fn headers(api_key: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-API-KEY", api_key.parse().unwrap());
+        headers
+    }
+
+    fn host() -> Host {
+        Host(String::from("localhost"))
+    }
+
+    async fn add_author(server: &BookStoreServer) -> models::Author {
+        let body = models::NewAuthor::new(
+            String::from("Johann"),
+            String::from("Goethe"),
+            NaiveDate::from_ymd_opt(1749, 8, 28).unwrap(),
+        );
+        match server
+            .add_author(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await
+        {
+            Ok(author::AddAuthorResponse::Status200_SuccessfulOperation(author)) => author,
+            other => panic!("Expected author to be created, got {:?}", other),
+        }
+    }
+
+    async fn add_book(
+        server: &BookStoreServer,
+        authors: Vec<String>,
+        available: i32,
+    ) -> models::Book {
+        let body = models::NewBook::new(
+            String::from("Faust"),
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            authors,
+            String::from("12.50"),
+            available,
+        );
+        match server
+            .add_book(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await
+        {
+            Ok(book::AddBookResponse::Status200_SuccessfulOperation(book)) => book,
+            other => panic!("Expected book to be created, got {:?}", other),
+        }
+    }
+
+    async fn add_discount(
+        server: &BookStoreServer,
+        code: &str,
+        percentage: u8,
+        valid_to: NaiveDate,
+    ) -> models::DiscountCode {
+        let body = models::NewDiscountCode::new(
+            percentage,
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            valid_to,
+            String::from(code),
+        );
+        match server
+            .add_discount(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await
+        {
+            Ok(discount::AddDiscountResponse::Status200_SuccessfulOperation(discount)) => discount,
+            other => panic!("Expected discount code to be created, got {:?}", other),
+        }
+    }
+
+    async fn add_customer(server: &BookStoreServer, email: &str) -> models::Customer {
+        let body = models::NewCustomer::new(String::from("Johann Wolfgang"), String::from(email));
+        match server
+            .add_customer(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await
+        {
+            Ok(customer::AddCustomerResponse::Status200_SuccessfulOperation(customer)) => customer,
+            other => panic!("Expected customer to be created, got {:?}", other),
+        }
+    }
+
+    fn new_order(customer_id: &str, book_id: &str, quantity: i32) -> models::NewOrder {
+        models::NewOrder::new(
+            String::from(customer_id),
+            vec![models::OrderedBook::new(String::from(book_id), quantity)],
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            models::Address::new(
+                String::from("Berlinerstrasse"),
+                String::from("12b"),
+                String::from("80331"),
+                String::from("Munich"),
+                String::from("Germany"),
+            ),
+        )
+    }
+
+    async fn place(server: &BookStoreServer, order: &models::NewOrder) -> models::Order {
+        match server
+            .place_order(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::PlaceOrderHeaderParams {
+                    accept_currency: None,
+                },
+                &models::PlaceOrderQueryParams { currency: None },
+                order,
+            )
+            .await
+        {
+            Ok(store::PlaceOrderResponse::Status200_SuccessfulOperation(order)) => order,
+            other => panic!("Expected order to be placed, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_liveness() {
+        // Arrange
+        let server = new_server();
+
+        // Act
+        let response = server
+            .get_liveness(&Method::GET, &host(), &CookieJar::new())
+            .await;
+
+        // Assert
+        match response {
+            Ok(health::GetLivenessResponse::Status200_Successful(body)) => {
+                assert_eq!(body.status.as_deref(), Some("serving"));
+            }
+            other => panic!("Expected liveness to succeed, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_readiness_not_ready_after_shutdown() {
+        // Arrange
+        let server = new_server();
+        let ready = server
+            .get_readiness(&Method::GET, &host(), &CookieJar::new())
+            .await;
+
+        // Act
+        server.health_service.begin_shutdown();
+        let response = server
+            .get_readiness(&Method::GET, &host(), &CookieJar::new())
+            .await;
+
+        // Assert
+        match ready {
+            Ok(health::GetReadinessResponse::Status200_Successful(body)) => {
+                assert_eq!(body.status.as_deref(), Some("ready"));
+            }
+            other => panic!("Expected readiness to succeed, got {:?}", other),
+        }
+        match response {
+            Ok(health::GetReadinessResponse::Status503_ServiceUnavailable(body)) => {
+                assert_eq!(body.status.as_deref(), Some("unavailable"));
+                let checks = body.checks.unwrap();
+                assert_eq!(checks.len(), 1);
+                assert_eq!(checks[0].name, "shutdown");
+                assert_eq!(checks[0].status, "down");
+            }
+            other => panic!("Expected service unavailable, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_and_get_book() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let book = add_book(&server, vec![author.id.clone()], 10).await;
+
+        // Act
+        let result = server
+            .get_book_by_id(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::GetBookByIdHeaderParams {
+                    accept_currency: None,
+                },
+                &models::GetBookByIdPathParams {
+                    book_id: book.id.clone(),
+                },
+                &models::GetBookByIdQueryParams { currency: None },
+            )
+            .await;
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(book::GetBookByIdResponse::Status200_SuccessfulOperation {
+                body: book,
+                etag: Some(String::from("\"1\"")),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_book_not_found() {
+        // Arrange
+        let server = new_server();
+        let id = Ksuid::new(None, None).to_string();
+
+        // Act
+        let result = server
+            .get_book_by_id(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::GetBookByIdHeaderParams {
+                    accept_currency: None,
+                },
+                &models::GetBookByIdPathParams { book_id: id },
+                &models::GetBookByIdQueryParams { currency: None },
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Ok(book::GetBookByIdResponse::Status404_BookNotFound(problem)) if problem.status == 404
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_book_invalid_id() {
+        // Arrange
+        let server = new_server();
+
+        // Act
+        let result = server
+            .get_book_by_id(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::GetBookByIdHeaderParams {
+                    accept_currency: None,
+                },
+                &models::GetBookByIdPathParams {
+                    book_id: String::from("invalid-id"),
+                },
+                &models::GetBookByIdQueryParams { currency: None },
+            )
+            .await;
+
+        // Assert
+        match result {
+            Ok(book::GetBookByIdResponse::Status400_InvalidParameters(problem)) => {
+                assert_eq!(problem.status, 400);
+                assert_eq!(problem.field, Some(String::from("book_id")));
+                assert_eq!(
+                    problem.detail,
+                    Some(String::from("Invalid KSUID format: invalid-id"))
+                );
+            }
+            other => panic!("Expected problem, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_book_in_requested_currency() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let book = add_book(&server, vec![author.id.clone()], 10).await;
+        let get = async |query: Option<&str>, header: Option<&str>| {
+            server
+                .get_book_by_id(
+                    &Method::GET,
+                    &host(),
+                    &CookieJar::new(),
+                    &admin(),
+                    &models::GetBookByIdHeaderParams {
+                        accept_currency: header.map(String::from),
+                    },
+                    &models::GetBookByIdPathParams {
+                        book_id: book.id.clone(),
+                    },
+                    &models::GetBookByIdQueryParams {
+                        currency: query.map(String::from),
+                    },
+                )
+                .await
+        };
+
+        // Act
+        let by_query = get(Some("eur"), Some("GBP")).await;
+        let by_header = get(None, Some("EUR")).await;
+        let unsupported = get(Some("GBP"), None).await;
+
+        // Assert
+        for result in [by_query, by_header] {
+            match result {
+                Ok(book::GetBookByIdResponse::Status200_SuccessfulOperation {
+                    body: converted,
+                    ..
+                }) => {
+                    // 12.50 * 0.92 = 11.50
+                    assert_eq!(converted.price, "11.50");
+                    assert_eq!(converted.currency, "EUR");
+                    let rate = converted.exchange_rate.unwrap();
+                    assert_eq!(rate.source_currency, "USD");
+                    assert_eq!(rate.rate, "0.92");
+                }
+                other => panic!("Expected converted book, got {:?}", other),
+            }
+        }
+        assert!(matches!(
+            unsupported,
+            Ok(book::GetBookByIdResponse::Status400_InvalidParameters(problem)) if problem.status == 400
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_add_book_with_unknown_author() {
+        // Arrange
+        let server = new_server();
+        let body = models::NewBook::new(
+            String::from("Faust"),
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            vec![Ksuid::new(None, None).to_string()],
+            String::from("12.50"),
+            10,
+        );
+
+        // Act
+        let result = server
+            .add_book(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Ok(book::AddBookResponse::Status422_ValidationException(problem)) if problem.status == 422
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_book_with_if_match() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let book = add_book(&server, vec![author.id.clone()], 10).await;
+        let update = |if_match: &str| {
+            let server = server.clone();
+            let book_id = book.id.clone();
+            let if_match = Some(String::from(if_match));
+            async move {
+                let mut body = models::BookProperties::new();
+                body.available = Some(5);
+                server
+                    .update_book(
+                        &Method::PATCH,
+                        &host(),
+                        &CookieJar::new(),
+                        &admin(),
+                        &models::UpdateBookHeaderParams { if_match },
+                        &models::UpdateBookPathParams { book_id },
+                        &body,
+                    )
+                    .await
+            }
+        };
+
+        // Act
+        let current = update("\"1\"").await;
+        let stale = update("\"1\"").await;
+        // a weak ETag never matches, even for the current version
+        let weak = update("W/\"2\"").await;
+        let list = update("\"1\", W/\"3\", \"2\"").await;
+        let invalid = update("2").await;
+
+        // Assert
+        assert!(matches!(
+            current,
+            Ok(book::UpdateBookResponse::Status200_SuccessfulOperation { ref etag, .. })
+                if etag.as_deref() == Some("\"2\"")
+        ));
+        assert!(matches!(
+            stale,
+            Ok(book::UpdateBookResponse::Status412_PreconditionFailed(problem)) if problem.status == 412
+        ));
+        assert!(matches!(
+            weak,
+            Ok(book::UpdateBookResponse::Status412_PreconditionFailed(problem)) if problem.status == 412
+        ));
+        assert!(matches!(
+            list,
+            Ok(book::UpdateBookResponse::Status200_SuccessfulOperation { ref etag, .. })
+                if etag.as_deref() == Some("\"3\"")
+        ));
+        assert!(matches!(
+            invalid,
+            Ok(book::UpdateBookResponse::Status400_InvalidParameters(problem)) if problem.status == 400
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_books_by_status_paginated() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        add_book(&server, vec![author.id.clone()], 10).await;
+        add_book(&server, vec![author.id.clone()], 10).await;
+        let query = |cursor: Option<String>| models::GetBooksByStatusQueryParams {
+            status: vec![String::from("available")],
+            limit: Some(1),
+            cursor,
+            currency: None,
+        };
+
+        // Act
+        let first = server
+            .get_books_by_status(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::GetBooksByStatusHeaderParams {
+                    accept_currency: None,
+                },
+                &query(None),
+            )
+            .await;
+        let first = match first {
+            Ok(book::GetBooksByStatusResponse::Status200_SuccessfulOperation(page)) => page,
+            other => panic!("Expected page, got {:?}", other),
+        };
+        let second = server
+            .get_books_by_status(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::GetBooksByStatusHeaderParams {
+                    accept_currency: None,
+                },
+                &query(first.next_cursor.clone()),
+            )
+            .await;
+
+        // Assert
+        assert_eq!(first.items.len(), 1);
+        assert!(first.next_cursor.is_some());
+        match second {
+            Ok(book::GetBooksByStatusResponse::Status200_SuccessfulOperation(page)) => {
+                assert_eq!(page.items.len(), 1);
+                assert_ne!(page.items[0].id, first.items[0].id);
+                assert_eq!(page.next_cursor, None);
+            }
+            other => panic!("Expected page, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_books_with_inverted_price_range() {
+        // Arrange
+        let server = new_server();
+        let query = models::SearchBooksQueryParams {
+            authors: None,
+            genres: None,
+            status: Some(vec![String::from("available,out-of-stock")]),
+            series: None,
+            min_price: Some(String::from("20")),
+            max_price: Some(String::from("10.00")),
+            released_from: None,
+            released_to: None,
+            title: None,
+            sort: Some(String::from("price")),
+            order: None,
+            limit: None,
+            cursor: None,
+            currency: None,
+        };
+
+        // Act
+        let result = server
+            .search_books(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::SearchBooksHeaderParams {
+                    accept_currency: None,
+                },
+                &query,
+            )
+            .await;
+
+        // Assert
+        match result {
+            Ok(book::SearchBooksResponse::Status400_InvalidSearchParameters(problem)) => {
+                assert_eq!(problem.field, Some(String::from("min_price")));
+            }
+            other => panic!("Expected invalid range, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_full_text_search_books_with_blank_text() {
+        // Arrange
+        let server = new_server();
+        let query = models::FullTextSearchBooksQueryParams {
+            q: String::from("   "),
+            limit: None,
+            currency: None,
+        };
+
+        // Act
+        let result = server
+            .full_text_search_books(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::FullTextSearchBooksHeaderParams {
+                    accept_currency: None,
+                },
+                &query,
+            )
+            .await;
+
+        // Assert
+        match result {
+            Ok(book::FullTextSearchBooksResponse::Status400_InvalidSearchText(problem)) => {
+                assert_eq!(problem.field, Some(String::from("q")));
+            }
+            other => panic!("Expected invalid search text, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_place_order_updates_inventory() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let book = add_book(&server, vec![author.id.clone()], 7).await;
+
+        // Act
+        let result = server
+            .place_order(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::PlaceOrderHeaderParams {
+                    accept_currency: None,
+                },
+                &models::PlaceOrderQueryParams { currency: None },
+                &new_order(&customer.id, &book.id, 2),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Ok(store::PlaceOrderResponse::Status200_SuccessfulOperation(_))
+        ));
+        assert_eq!(
+            server
+                .get_inventory(&Method::GET, &host(), &CookieJar::new(), &admin())
+                .await,
+            Ok(store::GetInventoryResponse::Status200_SuccessfulOperation(
+                models::Inventory::new(0, 1, 0)
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_place_order_locks_the_exchange_rate() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let book = add_book(&server, vec![author.id.clone()], 7).await;
+
+        // Act
+        let result = server
+            .place_order(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::PlaceOrderHeaderParams {
+                    accept_currency: Some(String::from("EUR")),
+                },
+                &models::PlaceOrderQueryParams { currency: None },
+                &new_order(&customer.id, &book.id, 2),
+            )
+            .await;
+
+        // Assert
+        match result {
+            Ok(store::PlaceOrderResponse::Status200_SuccessfulOperation(order)) => {
+                assert_eq!(order.currency, "EUR");
+                assert_eq!(order.lines[0].unit_price, "11.50");
+                assert_eq!(order.total, "23.00");
+                assert_eq!(order.exchange_rate.unwrap().rate, "0.92");
+            }
+            other => panic!("Expected order, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_place_order_with_discount_codes() {
+        // Arrange
+        let metrics = PrometheusMetrics::new(None);
+        let server = new_metered_server(vec![], metrics.clone());
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let valid_to = Utc::now().date_naive() + Duration::days(30);
+        let expired_on = Utc::now().date_naive() - Duration::days(1);
+        let summer = add_discount(&server, "SUMMER10", 10, valid_to).await;
+        let spring = add_discount(&server, "SPRING20", 20, expired_on).await;
+        let mut body = models::NewBook::new(
+            String::from("Faust"),
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            vec![author.id.clone()],
+            String::from("12.50"),
+            7,
+        );
+        body.discount_codes = Some(vec![summer.id.clone(), spring.id.clone()]);
+        let book = match server
+            .add_book(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await
+        {
+            Ok(book::AddBookResponse::Status200_SuccessfulOperation(book)) => book,
+            other => panic!("Expected book to be created, got {:?}", other),
+        };
+        let mut discounted = new_order(&customer.id, &book.id, 2);
+        discounted.discount_codes = Some(vec![String::from("SUMMER10")]);
+        let mut expired = new_order(&customer.id, &book.id, 2);
+        expired.discount_codes = Some(vec![String::from("SPRING20")]);
+
+        // Act
+        let discounted = server
+            .place_order(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::PlaceOrderHeaderParams {
+                    accept_currency: None,
+                },
+                &models::PlaceOrderQueryParams { currency: None },
+                &discounted,
+            )
+            .await;
+        let expired = server
+            .place_order(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::PlaceOrderHeaderParams {
+                    accept_currency: None,
+                },
+                &models::PlaceOrderQueryParams { currency: None },
+                &expired,
+            )
+            .await;
+
+        // Assert
+        match discounted {
+            Ok(store::PlaceOrderResponse::Status200_SuccessfulOperation(order)) => {
+                assert_eq!(order.lines.len(), 1);
+                assert_eq!(order.lines[0].unit_price, "12.50");
+                assert_eq!(order.lines[0].discount_code, Some(String::from("SUMMER10")));
+                assert_eq!(order.subtotal, "25.00");
+                assert_eq!(order.discount_total, "2.50");
+                assert_eq!(order.total, "22.50");
+                assert_eq!(order.currency, "USD");
+            }
+            other => panic!("Expected order to be placed, got {:?}", other),
+        }
+        assert!(matches!(
+            expired,
+            Ok(store::PlaceOrderResponse::Status422_ValidationException(problem)) if problem.status == 422
+        ));
+        let rendered = metrics.render().unwrap();
+        assert!(rendered.contains("orders_placed_total 1"));
+        assert!(rendered.contains("discount_redemptions_total{code=\"SUMMER10\"} 1"));
+        assert!(!rendered.contains("SPRING20"));
+    }
+
+    #[tokio::test]
+    async fn test_place_order_taxed_by_the_shipping_country() {
+        // Arrange
+        let server = new_taxed_server(vec![domain::models::TaxRateDomain {
+            country: String::from("DE"),
+            province: None,
+            genre: None,
+            product_type: None,
+            rate: "7".parse().unwrap(),
+        }]);
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let book = add_book(&server, vec![author.id.clone()], 7).await;
+        let mut taxed = new_order(&customer.id, &book.id, 2);
+        taxed.billing_address.country = String::from("de");
+        let untaxed = new_order(&customer.id, &book.id, 2);
+
+        // Act
+        let mut results = Vec::new();
+        for body in [taxed, untaxed] {
+            let result = server
+                .place_order(
+                    &Method::POST,
+                    &host(),
+                    &CookieJar::new(),
+                    &admin(),
+                    &models::PlaceOrderHeaderParams {
+                        accept_currency: None,
+                    },
+                    &models::PlaceOrderQueryParams { currency: None },
+                    &body,
+                )
+                .await;
+            results.push(result);
+        }
+
+        // Assert
+        match results.remove(0) {
+            Ok(store::PlaceOrderResponse::Status200_SuccessfulOperation(order)) => {
+                assert_eq!(order.lines[0].tax_rate, "7");
+                assert_eq!(order.lines[0].tax_amount, "1.75");
+                assert_eq!(order.net_total, "25.00");
+                assert_eq!(order.tax_total, "1.75");
+                assert_eq!(order.total, "26.75");
+                assert_eq!(order.tax_lines.len(), 1);
+            }
+            other => panic!("Expected order to be placed, got {:?}", other),
+        }
+        // no rate is configured for the country of the address
+        assert!(matches!(
+            results.remove(0),
+            Ok(store::PlaceOrderResponse::Status422_ValidationException(problem)) if problem.status == 422
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_place_order_insufficient_stock() {
+        // Arrange
+        let metrics = PrometheusMetrics::new(None);
+        let server = new_metered_server(vec![], metrics.clone());
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let book = add_book(&server, vec![author.id.clone()], 1).await;
+
+        // Act
+        let result = server
+            .place_order(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::PlaceOrderHeaderParams {
+                    accept_currency: None,
+                },
+                &models::PlaceOrderQueryParams { currency: None },
+                &new_order(&customer.id, &book.id, 2),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Ok(store::PlaceOrderResponse::Status422_ValidationException(problem)) if problem.status == 422
+        ));
+        let rendered = metrics.render().unwrap();
+        assert!(rendered.contains("stock_outs_total 1"));
+        assert!(rendered.contains("orders_placed_total 0"));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_shipped_order_rejected() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let book = add_book(&server, vec![author.id.clone()], 7).await;
+        let order = match server
+            .place_order(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::PlaceOrderHeaderParams {
+                    accept_currency: None,
+                },
+                &models::PlaceOrderQueryParams { currency: None },
+                &new_order(&customer.id, &book.id, 2),
+            )
+            .await
+        {
+            Ok(store::PlaceOrderResponse::Status200_SuccessfulOperation(order)) => order,
+            other => panic!("Expected order to be placed, got {:?}", other),
+        };
+        let order_id = order.id.clone();
+        let shipped = server
+            .update_order(
+                &Method::PATCH,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::UpdateOrderHeaderParams { if_match: None },
+                &models::UpdateOrderPathParams {
+                    order_id: order_id.clone(),
+                },
+                &models::OrderProperties::new(order.shipping_date, String::from("shipped")),
+            )
+            .await;
+
+        // Act
+        let result = server
+            .delete_order(
+                &Method::DELETE,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::DeleteOrderPathParams { order_id },
+                &models::DeleteOrderQueryParams { reason: None },
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            shipped,
+            Ok(store::UpdateOrderResponse::Status200_SuccessfulOperation { body: ref o, .. }) if o.shipped_at.is_some()
+        ));
+        assert!(matches!(
+            result,
+            Ok(store::DeleteOrderResponse::Status422_OrderCanNotBeCanceled(problem)) if problem.status == 422
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_order_history() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let book = add_book(&server, vec![author.id.clone()], 7).await;
+        let order = match server
+            .place_order(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::PlaceOrderHeaderParams {
+                    accept_currency: None,
+                },
+                &models::PlaceOrderQueryParams { currency: None },
+                &new_order(&customer.id, &book.id, 2),
+            )
+            .await
+        {
+            Ok(store::PlaceOrderResponse::Status200_SuccessfulOperation(order)) => order,
+            other => panic!("Expected order to be placed, got {:?}", other),
+        };
+        let _ = server
+            .delete_order(
+                &Method::DELETE,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::DeleteOrderPathParams {
+                    order_id: order.id.clone(),
+                },
+                &models::DeleteOrderQueryParams {
+                    reason: Some(String::from("  ")),
+                },
+            )
+            .await;
+
+        // Act
+        let result = server
+            .get_order_history(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::GetOrderHistoryPathParams {
+                    order_id: order.id.clone(),
+                },
+            )
+            .await;
+        let unknown = server
+            .get_order_history(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::GetOrderHistoryPathParams {
+                    order_id: Ksuid::new(None, None).to_string(),
+                },
+            )
+            .await;
+
+        // Assert
+        match result {
+            Ok(store::GetOrderHistoryResponse::Status200_SuccessfulOperation(history)) => {
+                assert_eq!(history.order_id, order.id);
+                assert_eq!(history.events.len(), 2);
+                assert_eq!(history.events[0].from_status, None);
+                assert_eq!(history.events[0].to_status, "placed");
+                assert_eq!(history.events[1].from_status.as_deref(), Some("placed"));
+                assert_eq!(history.events[1].to_status, "canceled");
+                // the actor is the name of the API key, a blank reason is dropped
+                assert_eq!(history.events[1].actor, "admin");
+                assert_eq!(history.events[1].reason, None);
+            }
+            other => panic!("Expected order history, got {:?}", other),
+        }
+        assert!(matches!(
+            unknown,
+            Ok(store::GetOrderHistoryResponse::Status404_OrderNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_add_and_update_customer() {
+        // Arrange
+        let server = new_server();
+        let mut body =
+            models::NewCustomer::new(String::from(" Johann "), String::from("johann@example.com"));
+        body.addresses = Some(vec![models::Address::new(
+            String::from("Berlinerstrasse"),
+            String::from("12b"),
+            String::from("80331"),
+            String::from("Munich"),
+            String::from("DE"),
+        )]);
+        let customer = match server
+            .add_customer(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await
+        {
+            Ok(customer::AddCustomerResponse::Status200_SuccessfulOperation(customer)) => customer,
+            other => panic!("Expected customer to be created, got {:?}", other),
+        };
+        let mut office = customer.addresses[0].clone();
+        office.street = String::from("Goethestrasse");
+        let props = models::CustomerProperties {
+            name: None,
+            email: Some(String::from("wolfgang@example.com")),
+            addresses: Some(vec![office.clone(), customer.addresses[0].clone()]),
+        };
+
+        // Act
+        let updated = server
+            .update_customer(
+                &Method::PATCH,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::UpdateCustomerPathParams {
+                    customer_id: customer.id.clone(),
+                },
+                &props,
+            )
+            .await;
+        let found = server
+            .get_customer_by_id(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::GetCustomerByIdPathParams {
+                    customer_id: customer.id.clone(),
+                },
+            )
+            .await;
+        let duplicate = server
+            .add_customer(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await;
+        body.email = String::from("johann at example.com");
+        let invalid = server
+            .add_customer(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await;
+
+        // Assert
+        assert_eq!(customer.name, "Johann");
+        assert!(matches!(
+            updated,
+            Ok(customer::UpdateCustomerResponse::Status200_SuccessfulOperation(ref c))
+                if c.name == "Johann" && c.email == "wolfgang@example.com"
+        ));
+        match found {
+            Ok(customer::GetCustomerByIdResponse::Status200_SuccessfulOperation(found)) => {
+                assert_eq!(found.addresses, vec![office, customer.addresses[0].clone()]);
+            }
+            other => panic!("Expected customer, got {:?}", other),
+        }
+        // the email of the updated customer is free again
+        assert!(matches!(
+            duplicate,
+            Ok(customer::AddCustomerResponse::Status200_SuccessfulOperation(_))
+        ));
+        assert!(matches!(
+            invalid,
+            Ok(customer::AddCustomerResponse::Status400_InvalidInput(problem))
+                if problem.field.as_deref() == Some("email")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_place_order_for_unknown_customer() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let book = add_book(&server, vec![author.id.clone()], 7).await;
+        let unknown = Ksuid::new(None, None).to_string();
+
+        // Act
+        let result = server
+            .place_order(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::PlaceOrderHeaderParams {
+                    accept_currency: None,
+                },
+                &models::PlaceOrderQueryParams { currency: None },
+                &new_order(&unknown, &book.id, 2),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Ok(store::PlaceOrderResponse::Status422_ValidationException(problem)) if problem.status == 422
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_customer_orders_by_status_paginated() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let other = add_customer(&server, "other@example.com").await;
+        let book = add_book(&server, vec![author.id.clone()], 10).await;
+        let mut placed = Vec::new();
+        for _ in 0..3 {
+            placed.push(place(&server, &new_order(&customer.id, &book.id, 1)).await);
+        }
+        place(&server, &new_order(&other.id, &book.id, 1)).await;
+        let _ = server
+            .delete_order(
+                &Method::DELETE,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::DeleteOrderPathParams {
+                    order_id: placed[1].id.clone(),
+                },
+                &models::DeleteOrderQueryParams { reason: None },
+            )
+            .await;
+        let query = |status: &str, cursor: Option<String>| models::GetCustomerOrdersQueryParams {
+            status: Some(vec![String::from(status)]),
+            limit: Some(1),
+            cursor,
+        };
+        let path = models::GetCustomerOrdersPathParams {
+            customer_id: customer.id.clone(),
+        };
+
+        // Act
+        let first = server
+            .get_customer_orders(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &path,
+                &query("placed", None),
+            )
+            .await;
+        let Ok(customer::GetCustomerOrdersResponse::Status200_SuccessfulOperation(first)) = first
+        else {
+            panic!("Expected the first page, got {:?}", first);
+        };
+        let second = server
+            .get_customer_orders(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &path,
+                &query("placed", first.next_cursor.clone()),
+            )
+            .await;
+        let invalid = server
+            .get_customer_orders(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &path,
+                &query("lost", None),
+            )
+            .await;
+        let unknown = server
+            .get_customer_orders(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::GetCustomerOrdersPathParams {
+                    customer_id: Ksuid::new(None, None).to_string(),
+                },
+                &query("placed", None),
+            )
+            .await;
+
+        // Assert
+        let mut expected = [placed[0].id.clone(), placed[2].id.clone()];
+        expected.sort();
+        assert_eq!(first.items.len(), 1);
+        assert_eq!(first.items[0].id, expected[0]);
+        assert!(first.next_cursor.is_some());
+        match second {
+            Ok(customer::GetCustomerOrdersResponse::Status200_SuccessfulOperation(page)) => {
+                assert_eq!(page.items.len(), 1);
+                assert_eq!(page.items[0].id, expected[1]);
+                assert_eq!(page.next_cursor, None);
+            }
+            other => panic!("Expected the second page, got {:?}", other),
+        }
+        assert!(matches!(
+            invalid,
+            Ok(customer::GetCustomerOrdersResponse::Status400_InvalidParameters(_))
+        ));
+        assert!(matches!(
+            unknown,
+            Ok(customer::GetCustomerOrdersResponse::Status404_CustomerNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_customer_with_orders_rejected() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let without_orders = add_customer(&server, "other@example.com").await;
+        let book = add_book(&server, vec![author.id.clone()], 7).await;
+        place(&server, &new_order(&customer.id, &book.id, 1)).await;
+
+        // Act
+        let rejected = server
+            .delete_customer(
+                &Method::DELETE,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::DeleteCustomerPathParams {
+                    customer_id: customer.id.clone(),
+                },
+            )
+            .await;
+        let deleted = server
+            .delete_customer(
+                &Method::DELETE,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::DeleteCustomerPathParams {
+                    customer_id: without_orders.id.clone(),
+                },
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            rejected,
+            Ok(customer::DeleteCustomerResponse::Status422_CustomerHasPlacedOrders(_))
+        ));
+        assert!(matches!(
+            deleted,
+            Ok(customer::DeleteCustomerResponse::Status200_SuccessfullyDeleted)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_referenced_author_and_book_rejected() {
+        // Arrange
+        let server = new_server();
+        let author = add_author(&server).await;
+        let customer = add_customer(&server, "johann@example.com").await;
+        let book = add_book(&server, vec![author.id.clone()], 7).await;
+        place(&server, &new_order(&customer.id, &book.id, 1)).await;
+
+        // Act
+        let author_deleted = server
+            .delete_author(
+                &Method::DELETE,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::DeleteAuthorPathParams {
+                    author_id: author.id.clone(),
+                },
+            )
+            .await;
+        let book_deleted = server
+            .delete_book(
+                &Method::DELETE,
+                &host(),
+                &CookieJar::new(),
+                &admin(),
+                &models::DeleteBookPathParams {
+                    book_id: book.id.clone(),
+                },
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            author_deleted,
+            Ok(author::DeleteAuthorResponse::Status422_AuthorHasBooks(problem))
+                if problem.r#type.ends_with("constraint-violation")
+        ));
+        assert!(matches!(
+            book_deleted,
+            Ok(book::DeleteBookResponse::Status422_BookIsPartOfOrders(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_extract_claims_from_header_valid_key() {
+        // Arrange
+        let server = new_server();
+
+        // Act
+        let claims = server
+            .extract_claims_from_header(&headers(API_KEY), "X-API-KEY")
+            .await;
+
+        // Assert
+        assert_eq!(
+            claims,
+            Some(domain::models::ClaimsDomain {
+                key_name: String::from("backoffice"),
+                roles: vec![domain::models::ApiKeyRole::CatalogRead],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_claims_from_header_missing_or_expired_key() {
+        // Arrange
+        let server = new_server();
+
+        // Act
+        let missing = server
+            .extract_claims_from_header(&HeaderMap::new(), "X-API-KEY")
+            .await;
+        let unknown = server
+            .extract_claims_from_header(&headers("unknown"), "X-API-KEY")
+            .await;
+        let expired = server
+            .extract_claims_from_header(&headers(EXPIRED_API_KEY), "X-API-KEY")
+            .await;
+
+        // Assert
+        assert_eq!(missing, None);
+        assert_eq!(unknown, None);
+        assert_eq!(expired, None);
+    }
+
+    #[tokio::test]
+    async fn test_missing_role_is_forbidden() {
+        // Arrange
+        let server = new_server();
+        let reader = domain::models::ClaimsDomain {
+            key_name: String::from("frontend"),
+            roles: vec![domain::models::ApiKeyRole::CatalogRead],
+        };
+        let body = models::NewGenre::new(String::from("Drama"));
+
+        // Act
+        let add_genre = server
+            .add_genre(&Method::POST, &host(), &CookieJar::new(), &reader, &body)
+            .await;
+        let get_inventory = server
+            .get_inventory(&Method::GET, &host(), &CookieJar::new(), &reader)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            add_genre,
+            Ok(genre::AddGenreResponse::Status403_Forbidden(problem)) if problem.status == 403
+        ));
+        assert!(matches!(
+            get_inventory,
+            Ok(store::GetInventoryResponse::Status403_Forbidden(_))
+        ));
+    }
+
+    fn webhook_admin() -> domain::models::ClaimsDomain {
+        domain::models::ClaimsDomain {
+            key_name: String::from("partners"),
+            roles: vec![domain::models::ApiKeyRole::WebhookAdmin],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_manage_webhooks() {
+        // Arrange
+        let server = new_server();
+        let body = models::NewWebhook::new(
+            String::from("https://partner.example.com/hooks"),
+            vec![String::from("OrderPlaced")],
+            String::from("0123456789abcdef"),
+        );
+        let props = models::WebhookProperties {
+            url: None,
+            event_types: Some(vec![
+                String::from("OrderPlaced"),
+                String::from("OrderStatusChanged"),
+            ]),
+            secret: None,
+        };
+
+        // Act
+        let forbidden = server
+            .add_webhook(&Method::POST, &host(), &CookieJar::new(), &admin(), &body)
+            .await;
+        let created = match server
+            .add_webhook(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &webhook_admin(),
+                &body,
+            )
+            .await
+        {
+            Ok(webhook::AddWebhookResponse::Status200_SuccessfulOperation(webhook)) => webhook,
+            other => panic!("Expected webhook to be created, got {:?}", other),
+        };
+        let updated = server
+            .update_webhook(
+                &Method::PATCH,
+                &host(),
+                &CookieJar::new(),
+                &webhook_admin(),
+                &models::UpdateWebhookPathParams {
+                    webhook_id: created.id.clone(),
+                },
+                &props,
+            )
+            .await;
+        let listed = server
+            .get_webhooks(&Method::GET, &host(), &CookieJar::new(), &webhook_admin())
+            .await;
+        let deliveries = server
+            .get_webhook_deliveries(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &webhook_admin(),
+                &models::GetWebhookDeliveriesPathParams {
+                    webhook_id: created.id.clone(),
+                },
+                &models::GetWebhookDeliveriesQueryParams {
+                    status: Some(String::from("failed")),
+                    limit: None,
+                    cursor: None,
+                },
+            )
+            .await;
+        let deleted = server
+            .delete_webhook(
+                &Method::DELETE,
+                &host(),
+                &CookieJar::new(),
+                &webhook_admin(),
+                &models::DeleteWebhookPathParams {
+                    webhook_id: created.id.clone(),
+                },
+            )
+            .await;
+        let not_found = server
+            .get_webhook_by_id(
+                &Method::GET,
+                &host(),
+                &CookieJar::new(),
+                &webhook_admin(),
+                &models::GetWebhookByIdPathParams {
+                    webhook_id: created.id.clone(),
+                },
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(
+            forbidden,
+            Ok(webhook::AddWebhookResponse::Status403_Forbidden(_))
+        ));
+        assert_eq!(created.url, "https://partner.example.com/hooks");
+        match updated {
+            Ok(webhook::UpdateWebhookResponse::Status200_SuccessfulOperation(webhook)) => {
+                assert_eq!(
+                    webhook.event_types,
+                    vec!["OrderPlaced", "OrderStatusChanged"]
+                );
+                assert_eq!(webhook.url, created.url);
+            }
+            other => panic!("Expected webhook to be updated, got {:?}", other),
+        }
+        assert!(matches!(
+            listed,
+            Ok(webhook::GetWebhooksResponse::Status200_SuccessfulOperation(webhooks))
+                if webhooks.len() == 1
+        ));
+        assert!(matches!(
+            deliveries,
+            Ok(webhook::GetWebhookDeliveriesResponse::Status200_SuccessfulOperation(page))
+                if page.items.is_empty() && page.next_cursor.is_none()
+        ));
+        assert!(matches!(
+            deleted,
+            Ok(webhook::DeleteWebhookResponse::Status200_SuccessfullyDeleted)
+        ));
+        assert!(matches!(
+            not_found,
+            Ok(webhook::GetWebhookByIdResponse::Status404_WebhookNotFound(
+                _
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_add_webhook_invalid_input() {
+        // Arrange
+        let server = new_server();
+        let unknown_event = models::NewWebhook::new(
+            String::from("https://partner.example.com/hooks"),
+            vec![String::from("OrderShipped")],
+            String::from("0123456789abcdef"),
+        );
+        let short_secret = models::NewWebhook::new(
+            String::from("https://partner.example.com/hooks"),
+            vec![String::from("OrderPlaced")],
+            String::from("too short"),
+        );
+
+        // Act
+        let unknown_event = server
+            .add_webhook(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &webhook_admin(),
+                &unknown_event,
+            )
+            .await;
+        let short_secret = server
+            .add_webhook(
+                &Method::POST,
+                &host(),
+                &CookieJar::new(),
+                &webhook_admin(),
+                &short_secret,
+            )
+            .await;
+
+        // Assert
+        match unknown_event {
+            Ok(webhook::AddWebhookResponse::Status400_InvalidInput(problem)) => {
+                assert_eq!(problem.field.as_deref(), Some("event_types"));
+                assert!(problem.r#type.ends_with("/invalid-event-type"));
+            }
+            other => panic!("Expected invalid input, got {:?}", other),
+        }
+        assert!(matches!(
+            short_secret,
+            Ok(webhook::AddWebhookResponse::Status422_ValidationException(
+                _
+            ))
+        ));
+    }
+
+    fn add_genre_request(idempotency_key: &str, name: &str) -> axum::extract::Request {
+        axum::extract::Request::builder()
+            .method(Method::POST)
+            .uri("/api/v1/genres")
+            .header("Host", "localhost")
+            .header("X-API-KEY", ADMIN_API_KEY)
+            .header("Idempotency-Key", idempotency_key)
+            .header("Content-Type", "application/json")
+            .body(axum::body::Body::from(format!("{{\"name\":\"{}\"}}", name)))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_router_replays_retried_post() {
+        // Arrange
+        let app = router(
+            new_server(),
+            domain::idempotency_service::IdempotencyService::new(
+                InMemoryRepository::new(),
+                Duration::hours(1),
+                Duration::minutes(1),
+            ),
+            PrometheusMetrics::new(None),
+        );
+
+        // Act
+        let first = app
+            .clone()
+            .oneshot(add_genre_request("genre-1", "Drama"))
+            .await
+            .unwrap();
+        let retry = app
+            .clone()
+            .oneshot(add_genre_request("genre-1", "Drama"))
+            .await
+            .unwrap();
+        let reused = app
+            .clone()
+            .oneshot(add_genre_request("genre-1", "Poetry"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(first.status(), http::StatusCode::OK);
+        assert_eq!(retry.status(), http::StatusCode::OK);
+        assert_eq!(
+            retry.headers().get(idempotency::IDEMPOTENT_REPLAYED_HEADER),
+            Some(&http::HeaderValue::from_static("true"))
+        );
+        let first = axum::body::to_bytes(first.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let retry = axum::body::to_bytes(retry.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        // the genre was created once
+        assert_eq!(first, retry);
+        assert_eq!(reused.status(), http::StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_router_records_requests_by_route() {
+        // Arrange
+        let metrics = PrometheusMetrics::new(None);
+        let app = router(
+            new_metered_server(vec![], metrics.clone()),
+            domain::idempotency_service::IdempotencyService::new(
+                InMemoryRepository::new(),
+                Duration::hours(1),
+                Duration::minutes(1),
+            ),
+            metrics.clone(),
+        );
+        let get = |uri: &str| {
+            axum::extract::Request::builder()
+                .uri(uri)
+                .header("Host", "localhost")
+                .header("X-API-KEY", API_KEY)
+                .body(axum::body::Body::empty())
+                .unwrap()
+        };
+
+        // Act
+        for uri in [
+            "/api/v1/books/2zBxXvN7cP6o7CNiRbgWsM7PjzD",
+            "/api/v1/books/2zBxXvN7cP6o7CNiRbgWsM7PjzE",
+            "/api/v1/unknown",
+        ] {
+            app.clone().oneshot(get(uri)).await.unwrap();
+        }
+
+        // Assert
+        let rendered = metrics.render().unwrap();
+        assert!(rendered.contains(
+            "http_requests_total{method=\"GET\",route=\"/api/v1/books/{book_id}\",status=\"404\"} 2"
+        ));
+        assert!(
+            rendered.contains(
+                "http_requests_total{method=\"GET\",route=\"unmatched\",status=\"404\"} 1"
+            )
+        );
+        assert!(rendered.contains(
+            "http_request_duration_seconds_count{method=\"GET\",route=\"/api/v1/books/{book_id}\",status=\"404\"} 2"
+        ));
     }
 }
@@ -0,0 +1,231 @@
+use axum::extract::{MatchedPath, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use http::HeaderValue;
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry_http::HeaderExtractor;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use svix_ksuid::{Ksuid, KsuidLike};
+use tracing::field::Empty;
+use tracing::{Instrument, info_span, warn};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use super::metrics::UNMATCHED_ROUTE;
+
+/// The header of the id which identifies the request in the logs, echoed in the response
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// The maximum length of a request id given by the client
+pub const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    /// The id of the request handled by the current task, set by the telemetry middleware
+    static REQUEST_ID: String;
+}
+
+/// The id of the request handled by the current task, None outside of the telemetry middleware
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// A request id given by the client is kept if it is short and printable,
+/// so it cannot break the log lines
+pub fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LENGTH
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+}
+
+/// Runs the request in a span which continues the W3C trace context of the traceparent header,
+/// a request without traceparent starts a new trace.
+/// The span carries the request id, the trace id and its span id, so every log line of the
+/// request can be correlated with the trace. The request id of the client is kept,
+/// otherwise a new one is generated, and is returned in the x-request-id header.
+/// The inner layers and the handlers get it from current_request_id.
+pub async fn telemetry_middleware(request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|id| id.to_str().ok())
+        .filter(|id| is_valid_request_id(id))
+        .map(String::from)
+        .unwrap_or_else(|| Ksuid::new(None, None).to_string());
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_owned())
+        .unwrap_or_else(|| String::from(UNMATCHED_ROUTE));
+
+    let span = info_span!(
+        "request",
+        otel.name = %format!("{} {}", method, route),
+        otel.kind = "server",
+        otel.status_code = Empty,
+        http.request.method = %method,
+        http.route = %route,
+        http.response.status_code = Empty,
+        request_id = %request_id,
+        trace_id = Empty,
+        span_id = Empty,
+    );
+    let parent = TraceContextPropagator::new().extract(&HeaderExtractor(request.headers()));
+    // fails only if the span is disabled by the filter or tracing is not initialized
+    let _ = span.set_parent(parent);
+    let context = span.context();
+    let span_context = context.span().span_context().clone();
+    if span_context.is_valid() {
+        span.record("trace_id", span_context.trace_id().to_string());
+        span.record("span_id", span_context.span_id().to_string());
+    }
+
+    let mut response = REQUEST_ID
+        .scope(
+            request_id.clone(),
+            next.run(request).instrument(span.clone()),
+        )
+        .await;
+
+    let status = response.status();
+    span.record("http.response.status_code", status.as_u16());
+    if status.is_server_error() {
+        span.record("otel.status_code", "ERROR");
+    }
+    match HeaderValue::from_str(&request_id) {
+        Ok(value) => {
+            response.headers_mut().insert(REQUEST_ID_HEADER, value);
+        }
+        Err(e) => warn!("failed to echo the request id: {}", e),
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::rest::problem_mappers::{
+        map_invalid_idempotency_key_to_problem, problem_response,
+    };
+    use axum::Router;
+    use axum::body::Body;
+    use axum::extract::State;
+    use axum::routing::get;
+    use opentelemetry::trace::TracerProvider;
+    use opentelemetry_sdk::trace::SdkTracerProvider;
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+    use tracing::Span;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// The trace ids the handler ran in
+    type TraceIds = Arc<Mutex<Vec<String>>>;
+
+    async fn handler(State(trace_ids): State<TraceIds>) -> &'static str {
+        let trace_id = Span::current()
+            .context()
+            .span()
+            .span_context()
+            .trace_id()
+            .to_string();
+        trace_ids.lock().unwrap().push(trace_id);
+        "ok"
+    }
+
+    fn router(trace_ids: TraceIds) -> Router {
+        Router::new()
+            .route("/books/{book_id}", get(handler))
+            .with_state(trace_ids)
+            .layer(axum::middleware::from_fn(telemetry_middleware))
+    }
+
+    /// Installs the OpenTelemetry layer for the current thread, the spans are not exported
+    fn subscriber() -> impl tracing::Subscriber + Send + Sync {
+        let tracer = SdkTracerProvider::builder().build().tracer("test");
+        tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer))
+    }
+
+    #[test]
+    fn test_is_valid_request_id() {
+        // Assert
+        assert!(is_valid_request_id("2zBxXvN7cP6o7CNiRbgWsM7PjzD"));
+        assert!(is_valid_request_id("6f1c0c1e-8b5e-4d7a-9a51-0e4f8c1b2d3a"));
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id("id with spaces"));
+        assert!(!is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LENGTH + 1)));
+    }
+
+    #[tokio::test]
+    async fn test_request_continues_the_trace_of_the_traceparent() {
+        // Arrange
+        let _guard = tracing::subscriber::set_default(subscriber());
+        let trace_ids = TraceIds::default();
+        let request = Request::get("/books/42")
+            .header(
+                "traceparent",
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            )
+            .header(REQUEST_ID_HEADER, "req-42")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router(trace_ids.clone()).oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "req-42");
+        assert_eq!(
+            *trace_ids.lock().unwrap(),
+            vec!["4bf92f3577b34da6a3ce929d0e0e4736"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_without_traceparent_starts_a_trace() {
+        // Arrange
+        let _guard = tracing::subscriber::set_default(subscriber());
+        let trace_ids = TraceIds::default();
+        let request = Request::get("/books/42")
+            .header(REQUEST_ID_HEADER, "not a valid id")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router(trace_ids.clone()).oneshot(request).await.unwrap();
+
+        // Assert
+        let request_id = response.headers()[REQUEST_ID_HEADER].to_str().unwrap();
+        assert!(Ksuid::from_base62(request_id).is_ok());
+        let trace_ids = trace_ids.lock().unwrap();
+        assert_eq!(trace_ids.len(), 1);
+        assert_ne!(trace_ids[0], "00000000000000000000000000000000");
+    }
+
+    #[tokio::test]
+    async fn test_problem_correlation_id_is_the_request_id() {
+        // Arrange
+        let router = Router::new()
+            .route(
+                "/problem",
+                get(|| async { problem_response(map_invalid_idempotency_key_to_problem()) }),
+            )
+            .layer(axum::middleware::from_fn(telemetry_middleware));
+        let request = Request::get("/problem").body(Body::empty()).unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        let request_id = response.headers()[REQUEST_ID_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let problem: openapi::models::Problem = serde_json::from_slice(&body).unwrap();
+        assert_eq!(problem.correlation_id, Some(request_id));
+        assert_eq!(current_request_id(), None);
+    }
+}
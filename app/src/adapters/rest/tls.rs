@@ -0,0 +1,451 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use axum::serve::Listener;
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::{ClientHello, ResolvesServerCert, WebPkiClientVerifier};
+use rustls::sign::CertifiedKey;
+use rustls::{RootCertStore, ServerConfig};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_rustls::TlsAcceptor;
+use tokio_rustls::server::TlsStream;
+use tracing::{debug, info, warn};
+
+/// The names of the self-signed development certificate
+pub const SELF_SIGNED_NAMES: [&str; 3] = ["localhost", "127.0.0.1", "::1"];
+/// The time a client has to complete the handshake, so slow clients cannot hold connections
+pub const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// The handshaken connections waiting to be served
+const ACCEPT_BACKLOG: usize = 64;
+
+#[derive(Debug)]
+pub enum TlsError {
+    /// a certificate, key or CA file cannot be read or parsed
+    File { path: PathBuf, message: String },
+    /// the certificates and keys are not a valid TLS configuration
+    Config { message: String },
+}
+
+impl fmt::Display for TlsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TlsError::File { path, message } => {
+                write!(f, "Failed to load {}: {}", path.display(), message)
+            }
+            TlsError::Config { message } => write!(f, "Invalid TLS configuration: {}", message),
+        }
+    }
+}
+
+impl Error for TlsError {}
+
+fn file_error(path: &Path, e: impl fmt::Display) -> TlsError {
+    TlsError::File {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    }
+}
+
+fn config_error(e: impl fmt::Display) -> TlsError {
+    TlsError::Config {
+        message: e.to_string(),
+    }
+}
+
+fn provider() -> Arc<CryptoProvider> {
+    Arc::new(rustls::crypto::ring::default_provider())
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, TlsError> {
+    std::fs::read(path).map_err(|e| file_error(path, e))
+}
+
+fn read_certificates(path: &Path, pem: &[u8]) -> Result<Vec<CertificateDer<'static>>, TlsError> {
+    let certificates = CertificateDer::pem_slice_iter(pem)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| file_error(path, e))?;
+    if certificates.is_empty() {
+        return Err(file_error(path, "no PEM certificate found"));
+    }
+    Ok(certificates)
+}
+
+/// Parses the PEM certificate chain, leaf first, and the PEM private key of the leaf
+fn certified_key(
+    cert: &Path,
+    cert_pem: &[u8],
+    key: &Path,
+    key_pem: &[u8],
+) -> Result<CertifiedKey, TlsError> {
+    let chain = read_certificates(cert, cert_pem)?;
+    let private_key = PrivateKeyDer::from_pem_slice(key_pem).map_err(|e| file_error(key, e))?;
+    CertifiedKey::from_der(chain, private_key, &provider()).map_err(|e| TlsError::Config {
+        message: format!(
+            "the key {} does not fit the certificate {}: {}",
+            key.display(),
+            cert.display(),
+            e
+        ),
+    })
+}
+
+/// The certificate and key files and the content the current certificate was loaded from
+#[derive(Debug)]
+struct CertificateFiles {
+    cert: PathBuf,
+    key: PathBuf,
+    loaded: Mutex<(Vec<u8>, Vec<u8>)>,
+}
+
+/// Resolves the certificate of the server for every handshake.
+/// A certificate loaded from files is replaced by a reload once the files changed,
+/// so the next handshakes use a rotated certificate without a restart.
+#[derive(Debug)]
+pub struct CertificateResolver {
+    current: RwLock<Arc<CertifiedKey>>,
+    files: Option<CertificateFiles>,
+}
+
+impl CertificateResolver {
+    /// Loads the PEM certificate chain, leaf first, and the PEM private key
+    pub fn from_files(cert: &Path, key: &Path) -> Result<Arc<Self>, TlsError> {
+        let cert_pem = read_file(cert)?;
+        let key_pem = read_file(key)?;
+        let certified = certified_key(cert, &cert_pem, key, &key_pem)?;
+        Ok(Arc::new(CertificateResolver {
+            current: RwLock::new(Arc::new(certified)),
+            files: Some(CertificateFiles {
+                cert: cert.to_path_buf(),
+                key: key.to_path_buf(),
+                loaded: Mutex::new((cert_pem, key_pem)),
+            }),
+        }))
+    }
+
+    /// Generates a self-signed certificate for localhost, for development only
+    pub fn self_signed() -> Result<Arc<Self>, TlsError> {
+        let names: Vec<String> = SELF_SIGNED_NAMES.iter().map(|n| n.to_string()).collect();
+        let generated = rcgen::generate_simple_self_signed(names).map_err(config_error)?;
+        let private_key =
+            PrivateKeyDer::try_from(generated.signing_key.serialize_der()).map_err(config_error)?;
+        let certified =
+            CertifiedKey::from_der(vec![generated.cert.der().clone()], private_key, &provider())
+                .map_err(config_error)?;
+        Ok(Arc::new(CertificateResolver {
+            current: RwLock::new(Arc::new(certified)),
+            files: None,
+        }))
+    }
+
+    /// The certificate the next handshakes use
+    pub fn current(&self) -> Arc<CertifiedKey> {
+        self.current
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Loads the certificate again if the files changed, returns whether it was replaced.
+    /// An invalid certificate is rejected and the current one is kept, e.g. if the
+    /// certificate was rotated but the key not yet, the next reload picks up both.
+    pub fn reload(&self) -> Result<bool, TlsError> {
+        let Some(files) = &self.files else {
+            return Ok(false);
+        };
+        let cert_pem = read_file(&files.cert)?;
+        let key_pem = read_file(&files.key)?;
+        let mut loaded = files.loaded.lock().unwrap_or_else(|e| e.into_inner());
+        if loaded.0 == cert_pem && loaded.1 == key_pem {
+            return Ok(false);
+        }
+        let certified = certified_key(&files.cert, &cert_pem, &files.key, &key_pem)?;
+        *self.current.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(certified);
+        *loaded = (cert_pem, key_pem);
+        Ok(true)
+    }
+
+    /// Reloads the certificate every interval until the task is aborted
+    pub async fn watch(self: Arc<Self>, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        // the first tick completes immediately, the certificate was just loaded
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            match self.reload() {
+                Ok(true) => info!("reloaded the rotated TLS certificate"),
+                Ok(false) => {}
+                Err(e) => warn!("keeping the current TLS certificate: {}", e),
+            }
+        }
+    }
+}
+
+impl ResolvesServerCert for CertificateResolver {
+    fn resolve(&self, _client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        Some(self.current())
+    }
+}
+
+/// How the certificates of the clients are verified
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClientVerification {
+    /// the clients are not asked for a certificate
+    None,
+    /// a certificate of a client must be issued by the CA, clients without one are accepted
+    Optional { ca: PathBuf },
+    /// every client must present a certificate issued by the CA
+    Required { ca: PathBuf },
+}
+
+/// Builds the acceptor of the TLS connections with the certificate of the resolver
+pub fn acceptor(
+    resolver: Arc<CertificateResolver>,
+    clients: &ClientVerification,
+) -> Result<TlsAcceptor, TlsError> {
+    let builder = ServerConfig::builder_with_provider(provider())
+        .with_safe_default_protocol_versions()
+        .map_err(config_error)?;
+    let builder = match clients {
+        ClientVerification::None => builder.with_no_client_auth(),
+        ClientVerification::Optional { ca } | ClientVerification::Required { ca } => {
+            let mut roots = RootCertStore::empty();
+            for certificate in read_certificates(ca, &read_file(ca)?)? {
+                roots.add(certificate).map_err(|e| file_error(ca, e))?;
+            }
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider());
+            let verifier = match clients {
+                ClientVerification::Optional { .. } => verifier.allow_unauthenticated(),
+                _ => verifier,
+            };
+            builder.with_client_cert_verifier(verifier.build().map_err(config_error)?)
+        }
+    };
+    let mut config = builder.with_cert_resolver(resolver);
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// The Listener of axum::serve for TLS connections.
+/// The handshakes run concurrently in the background, so a slow client does not
+/// delay the others, failed handshakes are dropped.
+pub struct TlsListener {
+    local_addr: SocketAddr,
+    connections: mpsc::Receiver<(TlsStream<TcpStream>, SocketAddr)>,
+    accept_task: JoinHandle<()>,
+}
+
+impl TlsListener {
+    pub fn new(listener: TcpListener, acceptor: TlsAcceptor) -> io::Result<Self> {
+        let local_addr = listener.local_addr()?;
+        let (sender, connections) = mpsc::channel(ACCEPT_BACKLOG);
+        let accept_task = tokio::spawn(accept_connections(listener, acceptor, sender));
+        Ok(TlsListener {
+            local_addr,
+            connections,
+            accept_task,
+        })
+    }
+}
+
+impl Drop for TlsListener {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+async fn accept_connections(
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+    sender: mpsc::Sender<(TlsStream<TcpStream>, SocketAddr)>,
+) {
+    loop {
+        let (stream, address) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                // e.g. too many open files, give the served connections time to close
+                warn!("failed to accept a connection: {}", e);
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+        };
+        let acceptor = acceptor.clone();
+        let sender = sender.clone();
+        tokio::spawn(async move {
+            match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                Ok(Ok(stream)) => {
+                    // the listener was dropped if the receiver is gone
+                    let _ = sender.send((stream, address)).await;
+                }
+                Ok(Err(e)) => debug!(%address, "TLS handshake failed: {}", e),
+                Err(_) => debug!(%address, "TLS handshake timed out"),
+            }
+        });
+    }
+}
+
+impl Listener for TlsListener {
+    type Io = TlsStream<TcpStream>;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        match self.connections.recv().await {
+            Some(connection) => connection,
+            // the accept task runs until the listener is dropped
+            None => std::future::pending().await,
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        Ok(self.local_addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::routing::get;
+    use rcgen::{BasicConstraints, CertificateParams, CertifiedIssuer, IsCa, KeyPair};
+    use svix_ksuid::{Ksuid, KsuidLike};
+
+    /// A directory of its own for the files of a test
+    fn test_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tls-{}", Ksuid::new(None, None)));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Serves a hello route with TLS on a free local port, returns the port
+    async fn serve(acceptor: TlsAcceptor) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let listener = TlsListener::new(listener, acceptor).unwrap();
+        let app = Router::new().route("/hello", get(|| async { "hello" }));
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        port
+    }
+
+    fn client(
+        resolver: &CertificateResolver,
+        identity: Option<reqwest::Identity>,
+    ) -> reqwest::Client {
+        let root = reqwest::Certificate::from_der(&resolver.current().cert[0]).unwrap();
+        let mut builder = reqwest::Client::builder()
+            .use_rustls_tls()
+            .add_root_certificate(root);
+        if let Some(identity) = identity {
+            builder = builder.identity(identity);
+        }
+        builder.build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_self_signed_serves_https() {
+        // Arrange
+        let resolver = CertificateResolver::self_signed().unwrap();
+        let port = serve(acceptor(resolver.clone(), &ClientVerification::None).unwrap()).await;
+
+        // Act
+        let response = client(&resolver, None)
+            .get(format!("https://localhost:{}/hello", port))
+            .send()
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().await.unwrap(), "hello");
+    }
+
+    #[test]
+    fn test_reload_rotated_certificate() {
+        // Arrange
+        let dir = test_dir();
+        let (cert, key) = (dir.join("tls.crt"), dir.join("tls.key"));
+        let write = |names: Vec<String>| {
+            let generated = rcgen::generate_simple_self_signed(names).unwrap();
+            std::fs::write(&cert, generated.cert.pem()).unwrap();
+            std::fs::write(&key, generated.signing_key.serialize_pem()).unwrap();
+            generated.cert.der().clone()
+        };
+        let first = write(vec![String::from("localhost")]);
+        let resolver = CertificateResolver::from_files(&cert, &key).unwrap();
+
+        // Act
+        let unchanged = resolver.reload().unwrap();
+        let rotated = write(vec![String::from("orders.internal")]);
+        let changed = resolver.reload().unwrap();
+        // a rotated certificate whose key is not written yet
+        let generated = rcgen::generate_simple_self_signed(vec![String::from("x")]).unwrap();
+        std::fs::write(&cert, generated.cert.pem()).unwrap();
+        let mismatched = resolver.reload();
+
+        // Assert
+        assert!(!unchanged);
+        assert!(changed);
+        assert_ne!(first, rotated);
+        assert!(matches!(mismatched, Err(TlsError::Config { .. })));
+        assert_eq!(resolver.current().cert[0], rotated);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_from_files_missing() {
+        // Act
+        let result = CertificateResolver::from_files(
+            Path::new("/nonexistent/tls.crt"),
+            Path::new("/nonexistent/tls.key"),
+        );
+
+        // Assert
+        assert!(matches!(result, Err(TlsError::File { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_required_client_certificate() {
+        // Arrange
+        let dir = test_dir();
+        let mut ca_params = CertificateParams::new(vec![]).unwrap();
+        ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        let ca = CertifiedIssuer::self_signed(ca_params, KeyPair::generate().unwrap()).unwrap();
+        let ca_file = dir.join("ca.crt");
+        std::fs::write(&ca_file, ca.pem()).unwrap();
+        let client_key = KeyPair::generate().unwrap();
+        let client_cert = CertificateParams::new(vec![String::from("internal-caller")])
+            .unwrap()
+            .signed_by(&client_key, &ca)
+            .unwrap();
+        let identity = reqwest::Identity::from_pem(
+            format!("{}{}", client_cert.pem(), client_key.serialize_pem()).as_bytes(),
+        )
+        .unwrap();
+        let resolver = CertificateResolver::self_signed().unwrap();
+        let port = serve(
+            acceptor(
+                resolver.clone(),
+                &ClientVerification::Required { ca: ca_file },
+            )
+            .unwrap(),
+        )
+        .await;
+        let url = format!("https://localhost:{}/hello", port);
+
+        // Act
+        let anonymous = client(&resolver, None).get(&url).send().await;
+        let authenticated = client(&resolver, Some(identity)).get(&url).send().await;
+
+        // Assert
+        assert!(anonymous.is_err());
+        assert_eq!(authenticated.unwrap().status(), 200);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::config::{LogFormat, StoreBackend};
+
+/// The startup mode of the service
+#[derive(Debug, Clone, PartialEq)]
+pub enum StartupMode {
+    /// apply pending migrations and serve the API
+    Serve,
+    /// apply pending migrations and exit
+    MigrateOnly,
+    /// check that the schema is up to date and exit
+    VerifyOnly,
+}
+
+#[derive(Parser, Debug)]
+#[command(version, about = "OrdeRS book store service")]
+pub struct Cli {
+    /// Apply pending database migrations and exit
+    #[arg(long, conflicts_with = "verify_only")]
+    pub migrate_only: bool,
+
+    /// Verify that the database schema is up to date and exit without applying migrations
+    #[arg(long)]
+    pub verify_only: bool,
+
+    /// Path of the TOML config file
+    #[arg(long, env = "ORDERS_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    /// Print a new random API key together with the hash for the keystore and exit
+    #[arg(long)]
+    pub generate_api_key: bool,
+
+    /// The persistence backend, the in-memory store requires no database
+    #[arg(long, value_enum)]
+    pub store: Option<StoreBackend>,
+
+    /// The socket address the REST API binds to
+    #[arg(long)]
+    pub bind_address: Option<String>,
+
+    /// Serve HTTPS with a generated self-signed certificate, for development only
+    #[arg(long)]
+    pub self_signed_tls: bool,
+
+    /// The postgres connection URL
+    #[arg(long)]
+    pub database_url: Option<String>,
+
+    /// The format of the log lines
+    #[arg(long, value_enum)]
+    pub log_format: Option<LogFormat>,
+}
+
+impl Cli {
+    pub fn startup_mode(&self) -> StartupMode {
+        if self.migrate_only {
+            StartupMode::MigrateOnly
+        } else if self.verify_only {
+            StartupMode::VerifyOnly
+        } else {
+            StartupMode::Serve
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_startup_mode_defaults_to_serve() {
+        // Act
+        let cli = Cli::parse_from(["app"]);
+
+        // Assert
+        assert_eq!(cli.startup_mode(), StartupMode::Serve);
+    }
+
+    #[test]
+    fn test_startup_mode_migrate_only() {
+        // Act
+        let cli = Cli::parse_from(["app", "--migrate-only"]);
+
+        // Assert
+        assert_eq!(cli.startup_mode(), StartupMode::MigrateOnly);
+    }
+
+    #[test]
+    fn test_startup_mode_verify_only() {
+        // Act
+        let cli = Cli::parse_from(["app", "--verify-only"]);
+
+        // Assert
+        assert_eq!(cli.startup_mode(), StartupMode::VerifyOnly);
+    }
+
+    #[test]
+    fn test_store_defaults_to_config() {
+        // Act
+        let cli = Cli::parse_from(["app"]);
+
+        // Assert
+        assert_eq!(cli.store, None);
+    }
+
+    #[test]
+    fn test_store_memory() {
+        // Act
+        let cli = Cli::parse_from(["app", "--store", "memory"]);
+
+        // Assert
+        assert_eq!(cli.store, Some(StoreBackend::Memory));
+    }
+
+    #[test]
+    fn test_migrate_only_conflicts_with_verify_only() {
+        // Act
+        let result = Cli::try_parse_from(["app", "--migrate-only", "--verify-only"]);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
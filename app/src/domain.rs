@@ -1,5 +1,15 @@
+pub mod auth_service;
 pub mod book_service;
+pub mod customer_service;
 pub mod error;
+pub mod event_dispatcher;
+pub mod exchange_service;
+pub mod health_service;
+pub mod idempotency_service;
 pub mod models;
 pub mod order_service;
+pub mod pricing;
 pub mod store;
+pub mod tax_service;
+pub mod webhook_dispatcher;
+pub mod webhook_service;
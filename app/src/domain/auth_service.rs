@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use tracing::instrument;
+
+use super::{error, models, store};
+
+const HASH_PREFIX: &str = "sha256:";
+
+/// Hashes an API key for the keystore.
+/// The keys are random 256 bit values, hence a fast unsalted hash is sufficient.
+pub fn hash_api_key(api_key: &str) -> String {
+    format!(
+        "{}{}",
+        HASH_PREFIX,
+        hex::encode(Sha256::digest(api_key.as_bytes()))
+    )
+}
+
+/// Checks if the value has the format of a hash created by hash_api_key
+pub fn is_api_key_hash(value: &str) -> bool {
+    value.strip_prefix(HASH_PREFIX).is_some_and(|digest| {
+        digest.len() == 64 && digest.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+    })
+}
+
+/// Generates a new random API key
+pub fn generate_api_key() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// The AuthService authenticates the API keys presented by the clients.
+/// The keys are looked up by their hash in the configured ApiKeyHandler keystore.
+pub struct AuthService {
+    repository: Arc<dyn store::ApiKeyHandler + Send + Sync>,
+}
+
+impl AuthService {
+    pub fn new(repository: Arc<dyn store::ApiKeyHandler + Send + Sync>) -> Arc<Self> {
+        Arc::new(AuthService { repository })
+    }
+
+    /// Returns the claims of the API key if it is known and currently valid
+    #[instrument(name = "AuthService::authenticate", skip_all)]
+    pub async fn authenticate(
+        &self,
+        api_key: &str,
+    ) -> Result<models::ClaimsDomain, error::DomainError> {
+        let key = self
+            .repository
+            .get_api_key_by_hash(&hash_api_key(api_key))
+            .await?;
+        if !key.is_valid_at(chrono::Utc::now()) {
+            return Err(error::DomainError::NotFound {
+                id: key.name.clone(),
+                source: Box::new(error::ApiKeyExpiredError(key.name)),
+            });
+        }
+        Ok(models::ClaimsDomain {
+            key_name: key.name,
+            roles: key.roles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::{Duration, Utc};
+
+    struct StaticKeys(Vec<models::ApiKeyDomain>);
+
+    #[async_trait]
+    impl store::ApiKeyHandler for StaticKeys {
+        async fn get_api_key_by_hash(
+            &self,
+            key_hash: &str,
+        ) -> Result<models::ApiKeyDomain, error::DomainError> {
+            self.0
+                .iter()
+                .find(|k| k.key_hash == key_hash)
+                .cloned()
+                .ok_or_else(|| error::DomainError::NotFound {
+                    id: key_hash.to_string(),
+                    source: Box::new(error::ApiKeyNotFoundError(key_hash.to_string())),
+                })
+        }
+    }
+
+    fn api_key(
+        key: &str,
+        valid_from: chrono::DateTime<Utc>,
+        valid_until: Option<chrono::DateTime<Utc>>,
+    ) -> models::ApiKeyDomain {
+        models::ApiKeyDomain {
+            key_hash: hash_api_key(key),
+            name: String::from("frontend"),
+            roles: vec![models::ApiKeyRole::CatalogRead],
+            valid_from,
+            valid_until,
+        }
+    }
+
+    #[test]
+    fn test_hash_api_key() {
+        // Act
+        let hash = hash_api_key("secret");
+
+        // Assert
+        assert_eq!(
+            hash,
+            "sha256:2bb80d537b1da3e38bd30361aa855686bde0eacd7162fef6a25fe97bf527a25b"
+        );
+        assert!(is_api_key_hash(&hash));
+        assert!(!is_api_key_hash("2bb80d537b1da3e38bd30361aa855686"));
+        assert!(!is_api_key_hash(&hash.to_uppercase()));
+    }
+
+    #[test]
+    fn test_generate_api_key() {
+        // Act
+        let first = generate_api_key();
+        let second = generate_api_key();
+
+        // Assert
+        assert_eq!(first.len(), 64);
+        assert_ne!(first, second);
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_accepts_both_keys_during_rotation() {
+        // Arrange
+        let now = Utc::now();
+        let service = AuthService::new(Arc::new(StaticKeys(vec![
+            api_key(
+                "old-key",
+                now - Duration::days(30),
+                Some(now + Duration::hours(1)),
+            ),
+            api_key("new-key", now - Duration::minutes(5), None),
+        ])));
+
+        // Act
+        let old = service.authenticate("old-key").await;
+        let new = service.authenticate("new-key").await;
+
+        // Assert
+        assert_eq!(old.unwrap().key_name, "frontend");
+        assert_eq!(new.unwrap().roles, vec![models::ApiKeyRole::CatalogRead]);
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_rejects_keys_outside_their_window() {
+        // Arrange
+        let now = Utc::now();
+        let service = AuthService::new(Arc::new(StaticKeys(vec![
+            api_key(
+                "expired",
+                now - Duration::days(30),
+                Some(now - Duration::seconds(1)),
+            ),
+            api_key("future", now + Duration::hours(1), None),
+        ])));
+
+        // Act
+        let expired = service.authenticate("expired").await;
+        let future = service.authenticate("future").await;
+        let unknown = service.authenticate("unknown").await;
+
+        // Assert
+        assert!(matches!(expired, Err(error::DomainError::NotFound { .. })));
+        assert!(matches!(future, Err(error::DomainError::NotFound { .. })));
+        assert!(matches!(unknown, Err(error::DomainError::NotFound { .. })));
+    }
+
+    #[test]
+    fn test_claims_catalog_admin_can_read_catalog() {
+        // Arrange
+        let claims = models::ClaimsDomain {
+            key_name: String::from("backoffice"),
+            roles: vec![models::ApiKeyRole::CatalogAdmin],
+        };
+
+        // Assert
+        assert!(claims.can_read_catalog());
+        assert!(claims.can_manage_catalog());
+        assert!(!claims.can_manage_orders());
+    }
+}
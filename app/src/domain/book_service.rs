@@ -4,260 +4,182 @@ use super::error;
 use super::models;
 use super::store;
 use async_trait::async_trait;
-use chrono::Days;
-use chrono::NaiveDate;
-use chrono::Utc;
 use svix_ksuid::Ksuid;
-use svix_ksuid::KsuidLike;
+use tracing::instrument;
 
+/// The BookService is the domain entry point for the catalog.
+/// Persistence is delegated to the configured BookHandler repository.
 pub struct BookService {
-    todo: String,
+    repository: Arc<dyn store::BookHandler + Send + Sync>,
 }
 
 impl BookService {
-    pub fn new(todo: String) -> Arc<Self> {
-        Arc::new(BookService { todo })
+    pub fn new(repository: Arc<dyn store::BookHandler + Send + Sync>) -> Arc<Self> {
+        Arc::new(BookService { repository })
     }
 }
 
 #[async_trait]
 impl store::BookHandler for BookService {
     /// Create a new book in the store
+    #[instrument(name = "BookService::create_book", skip_all)]
     async fn create_book(
         &self,
         book: models::NewBookDomain,
     ) -> Result<models::BookDomain, error::DomainError> {
-        Ok(models::BookDomain {
-            authors: vec![],
-            available: book.available,
-            discounts: None,
-            edition: book.edition,
-            firs_release: book.first_release,
-            genres: None,
-            id: book.id,
-            price: book.price,
-            release: book.release,
-            series: book.series,
-            status: book.status,
-            title: book.title,
-        })
+        self.repository.create_book(book).await
     }
 
     /// Delete an existing book in the store
+    #[instrument(name = "BookService::delete_book_by_id", skip_all)]
     async fn delete_book_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
-        Err(error::DomainError::NotFound {
-            id: id.to_string(),
-            source: Box::new(error::BookNotFoundError(id.to_string())),
-        })
+        self.repository.delete_book_by_id(id).await
     }
 
     /// Get an existing book by id
+    #[instrument(name = "BookService::get_book_by_id", skip_all)]
     async fn get_book_by_id(&self, id: Ksuid) -> Result<models::BookDomain, error::DomainError> {
-        Ok(models::BookDomain {
-            authors: vec![],
-            available: 2,
-            discounts: None,
-            edition: 1,
-            firs_release: Utc::now().date_naive(),
-            genres: None,
-            id,
-            price: 12.5,
-            release: Utc::now().date_naive(),
-            series: Some(String::from("1")),
-            status: models::BookStatus::Available,
-            title: String::from("The best book"),
-        })
+        self.repository.get_book_by_id(id).await
     }
 
-    /// get all books in the list of authors
+    /// get a page of the books in the list of authors
+    #[instrument(name = "BookService::get_books_by_authors", skip_all)]
     async fn get_books_by_authors(
         &self,
         authors: Vec<Ksuid>,
-    ) -> Result<Vec<models::BookDomain>, error::DomainError> {
-        Ok(vec![models::BookDomain {
-            authors: vec![],
-            available: 2,
-            discounts: None,
-            edition: 1,
-            firs_release: Utc::now().naive_utc().date(),
-            genres: None,
-            id: Ksuid::new(None, None),
-            price: 12.5,
-            release: Utc::now().naive_utc().date(),
-            series: Some(String::from("1")),
-            status: models::BookStatus::Available,
-            title: String::from("The best book"),
-        }])
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError> {
+        self.repository.get_books_by_authors(authors, page).await
     }
 
-    /// Get all books with matching at least one genre
+    /// Get a page of the books matching at least one genre
+    #[instrument(name = "BookService::get_books_by_generes", skip_all)]
     async fn get_books_by_generes(
         &self,
         genres: Vec<Ksuid>,
-    ) -> Result<Vec<models::BookDomain>, error::DomainError> {
-        Ok(vec![models::BookDomain {
-            authors: vec![],
-            available: 2,
-            discounts: None,
-            edition: 1,
-            firs_release: Utc::now().naive_utc().date(),
-            genres: None,
-            id: Ksuid::new(None, None),
-            price: 12.5,
-            release: Utc::now().naive_utc().date(),
-            series: Some(String::from("1")),
-            status: models::BookStatus::Available,
-            title: String::from("The best book"),
-        }])
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError> {
+        self.repository.get_books_by_generes(genres, page).await
     }
 
-    /// Get all books matching one of the status given in the list
+    /// Get a page of the books matching one of the status given in the list
+    #[instrument(name = "BookService::get_books_by_status", skip_all)]
     async fn get_books_by_status(
         &self,
         status: Vec<models::BookStatus>,
-    ) -> Result<Vec<models::BookDomain>, error::DomainError> {
-        Ok(vec![models::BookDomain {
-            authors: vec![],
-            available: 2,
-            discounts: None,
-            edition: 1,
-            firs_release: Utc::now().date_naive(),
-            genres: None,
-            id: Ksuid::new(None, None),
-            price: 12.5,
-            release: Utc::now().naive_utc().date(),
-            series: Some(String::from("1")),
-            status: models::BookStatus::Available,
-            title: String::from("The best book"),
-        }])
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError> {
+        self.repository.get_books_by_status(status, page).await
+    }
+
+    /// Query a page of the books matching all filters of the query
+    #[instrument(name = "BookService::query_books", skip_all)]
+    async fn query_books(
+        &self,
+        query: models::BookQuery,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError> {
+        self.repository.query_books(query, page).await
+    }
+
+    /// Search the titles, series and author names of the books for the words of the text,
+    /// the books are ordered by their rank
+    #[instrument(name = "BookService::search_books", skip_all)]
+    async fn search_books(
+        &self,
+        text: String,
+        limit: u32,
+    ) -> Result<Vec<models::BookSearchHitDomain>, error::DomainError> {
+        self.repository.search_books(text, limit).await
     }
 
     /// Update an existing book in the store
+    #[instrument(name = "BookService::update_book", skip_all)]
     async fn update_book(
         &self,
         props: models::BookUpdateProps,
     ) -> Result<models::BookDomain, error::DomainError> {
-        Ok(models::BookDomain {
-            authors: vec![],
-            available: 2,
-            discounts: None,
-            edition: 1,
-            firs_release: Utc::now().date_naive(),
-            genres: None,
-            id: props.id,
-            price: 12.5,
-            release: Utc::now().date_naive(),
-            series: Some(String::from("1")),
-            status: models::BookStatus::Available,
-            title: String::from("The best book"),
-        })
+        self.repository.update_book(props).await
     }
 
     // discount code functions
     /// Create a new discount code in the store
+    #[instrument(name = "BookService::create_discount_code", skip_all)]
     async fn create_discount_code(
         &self,
         discount_code: models::DiscountCodeDomain,
     ) -> Result<models::DiscountCodeDomain, error::DomainError> {
-        Ok(discount_code)
+        self.repository.create_discount_code(discount_code).await
     }
 
     /// Delete an existing discount code
+    #[instrument(name = "BookService::delte_discount_code_by_id", skip_all)]
     async fn delte_discount_code_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
-        Err(error::DomainError::NotFound {
-            id: id.to_string(),
-            source: Box::new(error::DiscountCodeNotFoundError(id.to_string())),
-        })
+        self.repository.delte_discount_code_by_id(id).await
     }
 
     /// Get a discount code by id
+    #[instrument(name = "BookService::get_discount_code_by_id", skip_all)]
     async fn get_discount_code_by_id(
         &self,
         id: Ksuid,
     ) -> Result<models::DiscountCodeDomain, error::DomainError> {
-        Ok(models::DiscountCodeDomain {
-            code: String::from("CODE25"),
-            id,
-            percentage_discount: 25,
-            valid_from: Utc::now().date_naive(),
-            valid_to: Utc::now()
-                .date_naive()
-                .checked_add_days(Days::new(30))
-                .unwrap(),
-        })
+        self.repository.get_discount_code_by_id(id).await
     }
 
     // gerne functions
     /// Create a new genre in the book store
+    #[instrument(name = "BookService::create_genre", skip_all)]
     async fn create_genre(
         &self,
         genre: models::GenereDomain,
     ) -> Result<models::GenereDomain, error::DomainError> {
-        Ok(genre)
+        self.repository.create_genre(genre).await
     }
 
     /// Delete an existing genre in the store
+    #[instrument(name = "BookService::delte_genre_by_id", skip_all)]
     async fn delte_genre_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
-        Err(error::DomainError::BusinessConstraintViolation {
-            message: format!("failed to delete genre {}", id),
-            source: Box::new(error::GenreNotFoundError(id.to_string())),
-        })
+        self.repository.delte_genre_by_id(id).await
     }
 
     /// Get an existing genre by id
+    #[instrument(name = "BookService::get_genre_by_id", skip_all)]
     async fn get_genre_by_id(&self, id: Ksuid) -> Result<models::GenereDomain, error::DomainError> {
-        Ok(models::GenereDomain {
-            id,
-            name: String::from("Horror"),
-        })
+        self.repository.get_genre_by_id(id).await
     }
 
     // author functions
     /// Create a new book author
+    #[instrument(name = "BookService::create_author", skip_all)]
     async fn create_author(
         &self,
         author: models::AuthorDomain,
     ) -> Result<models::AuthorDomain, error::DomainError> {
-        Ok(author)
+        self.repository.create_author(author).await
     }
 
     /// Delete an existing author
+    #[instrument(name = "BookService::delte_author_by_id", skip_all)]
     async fn delte_author_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
-        Err(error::DomainError::FatalDBFailure {
-            message: format!("Failed to delete author {}", id),
-            source: Box::new(error::AuthorNotFoundError(id.to_string())),
-        })
+        self.repository.delte_author_by_id(id).await
     }
 
     /// Get an existing author by id
+    #[instrument(name = "BookService::get_author_by_id", skip_all)]
     async fn get_author_by_id(
         &self,
         id: Ksuid,
     ) -> Result<models::AuthorDomain, error::DomainError> {
-        Ok(models::AuthorDomain {
-            date_of_birth: NaiveDate::from_ymd_opt(1920, 06, 06).unwrap(),
-            date_of_death: Some(NaiveDate::from_ymd_opt(1980, 12, 12).unwrap()),
-            first_name: String::from("Hans"),
-            id,
-            last_name: String::from("Wurst"),
-            second_names: Some(vec![String::from("Weiss")]),
-            title: None,
-        })
+        self.repository.get_author_by_id(id).await
     }
 
     /// Update an existing author
+    #[instrument(name = "BookService::update_author", skip_all)]
     async fn update_author(
         &self,
         props: models::AuthorUpdateProps,
     ) -> Result<models::AuthorDomain, error::DomainError> {
-        Ok(models::AuthorDomain {
-            date_of_birth: NaiveDate::from_ymd_opt(1980, 12, 12).unwrap(),
-            date_of_death: NaiveDate::from_ymd_opt(2024, 12, 13),
-            first_name: String::from("Hans"),
-            id: props.id,
-            last_name: String::from("Wurst"),
-            second_names: Some(vec![String::from("Weiss")]),
-            title: None,
-        })
+        self.repository.update_author(props).await
     }
 }
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use super::error;
+use super::models;
+use super::store;
+use async_trait::async_trait;
+use svix_ksuid::Ksuid;
+use tracing::instrument;
+
+/// The CustomerService is the domain entry point for the customers and their address books.
+/// Persistence is delegated to the configured CustomerHandler repository.
+pub struct CustomerService {
+    repository: Arc<dyn store::CustomerHandler + Send + Sync>,
+}
+
+impl CustomerService {
+    pub fn new(repository: Arc<dyn store::CustomerHandler + Send + Sync>) -> Arc<Self> {
+        Arc::new(CustomerService { repository })
+    }
+}
+
+#[async_trait]
+impl store::CustomerHandler for CustomerService {
+    /// Create a new customer
+    #[instrument(name = "CustomerService::create_customer", skip_all)]
+    async fn create_customer(
+        &self,
+        customer: models::CustomerDomain,
+    ) -> Result<models::CustomerDomain, error::DomainError> {
+        self.repository.create_customer(customer).await
+    }
+
+    /// Delete an existing customer without orders
+    #[instrument(name = "CustomerService::delete_customer_by_id", skip_all)]
+    async fn delete_customer_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
+        self.repository.delete_customer_by_id(id).await
+    }
+
+    /// Get an existing customer by id
+    #[instrument(name = "CustomerService::get_customer_by_id", skip_all)]
+    async fn get_customer_by_id(
+        &self,
+        id: Ksuid,
+    ) -> Result<models::CustomerDomain, error::DomainError> {
+        self.repository.get_customer_by_id(id).await
+    }
+
+    /// Update an existing customer
+    #[instrument(name = "CustomerService::update_customer", skip_all)]
+    async fn update_customer(
+        &self,
+        props: models::CustomerUpdateProps,
+    ) -> Result<models::CustomerDomain, error::DomainError> {
+        self.repository.update_customer(props).await
+    }
+}
@@ -12,6 +12,17 @@ impl fmt::Display for OrderNotFoundError {
 
 impl Error for OrderNotFoundError {}
 
+#[derive(Debug)]
+pub struct CustomerNotFoundError(pub String);
+
+impl fmt::Display for CustomerNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Customer not found: {}", self.0)
+    }
+}
+
+impl Error for CustomerNotFoundError {}
+
 #[derive(Debug)]
 pub struct BookNotFoundError(pub String);
 
@@ -56,6 +67,127 @@ impl fmt::Display for DiscountCodeNotFoundError {
 
 impl Error for DiscountCodeNotFoundError {}
 
+#[derive(Debug)]
+pub struct ApiKeyNotFoundError(pub String);
+
+impl fmt::Display for ApiKeyNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ApiKey not found: {}", self.0)
+    }
+}
+
+impl Error for ApiKeyNotFoundError {}
+
+#[derive(Debug)]
+pub struct ApiKeyExpiredError(pub String);
+
+impl fmt::Display for ApiKeyExpiredError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ApiKey is not valid at this time: {}", self.0)
+    }
+}
+
+impl Error for ApiKeyExpiredError {}
+
+#[derive(Debug)]
+pub struct InsufficientStockError(pub String);
+
+impl fmt::Display for InsufficientStockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Insufficient stock for book: {}", self.0)
+    }
+}
+
+impl Error for InsufficientStockError {}
+
+#[derive(Debug)]
+pub struct InvalidOrderTransitionError(pub String);
+
+impl fmt::Display for InvalidOrderTransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid order status transition: {}", self.0)
+    }
+}
+
+impl Error for InvalidOrderTransitionError {}
+
+#[derive(Debug)]
+pub struct InvalidDiscountCodeError(pub String);
+
+impl fmt::Display for InvalidDiscountCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid discount code: {}", self.0)
+    }
+}
+
+impl Error for InvalidDiscountCodeError {}
+
+#[derive(Debug)]
+pub struct CurrencyMismatchError(pub String);
+
+impl fmt::Display for CurrencyMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Amounts in different currencies: {}", self.0)
+    }
+}
+
+impl Error for CurrencyMismatchError {}
+
+#[derive(Debug)]
+pub struct UnsupportedCurrencyError(pub String);
+
+impl fmt::Display for UnsupportedCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No exchange rate for currency: {}", self.0)
+    }
+}
+
+impl Error for UnsupportedCurrencyError {}
+
+#[derive(Debug)]
+pub struct TaxRateNotFoundError(pub String);
+
+impl fmt::Display for TaxRateNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No tax rate for shipping country: {}", self.0)
+    }
+}
+
+impl Error for TaxRateNotFoundError {}
+
+#[derive(Debug)]
+pub struct WebhookNotFoundError(pub String);
+
+impl fmt::Display for WebhookNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Webhook not found: {}", self.0)
+    }
+}
+
+impl Error for WebhookNotFoundError {}
+
+#[derive(Debug)]
+pub struct VersionConflictError(pub String);
+
+impl fmt::Display for VersionConflictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Version conflict: {}", self.0)
+    }
+}
+
+impl Error for VersionConflictError {}
+
+#[derive(Debug)]
+pub struct StaleCursorError(pub String);
+
+impl fmt::Display for StaleCursorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stale cursor: {}", self.0)
+    }
+}
+
+impl Error for StaleCursorError {}
+
 #[derive(Debug)]
 pub enum DomainError {
     NotFound {
@@ -70,6 +202,16 @@ pub enum DomainError {
         message: String,
         source: Box<dyn Error + Send + Sync>,
     },
+    /// the item was changed since the version the update is based on
+    VersionConflict {
+        id: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    /// an event could not be published to the downstream systems
+    PublishFailure {
+        message: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
 }
 
 impl fmt::Display for DomainError {
@@ -82,6 +224,12 @@ impl fmt::Display for DomainError {
             DomainError::BusinessConstraintViolation { message, .. } => {
                 write!(f, "Business validation constraints not met: {}", message)
             }
+            DomainError::VersionConflict { id, .. } => {
+                write!(f, "Item was modified concurrently: {}", id)
+            }
+            DomainError::PublishFailure { message, .. } => {
+                write!(f, "Failed to publish event: {}", message)
+            }
         }
     }
 }
@@ -92,6 +240,8 @@ impl Error for DomainError {
             DomainError::NotFound { source, .. } => Some(source.as_ref()),
             DomainError::FatalDBFailure { source, .. } => Some(source.as_ref()),
             DomainError::BusinessConstraintViolation { source, .. } => Some(source.as_ref()),
+            DomainError::VersionConflict { source, .. } => Some(source.as_ref()),
+            DomainError::PublishFailure { source, .. } => Some(source.as_ref()),
         }
     }
 }
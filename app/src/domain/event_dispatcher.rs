@@ -0,0 +1,203 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::time::MissedTickBehavior;
+use tracing::warn;
+
+use super::{error, store};
+
+/// The EventDispatcher publishes the events of the outbox to the sinks.
+/// The events are published in the order of the outbox and marked as published afterwards,
+/// an event a sink fails to publish is retried by the next dispatch together with the later events.
+/// Hence every event is published at least once to every sink, the consumers deduplicate by the event id.
+pub struct EventDispatcher {
+    outbox: Arc<dyn store::OutboxHandler + Send + Sync>,
+    sinks: Vec<Arc<dyn store::EventSink + Send + Sync>>,
+    batch_size: u32,
+}
+
+impl EventDispatcher {
+    pub fn new(
+        outbox: Arc<dyn store::OutboxHandler + Send + Sync>,
+        sinks: Vec<Arc<dyn store::EventSink + Send + Sync>>,
+        batch_size: u32,
+    ) -> Arc<Self> {
+        Arc::new(EventDispatcher {
+            outbox,
+            sinks,
+            batch_size,
+        })
+    }
+
+    /// Publishes the unpublished events until the outbox is drained or a sink fails.
+    /// Returns the number of published events.
+    pub async fn dispatch(&self) -> Result<usize, error::DomainError> {
+        let mut published = 0;
+        loop {
+            let events = self.outbox.get_unpublished_events(self.batch_size).await?;
+            if events.is_empty() {
+                return Ok(published);
+            }
+            let mut ids = Vec::with_capacity(events.len());
+            let mut failure = None;
+            'events: for event in &events {
+                for sink in &self.sinks {
+                    if let Err(e) = sink.publish(event).await {
+                        failure = Some(e);
+                        break 'events;
+                    }
+                }
+                ids.push(event.id);
+            }
+            if !ids.is_empty() {
+                self.outbox.mark_events_published(&ids).await?;
+                published += ids.len();
+            }
+            if let Some(e) = failure {
+                return Err(e);
+            }
+            if events.len() < self.batch_size as usize {
+                return Ok(published);
+            }
+        }
+    }
+
+    /// Dispatches the outbox every interval, until the task running it is aborted
+    pub async fn run(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.dispatch().await {
+                warn!(
+                    "failed to dispatch the outbox, retrying in {:?}: {}",
+                    interval, e
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Mutex;
+    use svix_ksuid::{Ksuid, KsuidLike};
+
+    /// An outbox of the events with the ids 1 to the given count
+    struct StaticOutbox {
+        events: Mutex<Vec<models::OutboxEventDomain>>,
+    }
+
+    impl StaticOutbox {
+        fn new(count: i64) -> Arc<Self> {
+            let events = (1..=count)
+                .map(|id| models::OutboxEventDomain {
+                    id,
+                    event: models::DomainEvent::StockChanged {
+                        book_id: Ksuid::new(None, None),
+                        available: 10,
+                        delta: -1,
+                    },
+                    occurred_at: Utc::now(),
+                })
+                .collect();
+            Arc::new(StaticOutbox {
+                events: Mutex::new(events),
+            })
+        }
+
+        fn unpublished(&self) -> Vec<i64> {
+            self.events.lock().unwrap().iter().map(|e| e.id).collect()
+        }
+    }
+
+    #[async_trait]
+    impl store::OutboxHandler for StaticOutbox {
+        async fn get_unpublished_events(
+            &self,
+            limit: u32,
+        ) -> Result<Vec<models::OutboxEventDomain>, error::DomainError> {
+            let events = self.events.lock().unwrap();
+            Ok(events.iter().take(limit as usize).cloned().collect())
+        }
+
+        async fn mark_events_published(&self, ids: &[i64]) -> Result<(), error::DomainError> {
+            self.events.lock().unwrap().retain(|e| !ids.contains(&e.id));
+            Ok(())
+        }
+    }
+
+    /// A sink recording the ids of the published events, which fails for the given id
+    struct RecordingSink {
+        published: Mutex<Vec<i64>>,
+        failing_id: Option<i64>,
+    }
+
+    #[async_trait]
+    impl store::EventSink for RecordingSink {
+        async fn publish(
+            &self,
+            event: &models::OutboxEventDomain,
+        ) -> Result<(), error::DomainError> {
+            if self.failing_id == Some(event.id) {
+                return Err(error::DomainError::PublishFailure {
+                    message: format!("event {}", event.id),
+                    source: Box::new(std::io::Error::other("sink unavailable")),
+                });
+            }
+            self.published.lock().unwrap().push(event.id);
+            Ok(())
+        }
+    }
+
+    fn sink(failing_id: Option<i64>) -> Arc<RecordingSink> {
+        Arc::new(RecordingSink {
+            published: Mutex::new(vec![]),
+            failing_id,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_drains_the_outbox_in_batches() {
+        // Arrange
+        let outbox = StaticOutbox::new(5);
+        let sink = sink(None);
+        let dispatcher = EventDispatcher::new(outbox.clone(), vec![sink.clone()], 2);
+
+        // Act
+        let published = dispatcher.dispatch().await.unwrap();
+
+        // Assert
+        assert_eq!(published, 5);
+        assert_eq!(*sink.published.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+        assert!(outbox.unpublished().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_stops_at_the_failed_event() {
+        // Arrange
+        let outbox = StaticOutbox::new(4);
+        let healthy = sink(None);
+        let sink = sink(Some(3));
+        let dispatcher =
+            EventDispatcher::new(outbox.clone(), vec![healthy.clone(), sink.clone()], 10);
+
+        // Act
+        let result = dispatcher.dispatch().await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::PublishFailure { .. })
+        ));
+        assert_eq!(*sink.published.lock().unwrap(), vec![1, 2]);
+        // the healthy sink receives the failed event again with the retry
+        assert_eq!(*healthy.published.lock().unwrap(), vec![1, 2, 3]);
+        // the failed event and the later events are retried by the next dispatch
+        assert_eq!(outbox.unpublished(), vec![3, 4]);
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use tracing::instrument;
+
+use super::{error, models, store};
+
+/// The decimal places of the rates crossed between two currencies of the table
+const RATE_SCALE: u32 = 8;
+
+fn unsupported_currency(currency: models::Currency) -> error::DomainError {
+    error::DomainError::BusinessConstraintViolation {
+        message: format!("prices cannot be converted to or from {}", currency),
+        source: Box::new(error::UnsupportedCurrencyError(currency.to_string())),
+    }
+}
+
+/// The ExchangeService converts the catalog prices to the currency requested by the client.
+/// The rates table holds the rates against the base currency, the rates between two other
+/// currencies are crossed via the base currency.
+pub struct ExchangeService {
+    base_currency: models::Currency,
+    repository: Arc<dyn store::ExchangeRateHandler + Send + Sync>,
+}
+
+impl ExchangeService {
+    pub fn new(
+        base_currency: models::Currency,
+        repository: Arc<dyn store::ExchangeRateHandler + Send + Sync>,
+    ) -> Arc<Self> {
+        Arc::new(ExchangeService {
+            base_currency,
+            repository,
+        })
+    }
+
+    /// Loads the current rates to convert prices to the target currency,
+    /// a target currency without a rate in the table is rejected
+    #[instrument(name = "ExchangeService::rates_to", skip_all)]
+    pub async fn rates_to(
+        &self,
+        target: models::Currency,
+    ) -> Result<ExchangeRates, error::DomainError> {
+        let rates = self
+            .repository
+            .get_exchange_rates()
+            .await?
+            .into_iter()
+            .map(|rate| (rate.currency, rate))
+            .collect();
+        let rates = ExchangeRates {
+            base: self.base_currency,
+            target,
+            rates,
+        };
+        rates.rate_of(target)?;
+        Ok(rates)
+    }
+}
+
+/// A snapshot of the rates table to convert prices to the target currency
+#[derive(Debug, Clone)]
+pub struct ExchangeRates {
+    base: models::Currency,
+    target: models::Currency,
+    rates: HashMap<models::Currency, models::ExchangeRateDomain>,
+}
+
+impl ExchangeRates {
+    /// The rate against the base currency, the base currency itself has no update time
+    fn rate_of(
+        &self,
+        currency: models::Currency,
+    ) -> Result<(Decimal, Option<chrono::DateTime<chrono::Utc>>), error::DomainError> {
+        if currency == self.base {
+            return Ok((Decimal::ONE, None));
+        }
+        match self.rates.get(&currency) {
+            Some(rate) if rate.rate > Decimal::ZERO => Ok((rate.rate, Some(rate.updated_at))),
+            _ => Err(unsupported_currency(currency)),
+        }
+    }
+
+    /// Quotes the rate from the source to the target currency,
+    /// there is no quote if the currencies are the same
+    pub fn quote(
+        &self,
+        source: models::Currency,
+    ) -> Result<Option<models::ExchangeQuoteDomain>, error::DomainError> {
+        if source == self.target {
+            return Ok(None);
+        }
+        let (source_rate, source_updated_at) = self.rate_of(source)?;
+        let (target_rate, target_updated_at) = self.rate_of(self.target)?;
+        let rate = (target_rate / source_rate)
+            .round_dp_with_strategy(RATE_SCALE, RoundingStrategy::MidpointNearestEven);
+        // at least one of two different currencies is not the base currency
+        let updated_at = source_updated_at
+            .into_iter()
+            .chain(target_updated_at)
+            .min()
+            .unwrap_or_default();
+        Ok(Some(models::ExchangeQuoteDomain {
+            source,
+            target: self.target,
+            rate,
+            updated_at,
+        }))
+    }
+
+    /// Converts the price of the book to the target currency and returns the quote used
+    pub fn convert_book(
+        &self,
+        mut book: models::BookDomain,
+    ) -> Result<(models::BookDomain, Option<models::ExchangeQuoteDomain>), error::DomainError> {
+        let quote = self.quote(book.price.currency())?;
+        if let Some(quote) = &quote {
+            book.price = quote.convert(&book.price).map_err(|e| {
+                error::DomainError::BusinessConstraintViolation {
+                    message: String::from("the price does not match the quoted currency"),
+                    source: Box::new(e),
+                }
+            })?;
+        }
+        Ok((book, quote))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use std::str::FromStr;
+    use svix_ksuid::KsuidLike;
+
+    struct StaticRates(Vec<models::ExchangeRateDomain>);
+
+    #[async_trait]
+    impl store::ExchangeRateHandler for StaticRates {
+        async fn get_exchange_rates(
+            &self,
+        ) -> Result<Vec<models::ExchangeRateDomain>, error::DomainError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn currency(code: &str) -> models::Currency {
+        models::Currency::from_str(code).unwrap()
+    }
+
+    fn time(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value).unwrap().to_utc()
+    }
+
+    fn service() -> Arc<ExchangeService> {
+        ExchangeService::new(
+            models::Currency::USD,
+            Arc::new(StaticRates(vec![
+                models::ExchangeRateDomain {
+                    currency: currency("EUR"),
+                    rate: Decimal::from_str("0.92").unwrap(),
+                    updated_at: time("2026-10-01T08:00:00Z"),
+                },
+                models::ExchangeRateDomain {
+                    currency: currency("JPY"),
+                    rate: Decimal::from_str("149.5").unwrap(),
+                    updated_at: time("2026-10-02T08:00:00Z"),
+                },
+            ])),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_quote_from_the_base_currency() {
+        // Arrange
+        let rates = service().rates_to(currency("EUR")).await.unwrap();
+
+        // Act
+        let quote = rates.quote(models::Currency::USD).unwrap().unwrap();
+
+        // Assert
+        assert_eq!(quote.source, models::Currency::USD);
+        assert_eq!(quote.target, currency("EUR"));
+        assert_eq!(quote.rate, Decimal::from_str("0.92").unwrap());
+        assert_eq!(quote.updated_at, time("2026-10-01T08:00:00Z"));
+    }
+
+    #[tokio::test]
+    async fn test_quote_crosses_rates_via_the_base_currency() {
+        // Arrange
+        let rates = service().rates_to(currency("JPY")).await.unwrap();
+
+        // Act
+        let quote = rates.quote(currency("EUR")).unwrap().unwrap();
+        let same = rates.quote(currency("JPY")).unwrap();
+
+        // Assert
+        // 149.5 / 0.92 = 162.5, the older of both rates dates the quote
+        assert_eq!(quote.rate, Decimal::from_str("162.5").unwrap());
+        assert_eq!(quote.updated_at, time("2026-10-01T08:00:00Z"));
+        assert_eq!(same, None);
+    }
+
+    #[tokio::test]
+    async fn test_rates_to_rejects_unsupported_currencies() {
+        // Act
+        let result = service().rates_to(currency("GBP")).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_convert_book_rounds_to_the_target_minor_unit() {
+        // Arrange
+        let rates = service().rates_to(currency("JPY")).await.unwrap();
+        let release = chrono::NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let book = models::BookDomain {
+            authors: vec![],
+            available: 1,
+            discounts: None,
+            edition: 1,
+            firs_release: release,
+            genres: None,
+            id: svix_ksuid::Ksuid::new(None, None),
+            price: models::Money::parse("12.99", models::Currency::USD).unwrap(),
+            product_type: models::ProductType::Print,
+            release,
+            series: None,
+            status: models::BookStatus::Available,
+            title: String::from("Dune"),
+            version: 1,
+        };
+
+        // Act
+        let (converted, quote) = rates.convert_book(book).unwrap();
+
+        // Assert
+        // 12.99 * 149.5 = 1942.005 yen, rounded to the even 1942
+        assert_eq!(
+            converted.price,
+            models::Money::parse("1942", currency("JPY")).unwrap()
+        );
+        assert_eq!(quote.unwrap().source, models::Currency::USD);
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tracing::instrument;
+
+use super::{models, store};
+
+/// The HealthService reports whether the service is alive and ready to serve requests.
+/// Once a shutdown began the service is no longer ready, so the load balancer drains it
+/// while the in-flight requests complete.
+pub struct HealthService {
+    repository: Arc<dyn store::HealthHandler + Send + Sync>,
+    shutting_down: AtomicBool,
+}
+
+impl HealthService {
+    pub fn new(repository: Arc<dyn store::HealthHandler + Send + Sync>) -> Arc<Self> {
+        Arc::new(HealthService {
+            repository,
+            shutting_down: AtomicBool::new(false),
+        })
+    }
+
+    /// Marks the service as shutting down, it is not ready from now on
+    pub fn begin_shutdown(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    /// Checks the dependencies of the store and whether a shutdown is in progress
+    #[instrument(name = "HealthService::readiness", skip_all)]
+    pub async fn readiness(&self) -> models::ReadinessDomain {
+        let mut checks = self.repository.check_health().await;
+        if self.shutting_down.load(Ordering::SeqCst) {
+            checks.push(models::HealthCheckDomain::down(
+                "shutdown",
+                String::from("shutdown in progress"),
+            ));
+        } else {
+            checks.push(models::HealthCheckDomain::up("shutdown"));
+        }
+        models::ReadinessDomain { checks }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct StaticHealth(Vec<models::HealthCheckDomain>);
+
+    #[async_trait]
+    impl store::HealthHandler for StaticHealth {
+        async fn check_health(&self) -> Vec<models::HealthCheckDomain> {
+            self.0.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_readiness_with_checks_up() {
+        // Arrange
+        let service =
+            HealthService::new(Arc::new(StaticHealth(vec![models::HealthCheckDomain::up(
+                "database",
+            )])));
+
+        // Act
+        let readiness = service.readiness().await;
+
+        // Assert
+        assert!(readiness.is_ready());
+        assert_eq!(readiness.checks.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_with_check_down() {
+        // Arrange
+        let service = HealthService::new(Arc::new(StaticHealth(vec![
+            models::HealthCheckDomain::down("database", String::from("connection refused")),
+        ])));
+
+        // Act
+        let readiness = service.readiness().await;
+
+        // Assert
+        assert!(!readiness.is_ready());
+    }
+
+    #[tokio::test]
+    async fn test_readiness_during_shutdown() {
+        // Arrange
+        let service = HealthService::new(Arc::new(StaticHealth(vec![])));
+        service.begin_shutdown();
+
+        // Act
+        let readiness = service.readiness().await;
+
+        // Assert
+        assert!(!readiness.is_ready());
+        assert_eq!(
+            readiness.checks[0].detail,
+            Some(String::from("shutdown in progress"))
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+use tracing::instrument;
+
+use super::{error, models, store};
+
+/// The maximum length of an Idempotency-Key
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// Checks if the key has 1 to 255 visible ASCII characters, e.g. a UUID
+pub fn is_valid_idempotency_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= MAX_IDEMPOTENCY_KEY_LENGTH
+        && key.chars().all(|c| c.is_ascii_graphic())
+}
+
+/// Fingerprints the method, path with query and body of a request
+pub fn fingerprint_request(method: &str, uri: &str, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(uri.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(body);
+    hex::encode(hasher.finalize())
+}
+
+/// How to proceed with a request made with an Idempotency-Key
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdempotencyOutcome {
+    /// the key is reserved, the request is executed and its response completes the key
+    Execute,
+    /// the request was executed before, its response is replayed
+    Replay(models::IdempotentResponseDomain),
+    /// the key was used for a different request
+    Mismatch,
+    /// the first request with the key is still in progress
+    InProgress,
+}
+
+/// The IdempotencyService makes retried requests safe.
+/// The first request with a key is executed and its response is stored for the TTL,
+/// the retries with the same key get the stored response instead of executing the request again.
+/// While the request is in progress the key is reserved for the lease only, so the key of a
+/// request which never completes is freed soon.
+pub struct IdempotencyService {
+    repository: Arc<dyn store::IdempotencyHandler + Send + Sync>,
+    ttl: chrono::Duration,
+    lease: chrono::Duration,
+}
+
+impl IdempotencyService {
+    pub fn new(
+        repository: Arc<dyn store::IdempotencyHandler + Send + Sync>,
+        ttl: chrono::Duration,
+        lease: chrono::Duration,
+    ) -> Arc<Self> {
+        Arc::new(IdempotencyService {
+            repository,
+            ttl,
+            lease,
+        })
+    }
+
+    /// Reserves the key for the request or tells how to answer the retry
+    #[instrument(name = "IdempotencyService::begin", skip_all)]
+    pub async fn begin(
+        &self,
+        scope: &str,
+        key: &str,
+        fingerprint: String,
+    ) -> Result<IdempotencyOutcome, error::DomainError> {
+        let request = models::IdempotentRequestDomain {
+            scope: String::from(scope),
+            key: String::from(key),
+            fingerprint,
+            expires_at: chrono::Utc::now() + self.lease,
+        };
+        let fingerprint = request.fingerprint.clone();
+        Ok(
+            match self.repository.reserve_idempotency_key(request).await? {
+                None => IdempotencyOutcome::Execute,
+                Some(record) if record.request.fingerprint != fingerprint => {
+                    IdempotencyOutcome::Mismatch
+                }
+                Some(models::IdempotencyRecordDomain {
+                    response: Some(response),
+                    ..
+                }) => IdempotencyOutcome::Replay(response),
+                Some(_) => IdempotencyOutcome::InProgress,
+            },
+        )
+    }
+
+    /// Stores the response of the executed request for the TTL
+    #[instrument(name = "IdempotencyService::complete", skip_all)]
+    pub async fn complete(
+        &self,
+        scope: &str,
+        key: &str,
+        response: models::IdempotentResponseDomain,
+    ) -> Result<(), error::DomainError> {
+        self.repository
+            .complete_idempotency_key(scope, key, response, chrono::Utc::now() + self.ttl)
+            .await
+    }
+
+    /// Releases the key of a failed request, so the retry executes it again
+    #[instrument(name = "IdempotencyService::release", skip_all)]
+    pub async fn release(&self, scope: &str, key: &str) -> Result<(), error::DomainError> {
+        self.repository.release_idempotency_key(scope, key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::Utc;
+
+    struct StaticRecord(Option<models::IdempotencyRecordDomain>);
+
+    #[async_trait]
+    impl store::IdempotencyHandler for StaticRecord {
+        async fn reserve_idempotency_key(
+            &self,
+            _request: models::IdempotentRequestDomain,
+        ) -> Result<Option<models::IdempotencyRecordDomain>, error::DomainError> {
+            Ok(self.0.clone())
+        }
+
+        async fn complete_idempotency_key(
+            &self,
+            _scope: &str,
+            _key: &str,
+            _response: models::IdempotentResponseDomain,
+            _expires_at: chrono::DateTime<Utc>,
+        ) -> Result<(), error::DomainError> {
+            Ok(())
+        }
+
+        async fn release_idempotency_key(
+            &self,
+            _scope: &str,
+            _key: &str,
+        ) -> Result<(), error::DomainError> {
+            Ok(())
+        }
+    }
+
+    fn record(
+        fingerprint: &str,
+        response: Option<models::IdempotentResponseDomain>,
+    ) -> models::IdempotencyRecordDomain {
+        models::IdempotencyRecordDomain {
+            request: models::IdempotentRequestDomain {
+                scope: String::from("checkout"),
+                key: String::from("order-1"),
+                fingerprint: String::from(fingerprint),
+                expires_at: Utc::now(),
+            },
+            response,
+        }
+    }
+
+    async fn begin(existing: Option<models::IdempotencyRecordDomain>) -> IdempotencyOutcome {
+        let service = IdempotencyService::new(
+            Arc::new(StaticRecord(existing)),
+            chrono::Duration::hours(1),
+            chrono::Duration::minutes(1),
+        );
+        service
+            .begin("checkout", "order-1", String::from("abc"))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_begin_outcomes() {
+        // Arrange
+        let response = models::IdempotentResponseDomain {
+            status: 200,
+            headers: vec![(
+                String::from("content-type"),
+                String::from("application/json"),
+            )],
+            body: b"{}".to_vec(),
+        };
+
+        // Act & Assert
+        assert_eq!(begin(None).await, IdempotencyOutcome::Execute);
+        assert_eq!(
+            begin(Some(record("abc", Some(response.clone())))).await,
+            IdempotencyOutcome::Replay(response.clone())
+        );
+        assert_eq!(
+            begin(Some(record("def", Some(response)))).await,
+            IdempotencyOutcome::Mismatch
+        );
+        assert_eq!(
+            begin(Some(record("abc", None))).await,
+            IdempotencyOutcome::InProgress
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_request() {
+        // Act
+        let fingerprint = fingerprint_request("POST", "/api/v1/genres", b"{\"name\":\"Drama\"}");
+
+        // Assert
+        assert_eq!(fingerprint.len(), 64);
+        assert_eq!(
+            fingerprint,
+            fingerprint_request("POST", "/api/v1/genres", b"{\"name\":\"Drama\"}")
+        );
+        assert_ne!(
+            fingerprint,
+            fingerprint_request("POST", "/api/v1/genres", b"{\"name\":\"Poetry\"}")
+        );
+        assert_ne!(
+            fingerprint,
+            fingerprint_request("POST", "/api/v1/authors", b"{\"name\":\"Drama\"}")
+        );
+    }
+
+    #[test]
+    fn test_is_valid_idempotency_key() {
+        // Act & Assert
+        assert!(is_valid_idempotency_key(
+            "8e03978e-40d5-43e8-bc93-6894a57f9324"
+        ));
+        assert!(!is_valid_idempotency_key(""));
+        assert!(!is_valid_idempotency_key("order 1"));
+        assert!(!is_valid_idempotency_key(&"a".repeat(256)));
+    }
+}
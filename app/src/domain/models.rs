@@ -1,6 +1,183 @@
-use core::f64;
+use rust_decimal::{Decimal, RoundingStrategy};
 use svix_ksuid::Ksuid;
 
+use super::error;
+
+/// An ISO 4217 currency code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency([u8; 3]);
+
+impl Currency {
+    /// The currency of the catalog if no other currency is given
+    pub const USD: Currency = Currency(*b"USD");
+
+    pub fn code(&self) -> &str {
+        // only ASCII letters pass from_str
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+
+    /// The number of decimal places of the minor unit, e.g. cents
+    pub fn minor_unit_scale(&self) -> u32 {
+        match self.code() {
+            "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF"
+            | "UGX" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+            "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+            _ => 2,
+        }
+    }
+}
+
+impl Default for Currency {
+    fn default() -> Self {
+        Currency::USD
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl std::str::FromStr for Currency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match <[u8; 3]>::try_from(s.as_bytes()) {
+            Ok(code) if code.iter().all(|c| c.is_ascii_uppercase()) => Ok(Currency(code)),
+            _ => Err(format!("Invalid currency code: {}", s)),
+        }
+    }
+}
+
+/// An exact amount of money in a currency.
+/// The amount always has the scale of the minor unit of the currency,
+/// amounts with more decimal places are rounded half to even (banker's rounding).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: Decimal, currency: Currency) -> Self {
+        let scale = currency.minor_unit_scale();
+        let mut amount =
+            amount.round_dp_with_strategy(scale, RoundingStrategy::MidpointNearestEven);
+        amount.rescale(scale);
+        Money { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Money::new(Decimal::ZERO, currency)
+    }
+
+    /// Parses a decimal amount like 12.50, amounts with more decimal places
+    /// than the minor unit of the currency are rejected instead of rounded
+    pub fn parse(amount: &str, currency: Currency) -> Result<Self, String> {
+        let value = amount
+            .parse::<Decimal>()
+            .map_err(|_| format!("Invalid amount: {}", amount))?;
+        if value.normalize().scale() > currency.minor_unit_scale() {
+            return Err(format!(
+                "Amount {} has more decimal places than {} allows",
+                amount, currency
+            ));
+        }
+        Ok(Money::new(value, currency))
+    }
+
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.amount.is_sign_negative() && !self.amount.is_zero()
+    }
+
+    pub fn checked_add(&self, other: &Money) -> Result<Money, error::CurrencyMismatchError> {
+        self.same_currency(other)?;
+        Ok(Money::new(self.amount + other.amount, self.currency))
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, error::CurrencyMismatchError> {
+        self.same_currency(other)?;
+        Ok(Money::new(self.amount - other.amount, self.currency))
+    }
+
+    pub fn times(&self, quantity: i32) -> Money {
+        Money::new(self.amount * Decimal::from(quantity), self.currency)
+    }
+
+    /// The given percentage of the amount, rounded to the minor unit
+    pub fn percentage(&self, percent: i32) -> Money {
+        Money::new(
+            self.amount * Decimal::from(percent) / Decimal::ONE_HUNDRED,
+            self.currency,
+        )
+    }
+
+    fn same_currency(&self, other: &Money) -> Result<(), error::CurrencyMismatchError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(error::CurrencyMismatchError(format!(
+                "{} and {}",
+                self.currency, other.currency
+            )))
+        }
+    }
+}
+
+impl Default for Money {
+    fn default() -> Self {
+        Money::zero(Currency::default())
+    }
+}
+
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+/// An entry of the exchange rates table, the units of the currency one unit of the
+/// base currency buys
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExchangeRateDomain {
+    pub currency: Currency,
+    pub rate: Decimal,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// The rate used to convert prices from the source to the target currency,
+/// updated_at is the time the older of the underlying table rates was updated
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExchangeQuoteDomain {
+    pub source: Currency,
+    pub target: Currency,
+    pub rate: Decimal,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ExchangeQuoteDomain {
+    /// Converts an amount of the source currency, rounded half to even to the target minor unit
+    pub fn convert(&self, money: &Money) -> Result<Money, error::CurrencyMismatchError> {
+        if money.currency() != self.source {
+            return Err(error::CurrencyMismatchError(format!(
+                "{} and {}",
+                money.currency(),
+                self.source
+            )));
+        }
+        Ok(Money::new(money.amount() * self.rate, self.target))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct AddressDomain {
     pub city: String,
@@ -11,6 +188,33 @@ pub struct AddressDomain {
     pub zip_code: String,
 }
 
+/// The version of a newly created author, book or order
+pub const INITIAL_VERSION: i32 = 1;
+
+/// Checks that an update is based on the current version of an item, i.e. one of the expected
+/// versions is the current one. An update without versions is unconditional.
+pub fn check_version(
+    id: Ksuid,
+    current: i32,
+    expected: Option<&[i32]>,
+) -> Result<(), error::DomainError> {
+    match expected {
+        Some(expected) if !expected.contains(&current) => {
+            let expected: Vec<String> = expected.iter().map(|v| v.to_string()).collect();
+            Err(error::DomainError::VersionConflict {
+                id: id.to_string(),
+                source: Box::new(error::VersionConflictError(format!(
+                    "{} is at version {}, the update is based on version [{}]",
+                    id,
+                    current,
+                    expected.join(", ")
+                ))),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct AuthorDomain {
     pub date_of_birth: chrono::naive::NaiveDate,
@@ -20,6 +224,8 @@ pub struct AuthorDomain {
     pub last_name: String,
     pub second_names: Option<Vec<String>>,
     pub title: Option<String>,
+    /// incremented by every update, starts with INITIAL_VERSION
+    pub version: i32,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -29,6 +235,8 @@ pub struct AuthorUpdateProps {
     pub last_name: Option<String>,
     pub second_names: Option<Vec<String>>,
     pub title: Option<String>,
+    /// the versions the update may be based on, without versions the update is unconditional
+    pub version: Option<Vec<i32>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -40,11 +248,14 @@ pub struct BookDomain {
     pub firs_release: chrono::naive::NaiveDate,
     pub genres: Option<Vec<GenereDomain>>,
     pub id: Ksuid,
-    pub price: f64,
+    pub price: Money,
+    pub product_type: ProductType,
     pub release: chrono::naive::NaiveDate,
     pub series: Option<String>,
     pub status: BookStatus,
     pub title: String,
+    /// incremented by every update and stock change, starts with INITIAL_VERSION
+    pub version: i32,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -77,6 +288,38 @@ impl std::str::FromStr for BookStatus {
     }
 }
 
+/// The kind of product a book is sold as, the tax rate can differ between the product types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProductType {
+    #[default]
+    Print,
+    Ebook,
+    Audiobook,
+}
+
+impl std::fmt::Display for ProductType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProductType::Print => write!(f, "print"),
+            ProductType::Ebook => write!(f, "ebook"),
+            ProductType::Audiobook => write!(f, "audiobook"),
+        }
+    }
+}
+
+impl std::str::FromStr for ProductType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "print" => Ok(ProductType::Print),
+            "ebook" => Ok(ProductType::Ebook),
+            "audiobook" => Ok(ProductType::Audiobook),
+            _ => Err(format!("Invalid product type: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct BookUpdateProps {
     pub id: Ksuid,
@@ -85,11 +328,32 @@ pub struct BookUpdateProps {
     pub discounts: Option<Vec<Ksuid>>,
     pub genres: Option<Vec<Ksuid>>,
     pub edition: Option<i32>,
-    pub price: Option<f64>,
+    pub price: Option<Money>,
+    pub product_type: Option<ProductType>,
     pub release: Option<chrono::naive::NaiveDate>,
     pub series: Option<String>,
     pub status: Option<BookStatus>,
     pub title: Option<String>,
+    /// the versions the update may be based on, without versions the update is unconditional
+    pub version: Option<Vec<i32>>,
+}
+
+/// A customer with the saved addresses of the address book
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomerDomain {
+    pub id: Ksuid,
+    pub name: String,
+    pub email: String,
+    pub addresses: Vec<AddressDomain>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomerUpdateProps {
+    pub id: Ksuid,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    /// replaces the whole address book
+    pub addresses: Option<Vec<AddressDomain>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -123,7 +387,8 @@ pub struct NewBookDomain {
     pub first_release: chrono::naive::NaiveDate,
     pub genres: Option<Vec<Ksuid>>,
     pub id: Ksuid,
-    pub price: f64,
+    pub price: Money,
+    pub product_type: ProductType,
     pub release: chrono::naive::NaiveDate,
     pub series: Option<String>,
     pub status: BookStatus,
@@ -139,6 +404,49 @@ pub struct OrderDomain {
     pub shipping_address: AddressDomain,
     pub shipping_date: chrono::naive::NaiveDate,
     pub status: OrderStatus,
+    pub placed_at: chrono::DateTime<chrono::Utc>,
+    pub shipped_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub delivered_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub canceled_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub discount_codes: Vec<String>,
+    /// the currency the order is priced in, the currency of the books if not requested
+    pub currency: Option<Currency>,
+    pub pricing: OrderPricingDomain,
+    /// incremented by every update, starts with INITIAL_VERSION
+    pub version: i32,
+}
+
+impl OrderDomain {
+    /// Moves the order to the next status and records the time of the transition.
+    /// Setting the current status again changes nothing, illegal transitions are rejected.
+    pub fn transition_to(
+        &mut self,
+        next: OrderStatus,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), error::DomainError> {
+        if self.status == next {
+            return Ok(());
+        }
+        if !self.status.can_transition_to(&next) {
+            let transition = format!("{} -> {}", self.status, next);
+            return Err(error::DomainError::BusinessConstraintViolation {
+                message: format!(
+                    "order {} can not change from {} to {}",
+                    self.id, self.status, next
+                ),
+                source: Box::new(error::InvalidOrderTransitionError(transition)),
+            });
+        }
+        match next {
+            OrderStatus::Shipped => self.shipped_at = Some(at),
+            OrderStatus::Delivered => self.delivered_at = Some(at),
+            OrderStatus::Canceled => self.canceled_at = Some(at),
+            // an order is placed on creation, there is no transition back to placed
+            OrderStatus::Placed => {}
+        }
+        self.status = next;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -147,6 +455,101 @@ pub struct OrderedBookDomain {
     pub quantity: i32,
 }
 
+/// An ordered book priced at the time of the order
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderLineDomain {
+    pub book_id: Ksuid,
+    pub quantity: i32,
+    pub unit_price: Money,
+    pub discount_code: Option<String>,
+    pub discount_percentage: i32,
+    pub discount_amount: Money,
+    pub line_total: Money,
+    /// the tax rate in percent of the net price
+    pub tax_rate: Decimal,
+    /// the tax included in or added to the line total
+    pub tax_amount: Money,
+}
+
+impl OrderLineDomain {
+    /// The line total without the tax
+    pub fn net_total(
+        &self,
+        prices_include_tax: bool,
+    ) -> Result<Money, error::CurrencyMismatchError> {
+        if prices_include_tax {
+            self.line_total.checked_sub(&self.tax_amount)
+        } else {
+            Ok(self.line_total)
+        }
+    }
+}
+
+/// The tax of the order lines taxed at the same rate
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxLineDomain {
+    pub rate: Decimal,
+    pub net: Money,
+    pub tax: Money,
+}
+
+/// The prices of an order, snapshotted when the order is placed
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OrderPricingDomain {
+    pub lines: Vec<OrderLineDomain>,
+    pub subtotal: Money,
+    pub discount_total: Money,
+    /// the gross amount to pay, including the tax
+    pub total: Money,
+    /// the rate the catalog prices were converted with, locked when the order is placed
+    pub exchange_rate: Option<ExchangeQuoteDomain>,
+    /// the catalog prices are gross prices, otherwise the tax is added to the line totals
+    pub prices_include_tax: bool,
+    pub net_total: Money,
+    pub tax_total: Money,
+    /// the taxes summed by rate, lowest rate first
+    pub tax_lines: Vec<TaxLineDomain>,
+}
+
+impl OrderPricingDomain {
+    /// Sums the net amounts and taxes of the lines by tax rate
+    pub fn summarize_tax_lines(&self) -> Result<Vec<TaxLineDomain>, error::CurrencyMismatchError> {
+        let mut tax_lines: Vec<TaxLineDomain> = Vec::new();
+        for line in &self.lines {
+            let net = line.net_total(self.prices_include_tax)?;
+            match tax_lines
+                .iter_mut()
+                .find(|tax_line| tax_line.rate == line.tax_rate)
+            {
+                Some(tax_line) => {
+                    tax_line.net = tax_line.net.checked_add(&net)?;
+                    tax_line.tax = tax_line.tax.checked_add(&line.tax_amount)?;
+                }
+                None => tax_lines.push(TaxLineDomain {
+                    rate: line.tax_rate,
+                    net,
+                    tax: line.tax_amount,
+                }),
+            }
+        }
+        tax_lines.sort_by_key(|line| line.rate);
+        Ok(tax_lines)
+    }
+}
+
+/// A rate of the tax table in percent of the net price.
+/// A rate without province applies to the whole country, a rate with a genre or product type
+/// overrides the standard rate for the matching books.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxRateDomain {
+    /// ISO 3166-1 alpha-2 code of the shipping country
+    pub country: String,
+    pub province: Option<String>,
+    pub genre: Option<Ksuid>,
+    pub product_type: Option<ProductType>,
+    pub rate: Decimal,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum OrderStatus {
     Canceled,
@@ -155,6 +558,18 @@ pub enum OrderStatus {
     Shipped,
 }
 
+impl OrderStatus {
+    /// The order lifecycle: placed -> shipped -> delivered, a placed order can be canceled.
+    pub fn can_transition_to(&self, next: &OrderStatus) -> bool {
+        matches!(
+            (self, next),
+            (OrderStatus::Placed, OrderStatus::Shipped)
+                | (OrderStatus::Shipped, OrderStatus::Delivered)
+                | (OrderStatus::Placed, OrderStatus::Canceled)
+        )
+    }
+}
+
 impl std::fmt::Display for OrderStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -185,4 +600,780 @@ pub struct OrderUpdateProps {
     pub id: Ksuid,
     pub shipping_date: chrono::DateTime<chrono::Utc>,
     pub status: OrderStatus,
+    /// the versions the update may be based on, without versions the update is unconditional
+    pub version: Option<Vec<i32>>,
+}
+
+/// Who changes an order and why, recorded in the status history of the order
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderChangeDomain {
+    pub actor: String,
+    pub reason: Option<String>,
+}
+
+/// A change of the order status, from is None for the placement of the order
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderStatusEventDomain {
+    pub order_id: Ksuid,
+    pub from: Option<OrderStatus>,
+    pub to: OrderStatus,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A change of the catalog or the orders, published to the downstream systems.
+/// The events are written to the outbox in the same transaction as the change.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DomainEvent {
+    /// a book was added to the catalog
+    BookCreated {
+        book_id: Ksuid,
+        title: String,
+        price: Money,
+        available: i32,
+    },
+    /// the available books changed by the delta, e.g. by an order or a cancellation
+    StockChanged {
+        book_id: Ksuid,
+        available: i32,
+        delta: i32,
+    },
+    /// an order was placed, its books are reserved
+    OrderPlaced {
+        order_id: Ksuid,
+        customer_id: Ksuid,
+        books: Vec<OrderedBookDomain>,
+        total: Money,
+    },
+    /// an order moved from one status to the next
+    OrderStatusChanged {
+        order_id: Ksuid,
+        from: OrderStatus,
+        to: OrderStatus,
+    },
+}
+
+impl DomainEvent {
+    /// The names of all events, the types webhooks can subscribe to
+    pub const EVENT_TYPES: [&'static str; 4] = [
+        "BookCreated",
+        "StockChanged",
+        "OrderPlaced",
+        "OrderStatusChanged",
+    ];
+
+    pub fn book_created(book: &BookDomain) -> Self {
+        DomainEvent::BookCreated {
+            book_id: book.id,
+            title: book.title.clone(),
+            price: book.price,
+            available: book.available,
+        }
+    }
+
+    pub fn order_placed(order: &OrderDomain) -> Self {
+        DomainEvent::OrderPlaced {
+            order_id: order.id,
+            customer_id: order.customer_id,
+            books: order.books.clone(),
+            total: order.pricing.total,
+        }
+    }
+
+    /// The name of the event, e.g. OrderPlaced
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DomainEvent::BookCreated { .. } => "BookCreated",
+            DomainEvent::StockChanged { .. } => "StockChanged",
+            DomainEvent::OrderPlaced { .. } => "OrderPlaced",
+            DomainEvent::OrderStatusChanged { .. } => "OrderStatusChanged",
+        }
+    }
+
+    /// The id of the book or order the event belongs to
+    pub fn aggregate_id(&self) -> Ksuid {
+        match self {
+            DomainEvent::BookCreated { book_id, .. }
+            | DomainEvent::StockChanged { book_id, .. } => *book_id,
+            DomainEvent::OrderPlaced { order_id, .. }
+            | DomainEvent::OrderStatusChanged { order_id, .. } => *order_id,
+        }
+    }
+}
+
+/// An event in the outbox, the ids are ascending in the order the events were written
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxEventDomain {
+    pub id: i64,
+    pub event: DomainEvent,
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A partner endpoint subscribed to the events of the given types,
+/// the payloads posted to the endpoint are signed with the secret
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookDomain {
+    pub id: Ksuid,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub secret: String,
+}
+
+impl WebhookDomain {
+    pub fn subscribes_to(&self, event_type: &str) -> bool {
+        self.event_types.iter().any(|t| t == event_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookUpdateProps {
+    pub id: Ksuid,
+    pub url: Option<String>,
+    pub event_types: Option<Vec<String>>,
+    pub secret: Option<String>,
+}
+
+/// The state of the delivery of an event to a webhook,
+/// a delivery which exhausted its attempts is failed and not retried anymore
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl std::fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeliveryStatus::Pending => write!(f, "pending"),
+            DeliveryStatus::Delivered => write!(f, "delivered"),
+            DeliveryStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for DeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(DeliveryStatus::Pending),
+            "delivered" => Ok(DeliveryStatus::Delivered),
+            "failed" => Ok(DeliveryStatus::Failed),
+            _ => Err(format!("Invalid delivery status: {}", s)),
+        }
+    }
+}
+
+/// The delivery of an event to a webhook, the payload is kept so every attempt posts the same body
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookDeliveryDomain {
+    pub id: Ksuid,
+    pub webhook_id: Ksuid,
+    pub event_id: i64,
+    pub event_type: String,
+    pub payload: String,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub last_status_code: Option<u16>,
+    pub last_error: Option<String>,
+    /// None once the delivery is delivered or failed
+    pub next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub delivered_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A due delivery claimed for the next attempt with the webhook it is sent to
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClaimedDeliveryDomain {
+    pub webhook: WebhookDomain,
+    pub delivery: WebhookDeliveryDomain,
+}
+
+/// The permissions an API key can be granted
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiKeyRole {
+    CatalogAdmin,
+    CatalogRead,
+    OrderClerk,
+    WebhookAdmin,
+}
+
+impl std::fmt::Display for ApiKeyRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiKeyRole::CatalogAdmin => write!(f, "catalog-admin"),
+            ApiKeyRole::CatalogRead => write!(f, "catalog-read"),
+            ApiKeyRole::OrderClerk => write!(f, "order-clerk"),
+            ApiKeyRole::WebhookAdmin => write!(f, "webhook-admin"),
+        }
+    }
+}
+
+impl std::str::FromStr for ApiKeyRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "catalog-admin" => Ok(ApiKeyRole::CatalogAdmin),
+            "catalog-read" => Ok(ApiKeyRole::CatalogRead),
+            "order-clerk" => Ok(ApiKeyRole::OrderClerk),
+            "webhook-admin" => Ok(ApiKeyRole::WebhookAdmin),
+            _ => Err(format!("Invalid api key role: {}", s)),
+        }
+    }
+}
+
+/// An API key as stored in the keystore, only the hash of the key is kept
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiKeyDomain {
+    pub key_hash: String,
+    pub name: String,
+    pub roles: Vec<ApiKeyRole>,
+    pub valid_from: chrono::DateTime<chrono::Utc>,
+    pub valid_until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ApiKeyDomain {
+    /// Keys are rotated by adding the new key before the old one expires,
+    /// both are accepted during the overlap of their validity windows
+    pub fn is_valid_at(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.valid_from <= now && self.valid_until.is_none_or(|until| now < until)
+    }
+}
+
+/// The claims of an authenticated API key
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClaimsDomain {
+    pub key_name: String,
+    pub roles: Vec<ApiKeyRole>,
+}
+
+impl ClaimsDomain {
+    /// Catalog admins can read the catalog as well
+    pub fn can_read_catalog(&self) -> bool {
+        self.roles.contains(&ApiKeyRole::CatalogRead) || self.can_manage_catalog()
+    }
+
+    pub fn can_manage_catalog(&self) -> bool {
+        self.roles.contains(&ApiKeyRole::CatalogAdmin)
+    }
+
+    pub fn can_manage_orders(&self) -> bool {
+        self.roles.contains(&ApiKeyRole::OrderClerk)
+    }
+
+    pub fn can_manage_webhooks(&self) -> bool {
+        self.roles.contains(&ApiKeyRole::WebhookAdmin)
+    }
+}
+
+/// A request made with an Idempotency-Key, the key is unique within the scope of the caller.
+/// The fingerprint identifies the request, a key must not be reused for a different request.
+/// The key is reserved until it expires, the lease of the request in progress or the TTL of the
+/// stored response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdempotentRequestDomain {
+    pub scope: String,
+    pub key: String,
+    pub fingerprint: String,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// The stored response which is replayed for the retries of an idempotent request,
+/// the headers are the names and values of the response headers, e.g. Content-Type and Location
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdempotentResponseDomain {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// The record of an Idempotency-Key, without a response the first request is still in progress
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdempotencyRecordDomain {
+    pub request: IdempotentRequestDomain,
+    pub response: Option<IdempotentResponseDomain>,
+}
+
+/// The default number of items on a page
+pub const DEFAULT_PAGE_LIMIT: u32 = 20;
+/// The maximum number of items on a page
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+/// Request for a page of a listing ordered by the time ordered KSUID ids.
+/// The page starts after the cursor, which is the id of the last item of the previous page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRequest {
+    pub cursor: Option<Ksuid>,
+    pub limit: u32,
+}
+
+impl PageRequest {
+    /// The cursor of a sorted page points to a book which was deleted since,
+    /// its position in the sort order is lost and the search has to start again
+    pub fn stale_cursor(cursor: Ksuid) -> error::DomainError {
+        error::DomainError::BusinessConstraintViolation {
+            message: format!(
+                "the book {} of the cursor no longer exists, restart the search without cursor",
+                cursor
+            ),
+            source: Box::new(error::StaleCursorError(cursor.to_string())),
+        }
+    }
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        PageRequest {
+            cursor: None,
+            limit: DEFAULT_PAGE_LIMIT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageDomain<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<Ksuid>,
+}
+
+impl<T> PageDomain<T> {
+    /// Builds the page from up to limit + 1 items ordered by id,
+    /// the additional item only signals that there is a next page
+    pub fn from_items(mut items: Vec<T>, limit: u32, id: impl Fn(&T) -> Ksuid) -> Self {
+        let next_cursor = if items.len() > limit as usize {
+            items.truncate(limit as usize);
+            items.last().map(id)
+        } else {
+            None
+        };
+        PageDomain { items, next_cursor }
+    }
+}
+
+/// The fields the books can be sorted by besides their id
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BookSortField {
+    Price,
+    Release,
+    Title,
+}
+
+impl std::fmt::Display for BookSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BookSortField::Price => write!(f, "price"),
+            BookSortField::Release => write!(f, "release"),
+            BookSortField::Title => write!(f, "title"),
+        }
+    }
+}
+
+impl std::str::FromStr for BookSortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "price" => Ok(BookSortField::Price),
+            "release" => Ok(BookSortField::Release),
+            "title" => Ok(BookSortField::Title),
+            _ => Err(format!("Invalid sort field: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl std::fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortOrder::Asc => write!(f, "asc"),
+            SortOrder::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+impl std::str::FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => Err(format!("Invalid sort order: {}", s)),
+        }
+    }
+}
+
+/// The combined filters of a book search, empty lists and absent values do not filter.
+/// The books are sorted by the sort field if given, ties and unsorted searches are ordered by id.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BookQuery {
+    pub authors: Vec<Ksuid>,
+    pub genres: Vec<Ksuid>,
+    pub status: Vec<BookStatus>,
+    pub series: Option<String>,
+    pub min_price: Option<Decimal>,
+    pub max_price: Option<Decimal>,
+    pub released_from: Option<chrono::naive::NaiveDate>,
+    pub released_to: Option<chrono::naive::NaiveDate>,
+    pub title: Option<String>,
+    pub sort: Option<BookSortField>,
+    pub order: SortOrder,
+}
+
+/// The maximum number of words of a full text search
+pub const MAX_SEARCH_WORDS: usize = 10;
+
+/// Splits the text of a full text search into its lowercase words
+pub fn search_words(text: &str) -> Vec<String> {
+    text.split_whitespace().map(str::to_lowercase).collect()
+}
+
+/// Escapes the characters with a meaning in HTML, so a text can be embedded in markup
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Builds the snippet of a full text search hit from the title and series of the book.
+/// The text is HTML escaped and the words equal to one of the search words, ignoring the case,
+/// are enclosed in <mark> tags. Words are the alphanumeric runs of the text, like the lexemes of
+/// the simple text search configuration, so parts of a word are not highlighted.
+pub fn highlight_snippet(title: &str, series: Option<&str>, words: &[String]) -> String {
+    let words: Vec<String> = words
+        .iter()
+        .flat_map(|w| w.split(|c: char| !c.is_alphanumeric()))
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let text = match series {
+        Some(series) => format!("{} - {}", title, series),
+        None => String::from(title),
+    };
+    let mut snippet = String::with_capacity(text.len());
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| c.is_alphanumeric() != rest.starts_with(char::is_alphanumeric))
+            .unwrap_or(rest.len());
+        let (part, tail) = rest.split_at(end);
+        if part.starts_with(char::is_alphanumeric) && words.contains(&part.to_lowercase()) {
+            snippet.push_str(&format!("<mark>{}</mark>", part));
+        } else {
+            snippet.push_str(&escape_html(part));
+        }
+        rest = tail;
+    }
+    snippet
+}
+
+/// A book found by the full text search.
+/// The snippet is the HTML escaped title and series of the book with the matched words enclosed
+/// in <mark> tags, see highlight_snippet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookSearchHitDomain {
+    pub book: BookDomain,
+    pub rank: f32,
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+impl std::fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthStatus::Up => write!(f, "up"),
+            HealthStatus::Down => write!(f, "down"),
+        }
+    }
+}
+
+/// The result of checking a dependency of the service, the detail tells why it is down
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthCheckDomain {
+    pub name: String,
+    pub status: HealthStatus,
+    pub detail: Option<String>,
+}
+
+impl HealthCheckDomain {
+    pub fn up(name: &str) -> Self {
+        HealthCheckDomain {
+            name: String::from(name),
+            status: HealthStatus::Up,
+            detail: None,
+        }
+    }
+
+    pub fn down(name: &str, detail: String) -> Self {
+        HealthCheckDomain {
+            name: String::from(name),
+            status: HealthStatus::Down,
+            detail: Some(detail),
+        }
+    }
+}
+
+/// The service is ready to serve requests if all checks are up
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadinessDomain {
+    pub checks: Vec<HealthCheckDomain>,
+}
+
+impl ReadinessDomain {
+    pub fn is_ready(&self) -> bool {
+        self.checks.iter().all(|c| c.status == HealthStatus::Up)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use svix_ksuid::KsuidLike;
+
+    #[test]
+    fn test_highlight_snippet_escapes_and_marks_whole_words() {
+        // Arrange
+        let words = search_words("FAUST");
+
+        // Act
+        let snippet = highlight_snippet(
+            "<b onclick=\"x\">Faust</b> & Faustus",
+            Some("Faust's"),
+            &words,
+        );
+        let plain = highlight_snippet("Werther", None, &words);
+
+        // Assert
+        assert_eq!(
+            snippet,
+            "&lt;b onclick=&quot;x&quot;&gt;<mark>Faust</mark>&lt;/b&gt; \
+             &amp; Faustus - <mark>Faust</mark>&#39;s"
+        );
+        assert_eq!(plain, "Werther");
+    }
+
+    #[test]
+    fn test_currency_from_str() {
+        // Act & Assert
+        assert_eq!("EUR".parse::<Currency>().unwrap().code(), "EUR");
+        assert_eq!(Currency::default(), Currency::USD);
+        assert!("eur".parse::<Currency>().is_err());
+        assert!("EURO".parse::<Currency>().is_err());
+        assert!("".parse::<Currency>().is_err());
+    }
+
+    #[test]
+    fn test_money_rounds_half_to_even() {
+        // Arrange
+        let jpy = "JPY".parse::<Currency>().unwrap();
+        let kwd = "KWD".parse::<Currency>().unwrap();
+
+        // Act & Assert
+        assert_eq!(
+            Money::new(Decimal::new(1225, 3), Currency::USD).to_string(),
+            "1.22 USD"
+        );
+        assert_eq!(
+            Money::new(Decimal::new(1235, 3), Currency::USD).to_string(),
+            "1.24 USD"
+        );
+        assert_eq!(
+            Money::new(Decimal::new(-1225, 3), Currency::USD).to_string(),
+            "-1.22 USD"
+        );
+        assert_eq!(Money::new(Decimal::new(25, 1), jpy).to_string(), "2 JPY");
+        assert_eq!(Money::new(Decimal::new(5, 0), kwd).to_string(), "5.000 KWD");
+    }
+
+    #[test]
+    fn test_money_parse() {
+        // Act & Assert
+        assert_eq!(
+            Money::parse("12.5", Currency::USD)
+                .unwrap()
+                .amount()
+                .to_string(),
+            "12.50"
+        );
+        assert_eq!(
+            Money::parse("12.500", Currency::USD)
+                .unwrap()
+                .amount()
+                .to_string(),
+            "12.50"
+        );
+        assert!(Money::parse("12.505", Currency::USD).is_err());
+        assert!(Money::parse("twelve", Currency::USD).is_err());
+        assert!(Money::parse("-1", Currency::USD).unwrap().is_negative());
+    }
+
+    #[test]
+    fn test_money_arithmetic() {
+        // Arrange
+        let price = Money::parse("0.10", Currency::USD).unwrap();
+        let euro = Money::parse("0.10", "EUR".parse().unwrap()).unwrap();
+
+        // Act
+        let sum = (0..3).try_fold(Money::zero(Currency::USD), |sum, _| sum.checked_add(&price));
+
+        // Assert
+        // exact, unlike 0.1 + 0.1 + 0.1 in f64
+        assert_eq!(sum.unwrap(), Money::parse("0.30", Currency::USD).unwrap());
+        assert_eq!(
+            price.times(3).checked_sub(&price).unwrap().to_string(),
+            "0.20 USD"
+        );
+        assert_eq!(price.percentage(25).to_string(), "0.02 USD");
+        assert!(price.checked_add(&euro).is_err());
+    }
+
+    fn placed_order() -> OrderDomain {
+        let address = AddressDomain {
+            city: String::from("Munich"),
+            country: String::from("Germany"),
+            province: None,
+            street: String::from("Berlinerstrasse"),
+            street_number: String::from("12b"),
+            zip_code: String::from("80331"),
+        };
+        OrderDomain {
+            billing_address: address.clone(),
+            customer_id: Ksuid::new(None, None),
+            id: Ksuid::new(None, None),
+            books: vec![],
+            shipping_address: address,
+            shipping_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            status: OrderStatus::Placed,
+            placed_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            shipped_at: None,
+            delivered_at: None,
+            canceled_at: None,
+            discount_codes: vec![],
+            currency: None,
+            pricing: OrderPricingDomain::default(),
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn test_order_status_transitions() {
+        // Arrange
+        let statuses = [
+            OrderStatus::Placed,
+            OrderStatus::Shipped,
+            OrderStatus::Delivered,
+            OrderStatus::Canceled,
+        ];
+        let allowed = [
+            (OrderStatus::Placed, OrderStatus::Shipped),
+            (OrderStatus::Shipped, OrderStatus::Delivered),
+            (OrderStatus::Placed, OrderStatus::Canceled),
+        ];
+
+        // Act & Assert
+        for from in &statuses {
+            for to in &statuses {
+                let expected = allowed.contains(&(from.clone(), to.clone()));
+                assert_eq!(from.can_transition_to(to), expected, "{} -> {}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_order_transition_records_timestamps() {
+        // Arrange
+        let mut order = placed_order();
+        let shipped = Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap();
+        let delivered = Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap();
+
+        // Act
+        order.transition_to(OrderStatus::Shipped, shipped).unwrap();
+        order
+            .transition_to(OrderStatus::Delivered, delivered)
+            .unwrap();
+
+        // Assert
+        assert_eq!(order.status, OrderStatus::Delivered);
+        assert_eq!(order.shipped_at, Some(shipped));
+        assert_eq!(order.delivered_at, Some(delivered));
+        assert_eq!(order.canceled_at, None);
+    }
+
+    #[test]
+    fn test_order_transition_to_same_status_changes_nothing() {
+        // Arrange
+        let mut order = placed_order();
+        let expected = order.clone();
+
+        // Act
+        let result = order.transition_to(OrderStatus::Placed, Utc::now());
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(order, expected);
+    }
+
+    #[test]
+    fn test_order_illegal_transition_is_rejected() {
+        // Arrange
+        let mut order = placed_order();
+        order
+            .transition_to(OrderStatus::Canceled, Utc::now())
+            .unwrap();
+        let expected = order.clone();
+
+        // Act
+        let result = order.transition_to(OrderStatus::Placed, Utc::now());
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+        assert_eq!(order, expected);
+    }
+
+    #[test]
+    fn test_check_version() {
+        // Arrange
+        let id = Ksuid::new(None, None);
+
+        // Act & Assert
+        assert!(check_version(id, 3, Some(&[3])).is_ok());
+        assert!(check_version(id, 3, Some(&[2, 3])).is_ok());
+        assert!(check_version(id, 3, None).is_ok());
+        assert!(matches!(
+            check_version(id, 3, Some(&[2])),
+            Err(error::DomainError::VersionConflict { .. })
+        ));
+        // only weak ETags were given, nothing can match
+        assert!(matches!(
+            check_version(id, 3, Some(&[])),
+            Err(error::DomainError::VersionConflict { .. })
+        ));
+    }
 }
@@ -1,97 +1,208 @@
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use super::{error, models, store};
+use super::exchange_service::ExchangeService;
+use super::tax_service::TaxService;
+use super::{error, models, pricing, store};
 use async_trait::async_trait;
-use chrono::Utc;
-use svix_ksuid::{Ksuid, KsuidLike};
+use svix_ksuid::Ksuid;
+use tracing::instrument;
 
+/// The OrderService is the domain entry point for orders and the inventory.
+/// Persistence is delegated to the configured OrderHandler repository,
+/// orders are placed by the known customers of the CustomerHandler, the books of new orders are priced with the catalog of the BookHandler,
+/// converted to the requested currency with the current exchange rates
+/// and taxed by the shipping address. The placed orders are counted by the OrderMetrics.
 pub struct OrderService {
-    todo: String,
+    repository: Arc<dyn store::OrderHandler + Send + Sync>,
+    customers: Arc<dyn store::CustomerHandler + Send + Sync>,
+    books: Arc<dyn store::BookHandler + Send + Sync>,
+    exchange: Arc<ExchangeService>,
+    taxes: Arc<TaxService>,
+    metrics: Arc<dyn store::OrderMetrics + Send + Sync>,
 }
 
 impl OrderService {
-    pub fn new(todo: String) -> Arc<Self> {
-        Arc::new(OrderService { todo })
+    pub fn new(
+        repository: Arc<dyn store::OrderHandler + Send + Sync>,
+        customers: Arc<dyn store::CustomerHandler + Send + Sync>,
+        books: Arc<dyn store::BookHandler + Send + Sync>,
+        exchange: Arc<ExchangeService>,
+        taxes: Arc<TaxService>,
+        metrics: Arc<dyn store::OrderMetrics + Send + Sync>,
+    ) -> Arc<Self> {
+        Arc::new(OrderService {
+            repository,
+            customers,
+            books,
+            exchange,
+            taxes,
+            metrics,
+        })
+    }
+
+    /// Prices the order with the current catalog prices and the requested discount codes.
+    /// If a currency is requested the prices are converted and the rate is locked in the pricing,
+    /// the books must be priced in the same currency to be converted with a single rate.
+    /// The discounted lines are taxed with the rates of the shipping address.
+    #[instrument(name = "OrderService::price_order", skip_all)]
+    async fn price_order(
+        &self,
+        order: &models::OrderDomain,
+    ) -> Result<models::OrderPricingDomain, error::DomainError> {
+        let mut catalog = HashMap::new();
+        for ordered in &order.books {
+            if catalog.contains_key(&ordered.book_id) {
+                continue;
+            }
+            match self.books.get_book_by_id(ordered.book_id).await {
+                Ok(book) => {
+                    catalog.insert(book.id, book);
+                }
+                // the remaining books are reported by the pricing as unknown
+                Err(error::DomainError::NotFound { .. }) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        let mut exchange_rate: Option<models::ExchangeQuoteDomain> = None;
+        if let Some(currency) = order.currency {
+            let rates = self.exchange.rates_to(currency).await?;
+            for book in catalog.values_mut() {
+                let (converted, quote) = rates.convert_book(book.clone())?;
+                *book = converted;
+                match (&exchange_rate, quote) {
+                    (Some(locked), Some(quote)) if locked.source != quote.source => {
+                        return Err(error::DomainError::BusinessConstraintViolation {
+                            message: String::from(
+                                "the ordered books must be priced in the same currency",
+                            ),
+                            source: Box::new(error::CurrencyMismatchError(format!(
+                                "{} and {}",
+                                locked.source, quote.source
+                            ))),
+                        });
+                    }
+                    (_, Some(quote)) => exchange_rate = Some(quote),
+                    (_, None) => {}
+                }
+            }
+        }
+
+        let mut pricing = pricing::price_order(
+            &order.books,
+            &catalog,
+            &order.discount_codes,
+            order.placed_at.date_naive(),
+        )?;
+        pricing.exchange_rate = exchange_rate;
+        self.taxes
+            .tax_order(&mut pricing, &catalog, &order.shipping_address)
+            .await?;
+        Ok(pricing)
     }
 }
 
 #[async_trait]
 impl store::OrderHandler for OrderService {
     /// Get an existing order by id
+    #[instrument(name = "OrderService::get_order_by_id", skip_all)]
     async fn get_order_by_id(&self, id: Ksuid) -> Result<models::OrderDomain, error::DomainError> {
-        let address = models::AddressDomain {
-            street: String::from("Street"),
-            street_number: String::from("1b"),
-            city: String::from("My City"),
-            zip_code: String::from("123456"),
-            province: Some(String::from("province")),
-            country: String::from("Country"),
-        };
-        let books = vec![models::OrderedBookDomain {
-            book_id: Ksuid::new(None, None),
-            quantity: 8,
-        }];
-        Ok(models::OrderDomain {
-            billing_address: address.clone(),
-            books,
-            customer_id: Ksuid::new(None, None),
-            id,
-            shipping_address: address,
-            shipping_date: Utc::now().date_naive(),
-            status: models::OrderStatus::Shipped,
-        })
+        self.repository.get_order_by_id(id).await
     }
 
     /// Delete an existing order by id
-    async fn delete_order_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
-        Err(error::DomainError::NotFound {
-            id: id.to_string(),
-            source: Box::new(error::OrderNotFoundError(id.to_string())),
-        })
+    #[instrument(name = "OrderService::delete_order_by_id", skip_all)]
+    async fn delete_order_by_id(
+        &self,
+        id: Ksuid,
+        change: models::OrderChangeDomain,
+    ) -> Result<(), error::DomainError> {
+        self.repository.delete_order_by_id(id, change).await
     }
 
     /// Get inventory statistics
+    #[instrument(name = "OrderService::get_inventory", skip_all)]
     async fn get_inventory(&self) -> Result<models::InventoryDomain, error::DomainError> {
-        Ok(models::InventoryDomain {
-            books_available: 42,
-            books_reordered: 42,
-            books_out_of_stock: 42,
-        })
+        self.repository.get_inventory().await
     }
 
-    /// Create a new book order
+    /// Create a new book order, the placed order, its discount codes and a rejection
+    /// for insufficient stock are recorded by the metrics
+    #[instrument(name = "OrderService::create_order", skip_all)]
     async fn create_order(
         &self,
-        order: models::OrderDomain,
+        mut order: models::OrderDomain,
+        change: models::OrderChangeDomain,
     ) -> Result<models::OrderDomain, error::DomainError> {
-        Ok(order.clone())
+        match self.customers.get_customer_by_id(order.customer_id).await {
+            Ok(_) => {}
+            Err(error::DomainError::NotFound { .. }) => {
+                return Err(error::DomainError::BusinessConstraintViolation {
+                    message: format!("customer {} is unknown", order.customer_id),
+                    source: Box::new(error::CustomerNotFoundError(order.customer_id.to_string())),
+                });
+            }
+            Err(e) => return Err(e),
+        }
+        order.pricing = self.price_order(&order).await?;
+        order.currency = Some(order.pricing.total.currency());
+        match self.repository.create_order(order, change).await {
+            Ok(order) => {
+                self.metrics.order_placed();
+                let codes: HashSet<&str> = order
+                    .pricing
+                    .lines
+                    .iter()
+                    .filter_map(|line| line.discount_code.as_deref())
+                    .collect();
+                for code in codes {
+                    self.metrics.discount_redeemed(code);
+                }
+                Ok(order)
+            }
+            Err(error::DomainError::BusinessConstraintViolation { message, source })
+                if source.is::<error::InsufficientStockError>() =>
+            {
+                self.metrics.stock_out();
+                Err(error::DomainError::BusinessConstraintViolation { message, source })
+            }
+            Err(e) => Err(e),
+        }
     }
 
     /// Update an existing order
+    #[instrument(name = "OrderService::update_order", skip_all)]
     async fn update_order(
         &self,
         props: models::OrderUpdateProps,
+        change: models::OrderChangeDomain,
     ) -> Result<models::OrderDomain, error::DomainError> {
-        let address = models::AddressDomain {
-            street: String::from("Street"),
-            street_number: String::from("1b"),
-            city: String::from("My City"),
-            zip_code: String::from("123456"),
-            province: Some(String::from("province")),
-            country: String::from("Country"),
-        };
-        let books = vec![models::OrderedBookDomain {
-            book_id: Ksuid::new(None, None),
-            quantity: 8,
-        }];
-        Ok(models::OrderDomain {
-            billing_address: address.clone(),
-            books,
-            customer_id: Ksuid::new(None, None),
-            id: props.id,
-            shipping_address: address,
-            shipping_date: Utc::now().date_naive(),
-            status: models::OrderStatus::Shipped,
-        })
+        self.repository.update_order(props, change).await
+    }
+
+    /// Get the status changes of an order, oldest first
+    #[instrument(name = "OrderService::get_order_history", skip_all)]
+    async fn get_order_history(
+        &self,
+        id: Ksuid,
+    ) -> Result<Vec<models::OrderStatusEventDomain>, error::DomainError> {
+        self.repository.get_order_history(id).await
+    }
+
+    /// Get a page of the orders of a customer matching one of the status given in the list,
+    /// all orders of the customer if the list is empty.
+    /// The orders of an unknown customer are not found.
+    #[instrument(name = "OrderService::get_orders_by_customer", skip_all)]
+    async fn get_orders_by_customer(
+        &self,
+        customer_id: Ksuid,
+        status: Vec<models::OrderStatus>,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::OrderDomain>, error::DomainError> {
+        self.customers.get_customer_by_id(customer_id).await?;
+        self.repository
+            .get_orders_by_customer(customer_id, status, page)
+            .await
     }
 }
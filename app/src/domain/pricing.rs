@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+
+use super::{error, models};
+use svix_ksuid::Ksuid;
+
+fn mixed_currencies(e: error::CurrencyMismatchError) -> error::DomainError {
+    error::DomainError::BusinessConstraintViolation {
+        message: String::from("the ordered books must be priced in the same currency"),
+        source: Box::new(e),
+    }
+}
+
+fn invalid_code(message: String, code: &str) -> error::DomainError {
+    error::DomainError::BusinessConstraintViolation {
+        message,
+        source: Box::new(error::InvalidDiscountCodeError(code.to_string())),
+    }
+}
+
+/// Prices the ordered books with the current prices of the catalog.
+/// Every requested discount code must be valid on the given day and linked to at least one
+/// of the ordered books, if several codes apply to a book the highest discount wins.
+/// The order is priced in the currency of the books, discounts are rounded half to even.
+pub fn price_order(
+    books: &[models::OrderedBookDomain],
+    catalog: &HashMap<Ksuid, models::BookDomain>,
+    discount_codes: &[String],
+    day: chrono::naive::NaiveDate,
+) -> Result<models::OrderPricingDomain, error::DomainError> {
+    let mut codes: Vec<&String> = Vec::new();
+    for code in discount_codes {
+        if !codes.contains(&code) {
+            codes.push(code);
+        }
+    }
+
+    let currency = books
+        .first()
+        .and_then(|ordered| catalog.get(&ordered.book_id))
+        .map_or_else(models::Currency::default, |book| book.price.currency());
+    let mut pricing = models::OrderPricingDomain {
+        lines: Vec::new(),
+        subtotal: models::Money::zero(currency),
+        discount_total: models::Money::zero(currency),
+        total: models::Money::zero(currency),
+        exchange_rate: None,
+        prices_include_tax: false,
+        net_total: models::Money::zero(currency),
+        tax_total: models::Money::zero(currency),
+        tax_lines: Vec::new(),
+    };
+    let mut used = vec![false; codes.len()];
+    for ordered in books {
+        let book = catalog.get(&ordered.book_id).ok_or_else(|| {
+            error::DomainError::BusinessConstraintViolation {
+                message: format!("book {} is unknown", ordered.book_id),
+                source: Box::new(error::BookNotFoundError(ordered.book_id.to_string())),
+            }
+        })?;
+
+        let mut applied: Option<&models::DiscountCodeDomain> = None;
+        for (index, code) in codes.iter().enumerate() {
+            let Some(discount) = book
+                .discounts
+                .iter()
+                .flatten()
+                .find(|discount| &discount.code == *code)
+            else {
+                continue;
+            };
+            if day < discount.valid_from {
+                return Err(invalid_code(
+                    format!(
+                        "discount code {} is not valid before {}",
+                        code, discount.valid_from
+                    ),
+                    code,
+                ));
+            }
+            if day > discount.valid_to {
+                return Err(invalid_code(
+                    format!("discount code {} expired on {}", code, discount.valid_to),
+                    code,
+                ));
+            }
+            used[index] = true;
+            if applied.is_none_or(|best| discount.percentage_discount > best.percentage_discount) {
+                applied = Some(discount);
+            }
+        }
+
+        let gross = book.price.times(ordered.quantity);
+        let percentage = applied.map_or(0, |discount| discount.percentage_discount);
+        let discount_amount = gross.percentage(percentage);
+        let line_total = gross
+            .checked_sub(&discount_amount)
+            .map_err(mixed_currencies)?;
+        pricing.subtotal = pricing
+            .subtotal
+            .checked_add(&gross)
+            .map_err(mixed_currencies)?;
+        pricing.discount_total = pricing
+            .discount_total
+            .checked_add(&discount_amount)
+            .map_err(mixed_currencies)?;
+        pricing.lines.push(models::OrderLineDomain {
+            book_id: ordered.book_id,
+            quantity: ordered.quantity,
+            unit_price: book.price,
+            discount_code: applied.map(|discount| discount.code.clone()),
+            discount_percentage: percentage,
+            discount_amount,
+            line_total,
+            tax_rate: rust_decimal::Decimal::ZERO,
+            tax_amount: models::Money::zero(currency),
+        });
+    }
+
+    if let Some((code, _)) = codes.iter().zip(&used).find(|(_, used)| !**used) {
+        return Err(invalid_code(
+            format!("discount code {} does not apply to the ordered books", code),
+            code,
+        ));
+    }
+
+    pricing.total = pricing
+        .subtotal
+        .checked_sub(&pricing.discount_total)
+        .map_err(mixed_currencies)?;
+    // untaxed until the taxes of the shipping country are applied
+    pricing.net_total = pricing.total;
+    Ok(pricing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use svix_ksuid::KsuidLike;
+
+    fn discount(code: &str, percentage: i32) -> models::DiscountCodeDomain {
+        models::DiscountCodeDomain {
+            code: String::from(code),
+            id: Ksuid::new(None, None),
+            percentage_discount: percentage,
+            valid_from: NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+            valid_to: NaiveDate::from_ymd_opt(2025, 8, 31).unwrap(),
+        }
+    }
+
+    fn usd(amount: &str) -> models::Money {
+        models::Money::parse(amount, models::Currency::USD).unwrap()
+    }
+
+    fn book(
+        price: models::Money,
+        discounts: Vec<models::DiscountCodeDomain>,
+    ) -> models::BookDomain {
+        let release = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        models::BookDomain {
+            authors: vec![],
+            available: 10,
+            discounts: Some(discounts),
+            edition: 1,
+            firs_release: release,
+            genres: None,
+            id: Ksuid::new(None, None),
+            price,
+            product_type: models::ProductType::Print,
+            release,
+            series: None,
+            status: models::BookStatus::Available,
+            title: String::from("Dune"),
+            version: 1,
+        }
+    }
+
+    fn catalog(
+        books: Vec<models::BookDomain>,
+    ) -> (
+        Vec<models::OrderedBookDomain>,
+        HashMap<Ksuid, models::BookDomain>,
+    ) {
+        let ordered = books
+            .iter()
+            .map(|book| models::OrderedBookDomain {
+                book_id: book.id,
+                quantity: 2,
+            })
+            .collect();
+        let catalog = books.into_iter().map(|book| (book.id, book)).collect();
+        (ordered, catalog)
+    }
+
+    fn summer() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 7, 1).unwrap()
+    }
+
+    #[test]
+    fn test_price_order_without_discounts() {
+        // Arrange
+        let (ordered, catalog) =
+            catalog(vec![book(usd("9.99"), vec![]), book(usd("20.00"), vec![])]);
+
+        // Act
+        let pricing = price_order(&ordered, &catalog, &[], summer()).unwrap();
+
+        // Assert
+        assert_eq!(pricing.lines.len(), 2);
+        assert_eq!(pricing.lines[0].unit_price, usd("9.99"));
+        assert_eq!(pricing.lines[0].line_total, usd("19.98"));
+        assert_eq!(pricing.lines[0].discount_code, None);
+        assert_eq!(pricing.subtotal, usd("59.98"));
+        assert_eq!(pricing.discount_total, usd("0.00"));
+        assert_eq!(pricing.total, usd("59.98"));
+    }
+
+    #[test]
+    fn test_price_order_applies_the_highest_linked_discount() {
+        // Arrange
+        let (ordered, catalog) = catalog(vec![
+            book(
+                usd("9.99"),
+                vec![discount("SUMMER10", 10), discount("VIP25", 25)],
+            ),
+            book(usd("20.00"), vec![discount("SUMMER10", 10)]),
+        ]);
+        let codes = vec![
+            String::from("SUMMER10"),
+            String::from("VIP25"),
+            String::from("SUMMER10"),
+        ];
+
+        // Act
+        let pricing = price_order(&ordered, &catalog, &codes, summer()).unwrap();
+
+        // Assert
+        assert_eq!(pricing.lines[0].discount_code, Some(String::from("VIP25")));
+        assert_eq!(pricing.lines[0].discount_percentage, 25);
+        assert_eq!(pricing.lines[0].discount_amount, usd("5.00"));
+        assert_eq!(pricing.lines[0].line_total, usd("14.98"));
+        assert_eq!(
+            pricing.lines[1].discount_code,
+            Some(String::from("SUMMER10"))
+        );
+        assert_eq!(pricing.lines[1].line_total, usd("36.00"));
+        assert_eq!(pricing.subtotal, usd("59.98"));
+        assert_eq!(pricing.discount_total, usd("9.00"));
+        assert_eq!(pricing.total, usd("50.98"));
+    }
+
+    #[test]
+    fn test_price_order_rounds_discounts_half_to_even() {
+        // Arrange
+        let (ordered, catalog) = catalog(vec![book(usd("1.25"), vec![discount("VIP25", 25)])]);
+
+        // Act
+        let pricing = price_order(&ordered, &catalog, &[String::from("VIP25")], summer()).unwrap();
+
+        // Assert
+        // 25% of 2.50 are 0.625, the half cent is rounded to the even 0.62
+        assert_eq!(pricing.lines[0].discount_amount, usd("0.62"));
+        assert_eq!(pricing.total, usd("1.88"));
+    }
+
+    #[test]
+    fn test_price_order_rejects_mixed_currencies() {
+        // Arrange
+        let euro = "EUR".parse::<models::Currency>().unwrap();
+        let (ordered, catalog) = catalog(vec![
+            book(usd("9.99"), vec![]),
+            book(models::Money::parse("9.99", euro).unwrap(), vec![]),
+        ]);
+
+        // Act
+        let result = price_order(&ordered, &catalog, &[], summer());
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_price_order_rejects_invalid_codes() {
+        // Arrange
+        let (ordered, catalog) = catalog(vec![book(usd("9.99"), vec![discount("SUMMER10", 10)])]);
+        let before = NaiveDate::from_ymd_opt(2025, 5, 31).unwrap();
+        let after = NaiveDate::from_ymd_opt(2025, 9, 1).unwrap();
+        let summer_code = vec![String::from("SUMMER10")];
+
+        // Act
+        let not_yet_valid = price_order(&ordered, &catalog, &summer_code, before);
+        let expired = price_order(&ordered, &catalog, &summer_code, after);
+        let not_linked = price_order(&ordered, &catalog, &[String::from("VIP25")], summer());
+
+        // Assert
+        for result in [not_yet_valid, expired, not_linked] {
+            assert!(matches!(
+                result,
+                Err(error::DomainError::BusinessConstraintViolation { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_price_order_rejects_unknown_books() {
+        // Arrange
+        let (ordered, _) = catalog(vec![book(usd("9.99"), vec![])]);
+
+        // Act
+        let result = price_order(&ordered, &HashMap::new(), &[], summer());
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+    }
+}
@@ -12,7 +12,11 @@ pub trait OrderHandler {
     async fn get_order_by_id(&self, id: Ksuid) -> Result<models::OrderDomain, error::DomainError>;
 
     /// Delete an existing order by id
-    async fn delete_order_by_id(&self, id: Ksuid) -> Result<(), error::DomainError>;
+    async fn delete_order_by_id(
+        &self,
+        id: Ksuid,
+        change: models::OrderChangeDomain,
+    ) -> Result<(), error::DomainError>;
 
     /// Get inventory statistics
     async fn get_inventory(&self) -> Result<models::InventoryDomain, error::DomainError>;
@@ -21,13 +25,55 @@ pub trait OrderHandler {
     async fn create_order(
         &self,
         order: models::OrderDomain,
+        change: models::OrderChangeDomain,
     ) -> Result<models::OrderDomain, error::DomainError>;
 
     /// Update an existing order
     async fn update_order(
         &self,
         props: models::OrderUpdateProps,
+        change: models::OrderChangeDomain,
     ) -> Result<models::OrderDomain, error::DomainError>;
+
+    /// Get the status changes of an order, oldest first
+    async fn get_order_history(
+        &self,
+        id: Ksuid,
+    ) -> Result<Vec<models::OrderStatusEventDomain>, error::DomainError>;
+
+    /// Get a page of the orders of a customer matching one of the status given in the list,
+    /// all orders of the customer if the list is empty
+    async fn get_orders_by_customer(
+        &self,
+        customer_id: Ksuid,
+        status: Vec<models::OrderStatus>,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::OrderDomain>, error::DomainError>;
+}
+
+/// The CustomerHandler handles the customers placing the orders and their address books
+#[async_trait]
+pub trait CustomerHandler {
+    /// Create a new customer
+    async fn create_customer(
+        &self,
+        customer: models::CustomerDomain,
+    ) -> Result<models::CustomerDomain, error::DomainError>;
+
+    /// Delete an existing customer without orders
+    async fn delete_customer_by_id(&self, id: Ksuid) -> Result<(), error::DomainError>;
+
+    /// Get an existing customer by id
+    async fn get_customer_by_id(
+        &self,
+        id: Ksuid,
+    ) -> Result<models::CustomerDomain, error::DomainError>;
+
+    /// Update an existing customer
+    async fn update_customer(
+        &self,
+        props: models::CustomerUpdateProps,
+    ) -> Result<models::CustomerDomain, error::DomainError>;
 }
 
 /// The BookStore handles the request related to the books and related entities.
@@ -47,23 +93,41 @@ pub trait BookHandler {
     /// Get an existing book by id
     async fn get_book_by_id(&self, id: Ksuid) -> Result<models::BookDomain, error::DomainError>;
 
-    /// get all books in the list of authors
+    /// get a page of the books in the list of authors
     async fn get_books_by_authors(
         &self,
         authors: Vec<Ksuid>,
-    ) -> Result<Vec<models::BookDomain>, error::DomainError>;
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError>;
 
-    /// Get all books with matching at least one genre
+    /// Get a page of the books matching at least one genre
     async fn get_books_by_generes(
         &self,
         genres: Vec<Ksuid>,
-    ) -> Result<Vec<models::BookDomain>, error::DomainError>;
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError>;
 
-    /// Get all books matching one of the status given in the list
+    /// Get a page of the books matching one of the status given in the list
     async fn get_books_by_status(
         &self,
         status: Vec<models::BookStatus>,
-    ) -> Result<Vec<models::BookDomain>, error::DomainError>;
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError>;
+
+    /// Query a page of the books matching all filters of the query
+    async fn query_books(
+        &self,
+        query: models::BookQuery,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::BookDomain>, error::DomainError>;
+
+    /// Search the titles, series and author names of the books for the words of the text,
+    /// the books are ordered by their rank
+    async fn search_books(
+        &self,
+        text: String,
+        limit: u32,
+    ) -> Result<Vec<models::BookSearchHitDomain>, error::DomainError>;
 
     /// Update an existing book in the store
     async fn update_book(
@@ -120,3 +184,170 @@ pub trait BookHandler {
         props: models::AuthorUpdateProps,
     ) -> Result<models::AuthorDomain, error::DomainError>;
 }
+
+/// The ApiKeyHandler looks up the API keys in the keystore
+#[async_trait]
+pub trait ApiKeyHandler {
+    /// Get an API key by the hash of the key
+    async fn get_api_key_by_hash(
+        &self,
+        key_hash: &str,
+    ) -> Result<models::ApiKeyDomain, error::DomainError>;
+}
+
+/// The IdempotencyHandler stores the responses of the requests made with an Idempotency-Key
+#[async_trait]
+pub trait IdempotencyHandler {
+    /// Reserve the key of the request if it is unused or expired, expired keys are purged.
+    /// Returns the record of the key if it is already taken.
+    async fn reserve_idempotency_key(
+        &self,
+        request: models::IdempotentRequestDomain,
+    ) -> Result<Option<models::IdempotencyRecordDomain>, error::DomainError>;
+
+    /// Store the response of a reserved key to replay it until the key expires,
+    /// the expiry replaces the lease of the request in progress
+    async fn complete_idempotency_key(
+        &self,
+        scope: &str,
+        key: &str,
+        response: models::IdempotentResponseDomain,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), error::DomainError>;
+
+    /// Release a reserved key without response, so the request can be retried
+    async fn release_idempotency_key(
+        &self,
+        scope: &str,
+        key: &str,
+    ) -> Result<(), error::DomainError>;
+}
+
+/// The HealthHandler checks the dependencies of the store, e.g. the database connection
+#[async_trait]
+pub trait HealthHandler {
+    /// Check the dependencies, a failing check is reported as down and never as error
+    async fn check_health(&self) -> Vec<models::HealthCheckDomain>;
+}
+
+/// The ExchangeRateHandler looks up the exchange rates table against the base currency
+#[async_trait]
+pub trait ExchangeRateHandler {
+    /// Get the rates of all currencies the prices can be converted to
+    async fn get_exchange_rates(
+        &self,
+    ) -> Result<Vec<models::ExchangeRateDomain>, error::DomainError>;
+}
+
+/// The TaxRateHandler looks up the tax rates by shipping country
+#[async_trait]
+pub trait TaxRateHandler {
+    /// Get the rates of all countries orders are taxed for
+    async fn get_tax_rates(&self) -> Result<Vec<models::TaxRateDomain>, error::DomainError>;
+}
+
+/// The OutboxHandler reads the events written to the outbox together with the changes of the store
+#[async_trait]
+pub trait OutboxHandler {
+    /// Get the oldest unpublished events in the order they were written, at most limit
+    async fn get_unpublished_events(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<models::OutboxEventDomain>, error::DomainError>;
+
+    /// Mark the events as published, they are not returned as unpublished again
+    async fn mark_events_published(&self, ids: &[i64]) -> Result<(), error::DomainError>;
+}
+
+/// The EventSink publishes the domain events to the downstream systems
+#[async_trait]
+pub trait EventSink {
+    /// Publish an event, an event is published again if marking it as published fails
+    async fn publish(&self, event: &models::OutboxEventDomain) -> Result<(), error::DomainError>;
+}
+
+/// The WebhookHandler handles the webhooks of the partners and the log of their deliveries
+#[async_trait]
+pub trait WebhookHandler {
+    /// Create a new webhook
+    async fn create_webhook(
+        &self,
+        webhook: models::WebhookDomain,
+    ) -> Result<models::WebhookDomain, error::DomainError>;
+
+    /// Delete an existing webhook, its pending deliveries are not sent
+    async fn delete_webhook_by_id(&self, id: Ksuid) -> Result<(), error::DomainError>;
+
+    /// Get an existing webhook by id
+    async fn get_webhook_by_id(
+        &self,
+        id: Ksuid,
+    ) -> Result<models::WebhookDomain, error::DomainError>;
+
+    /// Get all webhooks ordered by id
+    async fn get_webhooks(&self) -> Result<Vec<models::WebhookDomain>, error::DomainError>;
+
+    /// Update an existing webhook
+    async fn update_webhook(
+        &self,
+        props: models::WebhookUpdateProps,
+    ) -> Result<models::WebhookDomain, error::DomainError>;
+
+    /// Get a page of the deliveries of an existing webhook ordered by id, all if status is None
+    async fn get_webhook_deliveries(
+        &self,
+        webhook_id: Ksuid,
+        status: Option<models::DeliveryStatus>,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::WebhookDeliveryDomain>, error::DomainError>;
+}
+
+/// The WebhookDeliveryHandler keeps the deliveries of the events to the webhooks
+#[async_trait]
+pub trait WebhookDeliveryHandler {
+    /// Create a pending delivery of the event for every webhook subscribed to the event type.
+    /// An event enqueued again is not delivered twice. Returns the number of new deliveries.
+    async fn enqueue_deliveries(
+        &self,
+        event_id: i64,
+        event_type: &str,
+        payload: &str,
+    ) -> Result<usize, error::DomainError>;
+
+    /// Claim the oldest pending deliveries which are due, at most limit.
+    /// A claimed delivery is due again after the lease, e.g. if the process died during the attempt.
+    async fn claim_due_deliveries(
+        &self,
+        limit: u32,
+        lease: chrono::Duration,
+    ) -> Result<Vec<models::ClaimedDeliveryDomain>, error::DomainError>;
+
+    /// Record the outcome of an attempt, the status, attempts, last_* fields and the times are updated
+    async fn update_delivery(
+        &self,
+        delivery: &models::WebhookDeliveryDomain,
+    ) -> Result<(), error::DomainError>;
+}
+
+/// The WebhookSender posts the payload of a delivery to the endpoint of the webhook
+#[async_trait]
+pub trait WebhookSender {
+    /// Returns the HTTP status the endpoint answered with, fails if the endpoint was unreachable
+    async fn send(
+        &self,
+        webhook: &models::WebhookDomain,
+        delivery: &models::WebhookDeliveryDomain,
+    ) -> Result<u16, error::DomainError>;
+}
+
+/// The OrderMetrics records the business metrics of the orders, e.g. for a metrics endpoint
+pub trait OrderMetrics {
+    /// An order was placed
+    fn order_placed(&self);
+
+    /// A discount code was redeemed by a placed order
+    fn discount_redeemed(&self, code: &str);
+
+    /// An order was rejected as a book has insufficient stock
+    fn stock_out(&self);
+}
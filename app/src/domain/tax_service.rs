@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use svix_ksuid::Ksuid;
+use tracing::instrument;
+
+use super::{error, models, store};
+
+fn mixed_currencies(e: error::CurrencyMismatchError) -> error::DomainError {
+    error::DomainError::BusinessConstraintViolation {
+        message: String::from("the ordered books must be priced in the same currency"),
+        source: Box::new(e),
+    }
+}
+
+/// Normalizes a country or province of an address to the codes of the tax table
+fn region_code(region: &str) -> String {
+    region.trim().to_uppercase()
+}
+
+/// The TaxService taxes the priced orders by the shipping address.
+/// The rates of the tax table are looked up by country and province, a rate for a genre or
+/// product type of the book overrides the standard rate. Without any rates orders are untaxed.
+pub struct TaxService {
+    prices_include_tax: bool,
+    repository: Arc<dyn store::TaxRateHandler + Send + Sync>,
+}
+
+impl TaxService {
+    pub fn new(
+        prices_include_tax: bool,
+        repository: Arc<dyn store::TaxRateHandler + Send + Sync>,
+    ) -> Arc<Self> {
+        Arc::new(TaxService {
+            prices_include_tax,
+            repository,
+        })
+    }
+
+    /// Taxes the lines of the priced order and sums the taxes by rate.
+    /// Gross prices contain the tax, net prices are increased by the tax.
+    /// The tax is rounded half to even per line.
+    #[instrument(name = "TaxService::tax_order", skip_all)]
+    pub async fn tax_order(
+        &self,
+        pricing: &mut models::OrderPricingDomain,
+        catalog: &HashMap<Ksuid, models::BookDomain>,
+        address: &models::AddressDomain,
+    ) -> Result<(), error::DomainError> {
+        pricing.prices_include_tax = self.prices_include_tax;
+        let rates = self.repository.get_tax_rates().await?;
+
+        let country = region_code(&address.country);
+        let province = address.province.as_deref().map(region_code);
+        for line in &mut pricing.lines {
+            let book = catalog.get(&line.book_id).ok_or_else(|| {
+                error::DomainError::BusinessConstraintViolation {
+                    message: format!("book {} is unknown", line.book_id),
+                    source: Box::new(error::BookNotFoundError(line.book_id.to_string())),
+                }
+            })?;
+            // without any rates the lines are taxed at zero
+            let rate = if rates.is_empty() {
+                Decimal::ZERO
+            } else {
+                rate_of(&rates, book, &country, province.as_deref())?
+            };
+            let currency = line.line_total.currency();
+            line.tax_rate = rate;
+            line.tax_amount = if self.prices_include_tax {
+                let net = models::Money::new(
+                    line.line_total.amount() * Decimal::ONE_HUNDRED / (Decimal::ONE_HUNDRED + rate),
+                    currency,
+                );
+                line.line_total
+                    .checked_sub(&net)
+                    .map_err(mixed_currencies)?
+            } else {
+                models::Money::new(
+                    line.line_total.amount() * rate / Decimal::ONE_HUNDRED,
+                    currency,
+                )
+            };
+        }
+
+        pricing.tax_lines = pricing.summarize_tax_lines().map_err(mixed_currencies)?;
+        let currency = pricing.total.currency();
+        let mut net_total = models::Money::zero(currency);
+        let mut tax_total = models::Money::zero(currency);
+        for tax_line in &pricing.tax_lines {
+            net_total = net_total
+                .checked_add(&tax_line.net)
+                .map_err(mixed_currencies)?;
+            tax_total = tax_total
+                .checked_add(&tax_line.tax)
+                .map_err(mixed_currencies)?;
+        }
+        pricing.net_total = net_total;
+        pricing.tax_total = tax_total;
+        if !self.prices_include_tax {
+            pricing.total = pricing
+                .total
+                .checked_add(&tax_total)
+                .map_err(mixed_currencies)?;
+        }
+        Ok(())
+    }
+}
+
+/// The most specific rate for the book: a genre override before a product type override
+/// before the standard rate, a rate of the province before the rate of the country.
+/// If overrides for several genres of the book apply, the lowest rate wins.
+fn rate_of(
+    rates: &[models::TaxRateDomain],
+    book: &models::BookDomain,
+    country: &str,
+    province: Option<&str>,
+) -> Result<Decimal, error::DomainError> {
+    let has_genre = |id: Ksuid| book.genres.iter().flatten().any(|genre| genre.id == id);
+    rates
+        .iter()
+        .filter(|rate| rate.country == country)
+        .filter(|rate| rate.province.as_deref().is_none_or(|p| Some(p) == province))
+        .filter_map(|rate| {
+            let category = match (rate.genre, rate.product_type) {
+                (Some(genre), _) if has_genre(genre) => 2,
+                (None, Some(product_type)) if product_type == book.product_type => 1,
+                (None, None) => 0,
+                _ => return None,
+            };
+            Some(((category, rate.province.is_some()), rate.rate))
+        })
+        .max_by(|(a_rank, a_rate), (b_rank, b_rate)| {
+            a_rank.cmp(b_rank).then_with(|| b_rate.cmp(a_rate))
+        })
+        .map(|(_, rate)| rate)
+        .ok_or_else(|| error::DomainError::BusinessConstraintViolation {
+            message: format!("book {} has no tax rate for {}", book.id, country),
+            source: Box::new(error::TaxRateNotFoundError(country.to_string())),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use std::str::FromStr;
+    use svix_ksuid::KsuidLike;
+
+    struct StaticRates(Vec<models::TaxRateDomain>);
+
+    #[async_trait]
+    impl store::TaxRateHandler for StaticRates {
+        async fn get_tax_rates(&self) -> Result<Vec<models::TaxRateDomain>, error::DomainError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn eur(amount: &str) -> models::Money {
+        models::Money::parse(amount, models::Currency::from_str("EUR").unwrap()).unwrap()
+    }
+
+    fn rate(
+        country: &str,
+        province: Option<&str>,
+        genre: Option<Ksuid>,
+        product_type: Option<models::ProductType>,
+        rate: &str,
+    ) -> models::TaxRateDomain {
+        models::TaxRateDomain {
+            country: String::from(country),
+            province: province.map(String::from),
+            genre,
+            product_type,
+            rate: Decimal::from_str(rate).unwrap(),
+        }
+    }
+
+    fn book(
+        product_type: models::ProductType,
+        genres: Vec<models::GenereDomain>,
+    ) -> models::BookDomain {
+        let release = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        models::BookDomain {
+            authors: vec![],
+            available: 10,
+            discounts: None,
+            edition: 1,
+            firs_release: release,
+            genres: Some(genres),
+            id: Ksuid::new(None, None),
+            price: eur("10.00"),
+            product_type,
+            release,
+            series: None,
+            status: models::BookStatus::Available,
+            title: String::from("Faust"),
+            version: 1,
+        }
+    }
+
+    fn address(country: &str, province: Option<&str>) -> models::AddressDomain {
+        models::AddressDomain {
+            city: String::from("Berlin"),
+            country: String::from(country),
+            province: province.map(String::from),
+            street: String::from("Unter den Linden"),
+            street_number: String::from("1"),
+            zip_code: String::from("10117"),
+        }
+    }
+
+    /// Prices one line of each book with the line totals of the book prices
+    fn priced(
+        books: &[models::BookDomain],
+    ) -> (
+        models::OrderPricingDomain,
+        HashMap<Ksuid, models::BookDomain>,
+    ) {
+        let mut total = eur("0");
+        let lines = books
+            .iter()
+            .map(|book| {
+                total = total.checked_add(&book.price).unwrap();
+                models::OrderLineDomain {
+                    book_id: book.id,
+                    quantity: 1,
+                    unit_price: book.price,
+                    discount_code: None,
+                    discount_percentage: 0,
+                    discount_amount: eur("0"),
+                    line_total: book.price,
+                    tax_rate: Decimal::ZERO,
+                    tax_amount: eur("0"),
+                }
+            })
+            .collect();
+        let pricing = models::OrderPricingDomain {
+            lines,
+            subtotal: total,
+            discount_total: eur("0"),
+            total,
+            exchange_rate: None,
+            prices_include_tax: false,
+            net_total: total,
+            tax_total: eur("0"),
+            tax_lines: vec![],
+        };
+        let catalog = books.iter().map(|book| (book.id, book.clone())).collect();
+        (pricing, catalog)
+    }
+
+    fn german_rates(genre: Ksuid) -> Vec<models::TaxRateDomain> {
+        vec![
+            rate("DE", None, None, None, "19"),
+            rate("DE", None, None, Some(models::ProductType::Print), "7"),
+            rate("DE", None, Some(genre), None, "5"),
+            rate("US", None, None, None, "0"),
+            rate("US", Some("CA"), None, None, "7.25"),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_tax_order_adds_tax_to_net_prices() {
+        // Arrange
+        let genre = models::GenereDomain {
+            id: Ksuid::new(None, None),
+            name: String::from("Poetry"),
+        };
+        let books = [
+            book(models::ProductType::Print, vec![]),
+            book(models::ProductType::Ebook, vec![]),
+            book(models::ProductType::Ebook, vec![genre.clone()]),
+        ];
+        let (mut pricing, catalog) = priced(&books);
+        let service = TaxService::new(false, Arc::new(StaticRates(german_rates(genre.id))));
+
+        // Act
+        service
+            .tax_order(&mut pricing, &catalog, &address(" de ", None))
+            .await
+            .unwrap();
+
+        // Assert
+        let rates: Vec<String> = pricing
+            .lines
+            .iter()
+            .map(|line| line.tax_rate.to_string())
+            .collect();
+        assert_eq!(rates, vec!["7", "19", "5"]);
+        assert_eq!(pricing.lines[1].tax_amount, eur("1.90"));
+        assert_eq!(pricing.tax_lines.len(), 3);
+        assert_eq!(pricing.tax_lines[0].rate, Decimal::from(5));
+        assert_eq!(pricing.tax_lines[0].net, eur("10.00"));
+        assert_eq!(pricing.tax_lines[0].tax, eur("0.50"));
+        assert_eq!(pricing.net_total, eur("30.00"));
+        assert_eq!(pricing.tax_total, eur("3.10"));
+        assert_eq!(pricing.total, eur("33.10"));
+    }
+
+    #[tokio::test]
+    async fn test_tax_order_extracts_tax_from_gross_prices() {
+        // Arrange
+        let books = [
+            book(models::ProductType::Print, vec![]),
+            book(models::ProductType::Print, vec![]),
+        ];
+        let (mut pricing, catalog) = priced(&books);
+        let rates = german_rates(Ksuid::new(None, None));
+        let service = TaxService::new(true, Arc::new(StaticRates(rates)));
+
+        // Act
+        service
+            .tax_order(&mut pricing, &catalog, &address("DE", None))
+            .await
+            .unwrap();
+
+        // Assert
+        // 10.00 / 1.07 = 9.3458 net, the tax is the rounded difference of 0.65 per line
+        assert_eq!(pricing.lines[0].tax_amount, eur("0.65"));
+        assert_eq!(pricing.tax_lines.len(), 1);
+        assert_eq!(pricing.tax_lines[0].net, eur("18.70"));
+        assert_eq!(pricing.tax_total, eur("1.30"));
+        assert_eq!(pricing.net_total, eur("18.70"));
+        assert_eq!(pricing.total, eur("20.00"));
+    }
+
+    #[tokio::test]
+    async fn test_tax_order_prefers_the_province_rate() {
+        // Arrange
+        let books = [book(models::ProductType::Print, vec![])];
+        let rates = german_rates(Ksuid::new(None, None));
+        let service = TaxService::new(false, Arc::new(StaticRates(rates)));
+        let (mut california, catalog) = priced(&books);
+        let (mut oregon, _) = priced(&books);
+
+        // Act
+        service
+            .tax_order(&mut california, &catalog, &address("US", Some("ca")))
+            .await
+            .unwrap();
+        service
+            .tax_order(&mut oregon, &catalog, &address("US", Some("OR")))
+            .await
+            .unwrap();
+
+        // Assert
+        // 7.25% of 10.00 are 0.725, the half cent is rounded to the even 0.72
+        assert_eq!(
+            california.lines[0].tax_rate,
+            Decimal::from_str("7.25").unwrap()
+        );
+        assert_eq!(california.total, eur("10.72"));
+        assert_eq!(oregon.lines[0].tax_rate, Decimal::ZERO);
+        assert_eq!(oregon.total, eur("10.00"));
+    }
+
+    #[tokio::test]
+    async fn test_tax_order_rejects_unknown_countries() {
+        // Arrange
+        let books = [book(models::ProductType::Print, vec![])];
+        let (mut pricing, catalog) = priced(&books);
+        let rates = german_rates(Ksuid::new(None, None));
+        let service = TaxService::new(false, Arc::new(StaticRates(rates)));
+
+        // Act
+        let result = service
+            .tax_order(&mut pricing, &catalog, &address("FR", None))
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(error::DomainError::BusinessConstraintViolation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tax_order_without_rates_is_untaxed() {
+        // Arrange
+        let books = [book(models::ProductType::Print, vec![])];
+        let (mut pricing, catalog) = priced(&books);
+        let service = TaxService::new(true, Arc::new(StaticRates(vec![])));
+
+        // Act
+        service
+            .tax_order(&mut pricing, &catalog, &address("Country", None))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(pricing.prices_include_tax);
+        assert_eq!(pricing.tax_lines.len(), 1);
+        assert_eq!(pricing.tax_lines[0].rate, Decimal::ZERO);
+        assert_eq!(pricing.tax_total, eur("0"));
+        assert_eq!(pricing.net_total, eur("10.00"));
+        assert_eq!(pricing.total, eur("10.00"));
+    }
+}
@@ -0,0 +1,349 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::task::JoinSet;
+use tokio::time::MissedTickBehavior;
+use tracing::{info, warn};
+
+use super::{error, models, store};
+
+/// How often a delivery is attempted and how long to wait between the attempts
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// The delay after the given number of failed attempts, doubled by every attempt up to max_backoff
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// The WebhookDispatcher posts the due deliveries to the endpoints of their webhooks.
+/// A failed attempt is retried with exponential backoff, a delivery which exhausted its attempts
+/// is failed and stays in the delivery log as dead letter.
+pub struct WebhookDispatcher {
+    repository: Arc<dyn store::WebhookDeliveryHandler + Send + Sync>,
+    sender: Arc<dyn store::WebhookSender + Send + Sync>,
+    policy: RetryPolicy,
+    lease: Duration,
+    batch_size: u32,
+}
+
+impl WebhookDispatcher {
+    pub fn new(
+        repository: Arc<dyn store::WebhookDeliveryHandler + Send + Sync>,
+        sender: Arc<dyn store::WebhookSender + Send + Sync>,
+        policy: RetryPolicy,
+        lease: Duration,
+        batch_size: u32,
+    ) -> Arc<Self> {
+        Arc::new(WebhookDispatcher {
+            repository,
+            sender,
+            policy,
+            lease,
+            batch_size,
+        })
+    }
+
+    /// Attempts the due deliveries until none is due anymore, the deliveries of a batch are sent
+    /// concurrently. Returns the number of attempts.
+    pub async fn dispatch(&self) -> Result<usize, error::DomainError> {
+        let lease = chrono::Duration::from_std(self.lease).unwrap_or(chrono::Duration::MAX);
+        let mut attempted = 0;
+        loop {
+            let claimed = self
+                .repository
+                .claim_due_deliveries(self.batch_size, lease)
+                .await?;
+            let claimed_count = claimed.len();
+            let mut attempts = JoinSet::new();
+            for claimed in claimed {
+                let sender = self.sender.clone();
+                let policy = self.policy.clone();
+                attempts.spawn(async move { attempt(sender.as_ref(), &policy, claimed).await });
+            }
+            while let Some(delivery) = attempts.join_next().await {
+                let delivery = delivery.map_err(|e| error::DomainError::PublishFailure {
+                    message: String::from("failed to attempt delivery"),
+                    source: Box::new(e),
+                })?;
+                self.repository.update_delivery(&delivery).await?;
+                attempted += 1;
+            }
+            if claimed_count < self.batch_size as usize {
+                return Ok(attempted);
+            }
+        }
+    }
+
+    /// Dispatches the due deliveries every interval, until the task running it is aborted
+    pub async fn run(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.dispatch().await {
+                warn!(
+                    "failed to dispatch the webhook deliveries, retrying in {:?}: {}",
+                    interval, e
+                );
+            }
+        }
+    }
+}
+
+/// Posts the delivery once, returns the delivery with the outcome of the attempt
+async fn attempt(
+    sender: &(dyn store::WebhookSender + Send + Sync),
+    policy: &RetryPolicy,
+    claimed: models::ClaimedDeliveryDomain,
+) -> models::WebhookDeliveryDomain {
+    let models::ClaimedDeliveryDomain {
+        webhook,
+        mut delivery,
+    } = claimed;
+    let result = sender.send(&webhook, &delivery).await;
+    let now = Utc::now();
+    delivery.attempts += 1;
+    let failure = match result {
+        Ok(status) if (200..300).contains(&status) => {
+            delivery.status = models::DeliveryStatus::Delivered;
+            delivery.last_status_code = Some(status);
+            delivery.last_error = None;
+            delivery.next_attempt_at = None;
+            delivery.delivered_at = Some(now);
+            return delivery;
+        }
+        Ok(status) => {
+            delivery.last_status_code = Some(status);
+            format!("the endpoint answered {}", status)
+        }
+        Err(e) => {
+            delivery.last_status_code = None;
+            e.to_string()
+        }
+    };
+    delivery.last_error = Some(failure);
+    if delivery.attempts as u32 >= policy.max_attempts {
+        info!(
+            delivery_id = %delivery.id,
+            webhook_id = %webhook.id,
+            "webhook delivery failed after {} attempts", delivery.attempts
+        );
+        delivery.status = models::DeliveryStatus::Failed;
+        delivery.next_attempt_at = None;
+    } else {
+        let backoff = policy.backoff(delivery.attempts as u32);
+        delivery.next_attempt_at =
+            Some(now + chrono::Duration::from_std(backoff).unwrap_or(chrono::Duration::MAX));
+    }
+    delivery
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+    use svix_ksuid::{Ksuid, KsuidLike};
+
+    /// A store of a single delivery, which is due while it is pending
+    struct SingleDelivery {
+        webhook: models::WebhookDomain,
+        delivery: Mutex<models::WebhookDeliveryDomain>,
+    }
+
+    impl SingleDelivery {
+        fn new() -> Arc<Self> {
+            let webhook = models::WebhookDomain {
+                id: Ksuid::new(None, None),
+                url: String::from("http://localhost/hooks"),
+                event_types: vec![String::from("OrderPlaced")],
+                secret: String::from("0123456789abcdef"),
+            };
+            let delivery = models::WebhookDeliveryDomain {
+                id: Ksuid::new(None, None),
+                webhook_id: webhook.id,
+                event_id: 1,
+                event_type: String::from("OrderPlaced"),
+                payload: String::from("{}"),
+                status: models::DeliveryStatus::Pending,
+                attempts: 0,
+                last_status_code: None,
+                last_error: None,
+                next_attempt_at: Some(Utc::now()),
+                created_at: Utc::now(),
+                delivered_at: None,
+            };
+            Arc::new(SingleDelivery {
+                webhook,
+                delivery: Mutex::new(delivery),
+            })
+        }
+
+        fn delivery(&self) -> models::WebhookDeliveryDomain {
+            self.delivery.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl store::WebhookDeliveryHandler for SingleDelivery {
+        async fn enqueue_deliveries(
+            &self,
+            _event_id: i64,
+            _event_type: &str,
+            _payload: &str,
+        ) -> Result<usize, error::DomainError> {
+            Ok(0)
+        }
+
+        async fn claim_due_deliveries(
+            &self,
+            _limit: u32,
+            _lease: chrono::Duration,
+        ) -> Result<Vec<models::ClaimedDeliveryDomain>, error::DomainError> {
+            let delivery = self.delivery();
+            if delivery.status != models::DeliveryStatus::Pending {
+                return Ok(vec![]);
+            }
+            Ok(vec![models::ClaimedDeliveryDomain {
+                webhook: self.webhook.clone(),
+                delivery,
+            }])
+        }
+
+        async fn update_delivery(
+            &self,
+            delivery: &models::WebhookDeliveryDomain,
+        ) -> Result<(), error::DomainError> {
+            *self.delivery.lock().unwrap() = delivery.clone();
+            Ok(())
+        }
+    }
+
+    /// A sender answering with the given statuses in turn, None for an unreachable endpoint
+    struct ScriptedSender {
+        statuses: Mutex<Vec<Option<u16>>>,
+    }
+
+    impl ScriptedSender {
+        fn new(statuses: Vec<Option<u16>>) -> Arc<Self> {
+            Arc::new(ScriptedSender {
+                statuses: Mutex::new(statuses),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl store::WebhookSender for ScriptedSender {
+        async fn send(
+            &self,
+            _webhook: &models::WebhookDomain,
+            _delivery: &models::WebhookDeliveryDomain,
+        ) -> Result<u16, error::DomainError> {
+            match self.statuses.lock().unwrap().remove(0) {
+                Some(status) => Ok(status),
+                None => Err(error::DomainError::PublishFailure {
+                    message: String::from("connection refused"),
+                    source: Box::new(std::io::Error::other("connection refused")),
+                }),
+            }
+        }
+    }
+
+    fn dispatcher(
+        repository: Arc<SingleDelivery>,
+        sender: Arc<ScriptedSender>,
+    ) -> Arc<WebhookDispatcher> {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(10),
+            max_backoff: Duration::from_secs(60),
+        };
+        WebhookDispatcher::new(repository, sender, policy, Duration::from_secs(30), 10)
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_maximum() {
+        // Arrange
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_secs(10),
+            max_backoff: Duration::from_secs(60),
+        };
+
+        // Act
+        let backoffs: Vec<u64> = (1..=5).map(|a| policy.backoff(a).as_secs()).collect();
+
+        // Assert
+        assert_eq!(backoffs, vec![10, 20, 40, 60, 60]);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_retries_until_delivered() {
+        // Arrange
+        let repository = SingleDelivery::new();
+        let sender = ScriptedSender::new(vec![None, Some(503), Some(204)]);
+        let dispatcher = dispatcher(repository.clone(), sender);
+
+        // Act
+        dispatcher.dispatch().await.unwrap();
+        let unreachable = repository.delivery();
+        dispatcher.dispatch().await.unwrap();
+        let rejected = repository.delivery();
+        dispatcher.dispatch().await.unwrap();
+
+        // Assert
+        assert_eq!(unreachable.attempts, 1);
+        assert_eq!(unreachable.last_status_code, None);
+        assert_eq!(
+            unreachable.last_error.as_deref(),
+            Some("Failed to publish event: connection refused")
+        );
+        assert!(unreachable.next_attempt_at.unwrap() > Utc::now() + chrono::Duration::seconds(9));
+        assert_eq!(rejected.last_status_code, Some(503));
+        // the backoff doubled after the second failed attempt
+        assert!(rejected.next_attempt_at.unwrap() > Utc::now() + chrono::Duration::seconds(19));
+        let delivered = repository.delivery();
+        assert_eq!(delivered.status, models::DeliveryStatus::Delivered);
+        assert_eq!(delivered.attempts, 3);
+        assert_eq!(delivered.last_status_code, Some(204));
+        assert_eq!(delivered.last_error, None);
+        assert!(delivered.delivered_at.is_some());
+        assert!(delivered.next_attempt_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_dead_letters_after_max_attempts() {
+        // Arrange
+        let repository = SingleDelivery::new();
+        let sender = ScriptedSender::new(vec![Some(500), Some(500), Some(500)]);
+        let dispatcher = dispatcher(repository.clone(), sender);
+
+        // Act
+        for _ in 0..3 {
+            dispatcher.dispatch().await.unwrap();
+        }
+        let after_dead_letter = dispatcher.dispatch().await.unwrap();
+
+        // Assert
+        assert_eq!(after_dead_letter, 0);
+        let failed = repository.delivery();
+        assert_eq!(failed.status, models::DeliveryStatus::Failed);
+        assert_eq!(failed.attempts, 3);
+        assert_eq!(
+            failed.last_error.as_deref(),
+            Some("the endpoint answered 500")
+        );
+        assert!(failed.next_attempt_at.is_none());
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use super::error;
+use super::models;
+use super::store;
+use async_trait::async_trait;
+use svix_ksuid::Ksuid;
+use tracing::instrument;
+
+/// The WebhookService is the domain entry point for the webhooks and their delivery log.
+/// Persistence is delegated to the configured WebhookHandler repository,
+/// the events are delivered by the WebhookDispatcher.
+pub struct WebhookService {
+    repository: Arc<dyn store::WebhookHandler + Send + Sync>,
+}
+
+impl WebhookService {
+    pub fn new(repository: Arc<dyn store::WebhookHandler + Send + Sync>) -> Arc<Self> {
+        Arc::new(WebhookService { repository })
+    }
+}
+
+#[async_trait]
+impl store::WebhookHandler for WebhookService {
+    /// Create a new webhook
+    #[instrument(name = "WebhookService::create_webhook", skip_all)]
+    async fn create_webhook(
+        &self,
+        webhook: models::WebhookDomain,
+    ) -> Result<models::WebhookDomain, error::DomainError> {
+        self.repository.create_webhook(webhook).await
+    }
+
+    /// Delete an existing webhook, its pending deliveries are not sent
+    #[instrument(name = "WebhookService::delete_webhook_by_id", skip_all)]
+    async fn delete_webhook_by_id(&self, id: Ksuid) -> Result<(), error::DomainError> {
+        self.repository.delete_webhook_by_id(id).await
+    }
+
+    /// Get an existing webhook by id
+    #[instrument(name = "WebhookService::get_webhook_by_id", skip_all)]
+    async fn get_webhook_by_id(
+        &self,
+        id: Ksuid,
+    ) -> Result<models::WebhookDomain, error::DomainError> {
+        self.repository.get_webhook_by_id(id).await
+    }
+
+    /// Get all webhooks ordered by id
+    #[instrument(name = "WebhookService::get_webhooks", skip_all)]
+    async fn get_webhooks(&self) -> Result<Vec<models::WebhookDomain>, error::DomainError> {
+        self.repository.get_webhooks().await
+    }
+
+    /// Update an existing webhook
+    #[instrument(name = "WebhookService::update_webhook", skip_all)]
+    async fn update_webhook(
+        &self,
+        props: models::WebhookUpdateProps,
+    ) -> Result<models::WebhookDomain, error::DomainError> {
+        self.repository.update_webhook(props).await
+    }
+
+    /// Get a page of the deliveries of an existing webhook ordered by id, all if status is None
+    #[instrument(name = "WebhookService::get_webhook_deliveries", skip_all)]
+    async fn get_webhook_deliveries(
+        &self,
+        webhook_id: Ksuid,
+        status: Option<models::DeliveryStatus>,
+        page: models::PageRequest,
+    ) -> Result<models::PageDomain<models::WebhookDeliveryDomain>, error::DomainError> {
+        self.repository
+            .get_webhook_deliveries(webhook_id, status, page)
+            .await
+    }
+}
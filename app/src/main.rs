@@ -1,24 +1,472 @@
 mod adapters;
+mod cli;
+mod config;
 mod domain;
 
+use adapters::events::file_sink::FileEventSink;
+use adapters::events::http_webhook_sender::HttpWebhookSender;
+use adapters::events::webhook_sink::WebhookEventSink;
+use adapters::metrics::prometheus_metrics::PrometheusMetrics;
+use adapters::persistence::api_key_repository::ApiKeyRepository;
+use adapters::persistence::book_repository::BookRepository;
+use adapters::persistence::config_api_key_repository::ConfigApiKeyRepository;
+use adapters::persistence::config_tax_rate_repository::ConfigTaxRateRepository;
+use adapters::persistence::customer_repository::CustomerRepository;
+use adapters::persistence::exchange_rate_repository::ExchangeRateRepository;
+use adapters::persistence::file_exchange_rate_repository::FileExchangeRateRepository;
+use adapters::persistence::health_repository::HealthRepository;
+use adapters::persistence::idempotency_repository::IdempotencyRepository;
+use adapters::persistence::in_memory_repository::InMemoryRepository;
+use adapters::persistence::migrations::{self, SchemaStatus};
+use adapters::persistence::order_repository::OrderRepository;
+use adapters::persistence::outbox_repository::OutboxRepository;
+use adapters::persistence::webhook_repository::WebhookRepository;
+use adapters::rest::metrics::start_admin_server;
 use adapters::rest::server;
-use chrono::Local;
-use openapi::models;
-use svix_ksuid::{Ksuid, KsuidLike};
+use adapters::rest::tls::{self, CertificateResolver, ClientVerification};
+use clap::Parser;
+use cli::{Cli, StartupMode};
+use config::{
+    ClientAuth, Config, DatabaseConfig, EventSinkKind, EventsConfig, ExchangeRateSource, KeyStore,
+    LogConfig, LogFormat, StoreBackend, TlsConfig, TracingConfig,
+};
+use domain::auth_service::{generate_api_key, hash_api_key};
+use domain::event_dispatcher::EventDispatcher;
+use domain::exchange_service::ExchangeService;
+use domain::idempotency_service::IdempotencyService;
+use domain::store::{
+    ApiKeyHandler, BookHandler, CustomerHandler, EventSink, ExchangeRateHandler, HealthHandler,
+    IdempotencyHandler, OrderHandler, OutboxHandler, WebhookDeliveryHandler, WebhookHandler,
+};
+use domain::tax_service::TaxService;
+use domain::webhook_dispatcher::WebhookDispatcher;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+use sqlx::PgPool;
+use sqlx::postgres::PgPoolOptions;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio_rustls::TlsAcceptor;
+use tracing::{error, info, warn};
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// The repositories of the store backend the service runs on.
+struct Repositories {
+    book_repository: Arc<dyn BookHandler + Send + Sync>,
+    order_repository: Arc<dyn OrderHandler + Send + Sync>,
+    customer_repository: Arc<dyn CustomerHandler + Send + Sync>,
+    health_repository: Arc<dyn HealthHandler + Send + Sync>,
+    idempotency_repository: Arc<dyn IdempotencyHandler + Send + Sync>,
+    outbox_repository: Arc<dyn OutboxHandler + Send + Sync>,
+    webhook_repository: Arc<dyn WebhookHandler + Send + Sync>,
+    webhook_delivery_repository: Arc<dyn WebhookDeliveryHandler + Send + Sync>,
+    pool: Option<PgPool>,
+}
 
 #[tokio::main()]
 async fn main() {
-    let author_model = models::Author::new(
-        Ksuid::new(None, None).to_string(),
-        String::from("Johann"),
-        String::from("Goethe"),
-        Local::now().date_naive(),
+    let cli = Cli::parse();
+    if cli.generate_api_key {
+        let api_key = generate_api_key();
+        println!("api key:  {}", api_key);
+        println!("key_hash: {}", hash_api_key(&api_key));
+        return;
+    }
+    let config = match Config::load(&cli) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+    let file_rates = match config.exchange_rates.load_file_rates() {
+        Ok(rates) => rates,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
+    // initialize tracing
+    let tracer_provider = init_tracing(&config.log, &config.tracing);
+    info!("effective configuration:\n{}", config);
+
+    let mode = cli.startup_mode();
+    let Repositories {
+        book_repository,
+        order_repository,
+        customer_repository,
+        health_repository,
+        idempotency_repository,
+        outbox_repository,
+        webhook_repository,
+        webhook_delivery_repository,
+        pool,
+    } = match config.store {
+        StoreBackend::Memory => {
+            if mode != StartupMode::Serve {
+                error!("migrations require the postgres store");
+                std::process::exit(1);
+            }
+            warn!("using the in-memory store, all data is lost on shutdown");
+            let repository = InMemoryRepository::new();
+            Repositories {
+                book_repository: repository.clone(),
+                order_repository: repository.clone(),
+                customer_repository: repository.clone(),
+                health_repository: repository.clone(),
+                idempotency_repository: repository.clone(),
+                outbox_repository: repository.clone(),
+                webhook_repository: repository.clone(),
+                webhook_delivery_repository: repository,
+                pool: None,
+            }
+        }
+        StoreBackend::Postgres => match setup_postgres(&config, &mode, &tracer_provider).await {
+            Some(pool) => Repositories {
+                book_repository: BookRepository::new(pool.clone()),
+                order_repository: OrderRepository::new(pool.clone()),
+                customer_repository: CustomerRepository::new(pool.clone()),
+                health_repository: HealthRepository::new(pool.clone()),
+                idempotency_repository: IdempotencyRepository::new(pool.clone()),
+                outbox_repository: OutboxRepository::new(pool.clone()),
+                webhook_repository: WebhookRepository::new(pool.clone()),
+                webhook_delivery_repository: WebhookRepository::new(pool.clone()),
+                pool: Some(pool),
+            },
+            None => return,
+        },
+    };
+
+    let exchange_rate_repository: Arc<dyn ExchangeRateHandler + Send + Sync> =
+        match (config.exchange_rates.source, &pool) {
+            (ExchangeRateSource::Database, Some(pool)) => ExchangeRateRepository::new(pool.clone()),
+            _ => {
+                if file_rates.is_empty() {
+                    info!("no exchange rates are configured, prices are not converted");
+                }
+                FileExchangeRateRepository::new(file_rates)
+            }
+        };
+
+    let exchange_service = ExchangeService::new(
+        config.exchange_rates.base_currency(),
+        exchange_rate_repository,
     );
-    println!(
-        "{} {} was born on {}, right?",
-        author_model.first_name, author_model.last_name, author_model.date_of_birth
+
+    if config.tax.rates.is_empty() {
+        info!("no tax rates are configured, orders are not taxed");
+    }
+    let tax_service = TaxService::new(
+        config.tax.prices_include_tax,
+        ConfigTaxRateRepository::new(config.tax.to_domain()),
     );
 
-    println!("Starting web server");
-    server::start_server("0.0.0.0:8443").await;
+    // the metrics are recorded even if the admin server is disabled
+    let metrics = PrometheusMetrics::new(pool.clone());
+    let admin_task = if config.metrics.enabled {
+        let listener = match TcpListener::bind(&config.metrics.bind_address).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!(address = %config.metrics.bind_address, "failed to bind the admin server: {}", e);
+                std::process::exit(1);
+            }
+        };
+        Some(tokio::spawn(start_admin_server(listener, metrics.clone())))
+    } else {
+        info!("metrics are disabled, the admin server is not started");
+        None
+    };
+
+    let api_key_repository: Arc<dyn ApiKeyHandler + Send + Sync> =
+        match (config.auth.keystore, pool) {
+            (KeyStore::Database, Some(pool)) => ApiKeyRepository::new(pool),
+            _ => {
+                if config.api_keys.is_empty() {
+                    warn!("no api keys are configured, all requests will be rejected");
+                }
+                ConfigApiKeyRepository::new(config.api_keys.iter().map(|k| k.to_domain()).collect())
+            }
+        };
+
+    let mut event_sinks = vec![];
+    if let Some(sink) = event_sink(&config.events).await {
+        event_sinks.push(sink);
+    }
+    let webhook_dispatcher = if config.webhooks.enabled {
+        let sender =
+            match HttpWebhookSender::new(Duration::from_secs(config.webhooks.request_timeout_secs))
+            {
+                Ok(sender) => sender,
+                Err(e) => {
+                    error!("failed to build the webhook client: {}", e);
+                    std::process::exit(1);
+                }
+            };
+        event_sinks.push(WebhookEventSink::new(webhook_delivery_repository.clone()));
+        Some(WebhookDispatcher::new(
+            webhook_delivery_repository,
+            sender,
+            config.webhooks.retry_policy(),
+            config.webhooks.lease(),
+            config.webhooks.batch_size,
+        ))
+    } else {
+        info!("webhooks are disabled, the webhooks receive no events");
+        None
+    };
+    let event_dispatcher = if event_sinks.is_empty() {
+        info!("no event sink is configured, the events stay in the outbox");
+        None
+    } else {
+        Some(EventDispatcher::new(
+            outbox_repository,
+            event_sinks,
+            config.events.batch_size,
+        ))
+    };
+    let dispatcher_task = event_dispatcher.clone().map(|dispatcher| {
+        let interval = Duration::from_millis(config.events.poll_interval_ms);
+        tokio::spawn(async move { dispatcher.run(interval).await })
+    });
+    let webhook_task = webhook_dispatcher.map(|dispatcher| {
+        let interval = Duration::from_millis(config.webhooks.poll_interval_ms);
+        tokio::spawn(async move { dispatcher.run(interval).await })
+    });
+
+    info!(address = %config.server.bind_address, "starting the web server");
+    let server = server::BookStoreServer::new(
+        book_repository,
+        order_repository,
+        customer_repository,
+        api_key_repository,
+        health_repository,
+        exchange_service,
+        tax_service,
+        webhook_repository,
+        metrics.clone(),
+    );
+    let idempotency_service = IdempotencyService::new(
+        idempotency_repository,
+        config.idempotency.ttl(),
+        config.idempotency.lease(),
+    );
+    let (tls_acceptor, reload_task) = match tls_acceptor(&config.tls) {
+        Some((acceptor, reload_task)) => (Some(acceptor), reload_task),
+        None => (None, None),
+    };
+    let served = server::start_server(
+        &config.server.bind_address,
+        Duration::from_secs(config.server.shutdown_drain_secs),
+        server,
+        idempotency_service,
+        metrics,
+        tls_acceptor,
+    )
+    .await;
+    if let Err(e) = &served {
+        error!(address = %config.server.bind_address, "failed to serve: {}", e);
+    }
+
+    if let Some(task) = reload_task {
+        task.abort();
+        let _ = task.await;
+    }
+    if let Some(task) = admin_task {
+        task.abort();
+        let _ = task.await;
+    }
+    // the pending deliveries are attempted by the next start
+    if let Some(task) = webhook_task {
+        task.abort();
+        let _ = task.await;
+    }
+    // publish the events of the last requests before exiting
+    if let Some(task) = dispatcher_task {
+        task.abort();
+        let _ = task.await;
+    }
+    if let Some(dispatcher) = event_dispatcher {
+        match dispatcher.dispatch().await {
+            Ok(published) => info!(published, "published the remaining events"),
+            Err(e) => warn!("failed to publish the remaining events: {}", e),
+        }
+    }
+    if let Err(e) = tracer_provider.shutdown() {
+        eprintln!("Failed to export the remaining spans: {}", e);
+    }
+    if served.is_err() {
+        std::process::exit(1);
+    }
+}
+
+/// Creates the configured sink of the events, None if the events are not published
+async fn event_sink(events: &EventsConfig) -> Option<Arc<dyn EventSink + Send + Sync>> {
+    match (events.sink, &events.file) {
+        (EventSinkKind::Stdout, _) => Some(FileEventSink::stdout()),
+        (EventSinkKind::File, Some(path)) => match FileEventSink::open(path).await {
+            Ok(sink) => Some(sink),
+            Err(e) => {
+                error!(path = %path.display(), "failed to open the events file: {}", e);
+                std::process::exit(1);
+            }
+        },
+        // the config validation guarantees the file of the file sink
+        _ => None,
+    }
+}
+
+/// Builds the acceptor of the TLS connections, None if TLS is disabled.
+/// The certificate files are watched by the returned task, a self-signed certificate is not.
+fn tls_acceptor(config: &TlsConfig) -> Option<(TlsAcceptor, Option<JoinHandle<()>>)> {
+    if !config.enabled {
+        return None;
+    }
+    // the config validation guarantees the files unless the certificate is self-signed
+    let resolver = match (&config.cert, &config.key) {
+        (Some(cert), Some(key)) if !config.self_signed => {
+            CertificateResolver::from_files(cert, key)
+        }
+        _ => {
+            warn!("serving a self-signed certificate, for development only");
+            CertificateResolver::self_signed()
+        }
+    };
+    let resolver = match resolver {
+        Ok(resolver) => resolver,
+        Err(e) => {
+            error!("failed to load the TLS certificate: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let clients = match (config.client_auth, &config.client_ca) {
+        (ClientAuth::Optional, Some(ca)) => ClientVerification::Optional { ca: ca.clone() },
+        (ClientAuth::Required, Some(ca)) => ClientVerification::Required { ca: ca.clone() },
+        // the config validation guarantees the CA to verify the clients
+        _ => ClientVerification::None,
+    };
+    let acceptor = match tls::acceptor(resolver.clone(), &clients) {
+        Ok(acceptor) => acceptor,
+        Err(e) => {
+            error!("failed to configure TLS: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let reload_task =
+        (!config.self_signed).then(|| tokio::spawn(resolver.watch(config.reload_interval())));
+    Some((acceptor, reload_task))
+}
+
+/// Installs the logs and the OpenTelemetry layer, the spans are exported if tracing is enabled.
+/// Without export the spans still get trace ids, so the log lines of a request can be correlated.
+/// The returned provider flushes the pending spans on shutdown.
+fn init_tracing(log: &LogConfig, tracing: &TracingConfig) -> SdkTracerProvider {
+    let mut provider = SdkTracerProvider::builder()
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            tracing.sample_ratio,
+        ))))
+        .with_resource(
+            Resource::builder()
+                .with_service_name(tracing.service_name.clone())
+                .build(),
+        );
+    if tracing.enabled {
+        match SpanExporter::builder()
+            .with_http()
+            .with_endpoint(&tracing.endpoint)
+            .build()
+        {
+            Ok(exporter) => provider = provider.with_batch_exporter(exporter),
+            Err(e) => {
+                eprintln!("Failed to build the span exporter: {}", e);
+                std::process::exit(2);
+            }
+        }
+    }
+    let provider = provider.build();
+
+    let subscriber = tracing_subscriber::registry()
+        .with(EnvFilter::new(&log.filter))
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("orders")));
+    match log.format {
+        LogFormat::Text => subscriber.with(tracing_subscriber::fmt::layer()).init(),
+        LogFormat::Json => subscriber
+            .with(tracing_subscriber::fmt::layer().json())
+            .init(),
+    }
+    provider
+}
+
+async fn connect_postgres(database: &DatabaseConfig) -> Result<PgPool, sqlx::Error> {
+    // the config validation guarantees the URL for the postgres store
+    let url = database.url.as_deref().unwrap_or_default();
+    PgPoolOptions::new()
+        .max_connections(database.max_connections)
+        .min_connections(database.min_connections)
+        .acquire_timeout(Duration::from_secs(database.acquire_timeout_secs))
+        .connect(url)
+        .await
+}
+
+/// Exits after a failed startup, the spans of the startup are exported before
+fn exit_on_startup_failure(tracer_provider: &SdkTracerProvider) -> ! {
+    if let Err(e) = tracer_provider.shutdown() {
+        eprintln!("Failed to export the remaining spans: {}", e);
+    }
+    std::process::exit(1);
+}
+
+/// Connects to the database and migrates or verifies the schema, exits if that fails.
+/// Returns None if the startup mode is done with the database and the service must not start.
+async fn setup_postgres(
+    config: &Config,
+    mode: &StartupMode,
+    tracer_provider: &SdkTracerProvider,
+) -> Option<PgPool> {
+    let pool = match connect_postgres(&config.database).await {
+        Ok(pool) => pool,
+        Err(e) => {
+            error!(error = %e, "failed to connect to the database");
+            exit_on_startup_failure(tracer_provider);
+        }
+    };
+
+    let migrate = match mode {
+        StartupMode::Serve => config.features.migrate_on_startup,
+        StartupMode::MigrateOnly => true,
+        StartupMode::VerifyOnly => false,
+    };
+
+    // refuse to start on a schema this binary cannot handle
+    if migrate {
+        if let Err(e) = migrations::run_migrations(&pool).await {
+            error!(error = %e, "database migration failed");
+            exit_on_startup_failure(tracer_provider);
+        }
+    } else {
+        match migrations::schema_status(&pool).await {
+            Ok(SchemaStatus::UpToDate) => {
+                info!("database schema is up to date");
+            }
+            Ok(SchemaStatus::Pending { versions }) => {
+                error!(?versions, "database schema has pending migrations");
+                exit_on_startup_failure(tracer_provider);
+            }
+            Err(e) => {
+                error!(error = %e, "database schema verification failed");
+                exit_on_startup_failure(tracer_provider);
+            }
+        }
+    }
+
+    match mode {
+        StartupMode::Serve => Some(pool),
+        _ => None,
+    }
 }
@@ -1,5 +1,6 @@
 -- https://docs.rs/sqlx-pg-migrate/latest/sqlx_pg_migrate/
 -- watching the migrations dir https://docs.rs/sqlx/latest/sqlx/macro.migrate.html
+-- all statements are idempotent, so the migration adopts schemas which were applied manually with psql
 
 -- Create tables for the Bookstore API
 
@@ -104,7 +105,7 @@ BEGIN
 END;
 $$ LANGUAGE plpgsql;
 
-CREATE TRIGGER check_book_deletion_trigger
+CREATE OR REPLACE TRIGGER check_book_deletion_trigger
 BEFORE DELETE ON books
 FOR EACH ROW
 EXECUTE FUNCTION check_book_deletion();
@@ -119,7 +120,7 @@ BEGIN
 END;
 $$ LANGUAGE plpgsql;
 
-CREATE TRIGGER check_author_deletion_trigger
+CREATE OR REPLACE TRIGGER check_author_deletion_trigger
 BEFORE DELETE ON authors
 FOR EACH ROW
 EXECUTE FUNCTION check_author_deletion();
@@ -134,7 +135,7 @@ BEGIN
 END;
 $$ LANGUAGE plpgsql;
 
-CREATE TRIGGER check_genre_deletion_trigger
+CREATE OR REPLACE TRIGGER check_genre_deletion_trigger
 BEFORE DELETE ON genres
 FOR EACH ROW
 EXECUTE FUNCTION check_genre_deletion();
@@ -149,7 +150,7 @@ BEGIN
 END;
 $$ LANGUAGE plpgsql;
 
-CREATE TRIGGER prevent_address_delete_trigger
+CREATE OR REPLACE TRIGGER prevent_address_delete_trigger
 BEFORE DELETE ON addresses
 FOR EACH ROW
 EXECUTE FUNCTION prevent_address_delete();
@@ -168,7 +169,7 @@ BEGIN
 END;
 $$ LANGUAGE plpgsql;
 
-CREATE TRIGGER update_book_status_trigger
+CREATE OR REPLACE TRIGGER update_book_status_trigger
 BEFORE INSERT OR UPDATE OF available ON books
 FOR EACH ROW
 EXECUTE FUNCTION update_book_status();
@@ -0,0 +1,16 @@
+-- API keys for the X-API-KEY header, only the SHA-256 hash of a key is stored
+-- keys are rotated by inserting the new key and setting valid_until of the old key,
+-- both keys are accepted until the old key expires
+
+CREATE TABLE IF NOT EXISTS api_keys (
+    key_hash TEXT PRIMARY KEY CHECK (key_hash ~ '^sha256:[0-9a-f]{64}$'),
+    name TEXT NOT NULL,
+    roles TEXT[] NOT NULL CHECK (
+        cardinality(roles) > 0
+        AND roles <@ ARRAY['catalog-read', 'catalog-admin', 'order-clerk']
+    ),
+    valid_from TIMESTAMPTZ NOT NULL DEFAULT now(),
+    valid_until TIMESTAMPTZ CHECK (valid_until IS NULL OR valid_until > valid_from)
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_keys_name ON api_keys(name);
@@ -0,0 +1,3 @@
+-- Index for the cursor pagination of the books, the KSUID ids are compared bytewise
+-- to keep their time order independent of the collation of the database
+CREATE INDEX IF NOT EXISTS idx_books_id_cursor ON books (id COLLATE "C");
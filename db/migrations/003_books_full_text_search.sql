@@ -0,0 +1,72 @@
+-- Full text search over the titles, series and author names of the books.
+-- search_vector weighs title (A) over series (B) and author names (C) to rank the matches,
+-- search_text backs the trigram index which matches misspelled words.
+-- Both are maintained by triggers, since the author names live in other tables.
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE books ADD COLUMN IF NOT EXISTS search_vector TSVECTOR NOT NULL DEFAULT ''::TSVECTOR;
+ALTER TABLE books ADD COLUMN IF NOT EXISTS search_text TEXT NOT NULL DEFAULT '';
+
+CREATE INDEX IF NOT EXISTS idx_books_search_vector ON books USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS idx_books_search_text ON books USING GIN (search_text gin_trgm_ops);
+
+CREATE OR REPLACE FUNCTION book_author_names(p_book_id TEXT) RETURNS TEXT AS $$
+    SELECT coalesce(
+        string_agg(concat_ws(' ', a.first_name, array_to_string(a.second_names, ' '), a.last_name), ' '),
+        ''
+    )
+    FROM book_authors ba JOIN authors a ON a.id = ba.author_id
+    WHERE ba.book_id = p_book_id
+$$ LANGUAGE sql STABLE;
+
+CREATE OR REPLACE FUNCTION update_book_search() RETURNS TRIGGER AS $$
+DECLARE
+    author_names TEXT := book_author_names(NEW.id);
+BEGIN
+    NEW.search_vector :=
+        setweight(to_tsvector('simple', NEW.title), 'A')
+        || setweight(to_tsvector('simple', coalesce(NEW.series, '')), 'B')
+        || setweight(to_tsvector('simple', author_names), 'C');
+    NEW.search_text := lower(concat_ws(' ', NEW.title, NEW.series, author_names));
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER update_book_search_trigger
+BEFORE INSERT OR UPDATE OF title, series ON books
+FOR EACH ROW
+EXECUTE FUNCTION update_book_search();
+
+-- touching the title recomputes the search columns of the books whose authors changed
+CREATE OR REPLACE FUNCTION refresh_book_search_of_book_authors() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        UPDATE books SET title = title WHERE id = OLD.book_id;
+    ELSE
+        UPDATE books SET title = title WHERE id = NEW.book_id;
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER refresh_book_search_of_book_authors_trigger
+AFTER INSERT OR DELETE ON book_authors
+FOR EACH ROW
+EXECUTE FUNCTION refresh_book_search_of_book_authors();
+
+CREATE OR REPLACE FUNCTION refresh_book_search_of_authors() RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE books SET title = title
+    WHERE id IN (SELECT book_id FROM book_authors WHERE author_id = NEW.id);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER refresh_book_search_of_authors_trigger
+AFTER UPDATE OF first_name, second_names, last_name ON authors
+FOR EACH ROW
+EXECUTE FUNCTION refresh_book_search_of_authors();
+
+-- backfill the books created before this migration
+UPDATE books SET title = title;
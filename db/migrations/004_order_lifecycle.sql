@@ -0,0 +1,13 @@
+-- The order lifecycle: placed -> shipped -> delivered, a placed order can be canceled.
+-- Each transition records its time, orders placed before this migration keep the time of the migration
+-- for the transitions their status has gone through.
+
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS placed_at TIMESTAMPTZ NOT NULL DEFAULT now();
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS shipped_at TIMESTAMPTZ;
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS delivered_at TIMESTAMPTZ;
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS canceled_at TIMESTAMPTZ;
+
+UPDATE orders SET shipped_at = placed_at
+WHERE status IN ('shipped', 'delivered') AND shipped_at IS NULL;
+UPDATE orders SET delivered_at = placed_at WHERE status = 'delivered' AND delivered_at IS NULL;
+UPDATE orders SET canceled_at = placed_at WHERE status = 'canceled' AND canceled_at IS NULL;
@@ -0,0 +1,27 @@
+-- The status history of the orders, written on placing, updating and canceling an order.
+-- from_status is NULL for the placement, the actor is the name of the API key that changed the order.
+
+CREATE TABLE IF NOT EXISTS order_status_events (
+    id BIGSERIAL PRIMARY KEY,
+    order_id TEXT NOT NULL REFERENCES orders(id) ON DELETE CASCADE,
+    from_status TEXT CHECK (from_status IN ('placed', 'shipped', 'delivered', 'canceled')),
+    to_status TEXT NOT NULL CHECK (to_status IN ('placed', 'shipped', 'delivered', 'canceled')),
+    actor TEXT NOT NULL,
+    reason TEXT,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS idx_order_status_events_order_id ON order_status_events(order_id, occurred_at);
+
+-- backfill the history of the orders placed before this migration from their transition timestamps
+INSERT INTO order_status_events (order_id, from_status, to_status, actor, occurred_at)
+SELECT id, NULL, 'placed', 'migration', placed_at FROM orders;
+
+INSERT INTO order_status_events (order_id, from_status, to_status, actor, occurred_at)
+SELECT id, 'placed', 'shipped', 'migration', shipped_at FROM orders WHERE shipped_at IS NOT NULL;
+
+INSERT INTO order_status_events (order_id, from_status, to_status, actor, occurred_at)
+SELECT id, 'shipped', 'delivered', 'migration', delivered_at FROM orders WHERE delivered_at IS NOT NULL;
+
+INSERT INTO order_status_events (order_id, from_status, to_status, actor, occurred_at)
+SELECT id, 'placed', 'canceled', 'migration', canceled_at FROM orders WHERE canceled_at IS NOT NULL;
@@ -0,0 +1,29 @@
+-- Orders keep the prices at the time of the order, later price changes of the books don't rewrite the order history.
+-- The discount code is a snapshot of the applied code and not a reference, discount codes can be removed later.
+-- Orders placed before this migration are priced with the current book prices and without discounts.
+
+ALTER TABLE order_items ADD COLUMN IF NOT EXISTS unit_price DECIMAL(10,2);
+ALTER TABLE order_items ADD COLUMN IF NOT EXISTS discount_code TEXT;
+ALTER TABLE order_items ADD COLUMN IF NOT EXISTS discount_percentage INTEGER NOT NULL DEFAULT 0
+    CHECK (discount_percentage BETWEEN 0 AND 100);
+ALTER TABLE order_items ADD COLUMN IF NOT EXISTS discount_amount DECIMAL(10,2) NOT NULL DEFAULT 0;
+ALTER TABLE order_items ADD COLUMN IF NOT EXISTS line_total DECIMAL(10,2);
+
+UPDATE order_items i SET unit_price = b.price, line_total = b.price * i.quantity
+FROM books b WHERE b.id = i.book_id AND i.unit_price IS NULL;
+
+ALTER TABLE order_items ALTER COLUMN unit_price SET NOT NULL;
+ALTER TABLE order_items ALTER COLUMN line_total SET NOT NULL;
+
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS discount_codes TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS subtotal DECIMAL(10,2);
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS discount_total DECIMAL(10,2) NOT NULL DEFAULT 0;
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS total DECIMAL(10,2);
+
+UPDATE orders o SET subtotal = t.amount, total = t.amount
+FROM (SELECT order_id, SUM(line_total) AS amount FROM order_items GROUP BY order_id) t
+WHERE t.order_id = o.id AND o.subtotal IS NULL;
+UPDATE orders SET subtotal = 0, total = 0 WHERE subtotal IS NULL;
+
+ALTER TABLE orders ALTER COLUMN subtotal SET NOT NULL;
+ALTER TABLE orders ALTER COLUMN total SET NOT NULL;
@@ -0,0 +1,16 @@
+-- Amounts of money carry the ISO 4217 code of their currency, existing prices are in USD.
+-- The scale is widened to 3 decimal places for the currencies with a minor unit of 1/1000, e.g. KWD.
+
+ALTER TABLE books ADD COLUMN IF NOT EXISTS currency TEXT NOT NULL DEFAULT 'USD'
+    CHECK (currency ~ '^[A-Z]{3}$');
+ALTER TABLE books ALTER COLUMN price TYPE NUMERIC(12, 3);
+
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS currency TEXT NOT NULL DEFAULT 'USD'
+    CHECK (currency ~ '^[A-Z]{3}$');
+ALTER TABLE orders ALTER COLUMN subtotal TYPE NUMERIC(12, 3);
+ALTER TABLE orders ALTER COLUMN discount_total TYPE NUMERIC(12, 3);
+ALTER TABLE orders ALTER COLUMN total TYPE NUMERIC(12, 3);
+
+ALTER TABLE order_items ALTER COLUMN unit_price TYPE NUMERIC(12, 3);
+ALTER TABLE order_items ALTER COLUMN discount_amount TYPE NUMERIC(12, 3);
+ALTER TABLE order_items ALTER COLUMN line_total TYPE NUMERIC(12, 3);
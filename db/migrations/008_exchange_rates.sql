@@ -0,0 +1,14 @@
+-- The exchange rates table holds the units of each currency one unit of the base currency buys,
+-- the base currency itself is configured and has no entry.
+-- Orders priced in another currency than the books lock the rate used at placement.
+
+CREATE TABLE IF NOT EXISTS exchange_rates (
+    currency TEXT PRIMARY KEY CHECK (currency ~ '^[A-Z]{3}$'),
+    rate NUMERIC(18, 8) NOT NULL CHECK (rate > 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS exchange_source_currency TEXT
+    CHECK (exchange_source_currency ~ '^[A-Z]{3}$');
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS exchange_rate NUMERIC(18, 8);
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS exchange_rate_updated_at TIMESTAMPTZ;
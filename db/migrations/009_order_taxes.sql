@@ -0,0 +1,18 @@
+-- Books are taxed by their product type, existing books are print books.
+-- Orders keep the taxes at the time of the order, the tax lines are summed from the items by rate.
+-- Orders placed before this migration are untaxed.
+
+ALTER TABLE books ADD COLUMN IF NOT EXISTS product_type TEXT NOT NULL DEFAULT 'print'
+    CHECK (product_type IN ('print', 'ebook', 'audiobook'));
+
+ALTER TABLE order_items ADD COLUMN IF NOT EXISTS tax_rate NUMERIC(6, 3) NOT NULL DEFAULT 0
+    CHECK (tax_rate BETWEEN 0 AND 100);
+ALTER TABLE order_items ADD COLUMN IF NOT EXISTS tax_amount NUMERIC(12, 3) NOT NULL DEFAULT 0;
+
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS prices_include_tax BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS net_total NUMERIC(12, 3);
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS tax_total NUMERIC(12, 3) NOT NULL DEFAULT 0;
+
+UPDATE orders SET net_total = total WHERE net_total IS NULL;
+
+ALTER TABLE orders ALTER COLUMN net_total SET NOT NULL;
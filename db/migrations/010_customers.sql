@@ -0,0 +1,27 @@
+-- The customers place the orders, each customer keeps an address book of saved addresses.
+-- The emails are unique regardless of their case.
+-- The orders placed before this migration reference customers which were never stored,
+-- the foreign key is only checked for new orders.
+
+CREATE TABLE IF NOT EXISTS customers (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL CHECK (name <> ''),
+    email TEXT NOT NULL CHECK (email LIKE '_%@_%')
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_customers_email ON customers (lower(email));
+
+-- The saved addresses of a customer in the order of the address book
+CREATE TABLE IF NOT EXISTS customer_addresses (
+    customer_id TEXT NOT NULL REFERENCES customers(id) ON DELETE CASCADE,
+    address_id INTEGER NOT NULL REFERENCES addresses(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL CHECK (position >= 0),
+    PRIMARY KEY (customer_id, position)
+);
+
+ALTER TABLE orders DROP CONSTRAINT IF EXISTS orders_customer_id_fkey;
+ALTER TABLE orders ADD CONSTRAINT orders_customer_id_fkey
+    FOREIGN KEY (customer_id) REFERENCES customers(id) ON DELETE RESTRICT NOT VALID;
+
+-- Index for the cursor pagination of the orders of a customer
+CREATE INDEX IF NOT EXISTS idx_orders_customer_id_cursor ON orders (customer_id, id COLLATE "C");
@@ -0,0 +1,19 @@
+-- The responses of the requests made with an Idempotency-Key, replayed for the retries until they expire.
+-- The keys are scoped to the name of the authenticated API key of the caller,
+-- a key without status is reserved by a request which is still in progress until its lease expires,
+-- the response extends the expiry to the TTL. The headers of the response are stored as 'name: value'.
+
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    scope TEXT NOT NULL,
+    key TEXT NOT NULL CHECK (length(key) BETWEEN 1 AND 255),
+    fingerprint TEXT NOT NULL,
+    status SMALLINT CHECK (status BETWEEN 100 AND 599),
+    headers TEXT[] NOT NULL DEFAULT '{}',
+    body BYTEA,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (scope, key)
+);
+
+-- Index for purging the expired keys
+CREATE INDEX IF NOT EXISTS idx_idempotency_keys_expires_at ON idempotency_keys (expires_at);
@@ -0,0 +1,7 @@
+-- The versions of the authors, books and orders for optimistic concurrency control.
+-- Every update increments the version, an update based on an older version is rejected,
+-- so concurrent updates of the same row can not silently overwrite each other.
+
+ALTER TABLE authors ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1 CHECK (version > 0);
+ALTER TABLE books ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1 CHECK (version > 0);
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1 CHECK (version > 0);
@@ -0,0 +1,15 @@
+-- The transactional outbox of the domain events, written in the same transaction as the change of the
+-- books and orders and published to the configured sink by the event dispatcher.
+-- The payload is the JSON of the event, published_at is NULL until the event is published.
+
+CREATE TABLE IF NOT EXISTS outbox (
+    id BIGSERIAL PRIMARY KEY,
+    event_type TEXT NOT NULL,
+    aggregate_id TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    published_at TIMESTAMPTZ
+);
+
+-- Index for loading the unpublished events in the order they were written
+CREATE INDEX IF NOT EXISTS idx_outbox_unpublished ON outbox (id) WHERE published_at IS NULL;
@@ -0,0 +1,42 @@
+-- The webhooks of the partners, the events of the outbox are delivered to the webhooks subscribed
+-- to their event type. Every delivery is kept as delivery log, a delivery which exhausted its attempts
+-- is failed and stays in the log as dead letter.
+
+ALTER TABLE api_keys DROP CONSTRAINT IF EXISTS api_keys_roles_check;
+ALTER TABLE api_keys ADD CONSTRAINT api_keys_roles_check CHECK (
+    cardinality(roles) > 0
+    AND roles <@ ARRAY['catalog-read', 'catalog-admin', 'order-clerk', 'webhook-admin']
+);
+
+CREATE TABLE IF NOT EXISTS webhooks (
+    id TEXT PRIMARY KEY,
+    url TEXT NOT NULL CHECK (url ~ '^https?://'),
+    event_types TEXT[] NOT NULL CHECK (cardinality(event_types) > 0),
+    secret TEXT NOT NULL CHECK (char_length(secret) >= 16),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- The payload is the JSON body posted to the webhook, next_attempt_at is NULL once the delivery
+-- is delivered or failed
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id TEXT PRIMARY KEY,
+    webhook_id TEXT NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+    event_id BIGINT NOT NULL,
+    event_type TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'delivered', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0 CHECK (attempts >= 0),
+    last_status_code SMALLINT,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    delivered_at TIMESTAMPTZ,
+    UNIQUE (webhook_id, event_id)
+);
+
+-- Index for claiming the due deliveries
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_due ON webhook_deliveries (next_attempt_at)
+    WHERE status = 'pending';
+
+-- Index for the cursor pagination of the delivery log of a webhook
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_cursor ON webhook_deliveries (webhook_id, id COLLATE "C");
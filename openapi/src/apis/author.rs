@@ -14,11 +14,15 @@ pub enum AddAuthorResponse {
     /// Successful operation
     Status200_SuccessfulOperation(models::Author),
     /// Invalid input
-    Status400_InvalidInput,
+    Status400_InvalidInput(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Validation exception
-    Status422_ValidationException,
+    Status422_ValidationException(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -28,11 +32,17 @@ pub enum DeleteAuthorResponse {
     /// Successfully deleted
     Status200_SuccessfullyDeleted,
     /// Invalid authorId value
-    Status400_InvalidAuthorIdValue,
+    Status400_InvalidAuthorIdValue(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Author not found
-    Status404_AuthorNotFound,
+    Status404_AuthorNotFound(models::Problem),
+    /// Author has books
+    Status422_AuthorHasBooks(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -40,13 +50,20 @@ pub enum DeleteAuthorResponse {
 #[allow(clippy::large_enum_variant)]
 pub enum GetAuthorByIdResponse {
     /// successful operation
-    Status200_SuccessfulOperation(models::Author),
+    Status200_SuccessfulOperation {
+        body: models::Author,
+        etag: Option<String>,
+    },
     /// Invalid parameters
-    Status400_InvalidParameters,
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Author not found
-    Status404_AuthorNotFound,
+    Status404_AuthorNotFound(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -54,21 +71,32 @@ pub enum GetAuthorByIdResponse {
 #[allow(clippy::large_enum_variant)]
 pub enum UpdateAuthorResponse {
     /// Successful operation
-    Status200_SuccessfulOperation(models::Author),
+    Status200_SuccessfulOperation {
+        body: models::Author,
+        etag: Option<String>,
+    },
     /// Invalid parameters
-    Status400_InvalidParameters,
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Author not found
-    Status404_AuthorNotFound,
+    Status404_AuthorNotFound(models::Problem),
+    /// Precondition failed
+    Status412_PreconditionFailed(models::Problem),
     /// Validation exception
-    Status422_ValidationException,
+    Status422_ValidationException(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 /// Author
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Author<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    type Claims;
+
     /// Add a new author to the store.
     ///
     /// AddAuthor - POST /api/v1/authors
@@ -77,6 +105,7 @@ pub trait Author<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorH
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         body: &models::NewAuthor,
     ) -> Result<AddAuthorResponse, E>;
 
@@ -88,6 +117,7 @@ pub trait Author<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorH
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteAuthorPathParams,
     ) -> Result<DeleteAuthorResponse, E>;
 
@@ -99,6 +129,7 @@ pub trait Author<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorH
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::GetAuthorByIdPathParams,
     ) -> Result<GetAuthorByIdResponse, E>;
 
@@ -110,6 +141,8 @@ pub trait Author<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorH
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::UpdateAuthorHeaderParams,
         path_params: &models::UpdateAuthorPathParams,
         body: &models::AuthorProperties,
     ) -> Result<UpdateAuthorResponse, E>;
@@ -14,11 +14,15 @@ pub enum AddBookResponse {
     /// Successful operation
     Status200_SuccessfulOperation(models::Book),
     /// Invalid input
-    Status400_InvalidInput,
+    Status400_InvalidInput(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Validation exception
-    Status422_ValidationException,
+    Status422_ValidationException(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -28,11 +32,33 @@ pub enum DeleteBookResponse {
     /// Successful operation
     Status200_SuccessfulOperation,
     /// Invalid bookId value
-    Status400_InvalidBookIdValue,
+    Status400_InvalidBookIdValue(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// BookId not found
-    Status404_BookIdNotFound,
+    Status404_BookIdNotFound(models::Problem),
+    /// Book is part of orders
+    Status422_BookIsPartOfOrders(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum FullTextSearchBooksResponse {
+    /// successful operation
+    Status200_SuccessfulOperation(models::BookSearchResult),
+    /// Invalid search text
+    Status400_InvalidSearchText(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -40,13 +66,20 @@ pub enum DeleteBookResponse {
 #[allow(clippy::large_enum_variant)]
 pub enum GetBookByIdResponse {
     /// successful operation
-    Status200_SuccessfulOperation(models::Book),
+    Status200_SuccessfulOperation {
+        body: models::Book,
+        etag: Option<String>,
+    },
     /// Invalid parameters
-    Status400_InvalidParameters,
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Book not found
-    Status404_BookNotFound,
+    Status404_BookNotFound(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -54,11 +87,15 @@ pub enum GetBookByIdResponse {
 #[allow(clippy::large_enum_variant)]
 pub enum GetBooksByAuthorsResponse {
     /// successful operation
-    Status200_SuccessfulOperation(Vec<models::Book>),
+    Status200_SuccessfulOperation(models::BookPage),
     /// Invalid author values
-    Status400_InvalidAuthorValues,
+    Status400_InvalidAuthorValues(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -66,11 +103,15 @@ pub enum GetBooksByAuthorsResponse {
 #[allow(clippy::large_enum_variant)]
 pub enum GetBooksByGenresResponse {
     /// successful operation
-    Status200_SuccessfulOperation(Vec<models::Book>),
+    Status200_SuccessfulOperation(models::BookPage),
     /// Invalid genre values
-    Status400_InvalidGenreValues,
+    Status400_InvalidGenreValues(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -78,11 +119,31 @@ pub enum GetBooksByGenresResponse {
 #[allow(clippy::large_enum_variant)]
 pub enum GetBooksByStatusResponse {
     /// successful operation
-    Status200_SuccessfulOperation(Vec<models::Book>),
+    Status200_SuccessfulOperation(models::BookPage),
     /// Invalid status value
-    Status400_InvalidStatusValue,
+    Status400_InvalidStatusValue(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum SearchBooksResponse {
+    /// successful operation
+    Status200_SuccessfulOperation(models::BookPage),
+    /// Invalid search parameters
+    Status400_InvalidSearchParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -90,21 +151,32 @@ pub enum GetBooksByStatusResponse {
 #[allow(clippy::large_enum_variant)]
 pub enum UpdateBookResponse {
     /// Successful operation
-    Status200_SuccessfulOperation(models::Book),
+    Status200_SuccessfulOperation {
+        body: models::Book,
+        etag: Option<String>,
+    },
     /// Invalid parameters
-    Status400_InvalidParameters,
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Book not found
-    Status404_BookNotFound,
+    Status404_BookNotFound(models::Problem),
+    /// Precondition failed
+    Status412_PreconditionFailed(models::Problem),
     /// Validation exception
-    Status422_ValidationException,
+    Status422_ValidationException(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 /// Book
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Book<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    type Claims;
+
     /// Add a new book to the store.
     ///
     /// AddBook - POST /api/v1/books
@@ -113,6 +185,7 @@ pub trait Book<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHan
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         body: &models::NewBook,
     ) -> Result<AddBookResponse, E>;
 
@@ -124,9 +197,23 @@ pub trait Book<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHan
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteBookPathParams,
     ) -> Result<DeleteBookResponse, E>;
 
+    /// Full text search of books.
+    ///
+    /// FullTextSearchBooks - GET /api/v1/books/search
+    async fn full_text_search_books(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::FullTextSearchBooksHeaderParams,
+        query_params: &models::FullTextSearchBooksQueryParams,
+    ) -> Result<FullTextSearchBooksResponse, E>;
+
     /// Finds book by Id.
     ///
     /// GetBookById - GET /api/v1/books/{bookId}
@@ -135,7 +222,10 @@ pub trait Book<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHan
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::GetBookByIdHeaderParams,
         path_params: &models::GetBookByIdPathParams,
+        query_params: &models::GetBookByIdQueryParams,
     ) -> Result<GetBookByIdResponse, E>;
 
     /// Finds Books by AuthorId.
@@ -146,6 +236,8 @@ pub trait Book<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHan
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::GetBooksByAuthorsHeaderParams,
         query_params: &models::GetBooksByAuthorsQueryParams,
     ) -> Result<GetBooksByAuthorsResponse, E>;
 
@@ -157,6 +249,8 @@ pub trait Book<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHan
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::GetBooksByGenresHeaderParams,
         query_params: &models::GetBooksByGenresQueryParams,
     ) -> Result<GetBooksByGenresResponse, E>;
 
@@ -168,9 +262,24 @@ pub trait Book<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHan
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::GetBooksByStatusHeaderParams,
         query_params: &models::GetBooksByStatusQueryParams,
     ) -> Result<GetBooksByStatusResponse, E>;
 
+    /// Search the books.
+    ///
+    /// SearchBooks - GET /api/v1/books
+    async fn search_books(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::SearchBooksHeaderParams,
+        query_params: &models::SearchBooksQueryParams,
+    ) -> Result<SearchBooksResponse, E>;
+
     /// Update an existing book.
     ///
     /// UpdateBook - PATCH /api/v1/books/{bookId}
@@ -179,6 +288,8 @@ pub trait Book<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHan
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::UpdateBookHeaderParams,
         path_params: &models::UpdateBookPathParams,
         body: &models::BookProperties,
     ) -> Result<UpdateBookResponse, E>;
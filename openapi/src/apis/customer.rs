@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Host};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AddCustomerResponse {
+    /// Successful operation
+    Status200_SuccessfulOperation(models::Customer),
+    /// Invalid input
+    Status400_InvalidInput(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Validation exception
+    Status422_ValidationException(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum DeleteCustomerResponse {
+    /// Successfully deleted
+    Status200_SuccessfullyDeleted,
+    /// Invalid customerId value
+    Status400_InvalidCustomerIdValue(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Customer not found
+    Status404_CustomerNotFound(models::Problem),
+    /// Customer has placed orders
+    Status422_CustomerHasPlacedOrders(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum GetCustomerByIdResponse {
+    /// Successful operation
+    Status200_SuccessfulOperation(models::Customer),
+    /// Invalid parameters
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Customer not found
+    Status404_CustomerNotFound(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum GetCustomerOrdersResponse {
+    /// Successful operation
+    Status200_SuccessfulOperation(models::OrderPage),
+    /// Invalid parameters
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Customer not found
+    Status404_CustomerNotFound(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum UpdateCustomerResponse {
+    /// Successful operation
+    Status200_SuccessfulOperation(models::Customer),
+    /// Invalid parameters
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Customer not found
+    Status404_CustomerNotFound(models::Problem),
+    /// Validation exception
+    Status422_ValidationException(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+/// Customer
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Customer<E: std::fmt::Debug + Send + Sync + 'static = ()>:
+    super::ErrorHandler<E>
+{
+    type Claims;
+
+    /// Add a new customer.
+    ///
+    /// AddCustomer - POST /api/v1/customers
+    async fn add_customer(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::NewCustomer,
+    ) -> Result<AddCustomerResponse, E>;
+
+    /// Deletes a customer.
+    ///
+    /// DeleteCustomer - DELETE /api/v1/customers/{customerId}
+    async fn delete_customer(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::DeleteCustomerPathParams,
+    ) -> Result<DeleteCustomerResponse, E>;
+
+    /// Finds customer by Id.
+    ///
+    /// GetCustomerById - GET /api/v1/customers/{customerId}
+    async fn get_customer_by_id(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetCustomerByIdPathParams,
+    ) -> Result<GetCustomerByIdResponse, E>;
+
+    /// Finds the orders of a customer.
+    ///
+    /// GetCustomerOrders - GET /api/v1/customers/{customerId}/orders
+    async fn get_customer_orders(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetCustomerOrdersPathParams,
+        query_params: &models::GetCustomerOrdersQueryParams,
+    ) -> Result<GetCustomerOrdersResponse, E>;
+
+    /// Update an existing customer.
+    ///
+    /// UpdateCustomer - PATCH /api/v1/customers/{customerId}
+    async fn update_customer(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::UpdateCustomerPathParams,
+        body: &models::CustomerProperties,
+    ) -> Result<UpdateCustomerResponse, E>;
+}
@@ -14,11 +14,15 @@ pub enum AddDiscountResponse {
     /// Successful operation
     Status200_SuccessfulOperation(models::DiscountCode),
     /// Invalid input
-    Status400_InvalidInput,
+    Status400_InvalidInput(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Validation exception
-    Status422_ValidationException,
+    Status422_ValidationException(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -28,11 +32,15 @@ pub enum DeleteDiscountResponse {
     /// Successful operation
     Status200_SuccessfulOperation,
     /// Invalid discountId value
-    Status400_InvalidDiscountIdValue,
+    Status400_InvalidDiscountIdValue(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Discount not found
-    Status404_DiscountNotFound,
+    Status404_DiscountNotFound(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -42,11 +50,15 @@ pub enum GetDiscountByIdResponse {
     /// successful operation
     Status200_SuccessfulOperation(models::DiscountCode),
     /// Invalid parameters
-    Status400_InvalidParameters,
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// DiscountCode not found
-    Status404_DiscountCodeNotFound,
+    Status404_DiscountCodeNotFound(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 /// Discount
@@ -55,6 +67,8 @@ pub enum GetDiscountByIdResponse {
 pub trait Discount<E: std::fmt::Debug + Send + Sync + 'static = ()>:
     super::ErrorHandler<E>
 {
+    type Claims;
+
     /// Add a new discount to the store.
     ///
     /// AddDiscount - POST /api/v1/discounts
@@ -63,6 +77,7 @@ pub trait Discount<E: std::fmt::Debug + Send + Sync + 'static = ()>:
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         body: &models::NewDiscountCode,
     ) -> Result<AddDiscountResponse, E>;
 
@@ -74,6 +89,7 @@ pub trait Discount<E: std::fmt::Debug + Send + Sync + 'static = ()>:
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteDiscountPathParams,
     ) -> Result<DeleteDiscountResponse, E>;
 
@@ -85,6 +101,7 @@ pub trait Discount<E: std::fmt::Debug + Send + Sync + 'static = ()>:
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::GetDiscountByIdPathParams,
     ) -> Result<GetDiscountByIdResponse, E>;
 }
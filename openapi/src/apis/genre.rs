@@ -14,11 +14,15 @@ pub enum AddGenreResponse {
     /// Successful operation
     Status200_SuccessfulOperation(models::Genre),
     /// Invalid input
-    Status400_InvalidInput,
+    Status400_InvalidInput(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Validation exception
-    Status422_ValidationException,
+    Status422_ValidationException(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -28,11 +32,17 @@ pub enum DeleteGenreResponse {
     /// Successful operation
     Status200_SuccessfulOperation,
     /// Invalid genreId value
-    Status400_InvalidGenreIdValue,
+    Status400_InvalidGenreIdValue(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Genre not found
-    Status404_GenreNotFound,
+    Status404_GenreNotFound(models::Problem),
+    /// Genre is assigned to books
+    Status422_GenreIsAssignedToBooks(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -42,17 +52,23 @@ pub enum GetGenreByIdResponse {
     /// successful operation
     Status200_SuccessfulOperation(models::Genre),
     /// Invalid parameters
-    Status400_InvalidParameters,
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Genre not found
-    Status404_GenreNotFound,
+    Status404_GenreNotFound(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 /// Genre
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Genre<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    type Claims;
+
     /// Add a new genre to the store.
     ///
     /// AddGenre - POST /api/v1/genres
@@ -61,6 +77,7 @@ pub trait Genre<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         body: &models::NewGenre,
     ) -> Result<AddGenreResponse, E>;
 
@@ -72,6 +89,7 @@ pub trait Genre<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteGenrePathParams,
     ) -> Result<DeleteGenreResponse, E>;
 
@@ -83,6 +101,7 @@ pub trait Genre<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::GetGenreByIdPathParams,
     ) -> Result<GetGenreByIdResponse, E>;
 }
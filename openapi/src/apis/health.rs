@@ -7,6 +7,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::{models, types::*};
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum GetLivenessResponse {
+    /// Successful
+    Status200_Successful(models::HealthCheckResponse),
+    /// Server error
+    Status500_ServerError,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
@@ -16,14 +26,24 @@ pub enum GetReadinessResponse {
     /// Server error
     Status500_ServerError,
     /// Service unavailable
-    Status503_ServiceUnavailable,
+    Status503_ServiceUnavailable(models::HealthCheckResponse),
 }
 
 /// Health
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Health<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
-    /// Get health status.
+    /// Get liveness status.
+    ///
+    /// GetLiveness - GET /api/v1/health/liveness
+    async fn get_liveness(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+    ) -> Result<GetLivenessResponse, E>;
+
+    /// Get readiness status.
     ///
     /// GetReadiness - GET /api/v1/health/readiness
     async fn get_readiness(
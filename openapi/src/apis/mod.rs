@@ -1,9 +1,11 @@
 pub mod author;
 pub mod book;
+pub mod customer;
 pub mod discount;
 pub mod genre;
 pub mod health;
 pub mod store;
+pub mod webhook;
 
 /// API Key Authentication - Header.
 #[async_trait::async_trait]
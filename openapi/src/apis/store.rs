@@ -14,11 +14,17 @@ pub enum DeleteOrderResponse {
     /// Successful operation
     Status200_SuccessfulOperation,
     /// Invalid ID supplied
-    Status400_InvalidIDSupplied,
+    Status400_InvalidIDSupplied(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Order not found
-    Status404_OrderNotFound,
+    Status404_OrderNotFound(models::Problem),
+    /// Order can not be canceled
+    Status422_OrderCanNotBeCanceled(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -27,8 +33,12 @@ pub enum DeleteOrderResponse {
 pub enum GetInventoryResponse {
     /// successful operation
     Status200_SuccessfulOperation(models::Inventory),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -36,13 +46,38 @@ pub enum GetInventoryResponse {
 #[allow(clippy::large_enum_variant)]
 pub enum GetOrderByIdResponse {
     /// successful operation
-    Status200_SuccessfulOperation(models::Order),
+    Status200_SuccessfulOperation {
+        body: models::Order,
+        etag: Option<String>,
+    },
+    /// Invalid ID supplied
+    Status400_InvalidIDSupplied(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Order not found
+    Status404_OrderNotFound(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum GetOrderHistoryResponse {
+    /// successful operation
+    Status200_SuccessfulOperation(models::OrderHistory),
     /// Invalid ID supplied
-    Status400_InvalidIDSupplied,
+    Status400_InvalidIDSupplied(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Order not found
-    Status404_OrderNotFound,
+    Status404_OrderNotFound(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -52,11 +87,15 @@ pub enum PlaceOrderResponse {
     /// Successful operation
     Status200_SuccessfulOperation(models::Order),
     /// Invalid input
-    Status400_InvalidInput,
+    Status400_InvalidInput(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Validation exception
-    Status422_ValidationException,
+    Status422_ValidationException(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -64,21 +103,32 @@ pub enum PlaceOrderResponse {
 #[allow(clippy::large_enum_variant)]
 pub enum UpdateOrderResponse {
     /// Successful operation
-    Status200_SuccessfulOperation(models::Order),
+    Status200_SuccessfulOperation {
+        body: models::Order,
+        etag: Option<String>,
+    },
     /// Invalid parameters
-    Status400_InvalidParameters,
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
     /// Order not found
-    Status404_OrderNotFound,
+    Status404_OrderNotFound(models::Problem),
+    /// Precondition failed
+    Status412_PreconditionFailed(models::Problem),
     /// Validation exception
-    Status422_ValidationException,
+    Status422_ValidationException(models::Problem),
     /// Server error
-    Status500_ServerError,
+    Status500_ServerError(models::Problem),
 }
 
 /// Store
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Store<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    type Claims;
+
     /// Delete purchase order by Id.
     ///
     /// DeleteOrder - DELETE /api/v1/store/orders/{orderId}
@@ -87,7 +137,9 @@ pub trait Store<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::DeleteOrderPathParams,
+        query_params: &models::DeleteOrderQueryParams,
     ) -> Result<DeleteOrderResponse, E>;
 
     /// Returns book inventories by status.
@@ -98,6 +150,7 @@ pub trait Store<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
     ) -> Result<GetInventoryResponse, E>;
 
     /// Find purchase order by Id..
@@ -108,9 +161,22 @@ pub trait Store<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
         path_params: &models::GetOrderByIdPathParams,
     ) -> Result<GetOrderByIdResponse, E>;
 
+    /// Get the status history of an order..
+    ///
+    /// GetOrderHistory - GET /api/v1/store/orders/{orderId}/history
+    async fn get_order_history(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetOrderHistoryPathParams,
+    ) -> Result<GetOrderHistoryResponse, E>;
+
     /// Place an order for a book.
     ///
     /// PlaceOrder - POST /api/v1/store/orders
@@ -119,6 +185,9 @@ pub trait Store<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::PlaceOrderHeaderParams,
+        query_params: &models::PlaceOrderQueryParams,
         body: &models::NewOrder,
     ) -> Result<PlaceOrderResponse, E>;
 
@@ -130,6 +199,8 @@ pub trait Store<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
         method: &Method,
         host: &Host,
         cookies: &CookieJar,
+        claims: &Self::Claims,
+        header_params: &models::UpdateOrderHeaderParams,
         path_params: &models::UpdateOrderPathParams,
         body: &models::OrderProperties,
     ) -> Result<UpdateOrderResponse, E>;
@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Host};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AddWebhookResponse {
+    /// Successful operation
+    Status200_SuccessfulOperation(models::Webhook),
+    /// Invalid input
+    Status400_InvalidInput(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Validation exception
+    Status422_ValidationException(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum DeleteWebhookResponse {
+    /// Successfully deleted
+    Status200_SuccessfullyDeleted,
+    /// Invalid webhookId value
+    Status400_InvalidWebhookIdValue(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Webhook not found
+    Status404_WebhookNotFound(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum GetWebhookByIdResponse {
+    /// Successful operation
+    Status200_SuccessfulOperation(models::Webhook),
+    /// Invalid parameters
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Webhook not found
+    Status404_WebhookNotFound(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum GetWebhookDeliveriesResponse {
+    /// Successful operation
+    Status200_SuccessfulOperation(models::WebhookDeliveryPage),
+    /// Invalid parameters
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Webhook not found
+    Status404_WebhookNotFound(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum GetWebhooksResponse {
+    /// Successful operation
+    Status200_SuccessfulOperation(Vec<models::Webhook>),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum UpdateWebhookResponse {
+    /// Successful operation
+    Status200_SuccessfulOperation(models::Webhook),
+    /// Invalid parameters
+    Status400_InvalidParameters(models::Problem),
+    /// Unauthorized
+    Status401_Unauthorized,
+    /// Forbidden
+    Status403_Forbidden(models::Problem),
+    /// Webhook not found
+    Status404_WebhookNotFound(models::Problem),
+    /// Validation exception
+    Status422_ValidationException(models::Problem),
+    /// Server error
+    Status500_ServerError(models::Problem),
+}
+
+/// Webhook
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Webhook<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    type Claims;
+
+    /// Subscribe an endpoint to the events.
+    ///
+    /// AddWebhook - POST /api/v1/webhooks
+    async fn add_webhook(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::NewWebhook,
+    ) -> Result<AddWebhookResponse, E>;
+
+    /// Deletes a webhook.
+    ///
+    /// DeleteWebhook - DELETE /api/v1/webhooks/{webhookId}
+    async fn delete_webhook(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::DeleteWebhookPathParams,
+    ) -> Result<DeleteWebhookResponse, E>;
+
+    /// Finds webhook by Id.
+    ///
+    /// GetWebhookById - GET /api/v1/webhooks/{webhookId}
+    async fn get_webhook_by_id(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetWebhookByIdPathParams,
+    ) -> Result<GetWebhookByIdResponse, E>;
+
+    /// Finds the deliveries of a webhook.
+    ///
+    /// GetWebhookDeliveries - GET /api/v1/webhooks/{webhookId}/deliveries
+    async fn get_webhook_deliveries(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::GetWebhookDeliveriesPathParams,
+        query_params: &models::GetWebhookDeliveriesQueryParams,
+    ) -> Result<GetWebhookDeliveriesResponse, E>;
+
+    /// Lists the webhooks.
+    ///
+    /// GetWebhooks - GET /api/v1/webhooks
+    async fn get_webhooks(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+    ) -> Result<GetWebhooksResponse, E>;
+
+    /// Update an existing webhook.
+    ///
+    /// UpdateWebhook - PATCH /api/v1/webhooks/{webhookId}
+    async fn update_webhook(
+        &self,
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        path_params: &models::UpdateWebhookPathParams,
+        body: &models::WebhookProperties,
+    ) -> Result<UpdateWebhookResponse, E>;
+}
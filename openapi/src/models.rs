@@ -21,6 +21,13 @@ pub struct GetAuthorByIdPathParams {
     pub author_id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct UpdateAuthorHeaderParams {
+    /// The ETags of the versions the update may be based on, weak ETags never match
+    pub if_match: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct UpdateAuthorPathParams {
@@ -35,6 +42,48 @@ pub struct DeleteBookPathParams {
     pub book_id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct DeleteOrderQueryParams {
+    /// Why the order is canceled, recorded in the order history
+    #[serde(rename = "reason")]
+    #[validate(length(max = 500))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct FullTextSearchBooksHeaderParams {
+    /// ISO 4217 code of the currency to convert the prices to
+    pub accept_currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct FullTextSearchBooksQueryParams {
+    /// Words to search for, e.g. goethe faust
+    #[serde(rename = "q")]
+    #[validate(length(min = 1, max = 200))]
+    pub q: String,
+    /// Maximum number of books to return
+    #[serde(rename = "limit")]
+    #[validate(range(min = 1i32, max = 100i32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// ISO 4217 code of the currency to convert the prices to, takes precedence over the Accept-Currency header
+    #[serde(rename = "currency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetBookByIdHeaderParams {
+    /// ISO 4217 code of the currency to convert the prices to
+    pub accept_currency: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct GetBookByIdPathParams {
@@ -42,12 +91,48 @@ pub struct GetBookByIdPathParams {
     pub book_id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetBookByIdQueryParams {
+    /// ISO 4217 code of the currency to convert the prices to, takes precedence over the Accept-Currency header
+    #[serde(rename = "currency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetBooksByAuthorsHeaderParams {
+    /// ISO 4217 code of the currency to convert the prices to
+    pub accept_currency: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct GetBooksByAuthorsQueryParams {
     /// Authors to filter by
     #[serde(rename = "authors")]
     pub authors: Vec<String>,
+    /// Maximum number of books on the page
+    #[serde(rename = "limit")]
+    #[validate(range(min = 1i32, max = 100i32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// Opaque cursor of the page, the next_cursor of the previous page
+    #[serde(rename = "cursor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// ISO 4217 code of the currency to convert the prices to, takes precedence over the Accept-Currency header
+    #[serde(rename = "currency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetBooksByGenresHeaderParams {
+    /// ISO 4217 code of the currency to convert the prices to
+    pub accept_currency: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
@@ -56,6 +141,26 @@ pub struct GetBooksByGenresQueryParams {
     /// Genres to filter by
     #[serde(rename = "genres")]
     pub genres: Vec<String>,
+    /// Maximum number of books on the page
+    #[serde(rename = "limit")]
+    #[validate(range(min = 1i32, max = 100i32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// Opaque cursor of the page, the next_cursor of the previous page
+    #[serde(rename = "cursor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// ISO 4217 code of the currency to convert the prices to, takes precedence over the Accept-Currency header
+    #[serde(rename = "currency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetBooksByStatusHeaderParams {
+    /// ISO 4217 code of the currency to convert the prices to
+    pub accept_currency: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
@@ -64,6 +169,121 @@ pub struct GetBooksByStatusQueryParams {
     /// Status to filter by
     #[serde(rename = "status")]
     pub status: Vec<String>,
+    /// Maximum number of books on the page
+    #[serde(rename = "limit")]
+    #[validate(range(min = 1i32, max = 100i32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// Opaque cursor of the page, the next_cursor of the previous page
+    #[serde(rename = "cursor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// ISO 4217 code of the currency to convert the prices to, takes precedence over the Accept-Currency header
+    #[serde(rename = "currency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetOrderHistoryPathParams {
+    /// Id of order to return the history of
+    pub order_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PlaceOrderHeaderParams {
+    /// ISO 4217 code of the currency to convert the prices to
+    pub accept_currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PlaceOrderQueryParams {
+    /// ISO 4217 code of the currency to convert the prices to, takes precedence over the Accept-Currency header
+    #[serde(rename = "currency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SearchBooksHeaderParams {
+    /// ISO 4217 code of the currency to convert the prices to
+    pub accept_currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SearchBooksQueryParams {
+    /// Authors to filter by
+    #[serde(rename = "authors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authors: Option<Vec<String>>,
+    /// Genres to filter by
+    #[serde(rename = "genres")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genres: Option<Vec<String>>,
+    /// Status to filter by
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Vec<String>>,
+    /// Series to filter by
+    #[serde(rename = "series")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<String>,
+    /// Minimum price, inclusive, a decimal number like 12.50
+    #[serde(rename = "min_price")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_price: Option<String>,
+    /// Maximum price, inclusive, a decimal number like 12.50
+    #[serde(rename = "max_price")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_price: Option<String>,
+    /// Earliest release date, inclusive
+    #[serde(rename = "released_from")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub released_from: Option<chrono::naive::NaiveDate>,
+    /// Latest release date, inclusive
+    #[serde(rename = "released_to")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub released_to: Option<chrono::naive::NaiveDate>,
+    /// Case insensitive substring of the title
+    #[serde(rename = "title")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Field to sort by, the books are sorted by id if absent
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "sort")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// Sort order
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "order")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+    /// Maximum number of books on the page
+    #[serde(rename = "limit")]
+    #[validate(range(min = 1i32, max = 100i32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// Opaque cursor of the page, the next_cursor of the previous page
+    #[serde(rename = "cursor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// ISO 4217 code of the currency to convert the prices to, takes precedence over the Accept-Currency header
+    #[serde(rename = "currency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct UpdateBookHeaderParams {
+    /// The ETags of the versions the update may be based on, weak ETags never match
+    pub if_match: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
@@ -73,6 +293,52 @@ pub struct UpdateBookPathParams {
     pub book_id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct DeleteCustomerPathParams {
+    /// customer to delete
+    pub customer_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetCustomerByIdPathParams {
+    /// Id of customer to return
+    pub customer_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetCustomerOrdersPathParams {
+    /// Id of customer to return the orders of
+    pub customer_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetCustomerOrdersQueryParams {
+    /// Status to filter by, all orders if absent
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Vec<String>>,
+    /// Maximum number of orders on the page
+    #[serde(rename = "limit")]
+    #[validate(range(min = 1i32, max = 100i32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// Opaque cursor of the page, the next_cursor of the previous page
+    #[serde(rename = "cursor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct UpdateCustomerPathParams {
+    /// Id of customer to update
+    pub customer_id: String,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct DeleteDiscountPathParams {
@@ -115,6 +381,13 @@ pub struct GetOrderByIdPathParams {
     pub order_id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct UpdateOrderHeaderParams {
+    /// The ETags of the versions the update may be based on, weak ETags never match
+    pub if_match: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct UpdateOrderPathParams {
@@ -122,6 +395,52 @@ pub struct UpdateOrderPathParams {
     pub order_id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct DeleteWebhookPathParams {
+    /// webhook to delete
+    pub webhook_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetWebhookByIdPathParams {
+    /// Id of webhook to return
+    pub webhook_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetWebhookDeliveriesPathParams {
+    /// Id of webhook to return the deliveries of
+    pub webhook_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GetWebhookDeliveriesQueryParams {
+    /// Status to filter by, all deliveries if absent
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Maximum number of deliveries on the page
+    #[serde(rename = "limit")]
+    #[validate(range(min = 1i32, max = 100i32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// Opaque cursor of the page, the next_cursor of the previous page
+    #[serde(rename = "cursor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct UpdateWebhookPathParams {
+    /// Id of webhook to update
+    pub webhook_id: String,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct Address {
@@ -807,14 +1126,27 @@ pub struct Book {
     #[serde(rename = "edition")]
     pub edition: i32,
 
-    /// the price of this book in Dollar
+    /// the price of this book as decimal number in the currency of the book
     #[serde(rename = "price")]
-    pub price: f64,
+    pub price: String,
+
+    /// ISO 4217 code of the currency of the price
+    #[serde(rename = "currency")]
+    pub currency: String,
+
+    /// the kind of product the book is sold as
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "product_type")]
+    pub product_type: String,
 
     #[serde(rename = "discounts")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discounts: Option<Vec<models::DiscountCode>>,
 
+    #[serde(rename = "exchange_rate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exchange_rate: Option<models::ExchangeRate>,
+
     /// The number of available items
     #[serde(rename = "available")]
     pub available: i32,
@@ -834,7 +1166,9 @@ impl Book {
         first_release: chrono::naive::NaiveDate,
         authors: Vec<models::Author>,
         edition: i32,
-        price: f64,
+        price: String,
+        currency: String,
+        product_type: String,
         available: i32,
         status: String,
     ) -> Book {
@@ -848,7 +1182,10 @@ impl Book {
             series: None,
             edition,
             price,
+            currency,
+            product_type,
             discounts: None,
+            exchange_rate: None,
             available,
             status,
         }
@@ -879,7 +1216,13 @@ impl std::fmt::Display for Book {
             Some(self.edition.to_string()),
             Some("price".to_string()),
             Some(self.price.to_string()),
+            Some("currency".to_string()),
+            Some(self.currency.to_string()),
+            Some("product_type".to_string()),
+            Some(self.product_type.to_string()),
             // Skipping discounts in query parameter serialization
+
+            // Skipping exchange_rate in query parameter serialization
             Some("available".to_string()),
             Some(self.available.to_string()),
             Some("status".to_string()),
@@ -913,8 +1256,11 @@ impl std::str::FromStr for Book {
             pub genres: Vec<Vec<models::Genre>>,
             pub series: Vec<String>,
             pub edition: Vec<i32>,
-            pub price: Vec<f64>,
+            pub price: Vec<String>,
+            pub currency: Vec<String>,
+            pub product_type: Vec<String>,
             pub discounts: Vec<Vec<models::DiscountCode>>,
+            pub exchange_rate: Vec<models::ExchangeRate>,
             pub available: Vec<i32>,
             pub status: Vec<String>,
         }
@@ -976,7 +1322,15 @@ impl std::str::FromStr for Book {
                     ),
                     #[allow(clippy::redundant_clone)]
                     "price" => intermediate_rep.price.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "currency" => intermediate_rep.currency.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "product_type" => intermediate_rep.product_type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     "discounts" => {
                         return std::result::Result::Err(
@@ -985,6 +1339,11 @@ impl std::str::FromStr for Book {
                         )
                     }
                     #[allow(clippy::redundant_clone)]
+                    "exchange_rate" => intermediate_rep.exchange_rate.push(
+                        <models::ExchangeRate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
                     "available" => intermediate_rep.available.push(
                         <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
@@ -1043,7 +1402,18 @@ impl std::str::FromStr for Book {
                 .into_iter()
                 .next()
                 .ok_or_else(|| "price missing in Book".to_string())?,
+            currency: intermediate_rep
+                .currency
+                .into_iter()
+                .next()
+                .ok_or_else(|| "currency missing in Book".to_string())?,
+            product_type: intermediate_rep
+                .product_type
+                .into_iter()
+                .next()
+                .ok_or_else(|| "product_type missing in Book".to_string())?,
             discounts: intermediate_rep.discounts.into_iter().next(),
+            exchange_rate: intermediate_rep.exchange_rate.into_iter().next(),
             available: intermediate_rep
                 .available
                 .into_iter()
@@ -1101,132 +1471,39 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Book> {
     }
 }
 
+/// A page of books ordered by id
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct BookProperties {
-    #[serde(rename = "title")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<String>,
-
-    /// the date when this edition of the book was released
-    #[serde(rename = "release")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub release: Option<chrono::naive::NaiveDate>,
-
-    #[serde(rename = "authors")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authors: Option<Vec<String>>,
-
-    #[serde(rename = "genres")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub genres: Option<Vec<String>>,
-
-    #[serde(rename = "discount_codes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub discount_codes: Option<Vec<String>>,
-
-    #[serde(rename = "series")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub series: Option<String>,
-
-    /// the edition of this book
-    #[serde(rename = "edition")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub edition: Option<i32>,
-
-    /// the price of this book in Dollar
-    #[serde(rename = "price")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub price: Option<f64>,
-
-    /// The number of available items
-    #[serde(rename = "available")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub available: Option<i32>,
+pub struct BookPage {
+    #[serde(rename = "items")]
+    pub items: Vec<models::Book>,
 
-    /// the inventory state of the book
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "status")]
+    /// Cursor of the next page, absent on the last page
+    #[serde(rename = "next_cursor")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub next_cursor: Option<String>,
 }
 
-impl BookProperties {
+impl BookPage {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> BookProperties {
-        BookProperties {
-            title: None,
-            release: None,
-            authors: None,
-            genres: None,
-            discount_codes: None,
-            series: None,
-            edition: None,
-            price: None,
-            available: None,
-            status: None,
+    pub fn new(items: Vec<models::Book>) -> BookPage {
+        BookPage {
+            items,
+            next_cursor: None,
         }
     }
 }
 
-/// Converts the BookProperties value to the Query Parameters representation (style=form, explode=false)
+/// Converts the BookPage value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for BookProperties {
+impl std::fmt::Display for BookPage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            self.title
+            // Skipping items in query parameter serialization
+            self.next_cursor
                 .as_ref()
-                .map(|title| ["title".to_string(), title.to_string()].join(",")),
-            // Skipping release in query parameter serialization
-            self.authors.as_ref().map(|authors| {
-                [
-                    "authors".to_string(),
-                    authors
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ]
-                .join(",")
-            }),
-            self.genres.as_ref().map(|genres| {
-                [
-                    "genres".to_string(),
-                    genres
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ]
-                .join(",")
-            }),
-            self.discount_codes.as_ref().map(|discount_codes| {
-                [
-                    "discount_codes".to_string(),
-                    discount_codes
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ]
-                .join(",")
-            }),
-            self.series
-                .as_ref()
-                .map(|series| ["series".to_string(), series.to_string()].join(",")),
-            self.edition
-                .as_ref()
-                .map(|edition| ["edition".to_string(), edition.to_string()].join(",")),
-            self.price
-                .as_ref()
-                .map(|price| ["price".to_string(), price.to_string()].join(",")),
-            self.available
-                .as_ref()
-                .map(|available| ["available".to_string(), available.to_string()].join(",")),
-            self.status
-                .as_ref()
-                .map(|status| ["status".to_string(), status.to_string()].join(",")),
+                .map(|next_cursor| ["next_cursor".to_string(), next_cursor.to_string()].join(",")),
         ];
 
         write!(
@@ -1237,10 +1514,10 @@ impl std::fmt::Display for BookProperties {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a BookProperties value
+/// Converts Query Parameters representation (style=form, explode=false) to a BookPage value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for BookProperties {
+impl std::str::FromStr for BookPage {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1248,16 +1525,8 @@ impl std::str::FromStr for BookProperties {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub title: Vec<String>,
-            pub release: Vec<chrono::naive::NaiveDate>,
-            pub authors: Vec<Vec<String>>,
-            pub genres: Vec<Vec<String>>,
-            pub discount_codes: Vec<Vec<String>>,
-            pub series: Vec<String>,
-            pub edition: Vec<i32>,
-            pub price: Vec<f64>,
-            pub available: Vec<i32>,
-            pub status: Vec<String>,
+            pub items: Vec<Vec<models::Book>>,
+            pub next_cursor: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1271,7 +1540,7 @@ impl std::str::FromStr for BookProperties {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing BookProperties".to_string(),
+                        "Missing value while parsing BookPage".to_string(),
                     )
                 }
             };
@@ -1279,56 +1548,19 @@ impl std::str::FromStr for BookProperties {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "title" => intermediate_rep.title.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "release" => intermediate_rep.release.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    "authors" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in BookProperties"
-                                .to_string(),
-                        )
-                    }
-                    "genres" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in BookProperties"
-                                .to_string(),
-                        )
-                    }
-                    "discount_codes" => {
+                    "items" => {
                         return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in BookProperties"
+                            "Parsing a container in this style is not supported in BookPage"
                                 .to_string(),
                         )
                     }
                     #[allow(clippy::redundant_clone)]
-                    "series" => intermediate_rep.series.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "edition" => intermediate_rep.edition.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "price" => intermediate_rep.price.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "available" => intermediate_rep.available.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
+                    "next_cursor" => intermediate_rep.next_cursor.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing BookProperties".to_string(),
+                            "Unexpected key while parsing BookPage".to_string(),
                         )
                     }
                 }
@@ -1339,35 +1571,31 @@ impl std::str::FromStr for BookProperties {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(BookProperties {
-            title: intermediate_rep.title.into_iter().next(),
-            release: intermediate_rep.release.into_iter().next(),
-            authors: intermediate_rep.authors.into_iter().next(),
-            genres: intermediate_rep.genres.into_iter().next(),
-            discount_codes: intermediate_rep.discount_codes.into_iter().next(),
-            series: intermediate_rep.series.into_iter().next(),
-            edition: intermediate_rep.edition.into_iter().next(),
-            price: intermediate_rep.price.into_iter().next(),
-            available: intermediate_rep.available.into_iter().next(),
-            status: intermediate_rep.status.into_iter().next(),
+        std::result::Result::Ok(BookPage {
+            items: intermediate_rep
+                .items
+                .into_iter()
+                .next()
+                .ok_or_else(|| "items missing in BookPage".to_string())?,
+            next_cursor: intermediate_rep.next_cursor.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<BookProperties> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<BookPage> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<BookProperties>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<BookPage>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<BookProperties>,
+        hdr_value: header::IntoHeaderValue<BookPage>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for BookProperties - value: {} is invalid {}",
+                "Invalid header value for BookPage - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1375,18 +1603,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<BookProperties>> for HeaderVa
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BookProperties> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BookPage> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <BookProperties as std::str::FromStr>::from_str(value) {
+                match <BookPage as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into BookProperties - {}",
+                        "Unable to convert header value '{}' into BookPage - {}",
                         value, err
                     )),
                 }
@@ -1399,60 +1627,44 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BookProperti
     }
 }
 
+/// A book found by the full text search
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct DiscountCode {
-    #[serde(rename = "id")]
-    pub id: String,
-
-    #[serde(rename = "percentage_discount")]
-    #[validate(range(min = 0u8, max = 100u8))]
-    pub percentage_discount: u8,
+pub struct BookSearchHit {
+    #[serde(rename = "book")]
+    pub book: models::Book,
 
-    #[serde(rename = "valid_from")]
-    pub valid_from: chrono::naive::NaiveDate,
-
-    #[serde(rename = "valid_to")]
-    pub valid_to: chrono::naive::NaiveDate,
+    /// Relevance of the book, higher ranks match better
+    #[serde(rename = "rank")]
+    pub rank: f32,
 
-    #[serde(rename = "code")]
-    pub code: String,
+    /// HTML escaped title and series of the book with the matched words enclosed in <mark> tags
+    #[serde(rename = "snippet")]
+    pub snippet: String,
 }
 
-impl DiscountCode {
+impl BookSearchHit {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        id: String,
-        percentage_discount: u8,
-        valid_from: chrono::naive::NaiveDate,
-        valid_to: chrono::naive::NaiveDate,
-        code: String,
-    ) -> DiscountCode {
-        DiscountCode {
-            id,
-            percentage_discount,
-            valid_from,
-            valid_to,
-            code,
+    pub fn new(book: models::Book, rank: f32, snippet: String) -> BookSearchHit {
+        BookSearchHit {
+            book,
+            rank,
+            snippet,
         }
     }
 }
 
-/// Converts the DiscountCode value to the Query Parameters representation (style=form, explode=false)
+/// Converts the BookSearchHit value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for DiscountCode {
+impl std::fmt::Display for BookSearchHit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("id".to_string()),
-            Some(self.id.to_string()),
-            Some("percentage_discount".to_string()),
-            Some(self.percentage_discount.to_string()),
-            // Skipping valid_from in query parameter serialization
-
-            // Skipping valid_to in query parameter serialization
-            Some("code".to_string()),
-            Some(self.code.to_string()),
+            // Skipping book in query parameter serialization
+            Some("rank".to_string()),
+            Some(self.rank.to_string()),
+            Some("snippet".to_string()),
+            Some(self.snippet.to_string()),
         ];
 
         write!(
@@ -1463,10 +1675,10 @@ impl std::fmt::Display for DiscountCode {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a DiscountCode value
+/// Converts Query Parameters representation (style=form, explode=false) to a BookSearchHit value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for DiscountCode {
+impl std::str::FromStr for BookSearchHit {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1474,11 +1686,9 @@ impl std::str::FromStr for DiscountCode {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub id: Vec<String>,
-            pub percentage_discount: Vec<u8>,
-            pub valid_from: Vec<chrono::naive::NaiveDate>,
-            pub valid_to: Vec<chrono::naive::NaiveDate>,
-            pub code: Vec<String>,
+            pub book: Vec<models::Book>,
+            pub rank: Vec<f32>,
+            pub snippet: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1492,7 +1702,7 @@ impl std::str::FromStr for DiscountCode {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing DiscountCode".to_string(),
+                        "Missing value while parsing BookSearchHit".to_string(),
                     )
                 }
             };
@@ -1501,30 +1711,21 @@ impl std::str::FromStr for DiscountCode {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "percentage_discount" => intermediate_rep
-                        .percentage_discount
-                        .push(<u8 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "valid_from" => intermediate_rep.valid_from.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                    "book" => intermediate_rep.book.push(
+                        <models::Book as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "valid_to" => intermediate_rep.valid_to.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "rank" => intermediate_rep.rank.push(
+                        <f32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
+                    "snippet" => intermediate_rep.snippet.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing DiscountCode".to_string(),
+                            "Unexpected key while parsing BookSearchHit".to_string(),
                         )
                     }
                 }
@@ -1535,50 +1736,40 @@ impl std::str::FromStr for DiscountCode {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(DiscountCode {
-            id: intermediate_rep
-                .id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "id missing in DiscountCode".to_string())?,
-            percentage_discount: intermediate_rep
-                .percentage_discount
-                .into_iter()
-                .next()
-                .ok_or_else(|| "percentage_discount missing in DiscountCode".to_string())?,
-            valid_from: intermediate_rep
-                .valid_from
+        std::result::Result::Ok(BookSearchHit {
+            book: intermediate_rep
+                .book
                 .into_iter()
                 .next()
-                .ok_or_else(|| "valid_from missing in DiscountCode".to_string())?,
-            valid_to: intermediate_rep
-                .valid_to
+                .ok_or_else(|| "book missing in BookSearchHit".to_string())?,
+            rank: intermediate_rep
+                .rank
                 .into_iter()
                 .next()
-                .ok_or_else(|| "valid_to missing in DiscountCode".to_string())?,
-            code: intermediate_rep
-                .code
+                .ok_or_else(|| "rank missing in BookSearchHit".to_string())?,
+            snippet: intermediate_rep
+                .snippet
                 .into_iter()
                 .next()
-                .ok_or_else(|| "code missing in DiscountCode".to_string())?,
+                .ok_or_else(|| "snippet missing in BookSearchHit".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<DiscountCode> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<BookSearchHit> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<DiscountCode>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<BookSearchHit>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<DiscountCode>,
+        hdr_value: header::IntoHeaderValue<BookSearchHit>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for DiscountCode - value: {} is invalid {}",
+                "Invalid header value for BookSearchHit - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1586,18 +1777,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<DiscountCode>> for HeaderValu
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<DiscountCode> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BookSearchHit> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <DiscountCode as std::str::FromStr>::from_str(value) {
+                match <BookSearchHit as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into DiscountCode - {}",
+                        "Unable to convert header value '{}' into BookSearchHit - {}",
                         value, err
                     )),
                 }
@@ -1610,33 +1801,28 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<DiscountCode
     }
 }
 
+/// The books found by the full text search ordered by rank
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Genre {
-    #[serde(rename = "id")]
-    pub id: String,
-
-    #[serde(rename = "name")]
-    pub name: String,
+pub struct BookSearchResult {
+    #[serde(rename = "items")]
+    pub items: Vec<models::BookSearchHit>,
 }
 
-impl Genre {
+impl BookSearchResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(id: String, name: String) -> Genre {
-        Genre { id, name }
+    pub fn new(items: Vec<models::BookSearchHit>) -> BookSearchResult {
+        BookSearchResult { items }
     }
 }
 
-/// Converts the Genre value to the Query Parameters representation (style=form, explode=false)
+/// Converts the BookSearchResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for Genre {
+impl std::fmt::Display for BookSearchResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("id".to_string()),
-            Some(self.id.to_string()),
-            Some("name".to_string()),
-            Some(self.name.to_string()),
+            // Skipping items in query parameter serialization
         ];
 
         write!(
@@ -1647,10 +1833,10 @@ impl std::fmt::Display for Genre {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Genre value
+/// Converts Query Parameters representation (style=form, explode=false) to a BookSearchResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Genre {
+impl std::str::FromStr for BookSearchResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1658,8 +1844,7 @@ impl std::str::FromStr for Genre {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub id: Vec<String>,
-            pub name: Vec<String>,
+            pub items: Vec<Vec<models::BookSearchHit>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1673,7 +1858,7 @@ impl std::str::FromStr for Genre {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing Genre".to_string(),
+                        "Missing value while parsing BookSearchResult".to_string(),
                     )
                 }
             };
@@ -1681,17 +1866,13 @@ impl std::str::FromStr for Genre {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "items" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in BookSearchResult"
+                            .to_string(),
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing Genre".to_string(),
+                            "Unexpected key while parsing BookSearchResult".to_string(),
                         )
                     }
                 }
@@ -1702,35 +1883,30 @@ impl std::str::FromStr for Genre {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Genre {
-            id: intermediate_rep
-                .id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "id missing in Genre".to_string())?,
-            name: intermediate_rep
-                .name
+        std::result::Result::Ok(BookSearchResult {
+            items: intermediate_rep
+                .items
                 .into_iter()
                 .next()
-                .ok_or_else(|| "name missing in Genre".to_string())?,
+                .ok_or_else(|| "items missing in BookSearchResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Genre> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<BookSearchResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<Genre>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<BookSearchResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<Genre>,
+        hdr_value: header::IntoHeaderValue<BookSearchResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for Genre - value: {} is invalid {}",
+                "Invalid header value for BookSearchResult - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1738,20 +1914,22 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Genre>> for HeaderValue {
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Genre> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BookSearchResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-            std::result::Result::Ok(value) => match <Genre as std::str::FromStr>::from_str(value) {
-                std::result::Result::Ok(value) => {
-                    std::result::Result::Ok(header::IntoHeaderValue(value))
+            std::result::Result::Ok(value) => {
+                match <BookSearchResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into BookSearchResult - {}",
+                        value, err
+                    )),
                 }
-                std::result::Result::Err(err) => std::result::Result::Err(format!(
-                    "Unable to convert header value '{}' into Genre - {}",
-                    value, err
-                )),
-            },
+            }
             std::result::Result::Err(e) => std::result::Result::Err(format!(
                 "Unable to convert header: {:?} to string: {}",
                 hdr_value, e
@@ -1762,29 +1940,4213 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Genre> {
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct HealthCheckResponse {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "status")]
+pub struct BookProperties {
+    #[serde(rename = "title")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// the date when this edition of the book was released
+    #[serde(rename = "release")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release: Option<chrono::naive::NaiveDate>,
+
+    #[serde(rename = "authors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authors: Option<Vec<String>>,
+
+    #[serde(rename = "genres")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genres: Option<Vec<String>>,
+
+    #[serde(rename = "discount_codes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_codes: Option<Vec<String>>,
+
+    #[serde(rename = "series")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<String>,
+
+    /// the edition of this book
+    #[serde(rename = "edition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edition: Option<i32>,
+
+    /// the price of this book as decimal number, e.g. 12.50
+    #[serde(rename = "price")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+
+    /// ISO 4217 code of the currency of the price, USD if not given
+    #[serde(rename = "currency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+
+    /// the kind of product the book is sold as, print if not given
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "product_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<String>,
+
+    /// The number of available items
+    #[serde(rename = "available")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available: Option<i32>,
+
+    /// the inventory state of the book
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl BookProperties {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> BookProperties {
+        BookProperties {
+            title: None,
+            release: None,
+            authors: None,
+            genres: None,
+            discount_codes: None,
+            series: None,
+            edition: None,
+            price: None,
+            currency: None,
+            product_type: None,
+            available: None,
+            status: None,
+        }
+    }
+}
+
+/// Converts the BookProperties value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for BookProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.title
+                .as_ref()
+                .map(|title| ["title".to_string(), title.to_string()].join(",")),
+            // Skipping release in query parameter serialization
+            self.authors.as_ref().map(|authors| {
+                [
+                    "authors".to_string(),
+                    authors
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+            self.genres.as_ref().map(|genres| {
+                [
+                    "genres".to_string(),
+                    genres
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+            self.discount_codes.as_ref().map(|discount_codes| {
+                [
+                    "discount_codes".to_string(),
+                    discount_codes
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+            self.series
+                .as_ref()
+                .map(|series| ["series".to_string(), series.to_string()].join(",")),
+            self.edition
+                .as_ref()
+                .map(|edition| ["edition".to_string(), edition.to_string()].join(",")),
+            self.price
+                .as_ref()
+                .map(|price| ["price".to_string(), price.to_string()].join(",")),
+            self.currency
+                .as_ref()
+                .map(|currency| ["currency".to_string(), currency.to_string()].join(",")),
+            self.product_type.as_ref().map(|product_type| {
+                ["product_type".to_string(), product_type.to_string()].join(",")
+            }),
+            self.available
+                .as_ref()
+                .map(|available| ["available".to_string(), available.to_string()].join(",")),
+            self.status
+                .as_ref()
+                .map(|status| ["status".to_string(), status.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a BookProperties value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for BookProperties {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub title: Vec<String>,
+            pub release: Vec<chrono::naive::NaiveDate>,
+            pub authors: Vec<Vec<String>>,
+            pub genres: Vec<Vec<String>>,
+            pub discount_codes: Vec<Vec<String>>,
+            pub series: Vec<String>,
+            pub edition: Vec<i32>,
+            pub price: Vec<String>,
+            pub currency: Vec<String>,
+            pub product_type: Vec<String>,
+            pub available: Vec<i32>,
+            pub status: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing BookProperties".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "release" => intermediate_rep.release.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "authors" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in BookProperties"
+                                .to_string(),
+                        )
+                    }
+                    "genres" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in BookProperties"
+                                .to_string(),
+                        )
+                    }
+                    "discount_codes" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in BookProperties"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "series" => intermediate_rep.series.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "edition" => intermediate_rep.edition.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "price" => intermediate_rep.price.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "currency" => intermediate_rep.currency.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "product_type" => intermediate_rep.product_type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "available" => intermediate_rep.available.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing BookProperties".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(BookProperties {
+            title: intermediate_rep.title.into_iter().next(),
+            release: intermediate_rep.release.into_iter().next(),
+            authors: intermediate_rep.authors.into_iter().next(),
+            genres: intermediate_rep.genres.into_iter().next(),
+            discount_codes: intermediate_rep.discount_codes.into_iter().next(),
+            series: intermediate_rep.series.into_iter().next(),
+            edition: intermediate_rep.edition.into_iter().next(),
+            price: intermediate_rep.price.into_iter().next(),
+            currency: intermediate_rep.currency.into_iter().next(),
+            product_type: intermediate_rep.product_type.into_iter().next(),
+            available: intermediate_rep.available.into_iter().next(),
+            status: intermediate_rep.status.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<BookProperties> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<BookProperties>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<BookProperties>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for BookProperties - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BookProperties> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <BookProperties as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into BookProperties - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// A customer with the saved addresses of the address book
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Customer {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "email")]
+    pub email: String,
+
+    /// The address book of the customer
+    #[serde(rename = "addresses")]
+    pub addresses: Vec<models::Address>,
+}
+
+impl Customer {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        name: String,
+        email: String,
+        addresses: Vec<models::Address>,
+    ) -> Customer {
+        Customer {
+            id,
+            name,
+            email,
+            addresses,
+        }
+    }
+}
+
+/// Converts the Customer value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Customer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("email".to_string()),
+            Some(self.email.to_string()),
+            // Skipping addresses in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Customer value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Customer {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<String>,
+            pub name: Vec<String>,
+            pub email: Vec<String>,
+            pub addresses: Vec<Vec<models::Address>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing Customer".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "email" => intermediate_rep.email.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "addresses" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Customer"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Customer".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Customer {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in Customer".to_string())?,
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in Customer".to_string())?,
+            email: intermediate_rep
+                .email
+                .into_iter()
+                .next()
+                .ok_or_else(|| "email missing in Customer".to_string())?,
+            addresses: intermediate_rep
+                .addresses
+                .into_iter()
+                .next()
+                .ok_or_else(|| "addresses missing in Customer".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Customer> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Customer>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Customer>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Customer - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Customer> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <Customer as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into Customer - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CustomerProperties {
+    #[serde(rename = "name")]
+    #[validate(length(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(rename = "email")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Replaces the whole address book of the customer
+    #[serde(rename = "addresses")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addresses: Option<Vec<models::Address>>,
+}
+
+impl CustomerProperties {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> CustomerProperties {
+        CustomerProperties {
+            name: None,
+            email: None,
+            addresses: None,
+        }
+    }
+}
+
+/// Converts the CustomerProperties value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CustomerProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.name
+                .as_ref()
+                .map(|name| ["name".to_string(), name.to_string()].join(",")),
+            self.email
+                .as_ref()
+                .map(|email| ["email".to_string(), email.to_string()].join(",")),
+            // Skipping addresses in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CustomerProperties value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CustomerProperties {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub email: Vec<String>,
+            pub addresses: Vec<Vec<models::Address>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CustomerProperties".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "email" => intermediate_rep.email.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "addresses" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in CustomerProperties"
+                            .to_string(),
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CustomerProperties".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CustomerProperties {
+            name: intermediate_rep.name.into_iter().next(),
+            email: intermediate_rep.email.into_iter().next(),
+            addresses: intermediate_rep.addresses.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CustomerProperties> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CustomerProperties>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CustomerProperties>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for CustomerProperties - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CustomerProperties> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CustomerProperties as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into CustomerProperties - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct DiscountCode {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    #[serde(rename = "percentage_discount")]
+    #[validate(range(min = 0u8, max = 100u8))]
+    pub percentage_discount: u8,
+
+    #[serde(rename = "valid_from")]
+    pub valid_from: chrono::naive::NaiveDate,
+
+    #[serde(rename = "valid_to")]
+    pub valid_to: chrono::naive::NaiveDate,
+
+    #[serde(rename = "code")]
+    pub code: String,
+}
+
+impl DiscountCode {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        percentage_discount: u8,
+        valid_from: chrono::naive::NaiveDate,
+        valid_to: chrono::naive::NaiveDate,
+        code: String,
+    ) -> DiscountCode {
+        DiscountCode {
+            id,
+            percentage_discount,
+            valid_from,
+            valid_to,
+            code,
+        }
+    }
+}
+
+/// Converts the DiscountCode value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for DiscountCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("percentage_discount".to_string()),
+            Some(self.percentage_discount.to_string()),
+            // Skipping valid_from in query parameter serialization
+
+            // Skipping valid_to in query parameter serialization
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a DiscountCode value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for DiscountCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<String>,
+            pub percentage_discount: Vec<u8>,
+            pub valid_from: Vec<chrono::naive::NaiveDate>,
+            pub valid_to: Vec<chrono::naive::NaiveDate>,
+            pub code: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing DiscountCode".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "percentage_discount" => intermediate_rep
+                        .percentage_discount
+                        .push(<u8 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "valid_from" => intermediate_rep.valid_from.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "valid_to" => intermediate_rep.valid_to.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing DiscountCode".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(DiscountCode {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in DiscountCode".to_string())?,
+            percentage_discount: intermediate_rep
+                .percentage_discount
+                .into_iter()
+                .next()
+                .ok_or_else(|| "percentage_discount missing in DiscountCode".to_string())?,
+            valid_from: intermediate_rep
+                .valid_from
+                .into_iter()
+                .next()
+                .ok_or_else(|| "valid_from missing in DiscountCode".to_string())?,
+            valid_to: intermediate_rep
+                .valid_to
+                .into_iter()
+                .next()
+                .ok_or_else(|| "valid_to missing in DiscountCode".to_string())?,
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in DiscountCode".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<DiscountCode> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<DiscountCode>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<DiscountCode>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for DiscountCode - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<DiscountCode> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <DiscountCode as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into DiscountCode - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// The rate the price was converted with from the currency of the catalog
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExchangeRate {
+    /// ISO 4217 code of the currency of the catalog price
+    #[serde(rename = "source_currency")]
+    pub source_currency: String,
+
+    /// ISO 4217 code of the currency the price was converted to
+    #[serde(rename = "currency")]
+    pub currency: String,
+
+    /// the units of the currency one unit of the source currency buys
+    #[serde(rename = "rate")]
+    pub rate: String,
+
+    /// the time the rate was last updated
+    #[serde(rename = "updated_at")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ExchangeRate {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        source_currency: String,
+        currency: String,
+        rate: String,
+        updated_at: chrono::DateTime<chrono::Utc>,
+    ) -> ExchangeRate {
+        ExchangeRate {
+            source_currency,
+            currency,
+            rate,
+            updated_at,
+        }
+    }
+}
+
+/// Converts the ExchangeRate value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExchangeRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("source_currency".to_string()),
+            Some(self.source_currency.to_string()),
+            Some("currency".to_string()),
+            Some(self.currency.to_string()),
+            Some("rate".to_string()),
+            Some(self.rate.to_string()),
+            // Skipping updated_at in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExchangeRate value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExchangeRate {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub source_currency: Vec<String>,
+            pub currency: Vec<String>,
+            pub rate: Vec<String>,
+            pub updated_at: Vec<chrono::DateTime<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExchangeRate".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "source_currency" => intermediate_rep.source_currency.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "currency" => intermediate_rep.currency.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "rate" => intermediate_rep.rate.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "updated_at" => intermediate_rep.updated_at.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExchangeRate".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExchangeRate {
+            source_currency: intermediate_rep
+                .source_currency
+                .into_iter()
+                .next()
+                .ok_or_else(|| "source_currency missing in ExchangeRate".to_string())?,
+            currency: intermediate_rep
+                .currency
+                .into_iter()
+                .next()
+                .ok_or_else(|| "currency missing in ExchangeRate".to_string())?,
+            rate: intermediate_rep
+                .rate
+                .into_iter()
+                .next()
+                .ok_or_else(|| "rate missing in ExchangeRate".to_string())?,
+            updated_at: intermediate_rep
+                .updated_at
+                .into_iter()
+                .next()
+                .ok_or_else(|| "updated_at missing in ExchangeRate".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExchangeRate> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExchangeRate>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExchangeRate>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for ExchangeRate - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExchangeRate> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExchangeRate as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into ExchangeRate - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Genre {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+impl Genre {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(id: String, name: String) -> Genre {
+        Genre { id, name }
+    }
+}
+
+/// Converts the Genre value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Genre {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Genre value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Genre {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<String>,
+            pub name: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing Genre".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Genre".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Genre {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in Genre".to_string())?,
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in Genre".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Genre> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Genre>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Genre>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Genre - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Genre> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => match <Genre as std::str::FromStr>::from_str(value) {
+                std::result::Result::Ok(value) => {
+                    std::result::Result::Ok(header::IntoHeaderValue(value))
+                }
+                std::result::Result::Err(err) => std::result::Result::Err(format!(
+                    "Unable to convert header value '{}' into Genre - {}",
+                    value, err
+                )),
+            },
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// The result of checking a dependency of the service
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct HealthCheck {
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "status")]
+    pub status: String,
+
+    /// Why the check is down
+    #[serde(rename = "detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl HealthCheck {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(name: String, status: String) -> HealthCheck {
+        HealthCheck {
+            name,
+            status,
+            detail: None,
+        }
+    }
+}
+
+/// Converts the HealthCheck value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for HealthCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            self.detail
+                .as_ref()
+                .map(|detail| ["detail".to_string(), detail.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a HealthCheck value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for HealthCheck {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub status: Vec<String>,
+            pub detail: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing HealthCheck".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "detail" => intermediate_rep.detail.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing HealthCheck".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(HealthCheck {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in HealthCheck".to_string())?,
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in HealthCheck".to_string())?,
+            detail: intermediate_rep.detail.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<HealthCheck> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<HealthCheck>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<HealthCheck>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for HealthCheck - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<HealthCheck> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <HealthCheck as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into HealthCheck - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct HealthCheckResponse {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    #[serde(rename = "checks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checks: Option<Vec<models::HealthCheck>>,
+}
+
+impl HealthCheckResponse {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> HealthCheckResponse {
+        HealthCheckResponse {
+            status: None,
+            checks: None,
+        }
+    }
+}
+
+/// Converts the HealthCheckResponse value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for HealthCheckResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.status
+                .as_ref()
+                .map(|status| ["status".to_string(), status.to_string()].join(",")),
+            // Skipping checks in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a HealthCheckResponse value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for HealthCheckResponse {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub status: Vec<String>,
+            pub checks: Vec<Vec<models::HealthCheck>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing HealthCheckResponse".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "checks" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in HealthCheckResponse"
+                            .to_string(),
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing HealthCheckResponse".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(HealthCheckResponse {
+            status: intermediate_rep.status.into_iter().next(),
+            checks: intermediate_rep.checks.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<HealthCheckResponse> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<HealthCheckResponse>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<HealthCheckResponse>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for HealthCheckResponse - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<HealthCheckResponse> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <HealthCheckResponse as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into HealthCheckResponse - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Inventory {
+    #[serde(rename = "books_available")]
+    pub books_available: i32,
+
+    #[serde(rename = "books_reordered")]
+    pub books_reordered: i32,
+
+    #[serde(rename = "books_out_of_stock")]
+    pub books_out_of_stock: i32,
+}
+
+impl Inventory {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(books_available: i32, books_reordered: i32, books_out_of_stock: i32) -> Inventory {
+        Inventory {
+            books_available,
+            books_reordered,
+            books_out_of_stock,
+        }
+    }
+}
+
+/// Converts the Inventory value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Inventory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("books_available".to_string()),
+            Some(self.books_available.to_string()),
+            Some("books_reordered".to_string()),
+            Some(self.books_reordered.to_string()),
+            Some("books_out_of_stock".to_string()),
+            Some(self.books_out_of_stock.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Inventory value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Inventory {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub books_available: Vec<i32>,
+            pub books_reordered: Vec<i32>,
+            pub books_out_of_stock: Vec<i32>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing Inventory".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "books_available" => intermediate_rep.books_available.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "books_reordered" => intermediate_rep.books_reordered.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "books_out_of_stock" => intermediate_rep.books_out_of_stock.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Inventory".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Inventory {
+            books_available: intermediate_rep
+                .books_available
+                .into_iter()
+                .next()
+                .ok_or_else(|| "books_available missing in Inventory".to_string())?,
+            books_reordered: intermediate_rep
+                .books_reordered
+                .into_iter()
+                .next()
+                .ok_or_else(|| "books_reordered missing in Inventory".to_string())?,
+            books_out_of_stock: intermediate_rep
+                .books_out_of_stock
+                .into_iter()
+                .next()
+                .ok_or_else(|| "books_out_of_stock missing in Inventory".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Inventory> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Inventory>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Inventory>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Inventory - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Inventory> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <Inventory as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into Inventory - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NewAuthor {
+    #[serde(rename = "title")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(rename = "first_name")]
+    pub first_name: String,
+
+    #[serde(rename = "second_names")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub second_names: Option<Vec<String>>,
+
+    #[serde(rename = "last_name")]
+    pub last_name: String,
+
+    #[serde(rename = "date_of_birth")]
+    pub date_of_birth: chrono::naive::NaiveDate,
+
+    #[serde(rename = "date_of_death")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_death: Option<chrono::naive::NaiveDate>,
+}
+
+impl NewAuthor {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        first_name: String,
+        last_name: String,
+        date_of_birth: chrono::naive::NaiveDate,
+    ) -> NewAuthor {
+        NewAuthor {
+            title: None,
+            first_name,
+            second_names: None,
+            last_name,
+            date_of_birth,
+            date_of_death: None,
+        }
+    }
+}
+
+/// Converts the NewAuthor value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NewAuthor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.title
+                .as_ref()
+                .map(|title| ["title".to_string(), title.to_string()].join(",")),
+            Some("first_name".to_string()),
+            Some(self.first_name.to_string()),
+            self.second_names.as_ref().map(|second_names| {
+                [
+                    "second_names".to_string(),
+                    second_names
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+            Some("last_name".to_string()),
+            Some(self.last_name.to_string()),
+            // Skipping date_of_birth in query parameter serialization
+
+            // Skipping date_of_death in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NewAuthor value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NewAuthor {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub title: Vec<String>,
+            pub first_name: Vec<String>,
+            pub second_names: Vec<Vec<String>>,
+            pub last_name: Vec<String>,
+            pub date_of_birth: Vec<chrono::naive::NaiveDate>,
+            pub date_of_death: Vec<chrono::naive::NaiveDate>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NewAuthor".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "first_name" => intermediate_rep.first_name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "second_names" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in NewAuthor"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "last_name" => intermediate_rep.last_name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "date_of_birth" => intermediate_rep.date_of_birth.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "date_of_death" => intermediate_rep.date_of_death.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NewAuthor".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NewAuthor {
+            title: intermediate_rep.title.into_iter().next(),
+            first_name: intermediate_rep
+                .first_name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "first_name missing in NewAuthor".to_string())?,
+            second_names: intermediate_rep.second_names.into_iter().next(),
+            last_name: intermediate_rep
+                .last_name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "last_name missing in NewAuthor".to_string())?,
+            date_of_birth: intermediate_rep
+                .date_of_birth
+                .into_iter()
+                .next()
+                .ok_or_else(|| "date_of_birth missing in NewAuthor".to_string())?,
+            date_of_death: intermediate_rep.date_of_death.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NewAuthor> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NewAuthor>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NewAuthor>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NewAuthor - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewAuthor> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NewAuthor as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NewAuthor - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NewBook {
+    #[serde(rename = "title")]
+    pub title: String,
+
+    /// the date when this edition of the book was released
+    #[serde(rename = "release")]
+    pub release: chrono::naive::NaiveDate,
+
+    /// the date when the first edition of the book was release
+    #[serde(rename = "first_release")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_release: Option<chrono::naive::NaiveDate>,
+
+    #[serde(rename = "authors")]
+    pub authors: Vec<String>,
+
+    #[serde(rename = "genres")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genres: Option<Vec<String>>,
+
+    #[serde(rename = "discount_codes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_codes: Option<Vec<String>>,
+
+    #[serde(rename = "series")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<String>,
+
+    /// the edition of this book
+    #[serde(rename = "edition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edition: Option<i32>,
+
+    /// the price of this book as decimal number, e.g. 12.50
+    #[serde(rename = "price")]
+    pub price: String,
+
+    /// ISO 4217 code of the currency of the price, USD if not given
+    #[serde(rename = "currency")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+
+    /// the kind of product the book is sold as, print if not given
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "product_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<String>,
+
+    /// The number of available items
+    #[serde(rename = "available")]
+    pub available: i32,
+}
+
+impl NewBook {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        title: String,
+        release: chrono::naive::NaiveDate,
+        authors: Vec<String>,
+        price: String,
+        available: i32,
+    ) -> NewBook {
+        NewBook {
+            title,
+            release,
+            first_release: None,
+            authors,
+            genres: None,
+            discount_codes: None,
+            series: None,
+            edition: None,
+            price,
+            currency: None,
+            product_type: None,
+            available,
+        }
+    }
+}
+
+/// Converts the NewBook value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NewBook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            // Skipping release in query parameter serialization
+
+            // Skipping first_release in query parameter serialization
+            Some("authors".to_string()),
+            Some(
+                self.authors
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            self.genres.as_ref().map(|genres| {
+                [
+                    "genres".to_string(),
+                    genres
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+            self.discount_codes.as_ref().map(|discount_codes| {
+                [
+                    "discount_codes".to_string(),
+                    discount_codes
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+            self.series
+                .as_ref()
+                .map(|series| ["series".to_string(), series.to_string()].join(",")),
+            self.edition
+                .as_ref()
+                .map(|edition| ["edition".to_string(), edition.to_string()].join(",")),
+            Some("price".to_string()),
+            Some(self.price.to_string()),
+            self.currency
+                .as_ref()
+                .map(|currency| ["currency".to_string(), currency.to_string()].join(",")),
+            self.product_type.as_ref().map(|product_type| {
+                ["product_type".to_string(), product_type.to_string()].join(",")
+            }),
+            Some("available".to_string()),
+            Some(self.available.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NewBook value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NewBook {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub title: Vec<String>,
+            pub release: Vec<chrono::naive::NaiveDate>,
+            pub first_release: Vec<chrono::naive::NaiveDate>,
+            pub authors: Vec<Vec<String>>,
+            pub genres: Vec<Vec<String>>,
+            pub discount_codes: Vec<Vec<String>>,
+            pub series: Vec<String>,
+            pub edition: Vec<i32>,
+            pub price: Vec<String>,
+            pub currency: Vec<String>,
+            pub product_type: Vec<String>,
+            pub available: Vec<i32>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NewBook".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "release" => intermediate_rep.release.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "first_release" => intermediate_rep.first_release.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "authors" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in NewBook"
+                                .to_string(),
+                        )
+                    }
+                    "genres" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in NewBook"
+                                .to_string(),
+                        )
+                    }
+                    "discount_codes" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in NewBook"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "series" => intermediate_rep.series.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "edition" => intermediate_rep.edition.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "price" => intermediate_rep.price.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "currency" => intermediate_rep.currency.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "product_type" => intermediate_rep.product_type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "available" => intermediate_rep.available.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NewBook".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NewBook {
+            title: intermediate_rep
+                .title
+                .into_iter()
+                .next()
+                .ok_or_else(|| "title missing in NewBook".to_string())?,
+            release: intermediate_rep
+                .release
+                .into_iter()
+                .next()
+                .ok_or_else(|| "release missing in NewBook".to_string())?,
+            first_release: intermediate_rep.first_release.into_iter().next(),
+            authors: intermediate_rep
+                .authors
+                .into_iter()
+                .next()
+                .ok_or_else(|| "authors missing in NewBook".to_string())?,
+            genres: intermediate_rep.genres.into_iter().next(),
+            discount_codes: intermediate_rep.discount_codes.into_iter().next(),
+            series: intermediate_rep.series.into_iter().next(),
+            edition: intermediate_rep.edition.into_iter().next(),
+            price: intermediate_rep
+                .price
+                .into_iter()
+                .next()
+                .ok_or_else(|| "price missing in NewBook".to_string())?,
+            currency: intermediate_rep.currency.into_iter().next(),
+            product_type: intermediate_rep.product_type.into_iter().next(),
+            available: intermediate_rep
+                .available
+                .into_iter()
+                .next()
+                .ok_or_else(|| "available missing in NewBook".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NewBook> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NewBook>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NewBook>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NewBook - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewBook> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NewBook as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NewBook - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NewCustomer {
+    #[serde(rename = "name")]
+    #[validate(length(min = 1))]
+    pub name: String,
+
+    #[serde(rename = "email")]
+    pub email: String,
+
+    /// The address book of the customer
+    #[serde(rename = "addresses")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addresses: Option<Vec<models::Address>>,
+}
+
+impl NewCustomer {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(name: String, email: String) -> NewCustomer {
+        NewCustomer {
+            name,
+            email,
+            addresses: None,
+        }
+    }
+}
+
+/// Converts the NewCustomer value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NewCustomer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("email".to_string()),
+            Some(self.email.to_string()),
+            // Skipping addresses in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NewCustomer value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NewCustomer {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub email: Vec<String>,
+            pub addresses: Vec<Vec<models::Address>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NewCustomer".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "email" => intermediate_rep.email.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "addresses" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in NewCustomer"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NewCustomer".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NewCustomer {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in NewCustomer".to_string())?,
+            email: intermediate_rep
+                .email
+                .into_iter()
+                .next()
+                .ok_or_else(|| "email missing in NewCustomer".to_string())?,
+            addresses: intermediate_rep.addresses.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NewCustomer> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NewCustomer>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NewCustomer>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NewCustomer - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewCustomer> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NewCustomer as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NewCustomer - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NewDiscountCode {
+    #[serde(rename = "percentage_discount")]
+    #[validate(range(min = 0u8, max = 100u8))]
+    pub percentage_discount: u8,
+
+    #[serde(rename = "valid_from")]
+    pub valid_from: chrono::naive::NaiveDate,
+
+    #[serde(rename = "valid_to")]
+    pub valid_to: chrono::naive::NaiveDate,
+
+    #[serde(rename = "code")]
+    pub code: String,
+}
+
+impl NewDiscountCode {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        percentage_discount: u8,
+        valid_from: chrono::naive::NaiveDate,
+        valid_to: chrono::naive::NaiveDate,
+        code: String,
+    ) -> NewDiscountCode {
+        NewDiscountCode {
+            percentage_discount,
+            valid_from,
+            valid_to,
+            code,
+        }
+    }
+}
+
+/// Converts the NewDiscountCode value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NewDiscountCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("percentage_discount".to_string()),
+            Some(self.percentage_discount.to_string()),
+            // Skipping valid_from in query parameter serialization
+
+            // Skipping valid_to in query parameter serialization
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NewDiscountCode value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NewDiscountCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub percentage_discount: Vec<u8>,
+            pub valid_from: Vec<chrono::naive::NaiveDate>,
+            pub valid_to: Vec<chrono::naive::NaiveDate>,
+            pub code: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NewDiscountCode".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "percentage_discount" => intermediate_rep
+                        .percentage_discount
+                        .push(<u8 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "valid_from" => intermediate_rep.valid_from.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "valid_to" => intermediate_rep.valid_to.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NewDiscountCode".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NewDiscountCode {
+            percentage_discount: intermediate_rep
+                .percentage_discount
+                .into_iter()
+                .next()
+                .ok_or_else(|| "percentage_discount missing in NewDiscountCode".to_string())?,
+            valid_from: intermediate_rep
+                .valid_from
+                .into_iter()
+                .next()
+                .ok_or_else(|| "valid_from missing in NewDiscountCode".to_string())?,
+            valid_to: intermediate_rep
+                .valid_to
+                .into_iter()
+                .next()
+                .ok_or_else(|| "valid_to missing in NewDiscountCode".to_string())?,
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in NewDiscountCode".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NewDiscountCode> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NewDiscountCode>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NewDiscountCode>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NewDiscountCode - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewDiscountCode> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NewDiscountCode as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NewDiscountCode - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NewGenre {
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+impl NewGenre {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(name: String) -> NewGenre {
+        NewGenre { name }
+    }
+}
+
+/// Converts the NewGenre value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NewGenre {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> =
+            vec![Some("name".to_string()), Some(self.name.to_string())];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NewGenre value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NewGenre {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NewGenre".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NewGenre".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NewGenre {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in NewGenre".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NewGenre> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NewGenre>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NewGenre>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NewGenre - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewGenre> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NewGenre as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NewGenre - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NewOrder {
+    /// Id of the customer placing the order, the customer must exist
+    #[serde(rename = "customer_id")]
+    pub customer_id: String,
+
+    #[serde(rename = "books")]
+    #[validate(length(min = 1))]
+    pub books: Vec<models::OrderedBook>,
+
+    #[serde(rename = "shipping_date")]
+    pub shipping_date: chrono::naive::NaiveDate,
+
+    #[serde(rename = "billing_address")]
+    pub billing_address: models::Address,
+
+    #[serde(rename = "shipping_address_override")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address_override: Option<models::Address>,
+
+    /// Discount codes to apply, a code must be valid on the day of the order and linked to an ordered book.
+    /// If several codes apply to a book, the highest discount wins.
+    #[serde(rename = "discount_codes")]
+    #[validate(length(max = 10))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_codes: Option<Vec<String>>,
+}
+
+impl NewOrder {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        customer_id: String,
+        books: Vec<models::OrderedBook>,
+        shipping_date: chrono::naive::NaiveDate,
+        billing_address: models::Address,
+    ) -> NewOrder {
+        NewOrder {
+            customer_id,
+            books,
+            shipping_date,
+            billing_address,
+            shipping_address_override: None,
+            discount_codes: None,
+        }
+    }
+}
+
+/// Converts the NewOrder value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NewOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("customer_id".to_string()),
+            Some(self.customer_id.to_string()),
+            // Skipping books in query parameter serialization
+
+            // Skipping shipping_date in query parameter serialization
+
+            // Skipping billing_address in query parameter serialization
+
+            // Skipping shipping_address_override in query parameter serialization
+            self.discount_codes.as_ref().map(|discount_codes| {
+                [
+                    "discount_codes".to_string(),
+                    discount_codes
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NewOrder value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NewOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub customer_id: Vec<String>,
+            pub books: Vec<Vec<models::OrderedBook>>,
+            pub shipping_date: Vec<chrono::naive::NaiveDate>,
+            pub billing_address: Vec<models::Address>,
+            pub shipping_address_override: Vec<models::Address>,
+            pub discount_codes: Vec<Vec<String>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NewOrder".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "customer_id" => intermediate_rep.customer_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "books" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in NewOrder"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "shipping_date" => intermediate_rep.shipping_date.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "billing_address" => intermediate_rep.billing_address.push(
+                        <models::Address as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "shipping_address_override" => intermediate_rep.shipping_address_override.push(
+                        <models::Address as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "discount_codes" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in NewOrder"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NewOrder".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NewOrder {
+            customer_id: intermediate_rep
+                .customer_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "customer_id missing in NewOrder".to_string())?,
+            books: intermediate_rep
+                .books
+                .into_iter()
+                .next()
+                .ok_or_else(|| "books missing in NewOrder".to_string())?,
+            shipping_date: intermediate_rep
+                .shipping_date
+                .into_iter()
+                .next()
+                .ok_or_else(|| "shipping_date missing in NewOrder".to_string())?,
+            billing_address: intermediate_rep
+                .billing_address
+                .into_iter()
+                .next()
+                .ok_or_else(|| "billing_address missing in NewOrder".to_string())?,
+            shipping_address_override: intermediate_rep
+                .shipping_address_override
+                .into_iter()
+                .next(),
+            discount_codes: intermediate_rep.discount_codes.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NewOrder> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NewOrder>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NewOrder>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NewOrder - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewOrder> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NewOrder as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NewOrder - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NewWebhook {
+    /// The endpoint the events are posted to
+    #[serde(rename = "url")]
+    pub url: String,
+
+    /// The types of the events posted to the endpoint
+    #[serde(rename = "event_types")]
+    #[validate(length(min = 1))]
+    pub event_types: Vec<String>,
+
+    /// The secret the payloads are signed with
+    #[serde(rename = "secret")]
+    #[validate(length(min = 16))]
+    pub secret: String,
+}
+
+impl NewWebhook {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(url: String, event_types: Vec<String>, secret: String) -> NewWebhook {
+        NewWebhook {
+            url,
+            event_types,
+            secret,
+        }
+    }
+}
+
+/// Converts the NewWebhook value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NewWebhook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("url".to_string()),
+            Some(self.url.to_string()),
+            Some("event_types".to_string()),
+            Some(
+                self.event_types
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            Some("secret".to_string()),
+            Some(self.secret.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NewWebhook value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NewWebhook {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub url: Vec<String>,
+            pub event_types: Vec<Vec<String>>,
+            pub secret: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NewWebhook".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "url" => intermediate_rep.url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "event_types" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in NewWebhook"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "secret" => intermediate_rep.secret.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NewWebhook".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NewWebhook {
+            url: intermediate_rep
+                .url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "url missing in NewWebhook".to_string())?,
+            event_types: intermediate_rep
+                .event_types
+                .into_iter()
+                .next()
+                .ok_or_else(|| "event_types missing in NewWebhook".to_string())?,
+            secret: intermediate_rep
+                .secret
+                .into_iter()
+                .next()
+                .ok_or_else(|| "secret missing in NewWebhook".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NewWebhook> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NewWebhook>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NewWebhook>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NewWebhook - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewWebhook> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NewWebhook as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NewWebhook - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Order {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    #[serde(rename = "books")]
+    #[validate(length(min = 1))]
+    pub books: Vec<models::OrderedBook>,
+
+    #[serde(rename = "customer_id")]
+    pub customer_id: String,
+
+    #[serde(rename = "shipping_date")]
+    pub shipping_date: chrono::naive::NaiveDate,
+
+    #[serde(rename = "billing_address")]
+    pub billing_address: models::Address,
+
+    #[serde(rename = "shipping_address_override")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address_override: Option<models::Address>,
+
+    /// Order Status
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "status")]
+    pub status: String,
+
+    #[serde(rename = "placed_at")]
+    pub placed_at: chrono::DateTime<chrono::Utc>,
+
+    #[serde(rename = "shipped_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipped_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[serde(rename = "delivered_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivered_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[serde(rename = "canceled_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canceled_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[serde(rename = "discount_codes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_codes: Option<Vec<String>>,
+
+    /// The ordered books priced at the time of the order
+    #[serde(rename = "lines")]
+    pub lines: Vec<models::OrderLine>,
+
+    /// ISO 4217 code of the currency of the amounts
+    #[serde(rename = "currency")]
+    pub currency: String,
+
+    /// Sum of the line prices before discounts as decimal number
+    #[serde(rename = "subtotal")]
+    pub subtotal: String,
+
+    /// Sum of the applied discounts as decimal number
+    #[serde(rename = "discount_total")]
+    pub discount_total: String,
+
+    /// Amount to pay including the tax as decimal number
+    #[serde(rename = "total")]
+    pub total: String,
+
+    /// The line prices include the tax, otherwise the tax is added to the total
+    #[serde(rename = "prices_include_tax")]
+    pub prices_include_tax: bool,
+
+    /// Amount to pay without the tax as decimal number
+    #[serde(rename = "net_total")]
+    pub net_total: String,
+
+    /// Sum of the taxes as decimal number
+    #[serde(rename = "tax_total")]
+    pub tax_total: String,
+
+    /// The taxes summed by rate
+    #[serde(rename = "tax_lines")]
+    pub tax_lines: Vec<models::TaxLine>,
+
+    #[serde(rename = "exchange_rate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exchange_rate: Option<models::ExchangeRate>,
+}
+
+impl Order {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        books: Vec<models::OrderedBook>,
+        customer_id: String,
+        shipping_date: chrono::naive::NaiveDate,
+        billing_address: models::Address,
+        status: String,
+        placed_at: chrono::DateTime<chrono::Utc>,
+        lines: Vec<models::OrderLine>,
+        currency: String,
+        subtotal: String,
+        discount_total: String,
+        total: String,
+        prices_include_tax: bool,
+        net_total: String,
+        tax_total: String,
+        tax_lines: Vec<models::TaxLine>,
+    ) -> Order {
+        Order {
+            id,
+            books,
+            customer_id,
+            shipping_date,
+            billing_address,
+            shipping_address_override: None,
+            status,
+            placed_at,
+            shipped_at: None,
+            delivered_at: None,
+            canceled_at: None,
+            discount_codes: None,
+            lines,
+            currency,
+            subtotal,
+            discount_total,
+            total,
+            prices_include_tax,
+            net_total,
+            tax_total,
+            tax_lines,
+            exchange_rate: None,
+        }
+    }
+}
+
+/// Converts the Order value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            // Skipping books in query parameter serialization
+            Some("customer_id".to_string()),
+            Some(self.customer_id.to_string()),
+            // Skipping shipping_date in query parameter serialization
+
+            // Skipping billing_address in query parameter serialization
+
+            // Skipping shipping_address_override in query parameter serialization
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            // Skipping placed_at in query parameter serialization
+
+            // Skipping shipped_at in query parameter serialization
+
+            // Skipping delivered_at in query parameter serialization
+
+            // Skipping canceled_at in query parameter serialization
+            self.discount_codes.as_ref().map(|discount_codes| {
+                [
+                    "discount_codes".to_string(),
+                    discount_codes
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+            // Skipping lines in query parameter serialization
+            Some("currency".to_string()),
+            Some(self.currency.to_string()),
+            Some("subtotal".to_string()),
+            Some(self.subtotal.to_string()),
+            Some("discount_total".to_string()),
+            Some(self.discount_total.to_string()),
+            Some("total".to_string()),
+            Some(self.total.to_string()),
+            Some("prices_include_tax".to_string()),
+            Some(self.prices_include_tax.to_string()),
+            Some("net_total".to_string()),
+            Some(self.net_total.to_string()),
+            Some("tax_total".to_string()),
+            Some(self.tax_total.to_string()),
+            // Skipping tax_lines in query parameter serialization
+
+            // Skipping exchange_rate in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Order value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Order {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<String>,
+            pub books: Vec<Vec<models::OrderedBook>>,
+            pub customer_id: Vec<String>,
+            pub shipping_date: Vec<chrono::naive::NaiveDate>,
+            pub billing_address: Vec<models::Address>,
+            pub shipping_address_override: Vec<models::Address>,
+            pub status: Vec<String>,
+            pub placed_at: Vec<chrono::DateTime<chrono::Utc>>,
+            pub shipped_at: Vec<chrono::DateTime<chrono::Utc>>,
+            pub delivered_at: Vec<chrono::DateTime<chrono::Utc>>,
+            pub canceled_at: Vec<chrono::DateTime<chrono::Utc>>,
+            pub discount_codes: Vec<Vec<String>>,
+            pub lines: Vec<Vec<models::OrderLine>>,
+            pub currency: Vec<String>,
+            pub subtotal: Vec<String>,
+            pub discount_total: Vec<String>,
+            pub total: Vec<String>,
+            pub prices_include_tax: Vec<bool>,
+            pub net_total: Vec<String>,
+            pub tax_total: Vec<String>,
+            pub tax_lines: Vec<Vec<models::TaxLine>>,
+            pub exchange_rate: Vec<models::ExchangeRate>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing Order".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "books" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Order"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "customer_id" => intermediate_rep.customer_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "shipping_date" => intermediate_rep.shipping_date.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "billing_address" => intermediate_rep.billing_address.push(
+                        <models::Address as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "shipping_address_override" => intermediate_rep.shipping_address_override.push(
+                        <models::Address as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "placed_at" => intermediate_rep.placed_at.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "shipped_at" => intermediate_rep.shipped_at.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "delivered_at" => intermediate_rep.delivered_at.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "canceled_at" => intermediate_rep.canceled_at.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "discount_codes" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Order"
+                                .to_string(),
+                        )
+                    }
+                    "lines" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Order"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "currency" => intermediate_rep.currency.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "subtotal" => intermediate_rep.subtotal.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "discount_total" => intermediate_rep.discount_total.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "total" => intermediate_rep.total.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "prices_include_tax" => intermediate_rep.prices_include_tax.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "net_total" => intermediate_rep.net_total.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "tax_total" => intermediate_rep.tax_total.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "tax_lines" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Order"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "exchange_rate" => intermediate_rep.exchange_rate.push(
+                        <models::ExchangeRate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Order".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Order {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in Order".to_string())?,
+            books: intermediate_rep
+                .books
+                .into_iter()
+                .next()
+                .ok_or_else(|| "books missing in Order".to_string())?,
+            customer_id: intermediate_rep
+                .customer_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "customer_id missing in Order".to_string())?,
+            shipping_date: intermediate_rep
+                .shipping_date
+                .into_iter()
+                .next()
+                .ok_or_else(|| "shipping_date missing in Order".to_string())?,
+            billing_address: intermediate_rep
+                .billing_address
+                .into_iter()
+                .next()
+                .ok_or_else(|| "billing_address missing in Order".to_string())?,
+            shipping_address_override: intermediate_rep
+                .shipping_address_override
+                .into_iter()
+                .next(),
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in Order".to_string())?,
+            placed_at: intermediate_rep
+                .placed_at
+                .into_iter()
+                .next()
+                .ok_or_else(|| "placed_at missing in Order".to_string())?,
+            shipped_at: intermediate_rep.shipped_at.into_iter().next(),
+            delivered_at: intermediate_rep.delivered_at.into_iter().next(),
+            canceled_at: intermediate_rep.canceled_at.into_iter().next(),
+            discount_codes: intermediate_rep.discount_codes.into_iter().next(),
+            lines: intermediate_rep
+                .lines
+                .into_iter()
+                .next()
+                .ok_or_else(|| "lines missing in Order".to_string())?,
+            currency: intermediate_rep
+                .currency
+                .into_iter()
+                .next()
+                .ok_or_else(|| "currency missing in Order".to_string())?,
+            subtotal: intermediate_rep
+                .subtotal
+                .into_iter()
+                .next()
+                .ok_or_else(|| "subtotal missing in Order".to_string())?,
+            discount_total: intermediate_rep
+                .discount_total
+                .into_iter()
+                .next()
+                .ok_or_else(|| "discount_total missing in Order".to_string())?,
+            total: intermediate_rep
+                .total
+                .into_iter()
+                .next()
+                .ok_or_else(|| "total missing in Order".to_string())?,
+            prices_include_tax: intermediate_rep
+                .prices_include_tax
+                .into_iter()
+                .next()
+                .ok_or_else(|| "prices_include_tax missing in Order".to_string())?,
+            net_total: intermediate_rep
+                .net_total
+                .into_iter()
+                .next()
+                .ok_or_else(|| "net_total missing in Order".to_string())?,
+            tax_total: intermediate_rep
+                .tax_total
+                .into_iter()
+                .next()
+                .ok_or_else(|| "tax_total missing in Order".to_string())?,
+            tax_lines: intermediate_rep
+                .tax_lines
+                .into_iter()
+                .next()
+                .ok_or_else(|| "tax_lines missing in Order".to_string())?,
+            exchange_rate: intermediate_rep.exchange_rate.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Order> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Order>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Order>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Order - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Order> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => match <Order as std::str::FromStr>::from_str(value) {
+                std::result::Result::Ok(value) => {
+                    std::result::Result::Ok(header::IntoHeaderValue(value))
+                }
+                std::result::Result::Err(err) => std::result::Result::Err(format!(
+                    "Unable to convert header value '{}' into Order - {}",
+                    value, err
+                )),
+            },
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct OrderHistory {
+    #[serde(rename = "order_id")]
+    pub order_id: String,
+
+    #[serde(rename = "events")]
+    pub events: Vec<models::OrderStatusEvent>,
+}
+
+impl OrderHistory {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(order_id: String, events: Vec<models::OrderStatusEvent>) -> OrderHistory {
+        OrderHistory { order_id, events }
+    }
+}
+
+/// Converts the OrderHistory value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for OrderHistory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("order_id".to_string()),
+            Some(self.order_id.to_string()),
+            // Skipping events in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a OrderHistory value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for OrderHistory {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub order_id: Vec<String>,
+            pub events: Vec<Vec<models::OrderStatusEvent>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing OrderHistory".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "order_id" => intermediate_rep.order_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "events" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in OrderHistory"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing OrderHistory".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(OrderHistory {
+            order_id: intermediate_rep
+                .order_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "order_id missing in OrderHistory".to_string())?,
+            events: intermediate_rep
+                .events
+                .into_iter()
+                .next()
+                .ok_or_else(|| "events missing in OrderHistory".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<OrderHistory> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<OrderHistory>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<OrderHistory>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for OrderHistory - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<OrderHistory> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <OrderHistory as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into OrderHistory - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct OrderLine {
+    #[serde(rename = "book_id")]
+    pub book_id: String,
+
+    #[serde(rename = "quantity")]
+    pub quantity: i32,
+
+    /// Price of the book at the time of the order as decimal number
+    #[serde(rename = "unit_price")]
+    pub unit_price: String,
+
+    /// The applied discount code
+    #[serde(rename = "discount_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount_code: Option<String>,
+
+    #[serde(rename = "discount_percentage")]
+    pub discount_percentage: i32,
+
+    /// The discount of the line as decimal number
+    #[serde(rename = "discount_amount")]
+    pub discount_amount: String,
+
+    /// Price of the line after the discount as decimal number
+    #[serde(rename = "line_total")]
+    pub line_total: String,
+
+    /// The tax rate in percent of the net price as decimal number
+    #[serde(rename = "tax_rate")]
+    pub tax_rate: String,
+
+    /// The tax included in or added to the line total as decimal number
+    #[serde(rename = "tax_amount")]
+    pub tax_amount: String,
+}
+
+impl OrderLine {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        book_id: String,
+        quantity: i32,
+        unit_price: String,
+        discount_percentage: i32,
+        discount_amount: String,
+        line_total: String,
+        tax_rate: String,
+        tax_amount: String,
+    ) -> OrderLine {
+        OrderLine {
+            book_id,
+            quantity,
+            unit_price,
+            discount_code: None,
+            discount_percentage,
+            discount_amount,
+            line_total,
+            tax_rate,
+            tax_amount,
+        }
+    }
+}
+
+/// Converts the OrderLine value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for OrderLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("book_id".to_string()),
+            Some(self.book_id.to_string()),
+            Some("quantity".to_string()),
+            Some(self.quantity.to_string()),
+            Some("unit_price".to_string()),
+            Some(self.unit_price.to_string()),
+            self.discount_code.as_ref().map(|discount_code| {
+                ["discount_code".to_string(), discount_code.to_string()].join(",")
+            }),
+            Some("discount_percentage".to_string()),
+            Some(self.discount_percentage.to_string()),
+            Some("discount_amount".to_string()),
+            Some(self.discount_amount.to_string()),
+            Some("line_total".to_string()),
+            Some(self.line_total.to_string()),
+            Some("tax_rate".to_string()),
+            Some(self.tax_rate.to_string()),
+            Some("tax_amount".to_string()),
+            Some(self.tax_amount.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a OrderLine value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for OrderLine {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub book_id: Vec<String>,
+            pub quantity: Vec<i32>,
+            pub unit_price: Vec<String>,
+            pub discount_code: Vec<String>,
+            pub discount_percentage: Vec<i32>,
+            pub discount_amount: Vec<String>,
+            pub line_total: Vec<String>,
+            pub tax_rate: Vec<String>,
+            pub tax_amount: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing OrderLine".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "book_id" => intermediate_rep.book_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "quantity" => intermediate_rep.quantity.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "unit_price" => intermediate_rep.unit_price.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "discount_code" => intermediate_rep.discount_code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "discount_percentage" => intermediate_rep.discount_percentage.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "discount_amount" => intermediate_rep.discount_amount.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "line_total" => intermediate_rep.line_total.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "tax_rate" => intermediate_rep.tax_rate.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "tax_amount" => intermediate_rep.tax_amount.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing OrderLine".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(OrderLine {
+            book_id: intermediate_rep
+                .book_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "book_id missing in OrderLine".to_string())?,
+            quantity: intermediate_rep
+                .quantity
+                .into_iter()
+                .next()
+                .ok_or_else(|| "quantity missing in OrderLine".to_string())?,
+            unit_price: intermediate_rep
+                .unit_price
+                .into_iter()
+                .next()
+                .ok_or_else(|| "unit_price missing in OrderLine".to_string())?,
+            discount_code: intermediate_rep.discount_code.into_iter().next(),
+            discount_percentage: intermediate_rep
+                .discount_percentage
+                .into_iter()
+                .next()
+                .ok_or_else(|| "discount_percentage missing in OrderLine".to_string())?,
+            discount_amount: intermediate_rep
+                .discount_amount
+                .into_iter()
+                .next()
+                .ok_or_else(|| "discount_amount missing in OrderLine".to_string())?,
+            line_total: intermediate_rep
+                .line_total
+                .into_iter()
+                .next()
+                .ok_or_else(|| "line_total missing in OrderLine".to_string())?,
+            tax_rate: intermediate_rep
+                .tax_rate
+                .into_iter()
+                .next()
+                .ok_or_else(|| "tax_rate missing in OrderLine".to_string())?,
+            tax_amount: intermediate_rep
+                .tax_amount
+                .into_iter()
+                .next()
+                .ok_or_else(|| "tax_amount missing in OrderLine".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<OrderLine> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<OrderLine>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<OrderLine>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for OrderLine - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<OrderLine> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <OrderLine as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into OrderLine - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// A page of orders ordered by id
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct OrderPage {
+    #[serde(rename = "items")]
+    pub items: Vec<models::Order>,
+
+    /// Cursor of the next page, absent on the last page
+    #[serde(rename = "next_cursor")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub next_cursor: Option<String>,
 }
 
-impl HealthCheckResponse {
+impl OrderPage {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> HealthCheckResponse {
-        HealthCheckResponse { status: None }
+    pub fn new(items: Vec<models::Order>) -> OrderPage {
+        OrderPage {
+            items,
+            next_cursor: None,
+        }
     }
 }
 
-/// Converts the HealthCheckResponse value to the Query Parameters representation (style=form, explode=false)
+/// Converts the OrderPage value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for HealthCheckResponse {
+impl std::fmt::Display for OrderPage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![self
-            .status
-            .as_ref()
-            .map(|status| ["status".to_string(), status.to_string()].join(","))];
+        let params: Vec<Option<String>> = vec![
+            // Skipping items in query parameter serialization
+            self.next_cursor
+                .as_ref()
+                .map(|next_cursor| ["next_cursor".to_string(), next_cursor.to_string()].join(",")),
+        ];
 
         write!(
             f,
@@ -1794,10 +6156,10 @@ impl std::fmt::Display for HealthCheckResponse {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a HealthCheckResponse value
+/// Converts Query Parameters representation (style=form, explode=false) to a OrderPage value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for HealthCheckResponse {
+impl std::str::FromStr for OrderPage {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1805,7 +6167,8 @@ impl std::str::FromStr for HealthCheckResponse {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub status: Vec<String>,
+            pub items: Vec<Vec<models::Order>>,
+            pub next_cursor: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1819,7 +6182,7 @@ impl std::str::FromStr for HealthCheckResponse {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing HealthCheckResponse".to_string(),
+                        "Missing value while parsing OrderPage".to_string(),
                     )
                 }
             };
@@ -1827,13 +6190,19 @@ impl std::str::FromStr for HealthCheckResponse {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
+                    "items" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in OrderPage"
+                                .to_string(),
+                        )
+                    }
                     #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
+                    "next_cursor" => intermediate_rep.next_cursor.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing HealthCheckResponse".to_string(),
+                            "Unexpected key while parsing OrderPage".to_string(),
                         )
                     }
                 }
@@ -1844,26 +6213,31 @@ impl std::str::FromStr for HealthCheckResponse {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(HealthCheckResponse {
-            status: intermediate_rep.status.into_iter().next(),
+        std::result::Result::Ok(OrderPage {
+            items: intermediate_rep
+                .items
+                .into_iter()
+                .next()
+                .ok_or_else(|| "items missing in OrderPage".to_string())?,
+            next_cursor: intermediate_rep.next_cursor.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<HealthCheckResponse> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<OrderPage> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<HealthCheckResponse>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<OrderPage>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<HealthCheckResponse>,
+        hdr_value: header::IntoHeaderValue<OrderPage>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for HealthCheckResponse - value: {} is invalid {}",
+                "Invalid header value for OrderPage - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1871,18 +6245,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<HealthCheckResponse>> for Hea
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<HealthCheckResponse> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<OrderPage> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <HealthCheckResponse as std::str::FromStr>::from_str(value) {
+                match <OrderPage as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into HealthCheckResponse - {}",
+                        "Unable to convert header value '{}' into OrderPage - {}",
                         value, err
                     )),
                 }
@@ -1895,42 +6269,49 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<HealthCheckR
     }
 }
 
+/// The status follows the order lifecycle placed -> shipped -> delivered, a placed order can be canceled.
+/// Setting the current status again only updates the shipping date.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Inventory {
-    #[serde(rename = "books_available")]
-    pub books_available: i32,
+pub struct OrderProperties {
+    #[serde(rename = "shipping_date")]
+    pub shipping_date: chrono::naive::NaiveDate,
 
-    #[serde(rename = "books_reordered")]
-    pub books_reordered: i32,
+    /// Order Status
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "status")]
+    pub status: String,
 
-    #[serde(rename = "books_out_of_stock")]
-    pub books_out_of_stock: i32,
+    /// Why the order is changed, recorded in the order history
+    #[serde(rename = "reason")]
+    #[validate(length(max = 500))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
 }
 
-impl Inventory {
+impl OrderProperties {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(books_available: i32, books_reordered: i32, books_out_of_stock: i32) -> Inventory {
-        Inventory {
-            books_available,
-            books_reordered,
-            books_out_of_stock,
+    pub fn new(shipping_date: chrono::naive::NaiveDate, status: String) -> OrderProperties {
+        OrderProperties {
+            shipping_date,
+            status,
+            reason: None,
         }
     }
 }
 
-/// Converts the Inventory value to the Query Parameters representation (style=form, explode=false)
+/// Converts the OrderProperties value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for Inventory {
+impl std::fmt::Display for OrderProperties {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("books_available".to_string()),
-            Some(self.books_available.to_string()),
-            Some("books_reordered".to_string()),
-            Some(self.books_reordered.to_string()),
-            Some("books_out_of_stock".to_string()),
-            Some(self.books_out_of_stock.to_string()),
+            // Skipping shipping_date in query parameter serialization
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            self.reason
+                .as_ref()
+                .map(|reason| ["reason".to_string(), reason.to_string()].join(",")),
         ];
 
         write!(
@@ -1941,10 +6322,10 @@ impl std::fmt::Display for Inventory {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Inventory value
+/// Converts Query Parameters representation (style=form, explode=false) to a OrderProperties value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Inventory {
+impl std::str::FromStr for OrderProperties {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1952,9 +6333,9 @@ impl std::str::FromStr for Inventory {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub books_available: Vec<i32>,
-            pub books_reordered: Vec<i32>,
-            pub books_out_of_stock: Vec<i32>,
+            pub shipping_date: Vec<chrono::naive::NaiveDate>,
+            pub status: Vec<String>,
+            pub reason: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1968,7 +6349,7 @@ impl std::str::FromStr for Inventory {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing Inventory".to_string(),
+                        "Missing value while parsing OrderProperties".to_string(),
                     )
                 }
             };
@@ -1977,20 +6358,21 @@ impl std::str::FromStr for Inventory {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "books_available" => intermediate_rep.books_available.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "shipping_date" => intermediate_rep.shipping_date.push(
+                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "books_reordered" => intermediate_rep.books_reordered.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "books_out_of_stock" => intermediate_rep.books_out_of_stock.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "reason" => intermediate_rep.reason.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing Inventory".to_string(),
+                            "Unexpected key while parsing OrderProperties".to_string(),
                         )
                     }
                 }
@@ -2001,40 +6383,36 @@ impl std::str::FromStr for Inventory {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Inventory {
-            books_available: intermediate_rep
-                .books_available
-                .into_iter()
-                .next()
-                .ok_or_else(|| "books_available missing in Inventory".to_string())?,
-            books_reordered: intermediate_rep
-                .books_reordered
+        std::result::Result::Ok(OrderProperties {
+            shipping_date: intermediate_rep
+                .shipping_date
                 .into_iter()
                 .next()
-                .ok_or_else(|| "books_reordered missing in Inventory".to_string())?,
-            books_out_of_stock: intermediate_rep
-                .books_out_of_stock
+                .ok_or_else(|| "shipping_date missing in OrderProperties".to_string())?,
+            status: intermediate_rep
+                .status
                 .into_iter()
                 .next()
-                .ok_or_else(|| "books_out_of_stock missing in Inventory".to_string())?,
+                .ok_or_else(|| "status missing in OrderProperties".to_string())?,
+            reason: intermediate_rep.reason.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Inventory> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<OrderProperties> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<Inventory>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<OrderProperties>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<Inventory>,
+        hdr_value: header::IntoHeaderValue<OrderProperties>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for Inventory - value: {} is invalid {}",
+                "Invalid header value for OrderProperties - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2042,18 +6420,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Inventory>> for HeaderValue {
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Inventory> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<OrderProperties> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <Inventory as std::str::FromStr>::from_str(value) {
+                match <OrderProperties as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into Inventory - {}",
+                        "Unable to convert header value '{}' into OrderProperties - {}",
                         value, err
                     )),
                 }
@@ -2066,76 +6444,63 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Inventory> {
     }
 }
 
+/// A change of the order, from_status is missing for the placement of the order
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct NewAuthor {
-    #[serde(rename = "title")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<String>,
-
-    #[serde(rename = "first_name")]
-    pub first_name: String,
-
-    #[serde(rename = "second_names")]
+pub struct OrderStatusEvent {
+    #[serde(rename = "from_status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub second_names: Option<Vec<String>>,
+    pub from_status: Option<String>,
 
-    #[serde(rename = "last_name")]
-    pub last_name: String,
+    #[serde(rename = "to_status")]
+    pub to_status: String,
 
-    #[serde(rename = "date_of_birth")]
-    pub date_of_birth: chrono::naive::NaiveDate,
+    /// Name of the API key that changed the order
+    #[serde(rename = "actor")]
+    pub actor: String,
 
-    #[serde(rename = "date_of_death")]
+    #[serde(rename = "reason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date_of_death: Option<chrono::naive::NaiveDate>,
+    pub reason: Option<String>,
+
+    #[serde(rename = "occurred_at")]
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
 }
 
-impl NewAuthor {
+impl OrderStatusEvent {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        first_name: String,
-        last_name: String,
-        date_of_birth: chrono::naive::NaiveDate,
-    ) -> NewAuthor {
-        NewAuthor {
-            title: None,
-            first_name,
-            second_names: None,
-            last_name,
-            date_of_birth,
-            date_of_death: None,
+        to_status: String,
+        actor: String,
+        occurred_at: chrono::DateTime<chrono::Utc>,
+    ) -> OrderStatusEvent {
+        OrderStatusEvent {
+            from_status: None,
+            to_status,
+            actor,
+            reason: None,
+            occurred_at,
         }
     }
 }
 
-/// Converts the NewAuthor value to the Query Parameters representation (style=form, explode=false)
+/// Converts the OrderStatusEvent value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for NewAuthor {
+impl std::fmt::Display for OrderStatusEvent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            self.title
+            self.from_status
                 .as_ref()
-                .map(|title| ["title".to_string(), title.to_string()].join(",")),
-            Some("first_name".to_string()),
-            Some(self.first_name.to_string()),
-            self.second_names.as_ref().map(|second_names| {
-                [
-                    "second_names".to_string(),
-                    second_names
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ]
-                .join(",")
-            }),
-            Some("last_name".to_string()),
-            Some(self.last_name.to_string()),
-            // Skipping date_of_birth in query parameter serialization
-
-            // Skipping date_of_death in query parameter serialization
+                .map(|from_status| ["from_status".to_string(), from_status.to_string()].join(",")),
+            Some("to_status".to_string()),
+            Some(self.to_status.to_string()),
+            Some("actor".to_string()),
+            Some(self.actor.to_string()),
+            self.reason
+                .as_ref()
+                .map(|reason| ["reason".to_string(), reason.to_string()].join(",")),
+            // Skipping occurred_at in query parameter serialization
         ];
 
         write!(
@@ -2146,10 +6511,10 @@ impl std::fmt::Display for NewAuthor {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a NewAuthor value
+/// Converts Query Parameters representation (style=form, explode=false) to a OrderStatusEvent value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for NewAuthor {
+impl std::str::FromStr for OrderStatusEvent {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2157,12 +6522,11 @@ impl std::str::FromStr for NewAuthor {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub title: Vec<String>,
-            pub first_name: Vec<String>,
-            pub second_names: Vec<Vec<String>>,
-            pub last_name: Vec<String>,
-            pub date_of_birth: Vec<chrono::naive::NaiveDate>,
-            pub date_of_death: Vec<chrono::naive::NaiveDate>,
+            pub from_status: Vec<String>,
+            pub to_status: Vec<String>,
+            pub actor: Vec<String>,
+            pub reason: Vec<String>,
+            pub occurred_at: Vec<chrono::DateTime<chrono::Utc>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2176,7 +6540,7 @@ impl std::str::FromStr for NewAuthor {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing NewAuthor".to_string(),
+                        "Missing value while parsing OrderStatusEvent".to_string(),
                     )
                 }
             };
@@ -2185,36 +6549,29 @@ impl std::str::FromStr for NewAuthor {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "title" => intermediate_rep.title.push(
+                    "from_status" => intermediate_rep.from_status.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "first_name" => intermediate_rep.first_name.push(
+                    "to_status" => intermediate_rep.to_status.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "second_names" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in NewAuthor"
-                                .to_string(),
-                        )
-                    }
                     #[allow(clippy::redundant_clone)]
-                    "last_name" => intermediate_rep.last_name.push(
+                    "actor" => intermediate_rep.actor.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "date_of_birth" => intermediate_rep.date_of_birth.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "reason" => intermediate_rep.reason.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "date_of_death" => intermediate_rep.date_of_death.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
+                    "occurred_at" => intermediate_rep.occurred_at.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing NewAuthor".to_string(),
+                            "Unexpected key while parsing OrderStatusEvent".to_string(),
                         )
                     }
                 }
@@ -2225,43 +6582,42 @@ impl std::str::FromStr for NewAuthor {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(NewAuthor {
-            title: intermediate_rep.title.into_iter().next(),
-            first_name: intermediate_rep
-                .first_name
+        std::result::Result::Ok(OrderStatusEvent {
+            from_status: intermediate_rep.from_status.into_iter().next(),
+            to_status: intermediate_rep
+                .to_status
                 .into_iter()
                 .next()
-                .ok_or_else(|| "first_name missing in NewAuthor".to_string())?,
-            second_names: intermediate_rep.second_names.into_iter().next(),
-            last_name: intermediate_rep
-                .last_name
+                .ok_or_else(|| "to_status missing in OrderStatusEvent".to_string())?,
+            actor: intermediate_rep
+                .actor
                 .into_iter()
                 .next()
-                .ok_or_else(|| "last_name missing in NewAuthor".to_string())?,
-            date_of_birth: intermediate_rep
-                .date_of_birth
+                .ok_or_else(|| "actor missing in OrderStatusEvent".to_string())?,
+            reason: intermediate_rep.reason.into_iter().next(),
+            occurred_at: intermediate_rep
+                .occurred_at
                 .into_iter()
                 .next()
-                .ok_or_else(|| "date_of_birth missing in NewAuthor".to_string())?,
-            date_of_death: intermediate_rep.date_of_death.into_iter().next(),
+                .ok_or_else(|| "occurred_at missing in OrderStatusEvent".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<NewAuthor> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<OrderStatusEvent> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<NewAuthor>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<OrderStatusEvent>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<NewAuthor>,
+        hdr_value: header::IntoHeaderValue<OrderStatusEvent>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for NewAuthor - value: {} is invalid {}",
+                "Invalid header value for OrderStatusEvent - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2269,18 +6625,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<NewAuthor>> for HeaderValue {
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewAuthor> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<OrderStatusEvent> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <NewAuthor as std::str::FromStr>::from_str(value) {
+                match <OrderStatusEvent as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into NewAuthor - {}",
+                        "Unable to convert header value '{}' into OrderStatusEvent - {}",
                         value, err
                     )),
                 }
@@ -2295,123 +6651,31 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewAuthor> {
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct NewBook {
-    #[serde(rename = "title")]
-    pub title: String,
-
-    /// the date when this edition of the book was released
-    #[serde(rename = "release")]
-    pub release: chrono::naive::NaiveDate,
-
-    /// the date when the first edition of the book was release
-    #[serde(rename = "first_release")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_release: Option<chrono::naive::NaiveDate>,
-
-    #[serde(rename = "authors")]
-    pub authors: Vec<String>,
-
-    #[serde(rename = "genres")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub genres: Option<Vec<String>>,
-
-    #[serde(rename = "discount_codes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub discount_codes: Option<Vec<String>>,
-
-    #[serde(rename = "series")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub series: Option<String>,
-
-    /// the edition of this book
-    #[serde(rename = "edition")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub edition: Option<i32>,
-
-    /// the price of this book in Dollar
-    #[serde(rename = "price")]
-    pub price: f64,
+pub struct OrderedBook {
+    #[serde(rename = "book_id")]
+    pub book_id: String,
 
-    /// The number of available items
-    #[serde(rename = "available")]
-    pub available: i32,
+    #[serde(rename = "quantity")]
+    pub quantity: i32,
 }
 
-impl NewBook {
+impl OrderedBook {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        title: String,
-        release: chrono::naive::NaiveDate,
-        authors: Vec<String>,
-        price: f64,
-        available: i32,
-    ) -> NewBook {
-        NewBook {
-            title,
-            release,
-            first_release: None,
-            authors,
-            genres: None,
-            discount_codes: None,
-            series: None,
-            edition: None,
-            price,
-            available,
-        }
+    pub fn new(book_id: String, quantity: i32) -> OrderedBook {
+        OrderedBook { book_id, quantity }
     }
 }
 
-/// Converts the NewBook value to the Query Parameters representation (style=form, explode=false)
+/// Converts the OrderedBook value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for NewBook {
+impl std::fmt::Display for OrderedBook {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("title".to_string()),
-            Some(self.title.to_string()),
-            // Skipping release in query parameter serialization
-
-            // Skipping first_release in query parameter serialization
-            Some("authors".to_string()),
-            Some(
-                self.authors
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
-            self.genres.as_ref().map(|genres| {
-                [
-                    "genres".to_string(),
-                    genres
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ]
-                .join(",")
-            }),
-            self.discount_codes.as_ref().map(|discount_codes| {
-                [
-                    "discount_codes".to_string(),
-                    discount_codes
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ]
-                .join(",")
-            }),
-            self.series
-                .as_ref()
-                .map(|series| ["series".to_string(), series.to_string()].join(",")),
-            self.edition
-                .as_ref()
-                .map(|edition| ["edition".to_string(), edition.to_string()].join(",")),
-            Some("price".to_string()),
-            Some(self.price.to_string()),
-            Some("available".to_string()),
-            Some(self.available.to_string()),
+            Some("book_id".to_string()),
+            Some(self.book_id.to_string()),
+            Some("quantity".to_string()),
+            Some(self.quantity.to_string()),
         ];
 
         write!(
@@ -2422,10 +6686,10 @@ impl std::fmt::Display for NewBook {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a NewBook value
+/// Converts Query Parameters representation (style=form, explode=false) to a OrderedBook value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for NewBook {
+impl std::str::FromStr for OrderedBook {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2433,16 +6697,8 @@ impl std::str::FromStr for NewBook {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub title: Vec<String>,
-            pub release: Vec<chrono::naive::NaiveDate>,
-            pub first_release: Vec<chrono::naive::NaiveDate>,
-            pub authors: Vec<Vec<String>>,
-            pub genres: Vec<Vec<String>>,
-            pub discount_codes: Vec<Vec<String>>,
-            pub series: Vec<String>,
-            pub edition: Vec<i32>,
-            pub price: Vec<f64>,
-            pub available: Vec<i32>,
+            pub book_id: Vec<String>,
+            pub quantity: Vec<i32>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2456,7 +6712,7 @@ impl std::str::FromStr for NewBook {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing NewBook".to_string(),
+                        "Missing value while parsing OrderedBook".to_string(),
                     )
                 }
             };
@@ -2465,56 +6721,16 @@ impl std::str::FromStr for NewBook {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "title" => intermediate_rep.title.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "release" => intermediate_rep.release.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "first_release" => intermediate_rep.first_release.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    "authors" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in NewBook"
-                                .to_string(),
-                        )
-                    }
-                    "genres" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in NewBook"
-                                .to_string(),
-                        )
-                    }
-                    "discount_codes" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in NewBook"
-                                .to_string(),
-                        )
-                    }
-                    #[allow(clippy::redundant_clone)]
-                    "series" => intermediate_rep.series.push(
+                    "book_id" => intermediate_rep.book_id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "edition" => intermediate_rep.edition.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "price" => intermediate_rep.price.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "available" => intermediate_rep.available.push(
+                    "quantity" => intermediate_rep.quantity.push(
                         <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing NewBook".to_string(),
+                            "Unexpected key while parsing OrderedBook".to_string(),
                         )
                     }
                 }
@@ -2525,55 +6741,35 @@ impl std::str::FromStr for NewBook {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(NewBook {
-            title: intermediate_rep
-                .title
-                .into_iter()
-                .next()
-                .ok_or_else(|| "title missing in NewBook".to_string())?,
-            release: intermediate_rep
-                .release
-                .into_iter()
-                .next()
-                .ok_or_else(|| "release missing in NewBook".to_string())?,
-            first_release: intermediate_rep.first_release.into_iter().next(),
-            authors: intermediate_rep
-                .authors
-                .into_iter()
-                .next()
-                .ok_or_else(|| "authors missing in NewBook".to_string())?,
-            genres: intermediate_rep.genres.into_iter().next(),
-            discount_codes: intermediate_rep.discount_codes.into_iter().next(),
-            series: intermediate_rep.series.into_iter().next(),
-            edition: intermediate_rep.edition.into_iter().next(),
-            price: intermediate_rep
-                .price
+        std::result::Result::Ok(OrderedBook {
+            book_id: intermediate_rep
+                .book_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "price missing in NewBook".to_string())?,
-            available: intermediate_rep
-                .available
+                .ok_or_else(|| "book_id missing in OrderedBook".to_string())?,
+            quantity: intermediate_rep
+                .quantity
                 .into_iter()
                 .next()
-                .ok_or_else(|| "available missing in NewBook".to_string())?,
+                .ok_or_else(|| "quantity missing in OrderedBook".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<NewBook> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<OrderedBook> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<NewBook>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<OrderedBook>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<NewBook>,
+        hdr_value: header::IntoHeaderValue<OrderedBook>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for NewBook - value: {} is invalid {}",
+                "Invalid header value for OrderedBook - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2581,18 +6777,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<NewBook>> for HeaderValue {
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewBook> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<OrderedBook> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <NewBook as std::str::FromStr>::from_str(value) {
+                match <OrderedBook as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into NewBook - {}",
+                        "Unable to convert header value '{}' into OrderedBook - {}",
                         value, err
                     )),
                 }
@@ -2605,53 +6801,73 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewBook> {
     }
 }
 
+/// RFC 7807 problem details of a failed request
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct NewDiscountCode {
-    #[serde(rename = "percentage_discount")]
-    #[validate(range(min = 0u8, max = 100u8))]
-    pub percentage_discount: u8,
+pub struct Problem {
+    /// URI identifying the problem type
+    #[serde(rename = "type")]
+    pub r#type: String,
 
-    #[serde(rename = "valid_from")]
-    pub valid_from: chrono::naive::NaiveDate,
+    /// Short summary of the problem type
+    #[serde(rename = "title")]
+    pub title: String,
 
-    #[serde(rename = "valid_to")]
-    pub valid_to: chrono::naive::NaiveDate,
+    /// The HTTP status code
+    #[serde(rename = "status")]
+    pub status: i32,
 
-    #[serde(rename = "code")]
-    pub code: String,
+    /// Explanation specific to this occurrence of the problem
+    #[serde(rename = "detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+
+    /// The request field which caused the problem
+    #[serde(rename = "field")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+
+    /// Id to correlate the problem with the service logs
+    #[serde(rename = "correlation_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
 }
 
-impl NewDiscountCode {
+impl Problem {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        percentage_discount: u8,
-        valid_from: chrono::naive::NaiveDate,
-        valid_to: chrono::naive::NaiveDate,
-        code: String,
-    ) -> NewDiscountCode {
-        NewDiscountCode {
-            percentage_discount,
-            valid_from,
-            valid_to,
-            code,
+    pub fn new(r#type: String, title: String, status: i32) -> Problem {
+        Problem {
+            r#type,
+            title,
+            status,
+            detail: None,
+            field: None,
+            correlation_id: None,
         }
     }
 }
 
-/// Converts the NewDiscountCode value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Problem value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for NewDiscountCode {
+impl std::fmt::Display for Problem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("percentage_discount".to_string()),
-            Some(self.percentage_discount.to_string()),
-            // Skipping valid_from in query parameter serialization
-
-            // Skipping valid_to in query parameter serialization
-            Some("code".to_string()),
-            Some(self.code.to_string()),
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            self.detail
+                .as_ref()
+                .map(|detail| ["detail".to_string(), detail.to_string()].join(",")),
+            self.field
+                .as_ref()
+                .map(|field| ["field".to_string(), field.to_string()].join(",")),
+            self.correlation_id.as_ref().map(|correlation_id| {
+                ["correlation_id".to_string(), correlation_id.to_string()].join(",")
+            }),
         ];
 
         write!(
@@ -2662,10 +6878,10 @@ impl std::fmt::Display for NewDiscountCode {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a NewDiscountCode value
+/// Converts Query Parameters representation (style=form, explode=false) to a Problem value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for NewDiscountCode {
+impl std::str::FromStr for Problem {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2673,10 +6889,12 @@ impl std::str::FromStr for NewDiscountCode {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub percentage_discount: Vec<u8>,
-            pub valid_from: Vec<chrono::naive::NaiveDate>,
-            pub valid_to: Vec<chrono::naive::NaiveDate>,
-            pub code: Vec<String>,
+            pub r#type: Vec<String>,
+            pub title: Vec<String>,
+            pub status: Vec<i32>,
+            pub detail: Vec<String>,
+            pub field: Vec<String>,
+            pub correlation_id: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2690,7 +6908,7 @@ impl std::str::FromStr for NewDiscountCode {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing NewDiscountCode".to_string(),
+                        "Missing value while parsing Problem".to_string(),
                     )
                 }
             };
@@ -2699,26 +6917,32 @@ impl std::str::FromStr for NewDiscountCode {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "percentage_discount" => intermediate_rep
-                        .percentage_discount
-                        .push(<u8 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "valid_from" => intermediate_rep.valid_from.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "valid_to" => intermediate_rep.valid_to.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "status" => intermediate_rep.status.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
+                    "detail" => intermediate_rep.detail.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "field" => intermediate_rep.field.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "correlation_id" => intermediate_rep.correlation_id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing NewDiscountCode".to_string(),
+                            "Unexpected key while parsing Problem".to_string(),
                         )
                     }
                 }
@@ -2729,45 +6953,43 @@ impl std::str::FromStr for NewDiscountCode {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(NewDiscountCode {
-            percentage_discount: intermediate_rep
-                .percentage_discount
-                .into_iter()
-                .next()
-                .ok_or_else(|| "percentage_discount missing in NewDiscountCode".to_string())?,
-            valid_from: intermediate_rep
-                .valid_from
+        std::result::Result::Ok(Problem {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "valid_from missing in NewDiscountCode".to_string())?,
-            valid_to: intermediate_rep
-                .valid_to
+                .ok_or_else(|| "type missing in Problem".to_string())?,
+            title: intermediate_rep
+                .title
                 .into_iter()
                 .next()
-                .ok_or_else(|| "valid_to missing in NewDiscountCode".to_string())?,
-            code: intermediate_rep
-                .code
+                .ok_or_else(|| "title missing in Problem".to_string())?,
+            status: intermediate_rep
+                .status
                 .into_iter()
                 .next()
-                .ok_or_else(|| "code missing in NewDiscountCode".to_string())?,
+                .ok_or_else(|| "status missing in Problem".to_string())?,
+            detail: intermediate_rep.detail.into_iter().next(),
+            field: intermediate_rep.field.into_iter().next(),
+            correlation_id: intermediate_rep.correlation_id.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<NewDiscountCode> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<Problem> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<NewDiscountCode>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Problem>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<NewDiscountCode>,
+        hdr_value: header::IntoHeaderValue<Problem>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for NewDiscountCode - value: {} is invalid {}",
+                "Invalid header value for Problem - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2775,18 +6997,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<NewDiscountCode>> for HeaderV
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewDiscountCode> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Problem> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <NewDiscountCode as std::str::FromStr>::from_str(value) {
+                match <Problem as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into NewDiscountCode - {}",
+                        "Unable to convert header value '{}' into Problem - {}",
                         value, err
                     )),
                 }
@@ -2799,27 +7021,43 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewDiscountC
     }
 }
 
+/// The taxes of the order lines taxed at the same rate
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct NewGenre {
-    #[serde(rename = "name")]
-    pub name: String,
+pub struct TaxLine {
+    /// The tax rate in percent of the net price as decimal number
+    #[serde(rename = "rate")]
+    pub rate: String,
+
+    /// Sum of the net amounts taxed at the rate as decimal number
+    #[serde(rename = "net")]
+    pub net: String,
+
+    /// Sum of the taxes at the rate as decimal number
+    #[serde(rename = "tax")]
+    pub tax: String,
 }
 
-impl NewGenre {
+impl TaxLine {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(name: String) -> NewGenre {
-        NewGenre { name }
+    pub fn new(rate: String, net: String, tax: String) -> TaxLine {
+        TaxLine { rate, net, tax }
     }
 }
 
-/// Converts the NewGenre value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TaxLine value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for NewGenre {
+impl std::fmt::Display for TaxLine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> =
-            vec![Some("name".to_string()), Some(self.name.to_string())];
+        let params: Vec<Option<String>> = vec![
+            Some("rate".to_string()),
+            Some(self.rate.to_string()),
+            Some("net".to_string()),
+            Some(self.net.to_string()),
+            Some("tax".to_string()),
+            Some(self.tax.to_string()),
+        ];
 
         write!(
             f,
@@ -2829,10 +7067,10 @@ impl std::fmt::Display for NewGenre {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a NewGenre value
+/// Converts Query Parameters representation (style=form, explode=false) to a TaxLine value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for NewGenre {
+impl std::str::FromStr for TaxLine {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2840,7 +7078,9 @@ impl std::str::FromStr for NewGenre {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub name: Vec<String>,
+            pub rate: Vec<String>,
+            pub net: Vec<String>,
+            pub tax: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2854,7 +7094,7 @@ impl std::str::FromStr for NewGenre {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing NewGenre".to_string(),
+                        "Missing value while parsing TaxLine".to_string(),
                     )
                 }
             };
@@ -2863,12 +7103,20 @@ impl std::str::FromStr for NewGenre {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
+                    "rate" => intermediate_rep.rate.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "net" => intermediate_rep.net.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "tax" => intermediate_rep.tax.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing NewGenre".to_string(),
+                            "Unexpected key while parsing TaxLine".to_string(),
                         )
                     }
                 }
@@ -2879,30 +7127,40 @@ impl std::str::FromStr for NewGenre {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(NewGenre {
-            name: intermediate_rep
-                .name
+        std::result::Result::Ok(TaxLine {
+            rate: intermediate_rep
+                .rate
                 .into_iter()
                 .next()
-                .ok_or_else(|| "name missing in NewGenre".to_string())?,
+                .ok_or_else(|| "rate missing in TaxLine".to_string())?,
+            net: intermediate_rep
+                .net
+                .into_iter()
+                .next()
+                .ok_or_else(|| "net missing in TaxLine".to_string())?,
+            tax: intermediate_rep
+                .tax
+                .into_iter()
+                .next()
+                .ok_or_else(|| "tax missing in TaxLine".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<NewGenre> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<TaxLine> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<NewGenre>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TaxLine>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<NewGenre>,
+        hdr_value: header::IntoHeaderValue<TaxLine>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for NewGenre - value: {} is invalid {}",
+                "Invalid header value for TaxLine - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2910,18 +7168,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<NewGenre>> for HeaderValue {
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewGenre> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TaxLine> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <NewGenre as std::str::FromStr>::from_str(value) {
+                match <TaxLine as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into NewGenre - {}",
+                        "Unable to convert header value '{}' into TaxLine - {}",
                         value, err
                     )),
                 }
@@ -2934,60 +7192,51 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewGenre> {
     }
 }
 
+/// A subscription of a partner endpoint to the domain events, the secret is never returned
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct NewOrder {
-    #[serde(rename = "customer_id")]
-    pub customer_id: String,
-
-    #[serde(rename = "books")]
-    #[validate(length(min = 1))]
-    pub books: Vec<models::OrderedBook>,
-
-    #[serde(rename = "shipping_date")]
-    pub shipping_date: chrono::naive::NaiveDate,
+pub struct Webhook {
+    #[serde(rename = "id")]
+    pub id: String,
 
-    #[serde(rename = "billing_address")]
-    pub billing_address: models::Address,
+    /// The endpoint the events are posted to
+    #[serde(rename = "url")]
+    pub url: String,
 
-    #[serde(rename = "shipping_address_override")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_address_override: Option<models::Address>,
+    /// The types of the events posted to the endpoint
+    #[serde(rename = "event_types")]
+    pub event_types: Vec<String>,
 }
 
-impl NewOrder {
+impl Webhook {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        customer_id: String,
-        books: Vec<models::OrderedBook>,
-        shipping_date: chrono::naive::NaiveDate,
-        billing_address: models::Address,
-    ) -> NewOrder {
-        NewOrder {
-            customer_id,
-            books,
-            shipping_date,
-            billing_address,
-            shipping_address_override: None,
+    pub fn new(id: String, url: String, event_types: Vec<String>) -> Webhook {
+        Webhook {
+            id,
+            url,
+            event_types,
         }
     }
 }
 
-/// Converts the NewOrder value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Webhook value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for NewOrder {
+impl std::fmt::Display for Webhook {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("customer_id".to_string()),
-            Some(self.customer_id.to_string()),
-            // Skipping books in query parameter serialization
-
-            // Skipping shipping_date in query parameter serialization
-
-            // Skipping billing_address in query parameter serialization
-
-            // Skipping shipping_address_override in query parameter serialization
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("url".to_string()),
+            Some(self.url.to_string()),
+            Some("event_types".to_string()),
+            Some(
+                self.event_types
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
         ];
 
         write!(
@@ -2998,10 +7247,10 @@ impl std::fmt::Display for NewOrder {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a NewOrder value
+/// Converts Query Parameters representation (style=form, explode=false) to a Webhook value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for NewOrder {
+impl std::str::FromStr for Webhook {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3009,11 +7258,9 @@ impl std::str::FromStr for NewOrder {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub customer_id: Vec<String>,
-            pub books: Vec<Vec<models::OrderedBook>>,
-            pub shipping_date: Vec<chrono::naive::NaiveDate>,
-            pub billing_address: Vec<models::Address>,
-            pub shipping_address_override: Vec<models::Address>,
+            pub id: Vec<String>,
+            pub url: Vec<String>,
+            pub event_types: Vec<Vec<String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3027,7 +7274,7 @@ impl std::str::FromStr for NewOrder {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing NewOrder".to_string(),
+                        "Missing value while parsing Webhook".to_string(),
                     )
                 }
             };
@@ -3036,33 +7283,22 @@ impl std::str::FromStr for NewOrder {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "customer_id" => intermediate_rep.customer_id.push(
+                    "id" => intermediate_rep.id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "books" => {
+                    #[allow(clippy::redundant_clone)]
+                    "url" => intermediate_rep.url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "event_types" => {
                         return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in NewOrder"
+                            "Parsing a container in this style is not supported in Webhook"
                                 .to_string(),
                         )
                     }
-                    #[allow(clippy::redundant_clone)]
-                    "shipping_date" => intermediate_rep.shipping_date.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "billing_address" => intermediate_rep.billing_address.push(
-                        <models::Address as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "shipping_address_override" => intermediate_rep.shipping_address_override.push(
-                        <models::Address as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing NewOrder".to_string(),
+                            "Unexpected key while parsing Webhook".to_string(),
                         )
                     }
                 }
@@ -3073,49 +7309,40 @@ impl std::str::FromStr for NewOrder {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(NewOrder {
-            customer_id: intermediate_rep
-                .customer_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "customer_id missing in NewOrder".to_string())?,
-            books: intermediate_rep
-                .books
-                .into_iter()
-                .next()
-                .ok_or_else(|| "books missing in NewOrder".to_string())?,
-            shipping_date: intermediate_rep
-                .shipping_date
+        std::result::Result::Ok(Webhook {
+            id: intermediate_rep
+                .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "shipping_date missing in NewOrder".to_string())?,
-            billing_address: intermediate_rep
-                .billing_address
+                .ok_or_else(|| "id missing in Webhook".to_string())?,
+            url: intermediate_rep
+                .url
                 .into_iter()
                 .next()
-                .ok_or_else(|| "billing_address missing in NewOrder".to_string())?,
-            shipping_address_override: intermediate_rep
-                .shipping_address_override
+                .ok_or_else(|| "url missing in Webhook".to_string())?,
+            event_types: intermediate_rep
+                .event_types
                 .into_iter()
-                .next(),
+                .next()
+                .ok_or_else(|| "event_types missing in Webhook".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<NewOrder> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<Webhook> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<NewOrder>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Webhook>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<NewOrder>,
+        hdr_value: header::IntoHeaderValue<Webhook>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for NewOrder - value: {} is invalid {}",
+                "Invalid header value for Webhook - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3123,18 +7350,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<NewOrder>> for HeaderValue {
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewOrder> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Webhook> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <NewOrder as std::str::FromStr>::from_str(value) {
+                match <Webhook as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into NewOrder - {}",
+                        "Unable to convert header value '{}' into Webhook - {}",
                         value, err
                     )),
                 }
@@ -3147,75 +7374,103 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NewOrder> {
     }
 }
 
+/// A delivery of an event to the endpoint of a webhook with the outcome of the last attempt
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Order {
+pub struct WebhookDelivery {
     #[serde(rename = "id")]
     pub id: String,
 
-    #[serde(rename = "books")]
-    #[validate(length(min = 1))]
-    pub books: Vec<models::OrderedBook>,
+    /// The id of the delivered event, the same for all webhooks
+    #[serde(rename = "event_id")]
+    pub event_id: i64,
 
-    #[serde(rename = "customer_id")]
-    pub customer_id: String,
+    #[serde(rename = "event_type")]
+    pub event_type: String,
 
-    #[serde(rename = "shipping_date")]
-    pub shipping_date: chrono::naive::NaiveDate,
+    /// pending, delivered or failed once the attempts are exhausted
+    #[serde(rename = "status")]
+    pub status: String,
 
-    #[serde(rename = "billing_address")]
-    pub billing_address: models::Address,
+    /// The number of attempts made so far
+    #[serde(rename = "attempts")]
+    pub attempts: i32,
 
-    #[serde(rename = "shipping_address_override")]
+    /// The HTTP status of the last attempt, absent if the endpoint was unreachable
+    #[serde(rename = "last_status_code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shipping_address_override: Option<models::Address>,
+    pub last_status_code: Option<i32>,
 
-    /// Order Status
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "status")]
-    pub status: String,
+    /// Why the last attempt failed
+    #[serde(rename = "last_error")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+
+    /// When the delivery is attempted next, absent once delivered or failed
+    #[serde(rename = "next_attempt_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[serde(rename = "created_at")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+
+    #[serde(rename = "delivered_at")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivered_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-impl Order {
+impl WebhookDelivery {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
         id: String,
-        books: Vec<models::OrderedBook>,
-        customer_id: String,
-        shipping_date: chrono::naive::NaiveDate,
-        billing_address: models::Address,
+        event_id: i64,
+        event_type: String,
         status: String,
-    ) -> Order {
-        Order {
+        attempts: i32,
+        created_at: chrono::DateTime<chrono::Utc>,
+    ) -> WebhookDelivery {
+        WebhookDelivery {
             id,
-            books,
-            customer_id,
-            shipping_date,
-            billing_address,
-            shipping_address_override: None,
+            event_id,
+            event_type,
             status,
+            attempts,
+            last_status_code: None,
+            last_error: None,
+            next_attempt_at: None,
+            created_at,
+            delivered_at: None,
         }
     }
 }
 
-/// Converts the Order value to the Query Parameters representation (style=form, explode=false)
+/// Converts the WebhookDelivery value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for Order {
+impl std::fmt::Display for WebhookDelivery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
             Some("id".to_string()),
             Some(self.id.to_string()),
-            // Skipping books in query parameter serialization
-            Some("customer_id".to_string()),
-            Some(self.customer_id.to_string()),
-            // Skipping shipping_date in query parameter serialization
-
-            // Skipping billing_address in query parameter serialization
-
-            // Skipping shipping_address_override in query parameter serialization
+            Some("event_id".to_string()),
+            Some(self.event_id.to_string()),
+            Some("event_type".to_string()),
+            Some(self.event_type.to_string()),
             Some("status".to_string()),
             Some(self.status.to_string()),
+            Some("attempts".to_string()),
+            Some(self.attempts.to_string()),
+            self.last_status_code.as_ref().map(|last_status_code| {
+                ["last_status_code".to_string(), last_status_code.to_string()].join(",")
+            }),
+            self.last_error
+                .as_ref()
+                .map(|last_error| ["last_error".to_string(), last_error.to_string()].join(",")),
+            // Skipping next_attempt_at in query parameter serialization
+
+            // Skipping created_at in query parameter serialization
+
+            // Skipping delivered_at in query parameter serialization
         ];
 
         write!(
@@ -3226,10 +7481,10 @@ impl std::fmt::Display for Order {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Order value
+/// Converts Query Parameters representation (style=form, explode=false) to a WebhookDelivery value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Order {
+impl std::str::FromStr for WebhookDelivery {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3238,12 +7493,15 @@ impl std::str::FromStr for Order {
         #[allow(dead_code)]
         struct IntermediateRep {
             pub id: Vec<String>,
-            pub books: Vec<Vec<models::OrderedBook>>,
-            pub customer_id: Vec<String>,
-            pub shipping_date: Vec<chrono::naive::NaiveDate>,
-            pub billing_address: Vec<models::Address>,
-            pub shipping_address_override: Vec<models::Address>,
+            pub event_id: Vec<i64>,
+            pub event_type: Vec<String>,
             pub status: Vec<String>,
+            pub attempts: Vec<i32>,
+            pub last_status_code: Vec<i32>,
+            pub last_error: Vec<String>,
+            pub next_attempt_at: Vec<chrono::DateTime<chrono::Utc>>,
+            pub created_at: Vec<chrono::DateTime<chrono::Utc>>,
+            pub delivered_at: Vec<chrono::DateTime<chrono::Utc>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3257,7 +7515,7 @@ impl std::str::FromStr for Order {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing Order".to_string(),
+                        "Missing value while parsing WebhookDelivery".to_string(),
                     )
                 }
             };
@@ -3269,38 +7527,48 @@ impl std::str::FromStr for Order {
                     "id" => intermediate_rep.id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "books" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in Order"
-                                .to_string(),
-                        )
-                    }
                     #[allow(clippy::redundant_clone)]
-                    "customer_id" => intermediate_rep.customer_id.push(
+                    "event_id" => intermediate_rep.event_id.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "event_type" => intermediate_rep.event_type.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "shipping_date" => intermediate_rep.shipping_date.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "billing_address" => intermediate_rep.billing_address.push(
-                        <models::Address as std::str::FromStr>::from_str(val)
+                    "attempts" => intermediate_rep.attempts.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last_status_code" => intermediate_rep.last_status_code.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last_error" => intermediate_rep.last_error.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "next_attempt_at" => intermediate_rep.next_attempt_at.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "shipping_address_override" => intermediate_rep.shipping_address_override.push(
-                        <models::Address as std::str::FromStr>::from_str(val)
+                    "created_at" => intermediate_rep.created_at.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "delivered_at" => intermediate_rep.delivered_at.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing Order".to_string(),
+                            "Unexpected key while parsing WebhookDelivery".to_string(),
                         )
                     }
                 }
@@ -3311,59 +7579,59 @@ impl std::str::FromStr for Order {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Order {
+        std::result::Result::Ok(WebhookDelivery {
             id: intermediate_rep
                 .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "id missing in Order".to_string())?,
-            books: intermediate_rep
-                .books
+                .ok_or_else(|| "id missing in WebhookDelivery".to_string())?,
+            event_id: intermediate_rep
+                .event_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "books missing in Order".to_string())?,
-            customer_id: intermediate_rep
-                .customer_id
+                .ok_or_else(|| "event_id missing in WebhookDelivery".to_string())?,
+            event_type: intermediate_rep
+                .event_type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "customer_id missing in Order".to_string())?,
-            shipping_date: intermediate_rep
-                .shipping_date
+                .ok_or_else(|| "event_type missing in WebhookDelivery".to_string())?,
+            status: intermediate_rep
+                .status
                 .into_iter()
                 .next()
-                .ok_or_else(|| "shipping_date missing in Order".to_string())?,
-            billing_address: intermediate_rep
-                .billing_address
+                .ok_or_else(|| "status missing in WebhookDelivery".to_string())?,
+            attempts: intermediate_rep
+                .attempts
                 .into_iter()
                 .next()
-                .ok_or_else(|| "billing_address missing in Order".to_string())?,
-            shipping_address_override: intermediate_rep
-                .shipping_address_override
-                .into_iter()
-                .next(),
-            status: intermediate_rep
-                .status
+                .ok_or_else(|| "attempts missing in WebhookDelivery".to_string())?,
+            last_status_code: intermediate_rep.last_status_code.into_iter().next(),
+            last_error: intermediate_rep.last_error.into_iter().next(),
+            next_attempt_at: intermediate_rep.next_attempt_at.into_iter().next(),
+            created_at: intermediate_rep
+                .created_at
                 .into_iter()
                 .next()
-                .ok_or_else(|| "status missing in Order".to_string())?,
+                .ok_or_else(|| "created_at missing in WebhookDelivery".to_string())?,
+            delivered_at: intermediate_rep.delivered_at.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Order> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<WebhookDelivery> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<Order>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<WebhookDelivery>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<Order>,
+        hdr_value: header::IntoHeaderValue<WebhookDelivery>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for Order - value: {} is invalid {}",
+                "Invalid header value for WebhookDelivery - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3371,20 +7639,22 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Order>> for HeaderValue {
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Order> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<WebhookDelivery> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-            std::result::Result::Ok(value) => match <Order as std::str::FromStr>::from_str(value) {
-                std::result::Result::Ok(value) => {
-                    std::result::Result::Ok(header::IntoHeaderValue(value))
+            std::result::Result::Ok(value) => {
+                match <WebhookDelivery as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into WebhookDelivery - {}",
+                        value, err
+                    )),
                 }
-                std::result::Result::Err(err) => std::result::Result::Err(format!(
-                    "Unable to convert header value '{}' into Order - {}",
-                    value, err
-                )),
-            },
+            }
             std::result::Result::Err(e) => std::result::Result::Err(format!(
                 "Unable to convert header: {:?} to string: {}",
                 hdr_value, e
@@ -3393,37 +7663,39 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Order> {
     }
 }
 
+/// A page of webhook deliveries ordered by id
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct OrderProperties {
-    #[serde(rename = "shipping_date")]
-    pub shipping_date: chrono::naive::NaiveDate,
+pub struct WebhookDeliveryPage {
+    #[serde(rename = "items")]
+    pub items: Vec<models::WebhookDelivery>,
 
-    /// Order Status
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "status")]
-    pub status: String,
+    /// Cursor of the next page, absent on the last page
+    #[serde(rename = "next_cursor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
-impl OrderProperties {
+impl WebhookDeliveryPage {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(shipping_date: chrono::naive::NaiveDate, status: String) -> OrderProperties {
-        OrderProperties {
-            shipping_date,
-            status,
+    pub fn new(items: Vec<models::WebhookDelivery>) -> WebhookDeliveryPage {
+        WebhookDeliveryPage {
+            items,
+            next_cursor: None,
         }
     }
 }
 
-/// Converts the OrderProperties value to the Query Parameters representation (style=form, explode=false)
+/// Converts the WebhookDeliveryPage value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for OrderProperties {
+impl std::fmt::Display for WebhookDeliveryPage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            // Skipping shipping_date in query parameter serialization
-            Some("status".to_string()),
-            Some(self.status.to_string()),
+            // Skipping items in query parameter serialization
+            self.next_cursor
+                .as_ref()
+                .map(|next_cursor| ["next_cursor".to_string(), next_cursor.to_string()].join(",")),
         ];
 
         write!(
@@ -3434,10 +7706,10 @@ impl std::fmt::Display for OrderProperties {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a OrderProperties value
+/// Converts Query Parameters representation (style=form, explode=false) to a WebhookDeliveryPage value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for OrderProperties {
+impl std::str::FromStr for WebhookDeliveryPage {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3445,8 +7717,8 @@ impl std::str::FromStr for OrderProperties {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub shipping_date: Vec<chrono::naive::NaiveDate>,
-            pub status: Vec<String>,
+            pub items: Vec<Vec<models::WebhookDelivery>>,
+            pub next_cursor: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3460,7 +7732,7 @@ impl std::str::FromStr for OrderProperties {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing OrderProperties".to_string(),
+                        "Missing value while parsing WebhookDeliveryPage".to_string(),
                     )
                 }
             };
@@ -3468,18 +7740,17 @@ impl std::str::FromStr for OrderProperties {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "shipping_date" => intermediate_rep.shipping_date.push(
-                        <chrono::naive::NaiveDate as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "items" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in WebhookDeliveryPage"
+                            .to_string(),
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
+                    "next_cursor" => intermediate_rep.next_cursor.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing OrderProperties".to_string(),
+                            "Unexpected key while parsing WebhookDeliveryPage".to_string(),
                         )
                     }
                 }
@@ -3490,35 +7761,31 @@ impl std::str::FromStr for OrderProperties {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(OrderProperties {
-            shipping_date: intermediate_rep
-                .shipping_date
-                .into_iter()
-                .next()
-                .ok_or_else(|| "shipping_date missing in OrderProperties".to_string())?,
-            status: intermediate_rep
-                .status
+        std::result::Result::Ok(WebhookDeliveryPage {
+            items: intermediate_rep
+                .items
                 .into_iter()
                 .next()
-                .ok_or_else(|| "status missing in OrderProperties".to_string())?,
+                .ok_or_else(|| "items missing in WebhookDeliveryPage".to_string())?,
+            next_cursor: intermediate_rep.next_cursor.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<OrderProperties> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<WebhookDeliveryPage> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<OrderProperties>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<WebhookDeliveryPage>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<OrderProperties>,
+        hdr_value: header::IntoHeaderValue<WebhookDeliveryPage>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for OrderProperties - value: {} is invalid {}",
+                "Invalid header value for WebhookDeliveryPage - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3526,18 +7793,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<OrderProperties>> for HeaderV
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<OrderProperties> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<WebhookDeliveryPage> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <OrderProperties as std::str::FromStr>::from_str(value) {
+                match <WebhookDeliveryPage as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into OrderProperties - {}",
+                        "Unable to convert header value '{}' into WebhookDeliveryPage - {}",
                         value, err
                     )),
                 }
@@ -3552,31 +7819,59 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<OrderPropert
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct OrderedBook {
-    #[serde(rename = "book_id")]
-    pub book_id: String,
+pub struct WebhookProperties {
+    /// The endpoint the events are posted to
+    #[serde(rename = "url")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 
-    #[serde(rename = "quantity")]
-    pub quantity: i32,
+    /// The types of the events posted to the endpoint
+    #[serde(rename = "event_types")]
+    #[validate(length(min = 1))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_types: Option<Vec<String>>,
+
+    /// Replaces the secret the payloads are signed with
+    #[serde(rename = "secret")]
+    #[validate(length(min = 16))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
 }
 
-impl OrderedBook {
+impl WebhookProperties {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(book_id: String, quantity: i32) -> OrderedBook {
-        OrderedBook { book_id, quantity }
+    pub fn new() -> WebhookProperties {
+        WebhookProperties {
+            url: None,
+            event_types: None,
+            secret: None,
+        }
     }
 }
 
-/// Converts the OrderedBook value to the Query Parameters representation (style=form, explode=false)
+/// Converts the WebhookProperties value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for OrderedBook {
+impl std::fmt::Display for WebhookProperties {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("book_id".to_string()),
-            Some(self.book_id.to_string()),
-            Some("quantity".to_string()),
-            Some(self.quantity.to_string()),
+            self.url
+                .as_ref()
+                .map(|url| ["url".to_string(), url.to_string()].join(",")),
+            self.event_types.as_ref().map(|event_types| {
+                [
+                    "event_types".to_string(),
+                    event_types
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+            self.secret
+                .as_ref()
+                .map(|secret| ["secret".to_string(), secret.to_string()].join(",")),
         ];
 
         write!(
@@ -3587,10 +7882,10 @@ impl std::fmt::Display for OrderedBook {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a OrderedBook value
+/// Converts Query Parameters representation (style=form, explode=false) to a WebhookProperties value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for OrderedBook {
+impl std::str::FromStr for WebhookProperties {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3598,8 +7893,9 @@ impl std::str::FromStr for OrderedBook {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub book_id: Vec<String>,
-            pub quantity: Vec<i32>,
+            pub url: Vec<String>,
+            pub event_types: Vec<Vec<String>>,
+            pub secret: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3613,7 +7909,7 @@ impl std::str::FromStr for OrderedBook {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing OrderedBook".to_string(),
+                        "Missing value while parsing WebhookProperties".to_string(),
                     )
                 }
             };
@@ -3622,16 +7918,20 @@ impl std::str::FromStr for OrderedBook {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "book_id" => intermediate_rep.book_id.push(
+                    "url" => intermediate_rep.url.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "event_types" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in WebhookProperties"
+                            .to_string(),
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "quantity" => intermediate_rep.quantity.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "secret" => intermediate_rep.secret.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing OrderedBook".to_string(),
+                            "Unexpected key while parsing WebhookProperties".to_string(),
                         )
                     }
                 }
@@ -3642,35 +7942,28 @@ impl std::str::FromStr for OrderedBook {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(OrderedBook {
-            book_id: intermediate_rep
-                .book_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "book_id missing in OrderedBook".to_string())?,
-            quantity: intermediate_rep
-                .quantity
-                .into_iter()
-                .next()
-                .ok_or_else(|| "quantity missing in OrderedBook".to_string())?,
+        std::result::Result::Ok(WebhookProperties {
+            url: intermediate_rep.url.into_iter().next(),
+            event_types: intermediate_rep.event_types.into_iter().next(),
+            secret: intermediate_rep.secret.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<OrderedBook> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<WebhookProperties> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<OrderedBook>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<WebhookProperties>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<OrderedBook>,
+        hdr_value: header::IntoHeaderValue<WebhookProperties>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for OrderedBook - value: {} is invalid {}",
+                "Invalid header value for WebhookProperties - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3678,18 +7971,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<OrderedBook>> for HeaderValue
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<OrderedBook> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<WebhookProperties> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <OrderedBook as std::str::FromStr>::from_str(value) {
+                match <WebhookProperties as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into OrderedBook - {}",
+                        "Unable to convert header value '{}' into WebhookProperties - {}",
                         value, err
                     )),
                 }
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use axum::{body::Body, extract::*, response::Response, routing::*};
-use axum_extra::extract::{CookieJar, Host};
+use axum_extra::extract::{CookieJar, Host, Query};
 use bytes::Bytes;
 use http::{header::CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
 use tracing::error;
@@ -16,64 +16,104 @@ use crate::{apis, models};
 pub fn new<I, A, E, C>(api_impl: I) -> Router
 where
     I: AsRef<A> + Clone + Send + Sync + 'static,
-    A: apis::author::Author<E>
-        + apis::book::Book<E>
-        + apis::discount::Discount<E>
-        + apis::genre::Genre<E>
+    A: apis::author::Author<E, Claims = C>
+        + apis::book::Book<E, Claims = C>
+        + apis::customer::Customer<E, Claims = C>
+        + apis::discount::Discount<E, Claims = C>
+        + apis::genre::Genre<E, Claims = C>
         + apis::health::Health<E>
-        + apis::store::Store<E>
+        + apis::store::Store<E, Claims = C>
+        + apis::webhook::Webhook<E, Claims = C>
         + apis::ApiKeyAuthHeader<Claims = C>
         + Send
         + Sync
         + 'static,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync + 'static,
 {
     // build our application with a route
     Router::new()
-        .route("/api/v1/authors", post(add_author::<I, A, E>))
+        .route("/api/v1/authors", post(add_author::<I, A, E, C>))
         .route(
             "/api/v1/authors/{author_id}",
-            delete(delete_author::<I, A, E>)
-                .get(get_author_by_id::<I, A, E>)
-                .patch(update_author::<I, A, E>),
+            delete(delete_author::<I, A, E, C>)
+                .get(get_author_by_id::<I, A, E, C>)
+                .patch(update_author::<I, A, E, C>),
+        )
+        .route(
+            "/api/v1/books",
+            get(search_books::<I, A, E, C>).post(add_book::<I, A, E, C>),
+        )
+        .route(
+            "/api/v1/books/search",
+            get(full_text_search_books::<I, A, E, C>),
         )
-        .route("/api/v1/books", post(add_book::<I, A, E>))
         .route(
             "/api/v1/books/findByAuthorId",
-            get(get_books_by_authors::<I, A, E>),
+            get(get_books_by_authors::<I, A, E, C>),
         )
         .route(
             "/api/v1/books/findByGenreId",
-            get(get_books_by_genres::<I, A, E>),
+            get(get_books_by_genres::<I, A, E, C>),
         )
         .route(
             "/api/v1/books/findByStatus",
-            get(get_books_by_status::<I, A, E>),
+            get(get_books_by_status::<I, A, E, C>),
         )
         .route(
             "/api/v1/books/{book_id}",
-            delete(delete_book::<I, A, E>)
-                .get(get_book_by_id::<I, A, E>)
-                .patch(update_book::<I, A, E>),
+            delete(delete_book::<I, A, E, C>)
+                .get(get_book_by_id::<I, A, E, C>)
+                .patch(update_book::<I, A, E, C>),
+        )
+        .route("/api/v1/customers", post(add_customer::<I, A, E, C>))
+        .route(
+            "/api/v1/customers/{customer_id}",
+            delete(delete_customer::<I, A, E, C>)
+                .get(get_customer_by_id::<I, A, E, C>)
+                .patch(update_customer::<I, A, E, C>),
+        )
+        .route(
+            "/api/v1/customers/{customer_id}/orders",
+            get(get_customer_orders::<I, A, E, C>),
         )
-        .route("/api/v1/discounts", post(add_discount::<I, A, E>))
+        .route("/api/v1/discounts", post(add_discount::<I, A, E, C>))
         .route(
             "/api/v1/discounts/{discount_id}",
-            delete(delete_discount::<I, A, E>).get(get_discount_by_id::<I, A, E>),
+            delete(delete_discount::<I, A, E, C>).get(get_discount_by_id::<I, A, E, C>),
         )
-        .route("/api/v1/genres", post(add_genre::<I, A, E>))
+        .route("/api/v1/genres", post(add_genre::<I, A, E, C>))
         .route(
             "/api/v1/genres/{genre_id}",
-            delete(delete_genre::<I, A, E>).get(get_genre_by_id::<I, A, E>),
+            delete(delete_genre::<I, A, E, C>).get(get_genre_by_id::<I, A, E, C>),
         )
+        .route("/api/v1/health/liveness", get(get_liveness::<I, A, E>))
         .route("/api/v1/health/readiness", get(get_readiness::<I, A, E>))
-        .route("/api/v1/store/inventory", get(get_inventory::<I, A, E>))
-        .route("/api/v1/store/orders", post(place_order::<I, A, E>))
+        .route("/api/v1/store/inventory", get(get_inventory::<I, A, E, C>))
+        .route("/api/v1/store/orders", post(place_order::<I, A, E, C>))
         .route(
             "/api/v1/store/orders/{order_id}",
-            delete(delete_order::<I, A, E>)
-                .get(get_order_by_id::<I, A, E>)
-                .patch(update_order::<I, A, E>),
+            delete(delete_order::<I, A, E, C>)
+                .get(get_order_by_id::<I, A, E, C>)
+                .patch(update_order::<I, A, E, C>),
+        )
+        .route(
+            "/api/v1/store/orders/{order_id}/history",
+            get(get_order_history::<I, A, E, C>),
+        )
+        .route(
+            "/api/v1/webhooks",
+            get(get_webhooks::<I, A, E, C>).post(add_webhook::<I, A, E, C>),
+        )
+        .route(
+            "/api/v1/webhooks/{webhook_id}",
+            delete(delete_webhook::<I, A, E, C>)
+                .get(get_webhook_by_id::<I, A, E, C>)
+                .patch(update_webhook::<I, A, E, C>),
+        )
+        .route(
+            "/api/v1/webhooks/{webhook_id}/deliveries",
+            get(get_webhook_deliveries::<I, A, E, C>),
         )
         .with_state(api_impl)
 }
@@ -96,18 +136,33 @@ fn add_author_validation(
 }
 /// AddAuthor - POST /api/v1/authors
 #[tracing::instrument(skip_all)]
-async fn add_author<I, A, E>(
+async fn add_author<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    headers: HeaderMap,
     State(api_impl): State<I>,
     Json(body): Json<models::NewAuthor>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::author::Author<E> + Send + Sync,
+    A: apis::author::Author<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
     let validation = tokio::task::spawn_blocking(move || add_author_validation(body))
         .await
@@ -122,7 +177,7 @@ where
 
     let result = api_impl
         .as_ref()
-        .add_author(&method, &host, &cookies, &body)
+        .add_author(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
@@ -152,17 +207,101 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::author::AddAuthorResponse::Status400_InvalidInput => {
+            apis::author::AddAuthorResponse::Status400_InvalidInput(body) => {
                 let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::AddAuthorResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::author::AddAuthorResponse::Status422_ValidationException => {
+            apis::author::AddAuthorResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::AddAuthorResponse::Status422_ValidationException(body) => {
                 let mut response = response.status(422);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::author::AddAuthorResponse::Status500_ServerError => {
+            apis::author::AddAuthorResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -191,18 +330,33 @@ fn delete_author_validation(
 }
 /// DeleteAuthor - DELETE /api/v1/authors/{authorId}
 #[tracing::instrument(skip_all)]
-async fn delete_author<I, A, E>(
+async fn delete_author<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    headers: HeaderMap,
     Path(path_params): Path<models::DeleteAuthorPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::author::Author<E> + Send + Sync,
+    A: apis::author::Author<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
     let validation = tokio::task::spawn_blocking(move || delete_author_validation(path_params))
         .await
@@ -217,7 +371,7 @@ where
 
     let result = api_impl
         .as_ref()
-        .delete_author(&method, &host, &cookies, &path_params)
+        .delete_author(&method, &host, &cookies, &claims, &path_params)
         .await;
 
     let mut response = Response::builder();
@@ -228,17 +382,124 @@ where
                 let mut response = response.status(200);
                 response.body(Body::empty())
             }
-            apis::author::DeleteAuthorResponse::Status400_InvalidAuthorIdValue => {
+            apis::author::DeleteAuthorResponse::Status400_InvalidAuthorIdValue(body) => {
                 let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::DeleteAuthorResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::author::DeleteAuthorResponse::Status404_AuthorNotFound => {
+            apis::author::DeleteAuthorResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::DeleteAuthorResponse::Status404_AuthorNotFound(body) => {
                 let mut response = response.status(404);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::DeleteAuthorResponse::Status422_AuthorHasBooks(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::author::DeleteAuthorResponse::Status500_ServerError => {
+            apis::author::DeleteAuthorResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -267,18 +528,33 @@ fn get_author_by_id_validation(
 }
 /// GetAuthorById - GET /api/v1/authors/{authorId}
 #[tracing::instrument(skip_all)]
-async fn get_author_by_id<I, A, E>(
+async fn get_author_by_id<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    headers: HeaderMap,
     Path(path_params): Path<models::GetAuthorByIdPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::author::Author<E> + Send + Sync,
+    A: apis::author::Author<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
     let validation = tokio::task::spawn_blocking(move || get_author_by_id_validation(path_params))
         .await
@@ -293,14 +569,35 @@ where
 
     let result = api_impl
         .as_ref()
-        .get_author_by_id(&method, &host, &cookies, &path_params)
+        .get_author_by_id(&method, &host, &cookies, &claims, &path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::author::GetAuthorByIdResponse::Status200_SuccessfulOperation(body) => {
+            apis::author::GetAuthorByIdResponse::Status200_SuccessfulOperation { body, etag } => {
+                if let Some(etag) = etag {
+                    let etag = match header::IntoHeaderValue(etag).try_into() {
+                        Ok(val) => val,
+                        Err(e) => {
+                            return Response::builder()
+                                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                .body(Body::from(format!(
+                                    "An internal server error occurred handling etag header - {e}"
+                                )))
+                                .map_err(|e| {
+                                    error!(error = ?e);
+                                    StatusCode::INTERNAL_SERVER_ERROR
+                                });
+                        }
+                    };
+
+                    {
+                        let mut response_headers = response.headers_mut().unwrap();
+                        response_headers.insert(HeaderName::from_static("etag"), etag);
+                    }
+                }
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -323,28 +620,112 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::author::GetAuthorByIdResponse::Status400_InvalidParameters => {
+            apis::author::GetAuthorByIdResponse::Status400_InvalidParameters(body) => {
                 let mut response = response.status(400);
-                response.body(Body::empty())
-            }
-            apis::author::GetAuthorByIdResponse::Status404_AuthorNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::author::GetAuthorByIdResponse::Status500_ServerError => {
-                let mut response = response.status(500);
+            apis::author::GetAuthorByIdResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-        },
-        Err(why) => {
-            // Application code returned an error. This should not happen, as the implementation should
-            // return a valid response.
-            return api_impl
-                .as_ref()
-                .handle_error(&method, &host, &cookies, why)
-                .await;
-        }
-    };
+            apis::author::GetAuthorByIdResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::GetAuthorByIdResponse::Status404_AuthorNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::GetAuthorByIdResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
 
     resp.map_err(|e| {
         error!(error = ?e);
@@ -361,38 +742,87 @@ struct UpdateAuthorBodyValidator<'a> {
 
 #[tracing::instrument(skip_all)]
 fn update_author_validation(
+    header_params: models::UpdateAuthorHeaderParams,
     path_params: models::UpdateAuthorPathParams,
     body: models::AuthorProperties,
-) -> std::result::Result<(models::UpdateAuthorPathParams, models::AuthorProperties), ValidationErrors>
-{
+) -> std::result::Result<
+    (
+        models::UpdateAuthorHeaderParams,
+        models::UpdateAuthorPathParams,
+        models::AuthorProperties,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
     path_params.validate()?;
     let b = UpdateAuthorBodyValidator { body: &body };
     b.validate()?;
 
-    Ok((path_params, body))
+    Ok((header_params, path_params, body))
 }
 /// UpdateAuthor - PATCH /api/v1/authors/{authorId}
 #[tracing::instrument(skip_all)]
-async fn update_author<I, A, E>(
+async fn update_author<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    headers: HeaderMap,
     Path(path_params): Path<models::UpdateAuthorPathParams>,
     State(api_impl): State<I>,
     Json(body): Json<models::AuthorProperties>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::author::Author<E> + Send + Sync,
+    A: apis::author::Author<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+        let header_if_match = match header_if_match {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!("Invalid header If-Match - {err}")))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::UpdateAuthorHeaderParams {
+            if_match: header_if_match,
+        }
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation =
-        tokio::task::spawn_blocking(move || update_author_validation(path_params, body))
-            .await
-            .unwrap();
+    let validation = tokio::task::spawn_blocking(move || {
+        update_author_validation(header_params, path_params, body)
+    })
+    .await
+    .unwrap();
 
-    let Ok((path_params, body)) = validation else {
+    let Ok((header_params, path_params, body)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -401,14 +831,43 @@ where
 
     let result = api_impl
         .as_ref()
-        .update_author(&method, &host, &cookies, &path_params, &body)
+        .update_author(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &path_params,
+            &body,
+        )
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::author::UpdateAuthorResponse::Status200_SuccessfulOperation(body) => {
+            apis::author::UpdateAuthorResponse::Status200_SuccessfulOperation { body, etag } => {
+                if let Some(etag) = etag {
+                    let etag = match header::IntoHeaderValue(etag).try_into() {
+                        Ok(val) => val,
+                        Err(e) => {
+                            return Response::builder()
+                                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                .body(Body::from(format!(
+                                    "An internal server error occurred handling etag header - {e}"
+                                )))
+                                .map_err(|e| {
+                                    error!(error = ?e);
+                                    StatusCode::INTERNAL_SERVER_ERROR
+                                });
+                        }
+                    };
+
+                    {
+                        let mut response_headers = response.headers_mut().unwrap();
+                        response_headers.insert(HeaderName::from_static("etag"), etag);
+                    }
+                }
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -431,21 +890,147 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::author::UpdateAuthorResponse::Status400_InvalidParameters => {
+            apis::author::UpdateAuthorResponse::Status400_InvalidParameters(body) => {
                 let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::UpdateAuthorResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::author::UpdateAuthorResponse::Status404_AuthorNotFound => {
+            apis::author::UpdateAuthorResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::UpdateAuthorResponse::Status404_AuthorNotFound(body) => {
                 let mut response = response.status(404);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::author::UpdateAuthorResponse::Status412_PreconditionFailed(body) => {
+                let mut response = response.status(412);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::author::UpdateAuthorResponse::Status422_ValidationException => {
+            apis::author::UpdateAuthorResponse::Status422_ValidationException(body) => {
                 let mut response = response.status(422);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::author::UpdateAuthorResponse::Status500_ServerError => {
+            apis::author::UpdateAuthorResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -466,36 +1051,51 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct AddBookBodyValidator<'a> {
+struct AddCustomerBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a models::NewBook,
+    body: &'a models::NewCustomer,
 }
 
 #[tracing::instrument(skip_all)]
-fn add_book_validation(
-    body: models::NewBook,
-) -> std::result::Result<(models::NewBook,), ValidationErrors> {
-    let b = AddBookBodyValidator { body: &body };
+fn add_customer_validation(
+    body: models::NewCustomer,
+) -> std::result::Result<(models::NewCustomer,), ValidationErrors> {
+    let b = AddCustomerBodyValidator { body: &body };
     b.validate()?;
 
     Ok((body,))
 }
-/// AddBook - POST /api/v1/books
+/// AddCustomer - POST /api/v1/customers
 #[tracing::instrument(skip_all)]
-async fn add_book<I, A, E>(
+async fn add_customer<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::NewBook>,
+    Json(body): Json<models::NewCustomer>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::book::Book<E> + Send + Sync,
+    A: apis::customer::Customer<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || add_book_validation(body))
+    let validation = tokio::task::spawn_blocking(move || add_customer_validation(body))
         .await
         .unwrap();
 
@@ -508,14 +1108,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .add_book(&method, &host, &cookies, &body)
+        .add_customer(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::book::AddBookResponse::Status200_SuccessfulOperation(body) => {
+            apis::customer::AddCustomerResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -538,93 +1138,101 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::book::AddBookResponse::Status400_InvalidInput => {
+            apis::customer::AddCustomerResponse::Status400_InvalidInput(body) => {
                 let mut response = response.status(400);
-                response.body(Body::empty())
-            }
-            apis::book::AddBookResponse::Status422_ValidationException => {
-                let mut response = response.status(422);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::book::AddBookResponse::Status500_ServerError => {
-                let mut response = response.status(500);
+            apis::customer::AddCustomerResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-        },
-        Err(why) => {
-            // Application code returned an error. This should not happen, as the implementation should
-            // return a valid response.
-            return api_impl
-                .as_ref()
-                .handle_error(&method, &host, &cookies, why)
-                .await;
-        }
-    };
-
-    resp.map_err(|e| {
-        error!(error = ?e);
-        StatusCode::INTERNAL_SERVER_ERROR
-    })
-}
-
-#[tracing::instrument(skip_all)]
-fn delete_book_validation(
-    path_params: models::DeleteBookPathParams,
-) -> std::result::Result<(models::DeleteBookPathParams,), ValidationErrors> {
-    path_params.validate()?;
-
-    Ok((path_params,))
-}
-/// DeleteBook - DELETE /api/v1/books/{bookId}
-#[tracing::instrument(skip_all)]
-async fn delete_book<I, A, E>(
-    method: Method,
-    host: Host,
-    cookies: CookieJar,
-    Path(path_params): Path<models::DeleteBookPathParams>,
-    State(api_impl): State<I>,
-) -> Result<Response, StatusCode>
-where
-    I: AsRef<A> + Send + Sync,
-    A: apis::book::Book<E> + Send + Sync,
-    E: std::fmt::Debug + Send + Sync + 'static,
-{
-    #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || delete_book_validation(path_params))
-        .await
-        .unwrap();
-
-    let Ok((path_params,)) = validation else {
-        return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
-            .map_err(|_| StatusCode::BAD_REQUEST);
-    };
-
-    let result = api_impl
-        .as_ref()
-        .delete_book(&method, &host, &cookies, &path_params)
-        .await;
-
-    let mut response = Response::builder();
+            apis::customer::AddCustomerResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
 
-    let resp = match result {
-        Ok(rsp) => match rsp {
-            apis::book::DeleteBookResponse::Status200_SuccessfulOperation => {
-                let mut response = response.status(200);
-                response.body(Body::empty())
-            }
-            apis::book::DeleteBookResponse::Status400_InvalidBookIdValue => {
-                let mut response = response.status(400);
-                response.body(Body::empty())
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::book::DeleteBookResponse::Status404_BookIdNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
+            apis::customer::AddCustomerResponse::Status422_ValidationException(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::book::DeleteBookResponse::Status500_ServerError => {
+            apis::customer::AddCustomerResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -644,29 +1252,44 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn get_book_by_id_validation(
-    path_params: models::GetBookByIdPathParams,
-) -> std::result::Result<(models::GetBookByIdPathParams,), ValidationErrors> {
+fn delete_customer_validation(
+    path_params: models::DeleteCustomerPathParams,
+) -> std::result::Result<(models::DeleteCustomerPathParams,), ValidationErrors> {
     path_params.validate()?;
 
     Ok((path_params,))
 }
-/// GetBookById - GET /api/v1/books/{bookId}
+/// DeleteCustomer - DELETE /api/v1/customers/{customerId}
 #[tracing::instrument(skip_all)]
-async fn get_book_by_id<I, A, E>(
+async fn delete_customer<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::GetBookByIdPathParams>,
+    headers: HeaderMap,
+    Path(path_params): Path<models::DeleteCustomerPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::book::Book<E> + Send + Sync,
+    A: apis::customer::Customer<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || get_book_by_id_validation(path_params))
+    let validation = tokio::task::spawn_blocking(move || delete_customer_validation(path_params))
         .await
         .unwrap();
 
@@ -679,20 +1302,24 @@ where
 
     let result = api_impl
         .as_ref()
-        .get_book_by_id(&method, &host, &cookies, &path_params)
+        .delete_customer(&method, &host, &cookies, &claims, &path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::book::GetBookByIdResponse::Status200_SuccessfulOperation(body) => {
+            apis::customer::DeleteCustomerResponse::Status200_SuccessfullyDeleted => {
                 let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::customer::DeleteCustomerResponse::Status400_InvalidCustomerIdValue(body) => {
+                let mut response = response.status(400);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
                         CONTENT_TYPE,
-                        HeaderValue::from_str("application/json").map_err(|e| {
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
                             error!(error = ?e);
                             StatusCode::INTERNAL_SERVER_ERROR
                         })?,
@@ -709,17 +1336,101 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::book::GetBookByIdResponse::Status400_InvalidParameters => {
-                let mut response = response.status(400);
+            apis::customer::DeleteCustomerResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::book::GetBookByIdResponse::Status404_BookNotFound => {
+            apis::customer::DeleteCustomerResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::customer::DeleteCustomerResponse::Status404_CustomerNotFound(body) => {
                 let mut response = response.status(404);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::customer::DeleteCustomerResponse::Status422_CustomerHasPlacedOrders(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::book::GetBookByIdResponse::Status500_ServerError => {
+            apis::customer::DeleteCustomerResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -739,34 +1450,49 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn get_books_by_authors_validation(
-    query_params: models::GetBooksByAuthorsQueryParams,
-) -> std::result::Result<(models::GetBooksByAuthorsQueryParams,), ValidationErrors> {
-    query_params.validate()?;
+fn get_customer_by_id_validation(
+    path_params: models::GetCustomerByIdPathParams,
+) -> std::result::Result<(models::GetCustomerByIdPathParams,), ValidationErrors> {
+    path_params.validate()?;
 
-    Ok((query_params,))
+    Ok((path_params,))
 }
-/// GetBooksByAuthors - GET /api/v1/books/findByAuthorId
+/// GetCustomerById - GET /api/v1/customers/{customerId}
 #[tracing::instrument(skip_all)]
-async fn get_books_by_authors<I, A, E>(
+async fn get_customer_by_id<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Query(query_params): Query<models::GetBooksByAuthorsQueryParams>,
+    headers: HeaderMap,
+    Path(path_params): Path<models::GetCustomerByIdPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::book::Book<E> + Send + Sync,
+    A: apis::customer::Customer<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || get_books_by_authors_validation(query_params))
+        tokio::task::spawn_blocking(move || get_customer_by_id_validation(path_params))
             .await
             .unwrap();
 
-    let Ok((query_params,)) = validation else {
+    let Ok((path_params,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -775,14 +1501,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .get_books_by_authors(&method, &host, &cookies, &query_params)
+        .get_customer_by_id(&method, &host, &cookies, &claims, &path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::book::GetBooksByAuthorsResponse::Status200_SuccessfulOperation(body) => {
+            apis::customer::GetCustomerByIdResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -805,82 +1531,40 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::book::GetBooksByAuthorsResponse::Status400_InvalidAuthorValues => {
+            apis::customer::GetCustomerByIdResponse::Status400_InvalidParameters(body) => {
                 let mut response = response.status(400);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::book::GetBooksByAuthorsResponse::Status500_ServerError => {
-                let mut response = response.status(500);
+            apis::customer::GetCustomerByIdResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-        },
-        Err(why) => {
-            // Application code returned an error. This should not happen, as the implementation should
-            // return a valid response.
-            return api_impl
-                .as_ref()
-                .handle_error(&method, &host, &cookies, why)
-                .await;
-        }
-    };
-
-    resp.map_err(|e| {
-        error!(error = ?e);
-        StatusCode::INTERNAL_SERVER_ERROR
-    })
-}
-
-#[tracing::instrument(skip_all)]
-fn get_books_by_genres_validation(
-    query_params: models::GetBooksByGenresQueryParams,
-) -> std::result::Result<(models::GetBooksByGenresQueryParams,), ValidationErrors> {
-    query_params.validate()?;
-
-    Ok((query_params,))
-}
-/// GetBooksByGenres - GET /api/v1/books/findByGenreId
-#[tracing::instrument(skip_all)]
-async fn get_books_by_genres<I, A, E>(
-    method: Method,
-    host: Host,
-    cookies: CookieJar,
-    Query(query_params): Query<models::GetBooksByGenresQueryParams>,
-    State(api_impl): State<I>,
-) -> Result<Response, StatusCode>
-where
-    I: AsRef<A> + Send + Sync,
-    A: apis::book::Book<E> + Send + Sync,
-    E: std::fmt::Debug + Send + Sync + 'static,
-{
-    #[allow(clippy::redundant_closure)]
-    let validation =
-        tokio::task::spawn_blocking(move || get_books_by_genres_validation(query_params))
-            .await
-            .unwrap();
-
-    let Ok((query_params,)) = validation else {
-        return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
-            .map_err(|_| StatusCode::BAD_REQUEST);
-    };
-
-    let result = api_impl
-        .as_ref()
-        .get_books_by_genres(&method, &host, &cookies, &query_params)
-        .await;
-
-    let mut response = Response::builder();
-
-    let resp = match result {
-        Ok(rsp) => match rsp {
-            apis::book::GetBooksByGenresResponse::Status200_SuccessfulOperation(body) => {
-                let mut response = response.status(200);
+            apis::customer::GetCustomerByIdResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
                         CONTENT_TYPE,
-                        HeaderValue::from_str("application/json").map_err(|e| {
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
                             error!(error = ?e);
                             StatusCode::INTERNAL_SERVER_ERROR
                         })?,
@@ -897,13 +1581,51 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::book::GetBooksByGenresResponse::Status400_InvalidGenreValues => {
-                let mut response = response.status(400);
-                response.body(Body::empty())
+            apis::customer::GetCustomerByIdResponse::Status404_CustomerNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::book::GetBooksByGenresResponse::Status500_ServerError => {
+            apis::customer::GetCustomerByIdResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -922,35 +1644,64 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct UpdateCustomerBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::CustomerProperties,
+}
+
 #[tracing::instrument(skip_all)]
-fn get_books_by_status_validation(
-    query_params: models::GetBooksByStatusQueryParams,
-) -> std::result::Result<(models::GetBooksByStatusQueryParams,), ValidationErrors> {
-    query_params.validate()?;
+fn update_customer_validation(
+    path_params: models::UpdateCustomerPathParams,
+    body: models::CustomerProperties,
+) -> std::result::Result<
+    (models::UpdateCustomerPathParams, models::CustomerProperties),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    let b = UpdateCustomerBodyValidator { body: &body };
+    b.validate()?;
 
-    Ok((query_params,))
+    Ok((path_params, body))
 }
-/// GetBooksByStatus - GET /api/v1/books/findByStatus
+/// UpdateCustomer - PATCH /api/v1/customers/{customerId}
 #[tracing::instrument(skip_all)]
-async fn get_books_by_status<I, A, E>(
+async fn update_customer<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Query(query_params): Query<models::GetBooksByStatusQueryParams>,
+    headers: HeaderMap,
+    Path(path_params): Path<models::UpdateCustomerPathParams>,
     State(api_impl): State<I>,
+    Json(body): Json<models::CustomerProperties>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::book::Book<E> + Send + Sync,
+    A: apis::customer::Customer<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || get_books_by_status_validation(query_params))
+        tokio::task::spawn_blocking(move || update_customer_validation(path_params, body))
             .await
             .unwrap();
 
-    let Ok((query_params,)) = validation else {
+    let Ok((path_params, body)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -959,14 +1710,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .get_books_by_status(&method, &host, &cookies, &query_params)
+        .update_customer(&method, &host, &cookies, &claims, &path_params, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::book::GetBooksByStatusResponse::Status200_SuccessfulOperation(body) => {
+            apis::customer::UpdateCustomerResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -989,13 +1740,124 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::book::GetBooksByStatusResponse::Status400_InvalidStatusValue => {
+            apis::customer::UpdateCustomerResponse::Status400_InvalidParameters(body) => {
                 let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::customer::UpdateCustomerResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::book::GetBooksByStatusResponse::Status500_ServerError => {
+            apis::customer::UpdateCustomerResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::customer::UpdateCustomerResponse::Status404_CustomerNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::customer::UpdateCustomerResponse::Status422_ValidationException(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::customer::UpdateCustomerResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -1014,45 +1876,60 @@ where
     })
 }
 
-#[derive(validator::Validate)]
-#[allow(dead_code)]
-struct UpdateBookBodyValidator<'a> {
-    #[validate(nested)]
-    body: &'a models::BookProperties,
-}
-
 #[tracing::instrument(skip_all)]
-fn update_book_validation(
-    path_params: models::UpdateBookPathParams,
-    body: models::BookProperties,
-) -> std::result::Result<(models::UpdateBookPathParams, models::BookProperties), ValidationErrors> {
+fn get_customer_orders_validation(
+    path_params: models::GetCustomerOrdersPathParams,
+    query_params: models::GetCustomerOrdersQueryParams,
+) -> std::result::Result<
+    (
+        models::GetCustomerOrdersPathParams,
+        models::GetCustomerOrdersQueryParams,
+    ),
+    ValidationErrors,
+> {
     path_params.validate()?;
-    let b = UpdateBookBodyValidator { body: &body };
-    b.validate()?;
+    query_params.validate()?;
 
-    Ok((path_params, body))
+    Ok((path_params, query_params))
 }
-/// UpdateBook - PATCH /api/v1/books/{bookId}
+/// GetCustomerOrders - GET /api/v1/customers/{customerId}/orders
 #[tracing::instrument(skip_all)]
-async fn update_book<I, A, E>(
+async fn get_customer_orders<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::UpdateBookPathParams>,
+    headers: HeaderMap,
+    Path(path_params): Path<models::GetCustomerOrdersPathParams>,
+    Query(query_params): Query<models::GetCustomerOrdersQueryParams>,
     State(api_impl): State<I>,
-    Json(body): Json<models::BookProperties>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::book::Book<E> + Send + Sync,
+    A: apis::customer::Customer<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || update_book_validation(path_params, body))
-        .await
-        .unwrap();
+    let validation = tokio::task::spawn_blocking(move || {
+        get_customer_orders_validation(path_params, query_params)
+    })
+    .await
+    .unwrap();
 
-    let Ok((path_params, body)) = validation else {
+    let Ok((path_params, query_params)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -1061,14 +1938,21 @@ where
 
     let result = api_impl
         .as_ref()
-        .update_book(&method, &host, &cookies, &path_params, &body)
+        .get_customer_orders(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &path_params,
+            &query_params,
+        )
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::book::UpdateBookResponse::Status200_SuccessfulOperation(body) => {
+            apis::customer::GetCustomerOrdersResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -1091,21 +1975,101 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::book::UpdateBookResponse::Status400_InvalidParameters => {
+            apis::customer::GetCustomerOrdersResponse::Status400_InvalidParameters(body) => {
                 let mut response = response.status(400);
-                response.body(Body::empty())
-            }
-            apis::book::UpdateBookResponse::Status404_BookNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::book::UpdateBookResponse::Status422_ValidationException => {
-                let mut response = response.status(422);
+            apis::customer::GetCustomerOrdersResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::book::UpdateBookResponse::Status500_ServerError => {
-                let mut response = response.status(500);
-                response.body(Body::empty())
+            apis::customer::GetCustomerOrdersResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::customer::GetCustomerOrdersResponse::Status404_CustomerNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::customer::GetCustomerOrdersResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -1126,36 +2090,51 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct AddDiscountBodyValidator<'a> {
+struct AddBookBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a models::NewDiscountCode,
+    body: &'a models::NewBook,
 }
 
 #[tracing::instrument(skip_all)]
-fn add_discount_validation(
-    body: models::NewDiscountCode,
-) -> std::result::Result<(models::NewDiscountCode,), ValidationErrors> {
-    let b = AddDiscountBodyValidator { body: &body };
+fn add_book_validation(
+    body: models::NewBook,
+) -> std::result::Result<(models::NewBook,), ValidationErrors> {
+    let b = AddBookBodyValidator { body: &body };
     b.validate()?;
 
     Ok((body,))
 }
-/// AddDiscount - POST /api/v1/discounts
+/// AddBook - POST /api/v1/books
 #[tracing::instrument(skip_all)]
-async fn add_discount<I, A, E>(
+async fn add_book<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::NewDiscountCode>,
+    Json(body): Json<models::NewBook>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::discount::Discount<E> + Send + Sync,
+    A: apis::book::Book<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || add_discount_validation(body))
+    let validation = tokio::task::spawn_blocking(move || add_book_validation(body))
         .await
         .unwrap();
 
@@ -1168,14 +2147,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .add_discount(&method, &host, &cookies, &body)
+        .add_book(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::discount::AddDiscountResponse::Status200_SuccessfulOperation(body) => {
+            apis::book::AddBookResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -1198,17 +2177,101 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::discount::AddDiscountResponse::Status400_InvalidInput => {
+            apis::book::AddBookResponse::Status400_InvalidInput(body) => {
                 let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::AddBookResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::discount::AddDiscountResponse::Status422_ValidationException => {
+            apis::book::AddBookResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::AddBookResponse::Status422_ValidationException(body) => {
                 let mut response = response.status(422);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::discount::AddDiscountResponse::Status500_ServerError => {
+            apis::book::AddBookResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -1228,29 +2291,44 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn delete_discount_validation(
-    path_params: models::DeleteDiscountPathParams,
-) -> std::result::Result<(models::DeleteDiscountPathParams,), ValidationErrors> {
+fn delete_book_validation(
+    path_params: models::DeleteBookPathParams,
+) -> std::result::Result<(models::DeleteBookPathParams,), ValidationErrors> {
     path_params.validate()?;
 
     Ok((path_params,))
 }
-/// DeleteDiscount - DELETE /api/v1/discounts/{discountId}
+/// DeleteBook - DELETE /api/v1/books/{bookId}
 #[tracing::instrument(skip_all)]
-async fn delete_discount<I, A, E>(
+async fn delete_book<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::DeleteDiscountPathParams>,
+    headers: HeaderMap,
+    Path(path_params): Path<models::DeleteBookPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::discount::Discount<E> + Send + Sync,
+    A: apis::book::Book<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || delete_discount_validation(path_params))
+    let validation = tokio::task::spawn_blocking(move || delete_book_validation(path_params))
         .await
         .unwrap();
 
@@ -1263,97 +2341,24 @@ where
 
     let result = api_impl
         .as_ref()
-        .delete_discount(&method, &host, &cookies, &path_params)
+        .delete_book(&method, &host, &cookies, &claims, &path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::discount::DeleteDiscountResponse::Status200_SuccessfulOperation => {
+            apis::book::DeleteBookResponse::Status200_SuccessfulOperation => {
                 let mut response = response.status(200);
                 response.body(Body::empty())
             }
-            apis::discount::DeleteDiscountResponse::Status400_InvalidDiscountIdValue => {
+            apis::book::DeleteBookResponse::Status400_InvalidBookIdValue(body) => {
                 let mut response = response.status(400);
-                response.body(Body::empty())
-            }
-            apis::discount::DeleteDiscountResponse::Status404_DiscountNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
-            }
-            apis::discount::DeleteDiscountResponse::Status500_ServerError => {
-                let mut response = response.status(500);
-                response.body(Body::empty())
-            }
-        },
-        Err(why) => {
-            // Application code returned an error. This should not happen, as the implementation should
-            // return a valid response.
-            return api_impl
-                .as_ref()
-                .handle_error(&method, &host, &cookies, why)
-                .await;
-        }
-    };
-
-    resp.map_err(|e| {
-        error!(error = ?e);
-        StatusCode::INTERNAL_SERVER_ERROR
-    })
-}
-
-#[tracing::instrument(skip_all)]
-fn get_discount_by_id_validation(
-    path_params: models::GetDiscountByIdPathParams,
-) -> std::result::Result<(models::GetDiscountByIdPathParams,), ValidationErrors> {
-    path_params.validate()?;
-
-    Ok((path_params,))
-}
-/// GetDiscountById - GET /api/v1/discounts/{discountId}
-#[tracing::instrument(skip_all)]
-async fn get_discount_by_id<I, A, E>(
-    method: Method,
-    host: Host,
-    cookies: CookieJar,
-    Path(path_params): Path<models::GetDiscountByIdPathParams>,
-    State(api_impl): State<I>,
-) -> Result<Response, StatusCode>
-where
-    I: AsRef<A> + Send + Sync,
-    A: apis::discount::Discount<E> + Send + Sync,
-    E: std::fmt::Debug + Send + Sync + 'static,
-{
-    #[allow(clippy::redundant_closure)]
-    let validation =
-        tokio::task::spawn_blocking(move || get_discount_by_id_validation(path_params))
-            .await
-            .unwrap();
-
-    let Ok((path_params,)) = validation else {
-        return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
-            .map_err(|_| StatusCode::BAD_REQUEST);
-    };
-
-    let result = api_impl
-        .as_ref()
-        .get_discount_by_id(&method, &host, &cookies, &path_params)
-        .await;
-
-    let mut response = Response::builder();
-
-    let resp = match result {
-        Ok(rsp) => match rsp {
-            apis::discount::GetDiscountByIdResponse::Status200_SuccessfulOperation(body) => {
-                let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
                         CONTENT_TYPE,
-                        HeaderValue::from_str("application/json").map_err(|e| {
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
                             error!(error = ?e);
                             StatusCode::INTERNAL_SERVER_ERROR
                         })?,
@@ -1370,17 +2375,4442 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::discount::GetDiscountByIdResponse::Status400_InvalidParameters => {
-                let mut response = response.status(400);
+            apis::book::DeleteBookResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::discount::GetDiscountByIdResponse::Status404_DiscountCodeNotFound => {
+            apis::book::DeleteBookResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::DeleteBookResponse::Status404_BookIdNotFound(body) => {
                 let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::DeleteBookResponse::Status422_BookIsPartOfOrders(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::DeleteBookResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn full_text_search_books_validation(
+    header_params: models::FullTextSearchBooksHeaderParams,
+    query_params: models::FullTextSearchBooksQueryParams,
+) -> std::result::Result<
+    (
+        models::FullTextSearchBooksHeaderParams,
+        models::FullTextSearchBooksQueryParams,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
+    query_params.validate()?;
+
+    Ok((header_params, query_params))
+}
+/// FullTextSearchBooks - GET /api/v1/books/search
+#[tracing::instrument(skip_all)]
+async fn full_text_search_books<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Query(query_params): Query<models::FullTextSearchBooksQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::book::Book<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_accept_currency = headers.get(HeaderName::from_static("accept-currency"));
+
+        let header_accept_currency = match header_accept_currency {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!(
+                            "Invalid header Accept-Currency - {err}"
+                        )))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::FullTextSearchBooksHeaderParams {
+            accept_currency: header_accept_currency,
+        }
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        full_text_search_books_validation(header_params, query_params)
+    })
+    .await
+    .unwrap();
+
+    let Ok((header_params, query_params)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .full_text_search_books(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &query_params,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::book::FullTextSearchBooksResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::FullTextSearchBooksResponse::Status400_InvalidSearchText(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::FullTextSearchBooksResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::discount::GetDiscountByIdResponse::Status500_ServerError => {
+            apis::book::FullTextSearchBooksResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::FullTextSearchBooksResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_book_by_id_validation(
+    header_params: models::GetBookByIdHeaderParams,
+    path_params: models::GetBookByIdPathParams,
+    query_params: models::GetBookByIdQueryParams,
+) -> std::result::Result<
+    (
+        models::GetBookByIdHeaderParams,
+        models::GetBookByIdPathParams,
+        models::GetBookByIdQueryParams,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
+    path_params.validate()?;
+    query_params.validate()?;
+
+    Ok((header_params, path_params, query_params))
+}
+/// GetBookById - GET /api/v1/books/{bookId}
+#[tracing::instrument(skip_all)]
+async fn get_book_by_id<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::GetBookByIdPathParams>,
+    Query(query_params): Query<models::GetBookByIdQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::book::Book<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_accept_currency = headers.get(HeaderName::from_static("accept-currency"));
+
+        let header_accept_currency = match header_accept_currency {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!(
+                            "Invalid header Accept-Currency - {err}"
+                        )))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::GetBookByIdHeaderParams {
+            accept_currency: header_accept_currency,
+        }
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        get_book_by_id_validation(header_params, path_params, query_params)
+    })
+    .await
+    .unwrap();
+
+    let Ok((header_params, path_params, query_params)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_book_by_id(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &path_params,
+            &query_params,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::book::GetBookByIdResponse::Status200_SuccessfulOperation { body, etag } => {
+                if let Some(etag) = etag {
+                    let etag = match header::IntoHeaderValue(etag).try_into() {
+                        Ok(val) => val,
+                        Err(e) => {
+                            return Response::builder()
+                                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                .body(Body::from(format!(
+                                    "An internal server error occurred handling etag header - {e}"
+                                )))
+                                .map_err(|e| {
+                                    error!(error = ?e);
+                                    StatusCode::INTERNAL_SERVER_ERROR
+                                });
+                        }
+                    };
+
+                    {
+                        let mut response_headers = response.headers_mut().unwrap();
+                        response_headers.insert(HeaderName::from_static("etag"), etag);
+                    }
+                }
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBookByIdResponse::Status400_InvalidParameters(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBookByIdResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::book::GetBookByIdResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBookByIdResponse::Status404_BookNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBookByIdResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_books_by_authors_validation(
+    header_params: models::GetBooksByAuthorsHeaderParams,
+    query_params: models::GetBooksByAuthorsQueryParams,
+) -> std::result::Result<
+    (
+        models::GetBooksByAuthorsHeaderParams,
+        models::GetBooksByAuthorsQueryParams,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
+    query_params.validate()?;
+
+    Ok((header_params, query_params))
+}
+/// GetBooksByAuthors - GET /api/v1/books/findByAuthorId
+#[tracing::instrument(skip_all)]
+async fn get_books_by_authors<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Query(query_params): Query<models::GetBooksByAuthorsQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::book::Book<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_accept_currency = headers.get(HeaderName::from_static("accept-currency"));
+
+        let header_accept_currency = match header_accept_currency {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!(
+                            "Invalid header Accept-Currency - {err}"
+                        )))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::GetBooksByAuthorsHeaderParams {
+            accept_currency: header_accept_currency,
+        }
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        get_books_by_authors_validation(header_params, query_params)
+    })
+    .await
+    .unwrap();
+
+    let Ok((header_params, query_params)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_books_by_authors(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &query_params,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::book::GetBooksByAuthorsResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBooksByAuthorsResponse::Status400_InvalidAuthorValues(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBooksByAuthorsResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::book::GetBooksByAuthorsResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBooksByAuthorsResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_books_by_genres_validation(
+    header_params: models::GetBooksByGenresHeaderParams,
+    query_params: models::GetBooksByGenresQueryParams,
+) -> std::result::Result<
+    (
+        models::GetBooksByGenresHeaderParams,
+        models::GetBooksByGenresQueryParams,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
+    query_params.validate()?;
+
+    Ok((header_params, query_params))
+}
+/// GetBooksByGenres - GET /api/v1/books/findByGenreId
+#[tracing::instrument(skip_all)]
+async fn get_books_by_genres<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Query(query_params): Query<models::GetBooksByGenresQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::book::Book<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_accept_currency = headers.get(HeaderName::from_static("accept-currency"));
+
+        let header_accept_currency = match header_accept_currency {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!(
+                            "Invalid header Accept-Currency - {err}"
+                        )))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::GetBooksByGenresHeaderParams {
+            accept_currency: header_accept_currency,
+        }
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        get_books_by_genres_validation(header_params, query_params)
+    })
+    .await
+    .unwrap();
+
+    let Ok((header_params, query_params)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_books_by_genres(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &query_params,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::book::GetBooksByGenresResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBooksByGenresResponse::Status400_InvalidGenreValues(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBooksByGenresResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::book::GetBooksByGenresResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBooksByGenresResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_books_by_status_validation(
+    header_params: models::GetBooksByStatusHeaderParams,
+    query_params: models::GetBooksByStatusQueryParams,
+) -> std::result::Result<
+    (
+        models::GetBooksByStatusHeaderParams,
+        models::GetBooksByStatusQueryParams,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
+    query_params.validate()?;
+
+    Ok((header_params, query_params))
+}
+/// GetBooksByStatus - GET /api/v1/books/findByStatus
+#[tracing::instrument(skip_all)]
+async fn get_books_by_status<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Query(query_params): Query<models::GetBooksByStatusQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::book::Book<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_accept_currency = headers.get(HeaderName::from_static("accept-currency"));
+
+        let header_accept_currency = match header_accept_currency {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!(
+                            "Invalid header Accept-Currency - {err}"
+                        )))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::GetBooksByStatusHeaderParams {
+            accept_currency: header_accept_currency,
+        }
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        get_books_by_status_validation(header_params, query_params)
+    })
+    .await
+    .unwrap();
+
+    let Ok((header_params, query_params)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_books_by_status(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &query_params,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::book::GetBooksByStatusResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBooksByStatusResponse::Status400_InvalidStatusValue(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBooksByStatusResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::book::GetBooksByStatusResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::GetBooksByStatusResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn search_books_validation(
+    header_params: models::SearchBooksHeaderParams,
+    query_params: models::SearchBooksQueryParams,
+) -> std::result::Result<
+    (
+        models::SearchBooksHeaderParams,
+        models::SearchBooksQueryParams,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
+    query_params.validate()?;
+
+    Ok((header_params, query_params))
+}
+/// SearchBooks - GET /api/v1/books
+#[tracing::instrument(skip_all)]
+async fn search_books<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Query(query_params): Query<models::SearchBooksQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::book::Book<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_accept_currency = headers.get(HeaderName::from_static("accept-currency"));
+
+        let header_accept_currency = match header_accept_currency {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!(
+                            "Invalid header Accept-Currency - {err}"
+                        )))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::SearchBooksHeaderParams {
+            accept_currency: header_accept_currency,
+        }
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || search_books_validation(header_params, query_params))
+            .await
+            .unwrap();
+
+    let Ok((header_params, query_params)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .search_books(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &query_params,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::book::SearchBooksResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::SearchBooksResponse::Status400_InvalidSearchParameters(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::SearchBooksResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::book::SearchBooksResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::SearchBooksResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct UpdateBookBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::BookProperties,
+}
+
+#[tracing::instrument(skip_all)]
+fn update_book_validation(
+    header_params: models::UpdateBookHeaderParams,
+    path_params: models::UpdateBookPathParams,
+    body: models::BookProperties,
+) -> std::result::Result<
+    (
+        models::UpdateBookHeaderParams,
+        models::UpdateBookPathParams,
+        models::BookProperties,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
+    path_params.validate()?;
+    let b = UpdateBookBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((header_params, path_params, body))
+}
+/// UpdateBook - PATCH /api/v1/books/{bookId}
+#[tracing::instrument(skip_all)]
+async fn update_book<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::UpdateBookPathParams>,
+    State(api_impl): State<I>,
+    Json(body): Json<models::BookProperties>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::book::Book<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+        let header_if_match = match header_if_match {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!("Invalid header If-Match - {err}")))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::UpdateBookHeaderParams {
+            if_match: header_if_match,
+        }
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        update_book_validation(header_params, path_params, body)
+    })
+    .await
+    .unwrap();
+
+    let Ok((header_params, path_params, body)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .update_book(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &path_params,
+            &body,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::book::UpdateBookResponse::Status200_SuccessfulOperation { body, etag } => {
+                if let Some(etag) = etag {
+                    let etag = match header::IntoHeaderValue(etag).try_into() {
+                        Ok(val) => val,
+                        Err(e) => {
+                            return Response::builder()
+                                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                .body(Body::from(format!(
+                                    "An internal server error occurred handling etag header - {e}"
+                                )))
+                                .map_err(|e| {
+                                    error!(error = ?e);
+                                    StatusCode::INTERNAL_SERVER_ERROR
+                                });
+                        }
+                    };
+
+                    {
+                        let mut response_headers = response.headers_mut().unwrap();
+                        response_headers.insert(HeaderName::from_static("etag"), etag);
+                    }
+                }
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::UpdateBookResponse::Status400_InvalidParameters(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::UpdateBookResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::book::UpdateBookResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::UpdateBookResponse::Status404_BookNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::UpdateBookResponse::Status412_PreconditionFailed(body) => {
+                let mut response = response.status(412);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::UpdateBookResponse::Status422_ValidationException(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::book::UpdateBookResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct AddDiscountBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::NewDiscountCode,
+}
+
+#[tracing::instrument(skip_all)]
+fn add_discount_validation(
+    body: models::NewDiscountCode,
+) -> std::result::Result<(models::NewDiscountCode,), ValidationErrors> {
+    let b = AddDiscountBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// AddDiscount - POST /api/v1/discounts
+#[tracing::instrument(skip_all)]
+async fn add_discount<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+    Json(body): Json<models::NewDiscountCode>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::discount::Discount<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || add_discount_validation(body))
+        .await
+        .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .add_discount(&method, &host, &cookies, &claims, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::discount::AddDiscountResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::AddDiscountResponse::Status400_InvalidInput(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::AddDiscountResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::discount::AddDiscountResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::AddDiscountResponse::Status422_ValidationException(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::AddDiscountResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn delete_discount_validation(
+    path_params: models::DeleteDiscountPathParams,
+) -> std::result::Result<(models::DeleteDiscountPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// DeleteDiscount - DELETE /api/v1/discounts/{discountId}
+#[tracing::instrument(skip_all)]
+async fn delete_discount<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::DeleteDiscountPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::discount::Discount<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || delete_discount_validation(path_params))
+        .await
+        .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .delete_discount(&method, &host, &cookies, &claims, &path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::discount::DeleteDiscountResponse::Status200_SuccessfulOperation => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::discount::DeleteDiscountResponse::Status400_InvalidDiscountIdValue(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::DeleteDiscountResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::discount::DeleteDiscountResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::DeleteDiscountResponse::Status404_DiscountNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::DeleteDiscountResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_discount_by_id_validation(
+    path_params: models::GetDiscountByIdPathParams,
+) -> std::result::Result<(models::GetDiscountByIdPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// GetDiscountById - GET /api/v1/discounts/{discountId}
+#[tracing::instrument(skip_all)]
+async fn get_discount_by_id<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::GetDiscountByIdPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::discount::Discount<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || get_discount_by_id_validation(path_params))
+            .await
+            .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_discount_by_id(&method, &host, &cookies, &claims, &path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::discount::GetDiscountByIdResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::GetDiscountByIdResponse::Status400_InvalidParameters(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::GetDiscountByIdResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::discount::GetDiscountByIdResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::GetDiscountByIdResponse::Status404_DiscountCodeNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::discount::GetDiscountByIdResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct AddGenreBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::NewGenre,
+}
+
+#[tracing::instrument(skip_all)]
+fn add_genre_validation(
+    body: models::NewGenre,
+) -> std::result::Result<(models::NewGenre,), ValidationErrors> {
+    let b = AddGenreBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// AddGenre - POST /api/v1/genres
+#[tracing::instrument(skip_all)]
+async fn add_genre<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+    Json(body): Json<models::NewGenre>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::genre::Genre<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || add_genre_validation(body))
+        .await
+        .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .add_genre(&method, &host, &cookies, &claims, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::genre::AddGenreResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::AddGenreResponse::Status400_InvalidInput(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::AddGenreResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::genre::AddGenreResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::AddGenreResponse::Status422_ValidationException(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::AddGenreResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn delete_genre_validation(
+    path_params: models::DeleteGenrePathParams,
+) -> std::result::Result<(models::DeleteGenrePathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// DeleteGenre - DELETE /api/v1/genres/{genreId}
+#[tracing::instrument(skip_all)]
+async fn delete_genre<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::DeleteGenrePathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::genre::Genre<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || delete_genre_validation(path_params))
+        .await
+        .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .delete_genre(&method, &host, &cookies, &claims, &path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::genre::DeleteGenreResponse::Status200_SuccessfulOperation => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::genre::DeleteGenreResponse::Status400_InvalidGenreIdValue(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::DeleteGenreResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::genre::DeleteGenreResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::DeleteGenreResponse::Status404_GenreNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::DeleteGenreResponse::Status422_GenreIsAssignedToBooks(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::DeleteGenreResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_genre_by_id_validation(
+    path_params: models::GetGenreByIdPathParams,
+) -> std::result::Result<(models::GetGenreByIdPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// GetGenreById - GET /api/v1/genres/{genreId}
+#[tracing::instrument(skip_all)]
+async fn get_genre_by_id<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::GetGenreByIdPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::genre::Genre<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || get_genre_by_id_validation(path_params))
+        .await
+        .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_genre_by_id(&method, &host, &cookies, &claims, &path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::genre::GetGenreByIdResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::GetGenreByIdResponse::Status400_InvalidParameters(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::GetGenreByIdResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::genre::GetGenreByIdResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::GetGenreByIdResponse::Status404_GenreNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::genre::GetGenreByIdResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_liveness_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// GetLiveness - GET /api/v1/health/liveness
+#[tracing::instrument(skip_all)]
+async fn get_liveness<I, A, E>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::health::Health<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || get_liveness_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_liveness(&method, &host, &cookies)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::health::GetLivenessResponse::Status200_Successful(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::health::GetLivenessResponse::Status500_ServerError => {
+                let mut response = response.status(500);
+                response.body(Body::empty())
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_readiness_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// GetReadiness - GET /api/v1/health/readiness
+#[tracing::instrument(skip_all)]
+async fn get_readiness<I, A, E>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::health::Health<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || get_readiness_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_readiness(&method, &host, &cookies)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::health::GetReadinessResponse::Status200_Successful(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::health::GetReadinessResponse::Status500_ServerError => {
+                let mut response = response.status(500);
+                response.body(Body::empty())
+            }
+            apis::health::GetReadinessResponse::Status503_ServiceUnavailable(body) => {
+                let mut response = response.status(503);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn delete_order_validation(
+    path_params: models::DeleteOrderPathParams,
+    query_params: models::DeleteOrderQueryParams,
+) -> std::result::Result<
+    (
+        models::DeleteOrderPathParams,
+        models::DeleteOrderQueryParams,
+    ),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    query_params.validate()?;
+
+    Ok((path_params, query_params))
+}
+/// DeleteOrder - DELETE /api/v1/store/orders/{orderId}
+#[tracing::instrument(skip_all)]
+async fn delete_order<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::DeleteOrderPathParams>,
+    Query(query_params): Query<models::DeleteOrderQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::store::Store<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || delete_order_validation(path_params, query_params))
+            .await
+            .unwrap();
+
+    let Ok((path_params, query_params)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .delete_order(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &path_params,
+            &query_params,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::store::DeleteOrderResponse::Status200_SuccessfulOperation => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::store::DeleteOrderResponse::Status400_InvalidIDSupplied(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::DeleteOrderResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::store::DeleteOrderResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::DeleteOrderResponse::Status404_OrderNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::DeleteOrderResponse::Status422_OrderCanNotBeCanceled(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::DeleteOrderResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_inventory_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// GetInventory - GET /api/v1/store/inventory
+#[tracing::instrument(skip_all)]
+async fn get_inventory<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::store::Store<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || get_inventory_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_inventory(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::store::GetInventoryResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetInventoryResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::store::GetInventoryResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetInventoryResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_order_by_id_validation(
+    path_params: models::GetOrderByIdPathParams,
+) -> std::result::Result<(models::GetOrderByIdPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// GetOrderById - GET /api/v1/store/orders/{orderId}
+#[tracing::instrument(skip_all)]
+async fn get_order_by_id<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::GetOrderByIdPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::store::Store<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || get_order_by_id_validation(path_params))
+        .await
+        .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_order_by_id(&method, &host, &cookies, &claims, &path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::store::GetOrderByIdResponse::Status200_SuccessfulOperation { body, etag } => {
+                if let Some(etag) = etag {
+                    let etag = match header::IntoHeaderValue(etag).try_into() {
+                        Ok(val) => val,
+                        Err(e) => {
+                            return Response::builder()
+                                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                .body(Body::from(format!(
+                                    "An internal server error occurred handling etag header - {e}"
+                                )))
+                                .map_err(|e| {
+                                    error!(error = ?e);
+                                    StatusCode::INTERNAL_SERVER_ERROR
+                                });
+                        }
+                    };
+
+                    {
+                        let mut response_headers = response.headers_mut().unwrap();
+                        response_headers.insert(HeaderName::from_static("etag"), etag);
+                    }
+                }
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetOrderByIdResponse::Status400_InvalidIDSupplied(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetOrderByIdResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::store::GetOrderByIdResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetOrderByIdResponse::Status404_OrderNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetOrderByIdResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_order_history_validation(
+    path_params: models::GetOrderHistoryPathParams,
+) -> std::result::Result<(models::GetOrderHistoryPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// GetOrderHistory - GET /api/v1/store/orders/{orderId}/history
+#[tracing::instrument(skip_all)]
+async fn get_order_history<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::GetOrderHistoryPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::store::Store<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || get_order_history_validation(path_params))
+        .await
+        .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .get_order_history(&method, &host, &cookies, &claims, &path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::store::GetOrderHistoryResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetOrderHistoryResponse::Status400_InvalidIDSupplied(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetOrderHistoryResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::store::GetOrderHistoryResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetOrderHistoryResponse::Status404_OrderNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::GetOrderHistoryResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct PlaceOrderBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::NewOrder,
+}
+
+#[tracing::instrument(skip_all)]
+fn place_order_validation(
+    header_params: models::PlaceOrderHeaderParams,
+    query_params: models::PlaceOrderQueryParams,
+    body: models::NewOrder,
+) -> std::result::Result<
+    (
+        models::PlaceOrderHeaderParams,
+        models::PlaceOrderQueryParams,
+        models::NewOrder,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
+    query_params.validate()?;
+    let b = PlaceOrderBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((header_params, query_params, body))
+}
+/// PlaceOrder - POST /api/v1/store/orders
+#[tracing::instrument(skip_all)]
+async fn place_order<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Query(query_params): Query<models::PlaceOrderQueryParams>,
+    State(api_impl): State<I>,
+    Json(body): Json<models::NewOrder>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::store::Store<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_accept_currency = headers.get(HeaderName::from_static("accept-currency"));
+
+        let header_accept_currency = match header_accept_currency {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!(
+                            "Invalid header Accept-Currency - {err}"
+                        )))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::PlaceOrderHeaderParams {
+            accept_currency: header_accept_currency,
+        }
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        place_order_validation(header_params, query_params, body)
+    })
+    .await
+    .unwrap();
+
+    let Ok((header_params, query_params, body)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .place_order(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &query_params,
+            &body,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::store::PlaceOrderResponse::Status200_SuccessfulOperation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::PlaceOrderResponse::Status400_InvalidInput(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::PlaceOrderResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::store::PlaceOrderResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::PlaceOrderResponse::Status422_ValidationException(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::PlaceOrderResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct UpdateOrderBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::OrderProperties,
+}
+
+#[tracing::instrument(skip_all)]
+fn update_order_validation(
+    header_params: models::UpdateOrderHeaderParams,
+    path_params: models::UpdateOrderPathParams,
+    body: models::OrderProperties,
+) -> std::result::Result<
+    (
+        models::UpdateOrderHeaderParams,
+        models::UpdateOrderPathParams,
+        models::OrderProperties,
+    ),
+    ValidationErrors,
+> {
+    header_params.validate()?;
+    path_params.validate()?;
+    let b = UpdateOrderBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((header_params, path_params, body))
+}
+/// UpdateOrder - PATCH /api/v1/store/orders/{orderId}
+#[tracing::instrument(skip_all)]
+async fn update_order<I, A, E, C>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::UpdateOrderPathParams>,
+    State(api_impl): State<I>,
+    Json(body): Json<models::OrderProperties>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::store::Store<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
+{
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
+    // Header parameters
+    let header_params = {
+        let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+        let header_if_match = match header_if_match {
+            Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                Ok(result) => Some(result.0),
+                Err(err) => {
+                    return Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from(format!("Invalid header If-Match - {err}")))
+                        .map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        });
+                }
+            },
+            None => None,
+        };
+
+        models::UpdateOrderHeaderParams {
+            if_match: header_if_match,
+        }
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        update_order_validation(header_params, path_params, body)
+    })
+    .await
+    .unwrap();
+
+    let Ok((header_params, path_params, body)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .update_order(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &header_params,
+            &path_params,
+            &body,
+        )
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::store::UpdateOrderResponse::Status200_SuccessfulOperation { body, etag } => {
+                if let Some(etag) = etag {
+                    let etag = match header::IntoHeaderValue(etag).try_into() {
+                        Ok(val) => val,
+                        Err(e) => {
+                            return Response::builder()
+                                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                .body(Body::from(format!(
+                                    "An internal server error occurred handling etag header - {e}"
+                                )))
+                                .map_err(|e| {
+                                    error!(error = ?e);
+                                    StatusCode::INTERNAL_SERVER_ERROR
+                                });
+                        }
+                    };
+
+                    {
+                        let mut response_headers = response.headers_mut().unwrap();
+                        response_headers.insert(HeaderName::from_static("etag"), etag);
+                    }
+                }
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::UpdateOrderResponse::Status400_InvalidParameters(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::UpdateOrderResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+            apis::store::UpdateOrderResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::UpdateOrderResponse::Status404_OrderNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::UpdateOrderResponse::Status412_PreconditionFailed(body) => {
+                let mut response = response.status(412);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::UpdateOrderResponse::Status422_ValidationException(body) => {
+                let mut response = response.status(422);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::store::UpdateOrderResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -1401,36 +6831,51 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct AddGenreBodyValidator<'a> {
+struct AddWebhookBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a models::NewGenre,
+    body: &'a models::NewWebhook,
 }
 
 #[tracing::instrument(skip_all)]
-fn add_genre_validation(
-    body: models::NewGenre,
-) -> std::result::Result<(models::NewGenre,), ValidationErrors> {
-    let b = AddGenreBodyValidator { body: &body };
+fn add_webhook_validation(
+    body: models::NewWebhook,
+) -> std::result::Result<(models::NewWebhook,), ValidationErrors> {
+    let b = AddWebhookBodyValidator { body: &body };
     b.validate()?;
 
     Ok((body,))
 }
-/// AddGenre - POST /api/v1/genres
+/// AddWebhook - POST /api/v1/webhooks
 #[tracing::instrument(skip_all)]
-async fn add_genre<I, A, E>(
+async fn add_webhook<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::NewGenre>,
+    Json(body): Json<models::NewWebhook>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::genre::Genre<E> + Send + Sync,
+    A: apis::webhook::Webhook<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || add_genre_validation(body))
+    let validation = tokio::task::spawn_blocking(move || add_webhook_validation(body))
         .await
         .unwrap();
 
@@ -1443,14 +6888,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .add_genre(&method, &host, &cookies, &body)
+        .add_webhook(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::genre::AddGenreResponse::Status200_SuccessfulOperation(body) => {
+            apis::webhook::AddWebhookResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -1473,17 +6918,101 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::genre::AddGenreResponse::Status400_InvalidInput => {
+            apis::webhook::AddWebhookResponse::Status400_InvalidInput(body) => {
                 let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::webhook::AddWebhookResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::genre::AddGenreResponse::Status422_ValidationException => {
+            apis::webhook::AddWebhookResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::webhook::AddWebhookResponse::Status422_ValidationException(body) => {
                 let mut response = response.status(422);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::genre::AddGenreResponse::Status500_ServerError => {
+            apis::webhook::AddWebhookResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -1503,29 +7032,44 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn delete_genre_validation(
-    path_params: models::DeleteGenrePathParams,
-) -> std::result::Result<(models::DeleteGenrePathParams,), ValidationErrors> {
+fn delete_webhook_validation(
+    path_params: models::DeleteWebhookPathParams,
+) -> std::result::Result<(models::DeleteWebhookPathParams,), ValidationErrors> {
     path_params.validate()?;
 
     Ok((path_params,))
 }
-/// DeleteGenre - DELETE /api/v1/genres/{genreId}
+/// DeleteWebhook - DELETE /api/v1/webhooks/{webhookId}
 #[tracing::instrument(skip_all)]
-async fn delete_genre<I, A, E>(
+async fn delete_webhook<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::DeleteGenrePathParams>,
+    headers: HeaderMap,
+    Path(path_params): Path<models::DeleteWebhookPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::genre::Genre<E> + Send + Sync,
+    A: apis::webhook::Webhook<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || delete_genre_validation(path_params))
+    let validation = tokio::task::spawn_blocking(move || delete_webhook_validation(path_params))
         .await
         .unwrap();
 
@@ -1538,96 +7082,97 @@ where
 
     let result = api_impl
         .as_ref()
-        .delete_genre(&method, &host, &cookies, &path_params)
+        .delete_webhook(&method, &host, &cookies, &claims, &path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::genre::DeleteGenreResponse::Status200_SuccessfulOperation => {
+            apis::webhook::DeleteWebhookResponse::Status200_SuccessfullyDeleted => {
                 let mut response = response.status(200);
                 response.body(Body::empty())
             }
-            apis::genre::DeleteGenreResponse::Status400_InvalidGenreIdValue => {
+            apis::webhook::DeleteWebhookResponse::Status400_InvalidWebhookIdValue(body) => {
                 let mut response = response.status(400);
-                response.body(Body::empty())
-            }
-            apis::genre::DeleteGenreResponse::Status404_GenreNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::genre::DeleteGenreResponse::Status500_ServerError => {
-                let mut response = response.status(500);
+            apis::webhook::DeleteWebhookResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-        },
-        Err(why) => {
-            // Application code returned an error. This should not happen, as the implementation should
-            // return a valid response.
-            return api_impl
-                .as_ref()
-                .handle_error(&method, &host, &cookies, why)
-                .await;
-        }
-    };
-
-    resp.map_err(|e| {
-        error!(error = ?e);
-        StatusCode::INTERNAL_SERVER_ERROR
-    })
-}
-
-#[tracing::instrument(skip_all)]
-fn get_genre_by_id_validation(
-    path_params: models::GetGenreByIdPathParams,
-) -> std::result::Result<(models::GetGenreByIdPathParams,), ValidationErrors> {
-    path_params.validate()?;
-
-    Ok((path_params,))
-}
-/// GetGenreById - GET /api/v1/genres/{genreId}
-#[tracing::instrument(skip_all)]
-async fn get_genre_by_id<I, A, E>(
-    method: Method,
-    host: Host,
-    cookies: CookieJar,
-    Path(path_params): Path<models::GetGenreByIdPathParams>,
-    State(api_impl): State<I>,
-) -> Result<Response, StatusCode>
-where
-    I: AsRef<A> + Send + Sync,
-    A: apis::genre::Genre<E> + Send + Sync,
-    E: std::fmt::Debug + Send + Sync + 'static,
-{
-    #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || get_genre_by_id_validation(path_params))
-        .await
-        .unwrap();
-
-    let Ok((path_params,)) = validation else {
-        return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
-            .map_err(|_| StatusCode::BAD_REQUEST);
-    };
-
-    let result = api_impl
-        .as_ref()
-        .get_genre_by_id(&method, &host, &cookies, &path_params)
-        .await;
+            apis::webhook::DeleteWebhookResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
 
-    let mut response = Response::builder();
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::webhook::DeleteWebhookResponse::Status404_WebhookNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
 
-    let resp = match result {
-        Ok(rsp) => match rsp {
-            apis::genre::GetGenreByIdResponse::Status200_SuccessfulOperation(body) => {
-                let mut response = response.status(200);
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::webhook::DeleteWebhookResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
                         CONTENT_TYPE,
-                        HeaderValue::from_str("application/json").map_err(|e| {
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
                             error!(error = ?e);
                             StatusCode::INTERNAL_SERVER_ERROR
                         })?,
@@ -1644,18 +7189,6 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::genre::GetGenreByIdResponse::Status400_InvalidParameters => {
-                let mut response = response.status(400);
-                response.body(Body::empty())
-            }
-            apis::genre::GetGenreByIdResponse::Status404_GenreNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
-            }
-            apis::genre::GetGenreByIdResponse::Status500_ServerError => {
-                let mut response = response.status(500);
-                response.body(Body::empty())
-            }
         },
         Err(why) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -1674,28 +7207,48 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn get_readiness_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn get_webhook_by_id_validation(
+    path_params: models::GetWebhookByIdPathParams,
+) -> std::result::Result<(models::GetWebhookByIdPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
 }
-/// GetReadiness - GET /api/v1/health/readiness
+/// GetWebhookById - GET /api/v1/webhooks/{webhookId}
 #[tracing::instrument(skip_all)]
-async fn get_readiness<I, A, E>(
+async fn get_webhook_by_id<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::GetWebhookByIdPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::health::Health<E> + Send + Sync,
+    A: apis::webhook::Webhook<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || get_readiness_validation())
+    let validation = tokio::task::spawn_blocking(move || get_webhook_by_id_validation(path_params))
         .await
         .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((path_params,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -1704,14 +7257,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .get_readiness(&method, &host, &cookies)
+        .get_webhook_by_id(&method, &host, &cookies, &claims, &path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::health::GetReadinessResponse::Status200_Successful(body) => {
+            apis::webhook::GetWebhookByIdResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -1734,14 +7287,102 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::health::GetReadinessResponse::Status500_ServerError => {
-                let mut response = response.status(500);
-                response.body(Body::empty())
+            apis::webhook::GetWebhookByIdResponse::Status400_InvalidParameters(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::health::GetReadinessResponse::Status503_ServiceUnavailable => {
-                let mut response = response.status(503);
+            apis::webhook::GetWebhookByIdResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
+            apis::webhook::GetWebhookByIdResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::webhook::GetWebhookByIdResponse::Status404_WebhookNotFound(body) => {
+                let mut response = response.status(404);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::webhook::GetWebhookByIdResponse::Status500_ServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
         },
         Err(why) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -1760,33 +7401,59 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn delete_order_validation(
-    path_params: models::DeleteOrderPathParams,
-) -> std::result::Result<(models::DeleteOrderPathParams,), ValidationErrors> {
+fn get_webhook_deliveries_validation(
+    path_params: models::GetWebhookDeliveriesPathParams,
+    query_params: models::GetWebhookDeliveriesQueryParams,
+) -> std::result::Result<
+    (
+        models::GetWebhookDeliveriesPathParams,
+        models::GetWebhookDeliveriesQueryParams,
+    ),
+    ValidationErrors,
+> {
     path_params.validate()?;
+    query_params.validate()?;
 
-    Ok((path_params,))
+    Ok((path_params, query_params))
 }
-/// DeleteOrder - DELETE /api/v1/store/orders/{orderId}
+/// GetWebhookDeliveries - GET /api/v1/webhooks/{webhookId}/deliveries
 #[tracing::instrument(skip_all)]
-async fn delete_order<I, A, E>(
+async fn get_webhook_deliveries<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::DeleteOrderPathParams>,
+    headers: HeaderMap,
+    Path(path_params): Path<models::GetWebhookDeliveriesPathParams>,
+    Query(query_params): Query<models::GetWebhookDeliveriesQueryParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::store::Store<E> + Send + Sync,
+    A: apis::webhook::Webhook<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || delete_order_validation(path_params))
-        .await
-        .unwrap();
+    let validation = tokio::task::spawn_blocking(move || {
+        get_webhook_deliveries_validation(path_params, query_params)
+    })
+    .await
+    .unwrap();
 
-    let Ok((path_params,)) = validation else {
+    let Ok((path_params, query_params)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -1795,91 +7462,100 @@ where
 
     let result = api_impl
         .as_ref()
-        .delete_order(&method, &host, &cookies, &path_params)
+        .get_webhook_deliveries(
+            &method,
+            &host,
+            &cookies,
+            &claims,
+            &path_params,
+            &query_params,
+        )
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::store::DeleteOrderResponse::Status200_SuccessfulOperation => {
+            apis::webhook::GetWebhookDeliveriesResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::store::DeleteOrderResponse::Status400_InvalidIDSupplied => {
+            apis::webhook::GetWebhookDeliveriesResponse::Status400_InvalidParameters(body) => {
                 let mut response = response.status(400);
-                response.body(Body::empty())
-            }
-            apis::store::DeleteOrderResponse::Status404_OrderNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::store::DeleteOrderResponse::Status500_ServerError => {
-                let mut response = response.status(500);
+            apis::webhook::GetWebhookDeliveriesResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-        },
-        Err(why) => {
-            // Application code returned an error. This should not happen, as the implementation should
-            // return a valid response.
-            return api_impl
-                .as_ref()
-                .handle_error(&method, &host, &cookies, why)
-                .await;
-        }
-    };
-
-    resp.map_err(|e| {
-        error!(error = ?e);
-        StatusCode::INTERNAL_SERVER_ERROR
-    })
-}
-
-#[tracing::instrument(skip_all)]
-fn get_inventory_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
-}
-/// GetInventory - GET /api/v1/store/inventory
-#[tracing::instrument(skip_all)]
-async fn get_inventory<I, A, E>(
-    method: Method,
-    host: Host,
-    cookies: CookieJar,
-    State(api_impl): State<I>,
-) -> Result<Response, StatusCode>
-where
-    I: AsRef<A> + Send + Sync,
-    A: apis::store::Store<E> + Send + Sync,
-    E: std::fmt::Debug + Send + Sync + 'static,
-{
-    #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || get_inventory_validation())
-        .await
-        .unwrap();
-
-    let Ok(()) = validation else {
-        return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
-            .map_err(|_| StatusCode::BAD_REQUEST);
-    };
-
-    let result = api_impl
-        .as_ref()
-        .get_inventory(&method, &host, &cookies)
-        .await;
-
-    let mut response = Response::builder();
+            apis::webhook::GetWebhookDeliveriesResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
 
-    let resp = match result {
-        Ok(rsp) => match rsp {
-            apis::store::GetInventoryResponse::Status200_SuccessfulOperation(body) => {
-                let mut response = response.status(200);
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::webhook::GetWebhookDeliveriesResponse::Status404_WebhookNotFound(body) => {
+                let mut response = response.status(404);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
                         CONTENT_TYPE,
-                        HeaderValue::from_str("application/json").map_err(|e| {
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
                             error!(error = ?e);
                             StatusCode::INTERNAL_SERVER_ERROR
                         })?,
@@ -1896,77 +7572,13 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::store::GetInventoryResponse::Status500_ServerError => {
+            apis::webhook::GetWebhookDeliveriesResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
-            }
-        },
-        Err(why) => {
-            // Application code returned an error. This should not happen, as the implementation should
-            // return a valid response.
-            return api_impl
-                .as_ref()
-                .handle_error(&method, &host, &cookies, why)
-                .await;
-        }
-    };
-
-    resp.map_err(|e| {
-        error!(error = ?e);
-        StatusCode::INTERNAL_SERVER_ERROR
-    })
-}
-
-#[tracing::instrument(skip_all)]
-fn get_order_by_id_validation(
-    path_params: models::GetOrderByIdPathParams,
-) -> std::result::Result<(models::GetOrderByIdPathParams,), ValidationErrors> {
-    path_params.validate()?;
-
-    Ok((path_params,))
-}
-/// GetOrderById - GET /api/v1/store/orders/{orderId}
-#[tracing::instrument(skip_all)]
-async fn get_order_by_id<I, A, E>(
-    method: Method,
-    host: Host,
-    cookies: CookieJar,
-    Path(path_params): Path<models::GetOrderByIdPathParams>,
-    State(api_impl): State<I>,
-) -> Result<Response, StatusCode>
-where
-    I: AsRef<A> + Send + Sync,
-    A: apis::store::Store<E> + Send + Sync,
-    E: std::fmt::Debug + Send + Sync + 'static,
-{
-    #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || get_order_by_id_validation(path_params))
-        .await
-        .unwrap();
-
-    let Ok((path_params,)) = validation else {
-        return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
-            .map_err(|_| StatusCode::BAD_REQUEST);
-    };
-
-    let result = api_impl
-        .as_ref()
-        .get_order_by_id(&method, &host, &cookies, &path_params)
-        .await;
-
-    let mut response = Response::builder();
-
-    let resp = match result {
-        Ok(rsp) => match rsp {
-            apis::store::GetOrderByIdResponse::Status200_SuccessfulOperation(body) => {
-                let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
                         CONTENT_TYPE,
-                        HeaderValue::from_str("application/json").map_err(|e| {
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
                             error!(error = ?e);
                             StatusCode::INTERNAL_SERVER_ERROR
                         })?,
@@ -1983,18 +7595,6 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::store::GetOrderByIdResponse::Status400_InvalidIDSupplied => {
-                let mut response = response.status(400);
-                response.body(Body::empty())
-            }
-            apis::store::GetOrderByIdResponse::Status404_OrderNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
-            }
-            apis::store::GetOrderByIdResponse::Status500_ServerError => {
-                let mut response = response.status(500);
-                response.body(Body::empty())
-            }
         },
         Err(why) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -2012,42 +7612,44 @@ where
     })
 }
 
-#[derive(validator::Validate)]
-#[allow(dead_code)]
-struct PlaceOrderBodyValidator<'a> {
-    #[validate(nested)]
-    body: &'a models::NewOrder,
-}
-
 #[tracing::instrument(skip_all)]
-fn place_order_validation(
-    body: models::NewOrder,
-) -> std::result::Result<(models::NewOrder,), ValidationErrors> {
-    let b = PlaceOrderBodyValidator { body: &body };
-    b.validate()?;
-
-    Ok((body,))
+fn get_webhooks_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
 }
-/// PlaceOrder - POST /api/v1/store/orders
+/// GetWebhooks - GET /api/v1/webhooks
 #[tracing::instrument(skip_all)]
-async fn place_order<I, A, E>(
+async fn get_webhooks<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::NewOrder>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::store::Store<E> + Send + Sync,
+    A: apis::webhook::Webhook<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || place_order_validation(body))
+    let validation = tokio::task::spawn_blocking(move || get_webhooks_validation())
         .await
         .unwrap();
 
-    let Ok((body,)) = validation else {
+    let Ok(()) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -2056,14 +7658,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .place_order(&method, &host, &cookies, &body)
+        .get_webhooks(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::store::PlaceOrderResponse::Status200_SuccessfulOperation(body) => {
+            apis::webhook::GetWebhooksResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -2086,17 +7688,55 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::store::PlaceOrderResponse::Status400_InvalidInput => {
-                let mut response = response.status(400);
+            apis::webhook::GetWebhooksResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::store::PlaceOrderResponse::Status422_ValidationException => {
-                let mut response = response.status(422);
-                response.body(Body::empty())
+            apis::webhook::GetWebhooksResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::store::PlaceOrderResponse::Status500_ServerError => {
+            apis::webhook::GetWebhooksResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
@@ -2117,41 +7757,58 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct UpdateOrderBodyValidator<'a> {
+struct UpdateWebhookBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a models::OrderProperties,
+    body: &'a models::WebhookProperties,
 }
 
 #[tracing::instrument(skip_all)]
-fn update_order_validation(
-    path_params: models::UpdateOrderPathParams,
-    body: models::OrderProperties,
-) -> std::result::Result<(models::UpdateOrderPathParams, models::OrderProperties), ValidationErrors>
-{
+fn update_webhook_validation(
+    path_params: models::UpdateWebhookPathParams,
+    body: models::WebhookProperties,
+) -> std::result::Result<
+    (models::UpdateWebhookPathParams, models::WebhookProperties),
+    ValidationErrors,
+> {
     path_params.validate()?;
-    let b = UpdateOrderBodyValidator { body: &body };
+    let b = UpdateWebhookBodyValidator { body: &body };
     b.validate()?;
 
     Ok((path_params, body))
 }
-/// UpdateOrder - PATCH /api/v1/store/orders/{orderId}
+/// UpdateWebhook - PATCH /api/v1/webhooks/{webhookId}
 #[tracing::instrument(skip_all)]
-async fn update_order<I, A, E>(
+async fn update_webhook<I, A, E, C>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::UpdateOrderPathParams>,
+    headers: HeaderMap,
+    Path(path_params): Path<models::UpdateWebhookPathParams>,
     State(api_impl): State<I>,
-    Json(body): Json<models::OrderProperties>,
+    Json(body): Json<models::WebhookProperties>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::store::Store<E> + Send + Sync,
+    A: apis::webhook::Webhook<E, Claims = C> + apis::ApiKeyAuthHeader<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
+    C: Send + Sync,
 {
+    // Authentication
+    let claims_in_header = api_impl
+        .as_ref()
+        .extract_claims_from_header(&headers, "X-API-KEY")
+        .await;
+    let claims = None.or(claims_in_header);
+    let Some(claims) = claims else {
+        return Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .body(Body::empty())
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR);
+    };
+
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || update_order_validation(path_params, body))
+        tokio::task::spawn_blocking(move || update_webhook_validation(path_params, body))
             .await
             .unwrap();
 
@@ -2164,14 +7821,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .update_order(&method, &host, &cookies, &path_params, &body)
+        .update_webhook(&method, &host, &cookies, &claims, &path_params, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::store::UpdateOrderResponse::Status200_SuccessfulOperation(body) => {
+            apis::webhook::UpdateWebhookResponse::Status200_SuccessfulOperation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -2194,21 +7851,124 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::store::UpdateOrderResponse::Status400_InvalidParameters => {
+            apis::webhook::UpdateWebhookResponse::Status400_InvalidParameters(body) => {
                 let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::webhook::UpdateWebhookResponse::Status401_Unauthorized => {
+                let mut response = response.status(401);
                 response.body(Body::empty())
             }
-            apis::store::UpdateOrderResponse::Status404_OrderNotFound => {
+            apis::webhook::UpdateWebhookResponse::Status403_Forbidden(body) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::webhook::UpdateWebhookResponse::Status404_WebhookNotFound(body) => {
                 let mut response = response.status(404);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::store::UpdateOrderResponse::Status422_ValidationException => {
+            apis::webhook::UpdateWebhookResponse::Status422_ValidationException(body) => {
                 let mut response = response.status(422);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::store::UpdateOrderResponse::Status500_ServerError => {
+            apis::webhook::UpdateWebhookResponse::Status500_ServerError(body) => {
                 let mut response = response.status(500);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/problem+json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
         },
         Err(why) => {
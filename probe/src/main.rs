@@ -1,4 +1,559 @@
 // container health probe
-fn main() {
-    // Exit with success (0)
+//
+// Requests the readiness endpoint of the service and exits with
+//   0 if the service answered with a 2xx status,
+//   1 if the service is unreachable or not ready,
+//   2 if the probe is misconfigured.
+//
+// The scratch image ships no shell or curl, hence the tiny HTTP/1.1 client.
+// An https:// url is requested with TLS, the certificate of the service is verified
+// only if PROBE_CA_FILE is set, as the probe checks the readiness of the local service.
+use std::env;
+use std::fmt;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::WebPkiSupportedAlgorithms;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{ClientConfig, ClientConnection, DigitallySignedStruct, RootCertStore, StreamOwned};
+
+const DEFAULT_URL: &str = "http://127.0.0.1:8443/api/v1/health/readiness";
+const DEFAULT_TIMEOUT_MS: u64 = 3000;
+
+#[derive(Debug, PartialEq)]
+struct Target {
+    tls: bool,
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl Target {
+    /// The host and port for the Host header, IPv6 literals are enclosed in brackets
+    fn authority(&self) -> String {
+        if self.host.contains(':') {
+            format!("[{}]:{}", self.host, self.port)
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+}
+
+/// The files of the TLS connection from the PROBE_* variables
+#[derive(Debug, Default)]
+struct TlsFiles {
+    /// the PEM CA certificates the certificate of the service is verified with
+    ca: Option<PathBuf>,
+    /// the PEM client certificate and key, if the service requires client certificates
+    client_cert: Option<PathBuf>,
+    client_key: Option<PathBuf>,
+}
+
+impl TlsFiles {
+    fn from_env() -> TlsFiles {
+        TlsFiles {
+            ca: env::var_os("PROBE_CA_FILE").map(PathBuf::from),
+            client_cert: env::var_os("PROBE_CLIENT_CERT").map(PathBuf::from),
+            client_key: env::var_os("PROBE_CLIENT_KEY").map(PathBuf::from),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum ProbeError {
+    Config(String),
+    Io(std::io::Error),
+    Response(String),
+}
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProbeError::Config(msg) => write!(f, "invalid probe configuration: {}", msg),
+            ProbeError::Io(e) => write!(f, "request failed: {}", e),
+            ProbeError::Response(msg) => write!(f, "unexpected response: {}", msg),
+        }
+    }
+}
+
+impl From<std::io::Error> for ProbeError {
+    fn from(e: std::io::Error) -> Self {
+        ProbeError::Io(e)
+    }
+}
+
+fn parse_url(url: &str) -> Result<Target, ProbeError> {
+    let (tls, rest, default_port) = if let Some(rest) = url.strip_prefix("http://") {
+        (false, rest, 80)
+    } else if let Some(rest) = url.strip_prefix("https://") {
+        (true, rest, 443)
+    } else {
+        return Err(ProbeError::Config(format!(
+            "only http:// and https:// urls are supported: {}",
+            url
+        )));
+    };
+    let (authority, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let parse_port = |port: &str| {
+        port.parse::<u16>()
+            .map_err(|_| ProbeError::Config(format!("invalid port in url: {}", url)))
+    };
+    // IPv6 literals are enclosed in brackets, e.g. [::1]:8443, the host is the address without them
+    let (host, port) = if let Some(literal) = authority.strip_prefix('[') {
+        let (host, rest) = literal
+            .split_once(']')
+            .ok_or_else(|| ProbeError::Config(format!("invalid IPv6 host in url: {}", url)))?;
+        match rest.strip_prefix(':') {
+            Some(port) => (host, parse_port(port)?),
+            None if rest.is_empty() => (host, default_port),
+            None => return Err(ProbeError::Config(format!("invalid port in url: {}", url))),
+        }
+    } else {
+        match authority.rsplit_once(':') {
+            Some((host, port)) => (host, parse_port(port)?),
+            None => (authority, default_port),
+        }
+    };
+    if host.is_empty() {
+        return Err(ProbeError::Config(format!("missing host in url: {}", url)));
+    }
+    Ok(Target {
+        tls,
+        host: host.to_string(),
+        port,
+        path: path.to_string(),
+    })
+}
+
+fn parse_timeout(value: Option<String>) -> Result<Duration, ProbeError> {
+    match value {
+        None => Ok(Duration::from_millis(DEFAULT_TIMEOUT_MS)),
+        Some(v) => match v.parse::<u64>() {
+            Ok(ms) if ms > 0 => Ok(Duration::from_millis(ms)),
+            _ => Err(ProbeError::Config(format!("invalid timeout: {}", v))),
+        },
+    }
+}
+
+/// Accepts any certificate of the service, the signatures of the handshake are still verified
+#[derive(Debug)]
+struct AcceptAnyCertificate(WebPkiSupportedAlgorithms);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(message, cert, dss, &self.0)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(message, cert, dss, &self.0)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.0.supported_schemes()
+    }
+}
+
+fn tls_config(files: &TlsFiles) -> Result<ClientConfig, ProbeError> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let builder = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| ProbeError::Config(e.to_string()))?;
+    let builder = match &files.ca {
+        Some(ca) => {
+            let mut roots = RootCertStore::empty();
+            for certificate in CertificateDer::pem_file_iter(ca)
+                .map_err(|e| ProbeError::Config(format!("{}: {}", ca.display(), e)))?
+            {
+                let certificate = certificate
+                    .map_err(|e| ProbeError::Config(format!("{}: {}", ca.display(), e)))?;
+                roots
+                    .add(certificate)
+                    .map_err(|e| ProbeError::Config(format!("{}: {}", ca.display(), e)))?;
+            }
+            builder.with_root_certificates(roots)
+        }
+        None => builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(
+                provider.signature_verification_algorithms,
+            ))),
+    };
+    match (&files.client_cert, &files.client_key) {
+        (Some(cert), Some(key)) => {
+            let chain = CertificateDer::pem_file_iter(cert)
+                .and_then(|certificates| certificates.collect::<Result<Vec<_>, _>>())
+                .map_err(|e| ProbeError::Config(format!("{}: {}", cert.display(), e)))?;
+            let key = PrivateKeyDer::from_pem_file(key)
+                .map_err(|e| ProbeError::Config(format!("{}: {}", key.display(), e)))?;
+            builder
+                .with_client_auth_cert(chain, key)
+                .map_err(|e| ProbeError::Config(e.to_string()))
+        }
+        (None, None) => Ok(builder.with_no_client_auth()),
+        _ => Err(ProbeError::Config(String::from(
+            "PROBE_CLIENT_CERT and PROBE_CLIENT_KEY must be set together",
+        ))),
+    }
+}
+
+/// Sends a GET request and returns the status code of the response.
+fn probe(target: &Target, timeout: Duration, files: &TlsFiles) -> Result<u16, ProbeError> {
+    let addr = (target.host.as_str(), target.port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| ProbeError::Config(format!("cannot resolve host {}", target.host)))?;
+    let stream = TcpStream::connect_timeout(&addr, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    if target.tls {
+        let server_name = ServerName::try_from(target.host.clone())
+            .map_err(|e| ProbeError::Config(format!("invalid host {}: {}", target.host, e)))?;
+        let connection = ClientConnection::new(Arc::new(tls_config(files)?), server_name)
+            .map_err(|e| ProbeError::Config(e.to_string()))?;
+        request_status(target, StreamOwned::new(connection, stream))
+    } else {
+        request_status(target, stream)
+    }
+}
+
+fn request_status(target: &Target, mut stream: impl Read + Write) -> Result<u16, ProbeError> {
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: probe\r\nAccept: application/json\r\nConnection: close\r\n\r\n",
+        target.path,
+        target.authority()
+    );
+    stream.write_all(request.as_bytes())?;
+
+    // the status line is all the probe needs
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 512];
+    while !buffer.windows(2).any(|w| w == b"\r\n") {
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+    parse_status_line(&buffer)
+}
+
+fn parse_status_line(response: &[u8]) -> Result<u16, ProbeError> {
+    let text = String::from_utf8_lossy(response);
+    let line = text.lines().next().unwrap_or_default();
+    let mut parts = line.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some(version), Some(code)) if version.starts_with("HTTP/") => code
+            .parse::<u16>()
+            .map_err(|_| ProbeError::Response(format!("invalid status line: {}", line))),
+        _ => Err(ProbeError::Response(format!(
+            "invalid status line: {}",
+            line
+        ))),
+    }
+}
+
+fn run(url: &str, timeout: Option<String>, files: &TlsFiles) -> Result<u16, ProbeError> {
+    let target = parse_url(url)?;
+    let timeout = parse_timeout(timeout)?;
+    probe(&target, timeout, files)
+}
+
+fn main() -> ExitCode {
+    let url = env::args()
+        .nth(1)
+        .or_else(|| env::var("PROBE_URL").ok())
+        .unwrap_or_else(|| String::from(DEFAULT_URL));
+    match run(
+        &url,
+        env::var("PROBE_TIMEOUT_MS").ok(),
+        &TlsFiles::from_env(),
+    ) {
+        Ok(status) if (200..300).contains(&status) => ExitCode::SUCCESS,
+        Ok(status) => {
+            eprintln!("service is not ready, status {}", status);
+            ExitCode::from(1)
+        }
+        Err(e @ ProbeError::Config(_)) => {
+            eprintln!("{}", e);
+            ExitCode::from(2)
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::from(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn serve_once(response: &'static str) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request).unwrap();
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        port
+    }
+
+    /// Answers one request with TLS, returns the port and the PEM certificate of localhost
+    fn serve_once_tls(response: &'static str) -> (u16, String) {
+        let generated =
+            rcgen::generate_simple_self_signed(vec![String::from("localhost")]).unwrap();
+        let config = rustls::ServerConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(
+            vec![generated.cert.der().clone()],
+            PrivateKeyDer::try_from(generated.signing_key.serialize_der()).unwrap(),
+        )
+        .unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let connection = rustls::ServerConnection::new(Arc::new(config)).unwrap();
+            let mut stream = StreamOwned::new(connection, stream);
+            let mut request = [0u8; 1024];
+            // the handshake fails if the probe rejects the certificate
+            if stream.read(&mut request).is_ok() {
+                stream.write_all(response.as_bytes()).unwrap();
+                stream.conn.send_close_notify();
+                let _ = stream.flush();
+            }
+        });
+        (port, generated.cert.pem())
+    }
+
+    /// Writes the PEM certificate to a file of its own
+    fn ca_file(pem: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!(
+            "probe-ca-{}-{:?}.crt",
+            std::process::id(),
+            thread::current().id()
+        ));
+        std::fs::write(&path, pem).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_parse_url() {
+        // Act
+        let target = parse_url("http://localhost:8443/api/v1/health/readiness").unwrap();
+        let without_port = parse_url("http://localhost").unwrap();
+        let https = parse_url("https://127.0.0.1/ready").unwrap();
+        let ipv6 = parse_url("https://[::1]:8443/ready").unwrap();
+        let ipv6_without_port = parse_url("http://[::1]/ready").unwrap();
+
+        // Assert
+        assert_eq!(
+            target,
+            Target {
+                tls: false,
+                host: String::from("localhost"),
+                port: 8443,
+                path: String::from("/api/v1/health/readiness"),
+            }
+        );
+        assert_eq!(without_port.port, 80);
+        assert_eq!(without_port.path, "/");
+        assert!(https.tls);
+        assert_eq!(https.port, 443);
+        assert_eq!(ipv6.host, "::1");
+        assert_eq!(ipv6.port, 8443);
+        assert_eq!(ipv6.authority(), "[::1]:8443");
+        assert!((ipv6.host.as_str(), ipv6.port).to_socket_addrs().is_ok());
+        assert_eq!(ipv6_without_port.host, "::1");
+        assert_eq!(ipv6_without_port.port, 80);
+        assert_eq!(target.authority(), "localhost:8443");
+    }
+
+    #[test]
+    fn test_parse_url_invalid() {
+        // Act & Assert
+        assert!(matches!(
+            parse_url("ftp://localhost/"),
+            Err(ProbeError::Config(_))
+        ));
+        assert!(matches!(
+            parse_url("http://localhost:port/"),
+            Err(ProbeError::Config(_))
+        ));
+        assert!(matches!(
+            parse_url("http://:80/"),
+            Err(ProbeError::Config(_))
+        ));
+        assert!(matches!(
+            parse_url("http://[::1/"),
+            Err(ProbeError::Config(_))
+        ));
+        assert!(matches!(
+            parse_url("http://[::1]8443/"),
+            Err(ProbeError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        // Act & Assert
+        assert_eq!(
+            parse_timeout(None).unwrap(),
+            Duration::from_millis(DEFAULT_TIMEOUT_MS)
+        );
+        assert_eq!(
+            parse_timeout(Some(String::from("250"))).unwrap(),
+            Duration::from_millis(250)
+        );
+        assert!(parse_timeout(Some(String::from("0"))).is_err());
+        assert!(parse_timeout(Some(String::from("soon"))).is_err());
+    }
+
+    #[test]
+    fn test_probe_ready() {
+        // Arrange
+        let port = serve_once("HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n");
+
+        // Act
+        let status = run(
+            &format!("http://127.0.0.1:{}/ready", port),
+            None,
+            &TlsFiles::default(),
+        );
+
+        // Assert
+        assert_eq!(status.unwrap(), 200);
+    }
+
+    #[test]
+    fn test_probe_not_ready() {
+        // Arrange
+        let port = serve_once("HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\n\r\n");
+
+        // Act
+        let status = run(
+            &format!("http://127.0.0.1:{}/ready", port),
+            None,
+            &TlsFiles::default(),
+        );
+
+        // Assert
+        assert_eq!(status.unwrap(), 503);
+    }
+
+    #[test]
+    fn test_probe_garbage_response() {
+        // Arrange
+        let port = serve_once("hello\r\n");
+
+        // Act
+        let status = run(
+            &format!("http://127.0.0.1:{}/ready", port),
+            None,
+            &TlsFiles::default(),
+        );
+
+        // Assert
+        assert!(matches!(status, Err(ProbeError::Response(_))));
+    }
+
+    #[test]
+    fn test_probe_unreachable() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        // Act
+        let status = run(
+            &format!("http://127.0.0.1:{}/ready", port),
+            Some(String::from("500")),
+            &TlsFiles::default(),
+        );
+
+        // Assert
+        assert!(matches!(status, Err(ProbeError::Io(_))));
+    }
+
+    #[test]
+    fn test_probe_https_ready() {
+        // Arrange
+        let (port, _) = serve_once_tls("HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n");
+
+        // Act
+        let status = run(
+            &format!("https://127.0.0.1:{}/ready", port),
+            None,
+            &TlsFiles::default(),
+        );
+
+        // Assert
+        assert_eq!(status.unwrap(), 200);
+    }
+
+    #[test]
+    fn test_probe_https_verifies_with_ca_file() {
+        // Arrange
+        let (trusted_port, pem) = serve_once_tls("HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n");
+        let (untrusted_port, _) = serve_once_tls("HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n");
+        let files = TlsFiles {
+            ca: Some(ca_file(&pem)),
+            ..TlsFiles::default()
+        };
+
+        // Act
+        let trusted = run(
+            &format!("https://localhost:{}/ready", trusted_port),
+            None,
+            &files,
+        );
+        let untrusted = run(
+            &format!("https://localhost:{}/ready", untrusted_port),
+            None,
+            &files,
+        );
+
+        // Assert
+        assert_eq!(trusted.unwrap(), 200);
+        assert!(matches!(untrusted, Err(ProbeError::Io(_))));
+        std::fs::remove_file(files.ca.unwrap()).unwrap();
+    }
 }